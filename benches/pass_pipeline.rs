@@ -0,0 +1,67 @@
+//! Measures the cost of instantiating a [PassPipeline] into a pass manager, compared to the cost of
+//! creating a context and running the instantiated pipeline.
+//!
+//! Run with `cargo bench --bench pass_pipeline`.
+
+use mlir_rs::{
+    ir::Module,
+    pass::{register_all_passes, Pass, PassPipeline},
+    Context, DialectRegistry,
+};
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+const ITERATIONS: u32 = 1000;
+
+/// Runs the provided closure `ITERATIONS` times, returning the average duration of a run.
+fn measure<F: FnMut()>(mut run: F) -> Duration {
+    run();
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        run();
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn main() {
+    register_all_passes();
+    let dialect_registry = DialectRegistry::default();
+    dialect_registry.register_all_dialects();
+    let context = Context::new(Some(&dialect_registry), false);
+    context.load_all_available_dialects();
+
+    let pipeline = PassPipeline::new()
+        .add_pipeline("canonicalize,cse")
+        .add_pass(Pass::scf_to_cf)
+        .add_pass(Pass::arith_to_llvm)
+        .add_pass(Pass::cf_to_llvm)
+        .add_pass(Pass::func_to_llvm)
+        .add_pass(Pass::reconcile_unrealized_casts);
+
+    let clone = measure(|| {
+        black_box(pipeline.clone());
+    });
+    let instantiate = measure(|| {
+        black_box(pipeline.instantiate(&context).unwrap());
+    });
+    let instantiate_textual = measure(|| {
+        let pipeline = PassPipeline::new().add_pipeline(
+            "canonicalize,cse,convert-scf-to-cf,convert-arith-to-llvm,convert-cf-to-llvm,\
+             convert-func-to-llvm,reconcile-unrealized-casts",
+        );
+        black_box(pipeline.instantiate(&context).unwrap());
+    });
+    let run = measure(|| {
+        let module = Module::parse(&context, "func.func private @f()").unwrap();
+        let pass_manager = pipeline.instantiate(&context).unwrap();
+        pass_manager.run(module.as_operation()).unwrap();
+    });
+
+    println!("clone pipeline:                  {:?}", clone);
+    println!("instantiate pipeline:            {:?}", instantiate);
+    println!("instantiate textual pipeline:    {:?}", instantiate_textual);
+    println!("instantiate and run on a module: {:?}", run);
+}
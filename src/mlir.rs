@@ -1,8 +1,22 @@
 mod context;
+mod diagnostic;
 mod dialect;
 mod dialect_handle;
 mod dialect_registry;
+pub mod dialects;
+mod execution_engine;
 pub mod ir;
+#[cfg(feature = "llvm-ir")]
+mod llvm_ir;
+mod llvm_thread_pool;
+pub mod pass;
+pub mod rewrite;
 mod string_ref;
+mod type_id;
 
-pub use self::{context::*, dialect::*, dialect_handle::*, dialect_registry::*, string_ref::*};
+#[cfg(feature = "llvm-ir")]
+pub use self::llvm_ir::*;
+pub use self::{
+    context::*, diagnostic::*, dialect::*, dialect_handle::*, dialect_registry::*,
+    execution_engine::*, llvm_thread_pool::*, string_ref::*, type_id::*,
+};
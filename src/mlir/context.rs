@@ -1,17 +1,24 @@
+mod builder;
+mod extension;
+
+pub use self::builder::ContextBuilder;
 use crate::{
+    ir::clear_location_scopes,
     support::binding::{impl_owned_mlir_value, impl_unowned_mlir_value, UnownedMlirValue},
-    DialectRef, DialectRegistryRef, StringRef,
+    Diagnostic, DiagnosticCapture, DiagnosticHandlerId, DialectRef, DialectRegistryRef,
+    LlvmThreadPoolRef, StringRef,
 };
 
-use std::marker::PhantomData;
+use std::{collections::BTreeMap, marker::PhantomData, sync::Mutex};
 
 use mlir_sys::{
-    mlirContextAppendDialectRegistry, mlirContextCreateWithRegistry,
-    mlirContextCreateWithThreading, mlirContextEnableMultithreading, mlirContextEqual,
-    mlirContextGetAllowUnregisteredDialects, mlirContextGetNumLoadedDialects,
+    mlirContextAppendDialectRegistry, mlirContextCreate, mlirContextCreateWithRegistry,
+    mlirContextCreateWithThreading, mlirContextDestroy, mlirContextEnableMultithreading,
+    mlirContextEqual, mlirContextGetAllowUnregisteredDialects, mlirContextGetNumLoadedDialects,
     mlirContextGetNumRegisteredDialects, mlirContextGetOrLoadDialect,
     mlirContextIsRegisteredOperation, mlirContextLoadAllAvailableDialects,
-    mlirContextSetAllowUnregisteredDialects, MlirContext,
+    mlirContextSetAllowUnregisteredDialects, mlirContextSetThreadPool,
+    mlirRegisterAllLLVMTranslations, MlirContext,
 };
 
 /// [Context] wraps the `mlir::MLIRContext` class, the top-level object for a collection of MLIR
@@ -19,9 +26,12 @@ use mlir_sys::{
 ///
 /// The following bindings into the MLIR C API are used/supported:
 /// - `mlirContextAppendDialectRegistry`
+/// - `mlirContextAttachDiagnosticHandler`
+/// - `mlirContextCreate`
 /// - `mlirContextCreateWithRegistry`
 /// - `mlirContextCreateWithThreading`
 /// - `mlirContextDestroy`
+/// - `mlirContextDetachDiagnosticHandler`
 /// - `mlirContextEnableMultithreading`
 /// - `mlirContextEqual`
 /// - `mlirContextGetAllowUnregisteredDialects`
@@ -31,12 +41,8 @@ use mlir_sys::{
 /// - `mlirContextIsRegisteredOperation`
 /// - `mlirContextLoadAllAvailableDialects`
 /// - `mlirContextSetAllowUnregisteredDialects`
-///
-/// The following bindings are not used/supported:
-/// - `mlirContextAttachDiagnosticHandler`
-/// - `mlirContextCreate`
-/// - `mlirContextDetachDiagnosticHandler`
 /// - `mlirContextSetThreadPool`
+/// - `mlirRegisterAllLLVMTranslations`
 #[repr(transparent)]
 #[derive(Debug)]
 pub struct Context {
@@ -46,6 +52,15 @@ pub struct Context {
 impl_owned_mlir_value!(no_refs, Context, MlirContext);
 
 impl Context {
+    /// Creates a builder for configuring a new context, which is preferred over the positional
+    /// arguments of [Context::new].
+    ///
+    /// # Returns
+    /// Returns a new [ContextBuilder] instance.
+    pub fn builder<'a>() -> ContextBuilder<'a> {
+        ContextBuilder::new()
+    }
+
     /// Creates a new MLIR Context.
     ///
     /// # Arguments
@@ -56,13 +71,58 @@ impl Context {
     /// # Returns
     /// Returns a new [Context] instance.
     pub fn new(dialect_registry: Option<&DialectRegistryRef>, threading_enabled: bool) -> Self {
-        let raw_context = match dialect_registry {
-            Some(dialect_registry) => unsafe {
+        match dialect_registry {
+            Some(dialect_registry) => Self::new_with_registry(dialect_registry, threading_enabled),
+            None => Self::new_with_threading(threading_enabled),
+        }
+    }
+
+    /// Creates a new MLIR Context with the default configuration of MLIR, which enables
+    /// multithreading unless it was disabled through the `--mlir-disable-threading` command line
+    /// option.
+    ///
+    /// # Returns
+    /// Returns a new [Context] instance.
+    pub fn new_default() -> Self {
+        Self {
+            raw: unsafe { mlirContextCreate() },
+        }
+    }
+
+    /// Creates a new MLIR Context with multithreading explicitly enabled or disabled.
+    ///
+    /// # Arguments
+    /// * `threading_enabled` - Whether to enable multithreading.
+    ///
+    /// # Returns
+    /// Returns a new [Context] instance.
+    pub fn new_with_threading(threading_enabled: bool) -> Self {
+        let context = Self {
+            raw: unsafe { mlirContextCreateWithThreading(threading_enabled) },
+        };
+        context.record_threading_enabled(threading_enabled);
+        context
+    }
+
+    /// Creates a new MLIR Context, pre-registering the dialects of the provided dialect registry.
+    ///
+    /// # Arguments
+    /// * `dialect_registry` - The dialect registry from which to pre-register dialects.
+    /// * `threading_enabled` - Whether to enable multithreading.
+    ///
+    /// # Returns
+    /// Returns a new [Context] instance.
+    pub fn new_with_registry(
+        dialect_registry: &DialectRegistryRef,
+        threading_enabled: bool,
+    ) -> Self {
+        let context = Self {
+            raw: unsafe {
                 mlirContextCreateWithRegistry(dialect_registry.to_raw(), threading_enabled)
             },
-            None => unsafe { mlirContextCreateWithThreading(threading_enabled) },
         };
-        Self { raw: raw_context }
+        context.record_threading_enabled(threading_enabled);
+        context
     }
 
     /// # Returns
@@ -72,6 +132,24 @@ impl Context {
     }
 }
 
+impl Drop for Context {
+    fn drop(&mut self) {
+        extension::drop_extensions(self);
+        clear_location_scopes(self.raw);
+        THREADING_ENABLED
+            .lock()
+            .unwrap()
+            .remove(&(self.raw.ptr as usize));
+        unsafe { mlirContextDestroy(self.raw) }
+    }
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        Self::new_default()
+    }
+}
+
 impl PartialEq for Context {
     fn eq(&self, other: &Self) -> bool {
         unsafe { mlirContextEqual(self.raw, other.raw) }
@@ -161,6 +239,62 @@ impl ContextRef {
     /// * `threading_enabled` - Whether to enable multithreading.
     pub fn set_threading_enabled(&self, threading_enabled: bool) {
         unsafe { mlirContextEnableMultithreading(self.to_raw(), threading_enabled) }
+        self.record_threading_enabled(threading_enabled);
+    }
+
+    /// The MLIR C API does not expose whether multithreading is enabled, so the threading mode is
+    /// tracked for each context whenever it is set through these bindings. Contexts created
+    /// through [Context::new_default] are assumed to have multithreading enabled.
+    ///
+    /// # Returns
+    /// Returns whether multithreading is enabled in the context.
+    pub fn is_threading_enabled(&self) -> bool {
+        let threading_enabled = THREADING_ENABLED.lock().unwrap();
+        threading_enabled
+            .get(&(self.to_raw().ptr as usize))
+            .copied()
+            .unwrap_or(true)
+    }
+
+    /// Records the threading mode of the context, as returned by
+    /// [ContextRef::is_threading_enabled].
+    ///
+    /// # Arguments
+    /// * `threading_enabled` - Whether multithreading is enabled.
+    fn record_threading_enabled(&self, threading_enabled: bool) {
+        let mut threading_enabled_by_context = THREADING_ENABLED.lock().unwrap();
+        threading_enabled_by_context.insert(self.to_raw().ptr as usize, threading_enabled);
+    }
+
+    /// Disables multithreading until the returned guard is dropped, e.g. while mutating IR or
+    /// registering types when other threads may be running passes.
+    ///
+    /// # Returns
+    /// Returns a [MultithreadingPause] which restores the previous threading mode when dropped.
+    pub fn pause_multithreading(&self) -> MultithreadingPause {
+        let threading_was_enabled = self.is_threading_enabled();
+        self.set_threading_enabled(false);
+        MultithreadingPause {
+            context: self,
+            threading_was_enabled,
+        }
+    }
+
+    /// Enables multithreading using the provided thread pool instead of a thread pool owned by the
+    /// context, so that several contexts can share the same worker threads.
+    ///
+    /// # Safety
+    /// The caller of this function is responsible for ensuring that the thread pool outlives the
+    /// context, or at least remains alive until multithreading is disabled again.
+    ///
+    /// # Arguments
+    /// * `thread_pool` - The thread pool to use.
+    pub unsafe fn set_thread_pool(&self, thread_pool: &LlvmThreadPoolRef) {
+        // MLIR only accepts an external thread pool while multithreading is disabled.
+        self.set_threading_enabled(false);
+        mlirContextSetThreadPool(self.to_raw(), thread_pool.to_raw());
+        // Setting the thread pool enables multithreading again.
+        self.record_threading_enabled(true);
     }
 
     /// Eagerly loads all dialects registered with the context, making them available for use in IR
@@ -169,6 +303,13 @@ impl ContextRef {
         unsafe { mlirContextLoadAllAvailableDialects(self.to_raw()) }
     }
 
+    /// Registers the translations to LLVM IR of all dialects which have one (including the builtin
+    /// and LLVM dialects) with the context, without which modules cannot be translated to LLVM IR.
+    /// The MLIR C API provides no way to register the translations of individual dialects.
+    pub fn register_all_llvm_translations(&self) {
+        unsafe { mlirRegisterAllLLVMTranslations(self.to_raw()) }
+    }
+
     /// Checks if the operation with the provided fully-qualified name (e.g. 'dialect.operation') is
     /// registered with, and its dialect is loaded in, the context.
     ///
@@ -181,8 +322,66 @@ impl ContextRef {
         let operation_name = operation_name.into().to_raw();
         unsafe { mlirContextIsRegisteredOperation(self.to_raw(), operation_name) }
     }
+
+    /// Starts capturing the diagnostics emitted in the context, instead of printing them to
+    /// stderr.
+    ///
+    /// # Returns
+    /// Returns a [DiagnosticCapture] which collects diagnostics until it is dropped.
+    pub fn capture_diagnostics(&self) -> DiagnosticCapture {
+        DiagnosticCapture::new(self)
+    }
+
+    /// Attaches a diagnostic handler to the context, which is invoked with every diagnostic
+    /// emitted in the context before any previously attached handler. The handler is dropped once
+    /// it is detached or the context is destroyed.
+    ///
+    /// # Arguments
+    /// * `handler` - The handler to invoke with each diagnostic, which returns whether it handled
+    ///   the diagnostic. Unhandled diagnostics are passed on to the previously attached handler
+    ///   (or the default handler, which prints them to stderr). A diagnostic is left unhandled if
+    ///   the handler panics.
+    ///
+    /// # Returns
+    /// Returns the [DiagnosticHandlerId] identifying the handler, which can be used to detach it.
+    pub fn attach_diagnostic_handler<F>(&self, handler: F) -> DiagnosticHandlerId
+    where
+        F: FnMut(Diagnostic) -> bool + Send + 'static,
+    {
+        DiagnosticHandlerId::attach(self, handler)
+    }
+
+    /// Detaches the diagnostic handler with the provided identifier from the context, dropping it.
+    ///
+    /// # Arguments
+    /// * `handler_id` - The identifier of the handler to detach.
+    pub fn detach_diagnostic_handler(&self, handler_id: DiagnosticHandlerId) {
+        handler_id.detach(self)
+    }
+}
+
+/// [MultithreadingPause] disables multithreading in a context for as long as it is alive, as
+/// returned by [ContextRef::pause_multithreading].
+///
+/// The threading mode from before the pause is restored when the [MultithreadingPause] is dropped,
+/// so pauses can be nested and pausing a context without multithreading leaves it disabled.
+#[derive(Debug)]
+pub struct MultithreadingPause<'c> {
+    context: &'c ContextRef,
+    threading_was_enabled: bool,
+}
+
+impl Drop for MultithreadingPause<'_> {
+    fn drop(&mut self) {
+        self.context
+            .set_threading_enabled(self.threading_was_enabled)
+    }
 }
 
+/// The threading mode of each context it was set for through these bindings, keyed by the address
+/// of the context. The entry of a context is removed when the context is destroyed.
+static THREADING_ENABLED: Mutex<BTreeMap<usize, bool>> = Mutex::new(BTreeMap::new());
+
 impl PartialEq for ContextRef {
     fn eq(&self, other: &Self) -> bool {
         unsafe { mlirContextEqual(self.to_raw(), other.to_raw()) }
@@ -200,7 +399,7 @@ impl Eq for ContextRef {}
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::DialectRegistry;
+    use crate::{DialectRegistry, LlvmThreadPool};
 
     use std::ops::Deref;
 
@@ -211,6 +410,20 @@ mod tests {
         assert_eq!(context.num_loaded_dialects(), 1);
     }
 
+    #[test]
+    fn new_default() {
+        let context = Context::default();
+        assert_eq!(context.num_registered_dialects(), 1);
+        assert_eq!(context.num_loaded_dialects(), 1);
+        assert_ne!(context, Context::new_default());
+    }
+
+    #[test]
+    fn new_with_threading() {
+        let context = Context::new_with_threading(false);
+        assert_eq!(context.num_registered_dialects(), 1);
+    }
+
     #[test]
     fn new_with_registry() {
         let dialect_registry = DialectRegistry::default();
@@ -218,6 +431,8 @@ mod tests {
         let context = Context::new(Some(&dialect_registry), false);
         assert_eq!(context.num_registered_dialects(), 42);
         assert_eq!(context.num_loaded_dialects(), 1);
+        let context = Context::new_with_registry(&dialect_registry, true);
+        assert_eq!(context.num_registered_dialects(), 42);
     }
 
     #[test]
@@ -247,6 +462,14 @@ mod tests {
         assert_eq!(context.num_loaded_dialects(), 42);
     }
 
+    #[test]
+    fn register_all_llvm_translations() {
+        let context = Context::new(None, false);
+        context.register_all_llvm_translations();
+        // Registering the translations again is a no-op.
+        context.register_all_llvm_translations();
+    }
+
     #[test]
     fn get_dialect() {
         let context = Context::new(None, false);
@@ -280,6 +503,53 @@ mod tests {
         assert!(context.is_operation_registered(&"func.func"));
     }
 
+    #[test]
+    fn pause_multithreading() {
+        let thread_pool = LlvmThreadPool::default();
+        let context = Context::new_with_threading(true);
+        {
+            let _pause = context.pause_multithreading();
+            // An external thread pool can only be set while multithreading is disabled, which
+            // MLIR asserts.
+            unsafe { mlirContextSetThreadPool(context.to_raw(), thread_pool.to_raw()) };
+        }
+        assert!(context.is_threading_enabled());
+        context.load_all_available_dialects();
+        context.set_threading_enabled(false);
+    }
+
+    #[test]
+    fn pause_multithreading_restores_threading_mode() {
+        let context = Context::new_with_threading(false);
+        assert!(!context.is_threading_enabled());
+        {
+            let _pause = context.pause_multithreading();
+            assert!(!context.is_threading_enabled());
+        }
+        assert!(!context.is_threading_enabled());
+
+        context.set_threading_enabled(true);
+        {
+            let _outer = context.pause_multithreading();
+            {
+                let _inner = context.pause_multithreading();
+                assert!(!context.is_threading_enabled());
+            }
+            assert!(!context.is_threading_enabled());
+        }
+        assert!(context.is_threading_enabled());
+        context.set_threading_enabled(false);
+    }
+
+    #[test]
+    fn threading_mode_cleared_on_drop() {
+        let context = Context::new_with_threading(false);
+        let key = context.to_raw().ptr as usize;
+        assert!(THREADING_ENABLED.lock().unwrap().contains_key(&key));
+        drop(context);
+        assert!(!THREADING_ENABLED.lock().unwrap().contains_key(&key));
+    }
+
     #[test]
     fn compare_contexts() {
         let context1 = Context::new(None, false);
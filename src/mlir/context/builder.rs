@@ -0,0 +1,162 @@
+use crate::{Context, Diagnostic, DialectRegistryRef};
+
+use std::fmt::{Debug, Formatter};
+
+/// The type of the diagnostic handler attached by [ContextBuilder::diagnostic_handler].
+type BoxedDiagnosticHandler = Box<dyn FnMut(Diagnostic) -> bool + Send>;
+
+/// [ContextBuilder] configures and creates a new [Context] in a single fluent chain, as returned by
+/// [Context::builder].
+///
+/// By default, the context has no dialect registry, has multithreading enabled, does not allow
+/// unregistered dialects, does not eagerly load any dialects, and has no diagnostic handler.
+pub struct ContextBuilder<'a> {
+    dialect_registry: Option<&'a DialectRegistryRef>,
+    threading_enabled: bool,
+    allow_unregistered_dialects: bool,
+    load_all_available_dialects: bool,
+    diagnostic_handler: Option<BoxedDiagnosticHandler>,
+}
+
+impl<'a> ContextBuilder<'a> {
+    /// # Returns
+    /// Returns a new [ContextBuilder] instance with the default configuration.
+    pub fn new() -> Self {
+        ContextBuilder {
+            dialect_registry: None,
+            threading_enabled: true,
+            allow_unregistered_dialects: false,
+            load_all_available_dialects: false,
+            diagnostic_handler: None,
+        }
+    }
+
+    /// Sets the dialect registry from which to pre-register dialects.
+    ///
+    /// # Arguments
+    /// * `dialect_registry` - The dialect registry to use.
+    pub fn dialect_registry(mut self, dialect_registry: &'a DialectRegistryRef) -> Self {
+        self.dialect_registry = Some(dialect_registry);
+        self
+    }
+
+    /// Sets whether to enable multithreading.
+    ///
+    /// # Arguments
+    /// * `threading_enabled` - Whether to enable multithreading.
+    pub fn threading(mut self, threading_enabled: bool) -> Self {
+        self.threading_enabled = threading_enabled;
+        self
+    }
+
+    /// Sets whether the context allows unregistered dialects.
+    ///
+    /// # Arguments
+    /// * `allow` - Whether to allow unregistered dialects.
+    pub fn allow_unregistered_dialects(mut self, allow: bool) -> Self {
+        self.allow_unregistered_dialects = allow;
+        self
+    }
+
+    /// Sets whether to eagerly load all dialects registered with the context once it is created.
+    ///
+    /// # Arguments
+    /// * `load` - Whether to eagerly load all available dialects.
+    pub fn load_all_available_dialects(mut self, load: bool) -> Self {
+        self.load_all_available_dialects = load;
+        self
+    }
+
+    /// Sets the diagnostic handler to attach to the context once it is created (see
+    /// [ContextRef::attach_diagnostic_handler](crate::ContextRef::attach_diagnostic_handler)).
+    ///
+    /// # Arguments
+    /// * `handler` - The handler to invoke with each diagnostic, which returns whether it handled
+    ///   the diagnostic.
+    pub fn diagnostic_handler<F>(mut self, handler: F) -> Self
+    where
+        F: FnMut(Diagnostic) -> bool + Send + 'static,
+    {
+        self.diagnostic_handler = Some(Box::new(handler));
+        self
+    }
+
+    /// Creates the context with the configuration of the builder.
+    ///
+    /// # Returns
+    /// Returns a new [Context] instance.
+    pub fn build(self) -> Context {
+        let context = Context::new(self.dialect_registry, self.threading_enabled);
+        if let Some(handler) = self.diagnostic_handler {
+            context.attach_diagnostic_handler(handler);
+        }
+        context.set_allow_unregistered_dialects(self.allow_unregistered_dialects);
+        if self.load_all_available_dialects {
+            context.load_all_available_dialects();
+        }
+        context
+    }
+}
+
+impl Default for ContextBuilder<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for ContextBuilder<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ContextBuilder")
+            .field("dialect_registry", &self.dialect_registry)
+            .field("threading_enabled", &self.threading_enabled)
+            .field(
+                "allow_unregistered_dialects",
+                &self.allow_unregistered_dialects,
+            )
+            .field(
+                "load_all_available_dialects",
+                &self.load_all_available_dialects,
+            )
+            .field(
+                "diagnostic_handler",
+                &self.diagnostic_handler.as_ref().map(|_| ".."),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ir::AttributeRef, Context, DialectRegistry};
+
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn default() {
+        let context = Context::builder().build();
+        assert_eq!(context.num_registered_dialects(), 1);
+        assert!(!context.allows_unregistered_dialects());
+    }
+
+    #[test]
+    fn configured() {
+        let dialect_registry = DialectRegistry::default();
+        dialect_registry.register_all_dialects();
+        let diagnostics = Arc::new(Mutex::new(Vec::new()));
+        let handler_diagnostics = diagnostics.clone();
+        let context = Context::builder()
+            .dialect_registry(&dialect_registry)
+            .threading(false)
+            .allow_unregistered_dialects(true)
+            .load_all_available_dialects(true)
+            .diagnostic_handler(move |diagnostic| {
+                handler_diagnostics.lock().unwrap().push(diagnostic);
+                true
+            })
+            .build();
+        assert_eq!(context.num_loaded_dialects(), 42);
+        assert!(context.allows_unregistered_dialects());
+        assert!(AttributeRef::parse(&context, "z").is_none());
+        assert_eq!(diagnostics.lock().unwrap().len(), 1);
+    }
+}
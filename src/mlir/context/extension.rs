@@ -0,0 +1,143 @@
+use crate::{Context, ContextRef, UnownedMlirValue};
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::{Mutex, OnceLock},
+};
+
+/// The extensions attached to a single context, keyed by their type.
+type ContextExtensions = HashMap<TypeId, Box<dyn Any + Send + Sync>>;
+
+/// The extensions attached to every live context, keyed by the address of the context. The boxed
+/// extensions are never moved or dropped before their context is destroyed, so references into
+/// them remain valid for as long as the context is borrowed.
+fn extensions() -> &'static Mutex<HashMap<usize, ContextExtensions>> {
+    static EXTENSIONS: OnceLock<Mutex<HashMap<usize, ContextExtensions>>> = OnceLock::new();
+    EXTENSIONS.get_or_init(Default::default)
+}
+
+/// Extensions allow arbitrary Rust values (e.g. analyses, interners, or symbol indexes) to be
+/// attached to a context, keyed by their type, so that they travel with the context instead of
+/// being kept in ad-hoc globals. At most one extension of each type can be attached to a context,
+/// and extensions are dropped when the owning [Context] is dropped. Extensions that need to be
+/// mutated must use interior mutability.
+impl ContextRef {
+    /// Attaches the provided value to the context as its extension of the type `T`, unless the
+    /// context already has one.
+    ///
+    /// # Arguments
+    /// * `value` - The extension to attach.
+    ///
+    /// # Returns
+    /// Returns a reference to the attached extension, or the provided value if the context already
+    /// has an extension of the type `T`.
+    pub fn insert_extension<T: Any + Send + Sync>(&self, value: T) -> Result<&T, T> {
+        let mut extensions = extensions().lock().unwrap();
+        let context_extensions = extensions.entry(self.to_raw().ptr as usize).or_default();
+        if context_extensions.contains_key(&TypeId::of::<T>()) {
+            return Err(value);
+        }
+        let extension = Box::new(value);
+        let extension_ptr = extension.as_ref() as *const T;
+        context_extensions.insert(TypeId::of::<T>(), extension);
+        Ok(unsafe { &*extension_ptr })
+    }
+
+    /// # Returns
+    /// Returns a reference to the extension of the type `T` attached to the context, if there is
+    /// one.
+    pub fn get_extension<T: Any + Send + Sync>(&self) -> Option<&T> {
+        let extensions = extensions().lock().unwrap();
+        let extension = extensions
+            .get(&(self.to_raw().ptr as usize))?
+            .get(&TypeId::of::<T>())?
+            .downcast_ref::<T>()? as *const T;
+        Some(unsafe { &*extension })
+    }
+
+    /// Gets the extension of the type `T` attached to the context, attaching the value returned by
+    /// the provided function first if there is none. The function is invoked without holding any
+    /// locks, so it may itself access the extensions of the context.
+    ///
+    /// # Arguments
+    /// * `init` - The function creating the extension if there is none.
+    ///
+    /// # Returns
+    /// Returns a reference to the extension of the type `T`.
+    pub fn get_or_insert_extension_with<T, F>(&self, init: F) -> &T
+    where
+        T: Any + Send + Sync,
+        F: FnOnce() -> T,
+    {
+        if let Some(extension) = self.get_extension() {
+            return extension;
+        }
+        match self.insert_extension(init()) {
+            Ok(extension) => extension,
+            // Another thread attached an extension of the same type in the meantime.
+            Err(_) => self.get_extension().unwrap(),
+        }
+    }
+}
+
+/// Drops the extensions attached to the provided context, which is about to be destroyed.
+pub(crate) fn drop_extensions(context: &Context) {
+    let context_extensions = extensions()
+        .lock()
+        .unwrap()
+        .remove(&(context.to_raw().ptr as usize));
+    // The extensions are dropped after the lock is released, as dropping them may access the
+    // extensions of other contexts.
+    drop(context_extensions);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Context;
+
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    #[derive(Debug, PartialEq)]
+    struct SymbolIndex(Vec<&'static str>);
+
+    #[test]
+    fn insert_and_get() {
+        let context = Context::new(None, false);
+        assert!(context.get_extension::<SymbolIndex>().is_none());
+        let index = context.insert_extension(SymbolIndex(vec!["a"])).unwrap();
+        assert_eq!(index, &SymbolIndex(vec!["a"]));
+        assert_eq!(
+            context
+                .insert_extension(SymbolIndex(vec!["b"]))
+                .unwrap_err(),
+            SymbolIndex(vec!["b"])
+        );
+        assert_eq!(context.get_extension(), Some(&SymbolIndex(vec!["a"])));
+        assert!(Context::new(None, false)
+            .get_extension::<SymbolIndex>()
+            .is_none());
+    }
+
+    #[test]
+    fn get_or_insert_with() {
+        let context = Context::new(None, false);
+        let counter = context.get_or_insert_extension_with(|| AtomicUsize::new(0));
+        counter.fetch_add(1, Ordering::Relaxed);
+        let counter = context.get_or_insert_extension_with(|| AtomicUsize::new(0));
+        assert_eq!(counter.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn dropped_with_context() {
+        let value = Arc::new(());
+        let context = Context::new(None, false);
+        context.insert_extension(value.clone()).unwrap();
+        assert_eq!(Arc::strong_count(&value), 2);
+        drop(context);
+        assert_eq!(Arc::strong_count(&value), 1);
+    }
+}
@@ -0,0 +1,387 @@
+use crate::{ir::LocationRef, support::string_reader::StringReader, ContextRef, UnownedMlirValue};
+
+use std::{
+    fmt::{Display, Formatter},
+    os::raw::c_void,
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::Mutex,
+};
+
+use mlir_sys::{
+    mlirContextAttachDiagnosticHandler, mlirContextDetachDiagnosticHandler,
+    mlirDiagnosticGetLocation, mlirDiagnosticGetNote, mlirDiagnosticGetNumNotes,
+    mlirDiagnosticGetSeverity, mlirDiagnosticPrint, MlirDiagnostic, MlirDiagnosticHandlerID,
+    MlirDiagnosticSeverity, MlirDiagnosticSeverity_MlirDiagnosticError,
+    MlirDiagnosticSeverity_MlirDiagnosticNote, MlirDiagnosticSeverity_MlirDiagnosticRemark,
+    MlirDiagnosticSeverity_MlirDiagnosticWarning, MlirLogicalResult,
+};
+
+/// [DiagnosticSeverity] represents the severity of an MLIR diagnostic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticSeverity {
+    Error,
+    Warning,
+    Note,
+    Remark,
+}
+
+impl DiagnosticSeverity {
+    /// Converts the provided raw severity into a [DiagnosticSeverity].
+    ///
+    /// # Arguments
+    /// * `raw` - The raw severity.
+    ///
+    /// # Returns
+    /// Returns the matching [DiagnosticSeverity], or `None` if the raw value is unknown.
+    pub fn from_raw(raw: MlirDiagnosticSeverity) -> Option<Self> {
+        #[allow(non_upper_case_globals)]
+        match raw {
+            MlirDiagnosticSeverity_MlirDiagnosticError => Some(Self::Error),
+            MlirDiagnosticSeverity_MlirDiagnosticWarning => Some(Self::Warning),
+            MlirDiagnosticSeverity_MlirDiagnosticNote => Some(Self::Note),
+            MlirDiagnosticSeverity_MlirDiagnosticRemark => Some(Self::Remark),
+            _ => None,
+        }
+    }
+
+    /// # Returns
+    /// Returns the raw [MlirDiagnosticSeverity] value.
+    pub fn to_raw(&self) -> MlirDiagnosticSeverity {
+        match self {
+            Self::Error => MlirDiagnosticSeverity_MlirDiagnosticError,
+            Self::Warning => MlirDiagnosticSeverity_MlirDiagnosticWarning,
+            Self::Note => MlirDiagnosticSeverity_MlirDiagnosticNote,
+            Self::Remark => MlirDiagnosticSeverity_MlirDiagnosticRemark,
+        }
+    }
+}
+
+impl Display for DiagnosticSeverity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+            Self::Note => "note",
+            Self::Remark => "remark",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// [Diagnostic] holds the contents of a diagnostic emitted by MLIR, copied out of the
+/// `mlir::Diagnostic` class so that it can outlive the diagnostic handler it was received in.
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirDiagnosticGetLocation`
+/// - `mlirDiagnosticGetNote`
+/// - `mlirDiagnosticGetNumNotes`
+/// - `mlirDiagnosticGetSeverity`
+/// - `mlirDiagnosticPrint`
+///
+/// The following bindings are not used/supported:
+/// - `mlirEmitError`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    severity: DiagnosticSeverity,
+    location: String,
+    message: String,
+    notes: Vec<Diagnostic>,
+}
+
+impl Diagnostic {
+    /// Copies the contents of the provided raw diagnostic, including its notes.
+    ///
+    /// # Safety
+    /// The caller of this function is responsible for ensuring that the provided raw diagnostic is
+    /// valid, i.e. that it is only used while the diagnostic handler it was received in is running.
+    ///
+    /// # Arguments
+    /// * `raw` - The raw diagnostic.
+    ///
+    /// # Returns
+    /// Returns a new [Diagnostic] instance.
+    pub unsafe fn from_raw(raw: MlirDiagnostic) -> Self {
+        let severity = DiagnosticSeverity::from_raw(mlirDiagnosticGetSeverity(raw))
+            .unwrap_or(DiagnosticSeverity::Error);
+        let location = LocationRef::from_raw(mlirDiagnosticGetLocation(raw)).to_string();
+        let mut message = String::new();
+        let mut reader = StringReader::new(&mut message);
+        mlirDiagnosticPrint(raw, reader.callback(), reader.as_raw_mut());
+        let notes = (0..mlirDiagnosticGetNumNotes(raw))
+            .map(|index| Self::from_raw(mlirDiagnosticGetNote(raw, index)))
+            .collect();
+        Self {
+            severity,
+            location,
+            message,
+            notes,
+        }
+    }
+
+    /// # Returns
+    /// Returns the severity of the diagnostic.
+    pub fn severity(&self) -> DiagnosticSeverity {
+        self.severity
+    }
+
+    /// # Returns
+    /// Returns the textual form of the location the diagnostic was emitted at.
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+
+    /// # Returns
+    /// Returns the message of the diagnostic.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// # Returns
+    /// Returns the notes attached to the diagnostic.
+    pub fn notes(&self) -> &[Diagnostic] {
+        &self.notes
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}: {}", self.location, self.severity, self.message)?;
+        for note in &self.notes {
+            write!(f, "\n{}", note)?;
+        }
+        Ok(())
+    }
+}
+
+/// [DiagnosticHandlerId] identifies a diagnostic handler attached to a context through
+/// [ContextRef::attach_diagnostic_handler].
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticHandlerId(MlirDiagnosticHandlerID);
+
+impl DiagnosticHandlerId {
+    /// Attaches the provided closure as a diagnostic handler to the provided context, handing
+    /// ownership of the closure over to the context.
+    pub(crate) fn attach<F>(context: &ContextRef, handler: F) -> Self
+    where
+        F: FnMut(Diagnostic) -> bool + Send + 'static,
+    {
+        let handler = Box::into_raw(Box::new(handler));
+        let raw = unsafe {
+            mlirContextAttachDiagnosticHandler(
+                context.to_raw(),
+                Some(invoke_diagnostic_handler::<F>),
+                handler as *mut c_void,
+                Some(drop_diagnostic_handler::<F>),
+            )
+        };
+        Self(raw)
+    }
+
+    /// Detaches the identified diagnostic handler from the provided context, which drops it.
+    pub(crate) fn detach(self, context: &ContextRef) {
+        unsafe { mlirContextDetachDiagnosticHandler(context.to_raw(), self.0) }
+    }
+
+    /// # Returns
+    /// Returns the raw [MlirDiagnosticHandlerID] value.
+    pub fn to_raw(&self) -> MlirDiagnosticHandlerID {
+        self.0
+    }
+}
+
+/// Invokes the closure attached by [DiagnosticHandlerId::attach] through the raw diagnostic
+/// handler callback.
+///
+/// # Safety
+/// The provided user data must point to a live closure of the type `F`.
+unsafe extern "C" fn invoke_diagnostic_handler<F>(
+    raw_diagnostic: MlirDiagnostic,
+    user_data: *mut c_void,
+) -> MlirLogicalResult
+where
+    F: FnMut(Diagnostic) -> bool,
+{
+    let handler = &mut *(user_data as *mut F);
+    // Unwinding into MLIR is undefined behavior, so a panicking handler leaves the diagnostic
+    // unhandled instead.
+    let handled = catch_unwind(AssertUnwindSafe(|| {
+        handler(Diagnostic::from_raw(raw_diagnostic))
+    }))
+    .unwrap_or(false);
+    MlirLogicalResult {
+        value: handled as i8,
+    }
+}
+
+/// Drops the closure attached by [DiagnosticHandlerId::attach] once the handler is detached or the
+/// context is destroyed.
+///
+/// # Safety
+/// The provided user data must be the pointer returned by `Box::into_raw` for a closure of the
+/// type `F`.
+unsafe extern "C" fn drop_diagnostic_handler<F>(user_data: *mut c_void) {
+    drop(Box::from_raw(user_data as *mut F));
+}
+
+/// [DiagnosticCapture] attaches a diagnostic handler to a context for as long as it is alive,
+/// collecting every diagnostic emitted in the context instead of letting it reach the default
+/// handler (which prints to stderr).
+///
+/// The handler is detached when the [DiagnosticCapture] is dropped.
+#[derive(Debug)]
+pub struct DiagnosticCapture<'c> {
+    context: &'c ContextRef,
+    handler_id: MlirDiagnosticHandlerID,
+    diagnostics: Box<Mutex<Vec<Diagnostic>>>,
+}
+
+impl<'c> DiagnosticCapture<'c> {
+    /// Starts capturing the diagnostics emitted in the provided context.
+    ///
+    /// # Arguments
+    /// * `context` - The context to capture diagnostics from.
+    ///
+    /// # Returns
+    /// Returns a new [DiagnosticCapture] instance.
+    pub fn new(context: &'c ContextRef) -> Self {
+        let diagnostics = Box::new(Mutex::new(Vec::new()));
+        let handler_id = unsafe {
+            mlirContextAttachDiagnosticHandler(
+                context.to_raw(),
+                Some(capture_diagnostic),
+                diagnostics.as_ref() as *const Mutex<Vec<Diagnostic>> as *mut c_void,
+                None,
+            )
+        };
+        Self {
+            context,
+            handler_id,
+            diagnostics,
+        }
+    }
+
+    /// # Returns
+    /// Returns a copy of the diagnostics captured so far.
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        self.diagnostics.lock().unwrap().clone()
+    }
+
+    /// Takes the diagnostics captured so far, leaving the capture empty.
+    ///
+    /// # Returns
+    /// Returns the diagnostics captured since the last call to this function.
+    pub fn take(&self) -> Vec<Diagnostic> {
+        std::mem::take(&mut *self.diagnostics.lock().unwrap())
+    }
+}
+
+impl<'c> Drop for DiagnosticCapture<'c> {
+    fn drop(&mut self) {
+        unsafe { mlirContextDetachDiagnosticHandler(self.context.to_raw(), self.handler_id) }
+    }
+}
+
+/// The diagnostic handler attached by [DiagnosticCapture].
+///
+/// # Safety
+/// The provided user data must point to the diagnostics collected by a live [DiagnosticCapture].
+unsafe extern "C" fn capture_diagnostic(
+    raw_diagnostic: MlirDiagnostic,
+    user_data: *mut c_void,
+) -> MlirLogicalResult {
+    let diagnostics = &*(user_data as *const Mutex<Vec<Diagnostic>>);
+    let diagnostic = Diagnostic::from_raw(raw_diagnostic);
+    diagnostics.lock().unwrap().push(diagnostic);
+    MlirLogicalResult { value: 1 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::AttributeRef, Context};
+
+    #[test]
+    fn capture_diagnostics() {
+        let context = Context::new(None, false);
+        let capture = context.capture_diagnostics();
+        assert!(AttributeRef::parse(&context, "z").is_none());
+
+        let diagnostics = capture.take();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity(), DiagnosticSeverity::Error);
+        assert!(!diagnostics[0].message().is_empty());
+        assert!(capture.diagnostics().is_empty());
+    }
+
+    #[test]
+    fn detach_on_drop() {
+        let context = Context::new(None, false);
+        let outer = context.capture_diagnostics();
+        {
+            let inner = context.capture_diagnostics();
+            assert!(AttributeRef::parse(&context, "z").is_none());
+            assert_eq!(inner.diagnostics().len(), 1);
+        }
+        assert!(outer.diagnostics().is_empty());
+        assert!(AttributeRef::parse(&context, "z").is_none());
+        assert_eq!(outer.diagnostics().len(), 1);
+    }
+
+    #[test]
+    fn attach_diagnostic_handler() {
+        let context = Context::new(None, false);
+        let diagnostics = std::sync::Arc::new(Mutex::new(Vec::new()));
+        let handler_diagnostics = diagnostics.clone();
+        let handler_id = context.attach_diagnostic_handler(move |diagnostic| {
+            handler_diagnostics.lock().unwrap().push(diagnostic);
+            true
+        });
+        assert!(AttributeRef::parse(&context, "z").is_none());
+        assert_eq!(diagnostics.lock().unwrap().len(), 1);
+        assert_eq!(
+            diagnostics.lock().unwrap()[0].severity(),
+            DiagnosticSeverity::Error
+        );
+
+        context.detach_diagnostic_handler(handler_id);
+        let capture = context.capture_diagnostics();
+        assert!(AttributeRef::parse(&context, "z").is_none());
+        assert_eq!(diagnostics.lock().unwrap().len(), 1);
+        assert_eq!(capture.diagnostics().len(), 1);
+        // The handler (and with it the last other reference to the diagnostics) was dropped.
+        assert_eq!(std::sync::Arc::strong_count(&diagnostics), 1);
+    }
+
+    #[test]
+    fn unhandled_diagnostics_propagate() {
+        let context = Context::new(None, false);
+        let capture = context.capture_diagnostics();
+        let _handler_id = context.attach_diagnostic_handler(|_| false);
+        assert!(AttributeRef::parse(&context, "z").is_none());
+        assert_eq!(capture.diagnostics().len(), 1);
+    }
+
+    #[test]
+    fn panicking_handler() {
+        let context = Context::new(None, false);
+        let capture = context.capture_diagnostics();
+        let _handler_id = context.attach_diagnostic_handler(|_| panic!("failed to handle"));
+        assert!(AttributeRef::parse(&context, "z").is_none());
+        assert_eq!(capture.diagnostics().len(), 1);
+    }
+
+    #[test]
+    fn severity_round_trip() {
+        for severity in [
+            DiagnosticSeverity::Error,
+            DiagnosticSeverity::Warning,
+            DiagnosticSeverity::Note,
+            DiagnosticSeverity::Remark,
+        ] {
+            assert_eq!(
+                DiagnosticSeverity::from_raw(severity.to_raw()),
+                Some(severity)
+            );
+        }
+    }
+}
@@ -0,0 +1,54 @@
+pub mod arith;
+pub mod func;
+pub mod gpu;
+pub mod math;
+pub mod nvgpu;
+pub mod nvvm;
+pub mod rocdl;
+pub mod scf;
+pub mod spirv;
+
+/// Implements the conversions between a typed reference to an operation of a dialect (e.g.
+/// [func::FuncOpRef]) and [crate::ir::OperationRef], checking the name of the operation.
+macro_rules! impl_operation_variant {
+    ($variant_type:ident, $operation_name:literal) => {
+        impl<'c> $variant_type<'c> {
+            /// The fully-qualified name of the operation.
+            pub const OPERATION_NAME: &'static str = $operation_name;
+
+            /// Converts a reference to an operation into a reference to this operation type.
+            ///
+            /// # Arguments
+            /// * `operation` - The operation to convert.
+            ///
+            /// # Returns
+            /// Returns the typed reference, or `None` if the operation has another name.
+            pub fn try_from_operation<'a>(
+                operation: &'a $crate::ir::OperationRef<'c>,
+            ) -> Option<&'a Self> {
+                if operation.name().value().as_str() == $operation_name {
+                    Some(unsafe { <Self as UnownedMlirValue>::from_raw(operation.to_raw()) })
+                } else {
+                    None
+                }
+            }
+
+            /// # Returns
+            /// Returns the reference to the operation, which is also available through
+            /// [std::ops::Deref].
+            pub fn as_operation(&self) -> &$crate::ir::OperationRef<'c> {
+                unsafe { $crate::ir::OperationRef::from_raw(self.to_raw()) }
+            }
+        }
+
+        impl<'c> std::ops::Deref for $variant_type<'c> {
+            type Target = $crate::ir::OperationRef<'c>;
+
+            fn deref(&self) -> &Self::Target {
+                self.as_operation()
+            }
+        }
+    };
+}
+
+use impl_operation_variant;
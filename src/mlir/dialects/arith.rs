@@ -0,0 +1,989 @@
+use crate::{
+    ir::{
+        AttributeRef, BlockRef, FloatAttributeRef, FloatTypeRef, IndexTypeRef, IntegerAttributeRef,
+        IntegerTypeRef, LocationRef, Operation, OperationBuilder, TypeRef, ValueRef,
+    },
+    support::binding::{OwnedMlirValue, UnownedMlirValue},
+    ContextRef, DialectHandle,
+};
+
+use mlir_sys::{mlirGetDialectHandle__arith__, mlirIntegerAttrGet};
+
+/// # Returns
+/// Returns the handle of the `arith` dialect, e.g. to register it with a context.
+pub fn handle() -> DialectHandle {
+    unsafe { DialectHandle::from_raw(mlirGetDialectHandle__arith__()) }
+}
+
+/// [FastMathFlags] are the fast-math flags of floating-point operations, as held by their
+/// `fastmath` attribute, which allow the results of the operations to be less precise.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FastMathFlags {
+    /// Allows the operands to be reassociated.
+    pub reassoc: bool,
+    /// Assumes that no operand or result is NaN.
+    pub nnan: bool,
+    /// Assumes that no operand or result is infinite.
+    pub ninf: bool,
+    /// Treats the sign of zero as insignificant.
+    pub nsz: bool,
+    /// Allows divisions to be replaced by multiplications with the reciprocal.
+    pub arcp: bool,
+    /// Allows operations to be contracted, e.g. into fused multiply-adds.
+    pub contract: bool,
+    /// Allows functions to be approximated.
+    pub afn: bool,
+}
+
+impl FastMathFlags {
+    /// No fast-math flags, which is the default.
+    pub const NONE: FastMathFlags = FastMathFlags {
+        reassoc: false,
+        nnan: false,
+        ninf: false,
+        nsz: false,
+        arcp: false,
+        contract: false,
+        afn: false,
+    };
+
+    /// All fast-math flags.
+    pub const FAST: FastMathFlags = FastMathFlags {
+        reassoc: true,
+        nnan: true,
+        ninf: true,
+        nsz: true,
+        arcp: true,
+        contract: true,
+        afn: true,
+    };
+
+    /// # Returns
+    /// Returns the names of the flags which are set, as printed in the `fastmath` attribute.
+    pub fn names(&self) -> Vec<&'static str> {
+        [
+            (self.reassoc, "reassoc"),
+            (self.nnan, "nnan"),
+            (self.ninf, "ninf"),
+            (self.nsz, "nsz"),
+            (self.arcp, "arcp"),
+            (self.contract, "contract"),
+            (self.afn, "afn"),
+        ]
+        .into_iter()
+        .filter_map(|(set, name)| set.then_some(name))
+        .collect()
+    }
+
+    /// # Arguments
+    /// * `context` - The context that should own the attribute.
+    ///
+    /// # Returns
+    /// Returns the `#arith.fastmath` attribute holding the flags.
+    pub fn to_attribute<'a>(&self, context: &'a ContextRef) -> &'a AttributeRef {
+        let names = self.names();
+        let source = if names.is_empty() {
+            "#arith.fastmath<none>".to_string()
+        } else {
+            format!("#arith.fastmath<{}>", names.join(","))
+        };
+        AttributeRef::parse(context, &source).expect("The arith dialect is not loaded.")
+    }
+}
+
+/// [IntegerOverflowFlags] are the overflow flags of the integer operations which support them
+/// (see [IntegerBinaryOp::supports_overflow_flags]), as held by their `overflowFlags` attribute,
+/// which make the results of the operations poison if they overflow.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IntegerOverflowFlags {
+    /// Assumes that the operation does not overflow when interpreting the operands as signed.
+    pub nsw: bool,
+    /// Assumes that the operation does not overflow when interpreting the operands as unsigned.
+    pub nuw: bool,
+}
+
+impl IntegerOverflowFlags {
+    /// No overflow flags, which is the default.
+    pub const NONE: IntegerOverflowFlags = IntegerOverflowFlags {
+        nsw: false,
+        nuw: false,
+    };
+
+    /// # Returns
+    /// Returns the names of the flags which are set, as printed in the `overflowFlags` attribute.
+    pub fn names(&self) -> Vec<&'static str> {
+        [(self.nsw, "nsw"), (self.nuw, "nuw")]
+            .into_iter()
+            .filter_map(|(set, name)| set.then_some(name))
+            .collect()
+    }
+
+    /// # Arguments
+    /// * `context` - The context that should own the attribute.
+    ///
+    /// # Returns
+    /// Returns the `#arith.overflow` attribute holding the flags.
+    pub fn to_attribute<'a>(&self, context: &'a ContextRef) -> &'a AttributeRef {
+        let names = self.names();
+        let source = if names.is_empty() {
+            "#arith.overflow<none>".to_string()
+        } else {
+            format!("#arith.overflow<{}>", names.join(","))
+        };
+        AttributeRef::parse(context, &source).expect("The arith dialect is not loaded.")
+    }
+}
+
+/// [IntegerBinaryOp] is a binary operation of the `arith` dialect on signless integers (or
+/// indices), whose operands and result all have the same type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegerBinaryOp {
+    /// Addition.
+    AddI,
+    /// Subtraction.
+    SubI,
+    /// Multiplication.
+    MulI,
+    /// Signed division, rounded towards zero.
+    DivSI,
+    /// Unsigned division.
+    DivUI,
+    /// Signed division, rounded up.
+    CeilDivSI,
+    /// Unsigned division, rounded up.
+    CeilDivUI,
+    /// Signed division, rounded down.
+    FloorDivSI,
+    /// Signed remainder.
+    RemSI,
+    /// Unsigned remainder.
+    RemUI,
+    /// Bitwise and.
+    AndI,
+    /// Bitwise or.
+    OrI,
+    /// Bitwise exclusive or.
+    XOrI,
+    /// Left shift.
+    ShLI,
+    /// Arithmetic right shift.
+    ShRSI,
+    /// Logical right shift.
+    ShRUI,
+    /// Signed maximum.
+    MaxSI,
+    /// Unsigned maximum.
+    MaxUI,
+    /// Signed minimum.
+    MinSI,
+    /// Unsigned minimum.
+    MinUI,
+}
+
+impl IntegerBinaryOp {
+    /// # Returns
+    /// Returns the fully-qualified name of the operation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IntegerBinaryOp::AddI => "arith.addi",
+            IntegerBinaryOp::SubI => "arith.subi",
+            IntegerBinaryOp::MulI => "arith.muli",
+            IntegerBinaryOp::DivSI => "arith.divsi",
+            IntegerBinaryOp::DivUI => "arith.divui",
+            IntegerBinaryOp::CeilDivSI => "arith.ceildivsi",
+            IntegerBinaryOp::CeilDivUI => "arith.ceildivui",
+            IntegerBinaryOp::FloorDivSI => "arith.floordivsi",
+            IntegerBinaryOp::RemSI => "arith.remsi",
+            IntegerBinaryOp::RemUI => "arith.remui",
+            IntegerBinaryOp::AndI => "arith.andi",
+            IntegerBinaryOp::OrI => "arith.ori",
+            IntegerBinaryOp::XOrI => "arith.xori",
+            IntegerBinaryOp::ShLI => "arith.shli",
+            IntegerBinaryOp::ShRSI => "arith.shrsi",
+            IntegerBinaryOp::ShRUI => "arith.shrui",
+            IntegerBinaryOp::MaxSI => "arith.maxsi",
+            IntegerBinaryOp::MaxUI => "arith.maxui",
+            IntegerBinaryOp::MinSI => "arith.minsi",
+            IntegerBinaryOp::MinUI => "arith.minui",
+        }
+    }
+
+    /// # Returns
+    /// Returns whether the operation has an `overflowFlags` attribute (see
+    /// [IntegerOverflowFlags]).
+    pub fn supports_overflow_flags(&self) -> bool {
+        matches!(
+            self,
+            IntegerBinaryOp::AddI
+                | IntegerBinaryOp::SubI
+                | IntegerBinaryOp::MulI
+                | IntegerBinaryOp::ShLI
+        )
+    }
+}
+
+/// [FloatBinaryOp] is a binary operation of the `arith` dialect on floating-point values, whose
+/// operands and result all have the same type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FloatBinaryOp {
+    /// Addition.
+    AddF,
+    /// Subtraction.
+    SubF,
+    /// Multiplication.
+    MulF,
+    /// Division.
+    DivF,
+    /// Remainder.
+    RemF,
+    /// Maximum, which is NaN if either operand is NaN.
+    MaximumF,
+    /// Minimum, which is NaN if either operand is NaN.
+    MinimumF,
+    /// Maximum, which is the other operand if one operand is NaN.
+    MaxNumF,
+    /// Minimum, which is the other operand if one operand is NaN.
+    MinNumF,
+}
+
+impl FloatBinaryOp {
+    /// # Returns
+    /// Returns the fully-qualified name of the operation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FloatBinaryOp::AddF => "arith.addf",
+            FloatBinaryOp::SubF => "arith.subf",
+            FloatBinaryOp::MulF => "arith.mulf",
+            FloatBinaryOp::DivF => "arith.divf",
+            FloatBinaryOp::RemF => "arith.remf",
+            FloatBinaryOp::MaximumF => "arith.maximumf",
+            FloatBinaryOp::MinimumF => "arith.minimumf",
+            FloatBinaryOp::MaxNumF => "arith.maxnumf",
+            FloatBinaryOp::MinNumF => "arith.minnumf",
+        }
+    }
+}
+
+/// [CmpIPredicate] is the predicate of an `arith.cmpi` operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CmpIPredicate {
+    /// Equal.
+    Eq = 0,
+    /// Not equal.
+    Ne = 1,
+    /// Signed less than.
+    Slt = 2,
+    /// Signed less than or equal.
+    Sle = 3,
+    /// Signed greater than.
+    Sgt = 4,
+    /// Signed greater than or equal.
+    Sge = 5,
+    /// Unsigned less than.
+    Ult = 6,
+    /// Unsigned less than or equal.
+    Ule = 7,
+    /// Unsigned greater than.
+    Ugt = 8,
+    /// Unsigned greater than or equal.
+    Uge = 9,
+}
+
+/// [CmpFPredicate] is the predicate of an `arith.cmpf` operation. Ordered predicates are false
+/// if either operand is NaN, unordered predicates are true if either operand is NaN.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CmpFPredicate {
+    /// Always false.
+    AlwaysFalse = 0,
+    /// Ordered and equal.
+    Oeq = 1,
+    /// Ordered and greater than.
+    Ogt = 2,
+    /// Ordered and greater than or equal.
+    Oge = 3,
+    /// Ordered and less than.
+    Olt = 4,
+    /// Ordered and less than or equal.
+    Ole = 5,
+    /// Ordered and not equal.
+    One = 6,
+    /// Ordered, i.e. neither operand is NaN.
+    Ord = 7,
+    /// Unordered or equal.
+    Ueq = 8,
+    /// Unordered or greater than.
+    Ugt = 9,
+    /// Unordered or greater than or equal.
+    Uge = 10,
+    /// Unordered or less than.
+    Ult = 11,
+    /// Unordered or less than or equal.
+    Ule = 12,
+    /// Unordered or not equal.
+    Une = 13,
+    /// Unordered, i.e. either operand is NaN.
+    Uno = 14,
+    /// Always true.
+    AlwaysTrue = 15,
+}
+
+/// [CastOp] is a cast operation of the `arith` dialect, which converts a value to another type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CastOp {
+    /// Sign extension of an integer to a wider integer type.
+    ExtSI,
+    /// Zero extension of an integer to a wider integer type.
+    ExtUI,
+    /// Truncation of an integer to a narrower integer type.
+    TruncI,
+    /// Conversion of a signed integer to a floating-point type.
+    SIToFP,
+    /// Conversion of an unsigned integer to a floating-point type.
+    UIToFP,
+    /// Conversion of a floating-point value to a signed integer type, rounded towards zero.
+    FPToSI,
+    /// Conversion of a floating-point value to an unsigned integer type, rounded towards zero.
+    FPToUI,
+    /// Extension of a floating-point value to a wider floating-point type.
+    ExtF,
+    /// Truncation of a floating-point value to a narrower floating-point type.
+    TruncF,
+    /// Conversion between indices and integers, sign-extending or truncating.
+    IndexCast,
+    /// Conversion between indices and integers, zero-extending or truncating.
+    IndexCastUI,
+    /// Reinterpretation of the bits of a value as another type of the same width.
+    Bitcast,
+}
+
+impl CastOp {
+    /// # Returns
+    /// Returns the fully-qualified name of the operation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CastOp::ExtSI => "arith.extsi",
+            CastOp::ExtUI => "arith.extui",
+            CastOp::TruncI => "arith.trunci",
+            CastOp::SIToFP => "arith.sitofp",
+            CastOp::UIToFP => "arith.uitofp",
+            CastOp::FPToSI => "arith.fptosi",
+            CastOp::FPToUI => "arith.fptoui",
+            CastOp::ExtF => "arith.extf",
+            CastOp::TruncF => "arith.truncf",
+            CastOp::IndexCast => "arith.index_cast",
+            CastOp::IndexCastUI => "arith.index_castui",
+            CastOp::Bitcast => "arith.bitcast",
+        }
+    }
+}
+
+/// Builds a new `arith.constant` operation, whose result has the type of the provided attribute.
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `value` - The value of the constant, e.g. an integer, float or dense elements attribute.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn constant<'c>(location: &'c LocationRef, value: &AttributeRef) -> Operation<'c> {
+    OperationBuilder::new("arith.constant", location)
+        .add_attribute("value", value)
+        .add_results(&[value.r#type()])
+        .build()
+        .expect("arith.constant operation could not be built.")
+}
+
+/// Builds a new `arith.constant` operation holding an integer.
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `value` - The value of the constant.
+/// * `ty` - The signless integer type of the constant.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn constant_int<'c>(
+    location: &'c LocationRef,
+    value: i64,
+    ty: &IntegerTypeRef,
+) -> Operation<'c> {
+    constant(location, IntegerAttributeRef::new(ty, value))
+}
+
+/// Builds a new `arith.constant` operation holding an index.
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `value` - The value of the constant.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn constant_index<'c>(location: &'c LocationRef, value: i64) -> Operation<'c> {
+    let index_type = IndexTypeRef::new(location.context());
+    let value = unsafe { AttributeRef::from_raw(mlirIntegerAttrGet(index_type.to_raw(), value)) };
+    constant(location, value)
+}
+
+/// Builds a new `arith.constant` operation holding a floating-point value.
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `value` - The value of the constant, which is rounded to the type of the constant.
+/// * `ty` - The floating-point type of the constant.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn constant_float<'c>(
+    location: &'c LocationRef,
+    value: f64,
+    ty: &FloatTypeRef,
+) -> Operation<'c> {
+    constant(
+        location,
+        FloatAttributeRef::new(location.context(), ty, value),
+    )
+}
+
+/// Builds a new binary operation on integers, whose result has the type of the operands.
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `op` - The operation to build.
+/// * `lhs` - The left-hand side of the operation.
+/// * `rhs` - The right-hand side of the operation, which must have the same type.
+/// * `overflow` - The overflow flags of the operation, which must be [IntegerOverflowFlags::NONE]
+///   unless the operation supports them (see [IntegerBinaryOp::supports_overflow_flags]).
+///
+/// # Returns
+/// Returns the new operation.
+pub fn integer_binary<'c>(
+    location: &'c LocationRef,
+    op: IntegerBinaryOp,
+    lhs: &ValueRef,
+    rhs: &ValueRef,
+    overflow: IntegerOverflowFlags,
+) -> Operation<'c> {
+    let mut builder = OperationBuilder::new(op.as_str(), location);
+    if overflow != IntegerOverflowFlags::NONE {
+        if !op.supports_overflow_flags() {
+            panic!("{} operations have no overflow flags.", op.as_str());
+        }
+        let overflow = overflow.to_attribute(location.context());
+        builder = builder.add_attribute("overflowFlags", overflow);
+    }
+    builder
+        .add_operands(&[lhs, rhs])
+        .add_results(&[lhs.r#type()])
+        .build()
+        .unwrap_or_else(|_| panic!("{} operation could not be built.", op.as_str()))
+}
+
+/// Builds a new binary operation on floating-point values, whose result has the type of the
+/// operands.
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `op` - The operation to build.
+/// * `lhs` - The left-hand side of the operation.
+/// * `rhs` - The right-hand side of the operation, which must have the same type.
+/// * `fastmath` - The fast-math flags of the operation.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn float_binary<'c>(
+    location: &'c LocationRef,
+    op: FloatBinaryOp,
+    lhs: &ValueRef,
+    rhs: &ValueRef,
+    fastmath: FastMathFlags,
+) -> Operation<'c> {
+    with_fastmath(
+        OperationBuilder::new(op.as_str(), location),
+        location,
+        fastmath,
+    )
+    .add_operands(&[lhs, rhs])
+    .add_results(&[lhs.r#type()])
+    .build()
+    .unwrap_or_else(|_| panic!("{} operation could not be built.", op.as_str()))
+}
+
+/// Builds a new `arith.negf` operation, whose result has the type of the operand.
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `operand` - The value to negate.
+/// * `fastmath` - The fast-math flags of the operation.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn negf<'c>(
+    location: &'c LocationRef,
+    operand: &ValueRef,
+    fastmath: FastMathFlags,
+) -> Operation<'c> {
+    with_fastmath(
+        OperationBuilder::new("arith.negf", location),
+        location,
+        fastmath,
+    )
+    .add_operands(&[operand])
+    .add_results(&[operand.r#type()])
+    .build()
+    .expect("arith.negf operation could not be built.")
+}
+
+/// Builds a new `arith.cmpi` operation, whose result is an `i1` (or a vector or tensor of `i1`
+/// with the shape of the operands).
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `predicate` - The predicate of the comparison.
+/// * `lhs` - The left-hand side of the comparison.
+/// * `rhs` - The right-hand side of the comparison, which must have the same type.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn cmpi<'c>(
+    location: &'c LocationRef,
+    predicate: CmpIPredicate,
+    lhs: &ValueRef,
+    rhs: &ValueRef,
+) -> Operation<'c> {
+    let i64_type = IntegerTypeRef::new_signless(location.context(), 64);
+    OperationBuilder::new("arith.cmpi", location)
+        .add_attribute(
+            "predicate",
+            IntegerAttributeRef::new(i64_type, predicate as i64),
+        )
+        .add_operands(&[lhs, rhs])
+        .enable_result_type_inference()
+        .build()
+        .expect("arith.cmpi operation could not be built.")
+}
+
+/// Builds a new `arith.cmpf` operation, whose result is an `i1` (or a vector or tensor of `i1`
+/// with the shape of the operands).
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `predicate` - The predicate of the comparison.
+/// * `lhs` - The left-hand side of the comparison.
+/// * `rhs` - The right-hand side of the comparison, which must have the same type.
+/// * `fastmath` - The fast-math flags of the operation.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn cmpf<'c>(
+    location: &'c LocationRef,
+    predicate: CmpFPredicate,
+    lhs: &ValueRef,
+    rhs: &ValueRef,
+    fastmath: FastMathFlags,
+) -> Operation<'c> {
+    let i64_type = IntegerTypeRef::new_signless(location.context(), 64);
+    with_fastmath(
+        OperationBuilder::new("arith.cmpf", location),
+        location,
+        fastmath,
+    )
+    .add_attribute(
+        "predicate",
+        IntegerAttributeRef::new(i64_type, predicate as i64),
+    )
+    .add_operands(&[lhs, rhs])
+    .enable_result_type_inference()
+    .build()
+    .expect("arith.cmpf operation could not be built.")
+}
+
+/// Builds a new `arith.select` operation, whose result has the type of the selected values.
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `condition` - The `i1` condition (or vector or tensor of `i1` to select elementwise).
+/// * `true_value` - The value selected if the condition is true.
+/// * `false_value` - The value selected if the condition is false, which must have the same
+///   type.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn select<'c>(
+    location: &'c LocationRef,
+    condition: &ValueRef,
+    true_value: &ValueRef,
+    false_value: &ValueRef,
+) -> Operation<'c> {
+    OperationBuilder::new("arith.select", location)
+        .add_operands(&[condition, true_value, false_value])
+        .add_results(&[true_value.r#type()])
+        .build()
+        .expect("arith.select operation could not be built.")
+}
+
+/// Builds a new cast operation.
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `op` - The operation to build.
+/// * `operand` - The value to cast.
+/// * `ty` - The type to cast the value to.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn cast<'c>(
+    location: &'c LocationRef,
+    op: CastOp,
+    operand: &ValueRef,
+    ty: &TypeRef,
+) -> Operation<'c> {
+    OperationBuilder::new(op.as_str(), location)
+        .add_operands(&[operand])
+        .add_results(&[ty])
+        .build()
+        .unwrap_or_else(|_| panic!("{} operation could not be built.", op.as_str()))
+}
+
+/// [ArithBuilder] appends operations of the `arith` dialect to a block at a single location,
+/// returning their results directly, which keeps scalar code generation concise. Each method
+/// builds its operation using the matching free function of this module.
+#[derive(Debug, Clone, Copy)]
+pub struct ArithBuilder<'a, 'c> {
+    block: &'a BlockRef<'c>,
+    location: &'c LocationRef,
+}
+
+impl<'a, 'c> ArithBuilder<'a, 'c> {
+    /// Constructs a new builder appending operations to the end of the provided block.
+    ///
+    /// # Arguments
+    /// * `block` - The block to append the operations to.
+    /// * `location` - The location of the operations.
+    ///
+    /// # Returns
+    /// Returns a new [ArithBuilder] instance.
+    pub fn new(block: &'a BlockRef<'c>, location: &'c LocationRef) -> Self {
+        ArithBuilder { block, location }
+    }
+
+    fn append(&self, operation: Operation<'c>) -> &'a ValueRef<'c> {
+        self.block.append_operation(operation).result(0)
+    }
+
+    /// Appends an `arith.constant` operation (see [constant_int]).
+    ///
+    /// # Returns
+    /// Returns the result of the operation.
+    pub fn constant_int(&self, value: i64, ty: &IntegerTypeRef) -> &'a ValueRef<'c> {
+        self.append(constant_int(self.location, value, ty))
+    }
+
+    /// Appends an `arith.constant` operation (see [constant_index]).
+    ///
+    /// # Returns
+    /// Returns the result of the operation.
+    pub fn constant_index(&self, value: i64) -> &'a ValueRef<'c> {
+        self.append(constant_index(self.location, value))
+    }
+
+    /// Appends an `arith.constant` operation (see [constant_float]).
+    ///
+    /// # Returns
+    /// Returns the result of the operation.
+    pub fn constant_float(&self, value: f64, ty: &FloatTypeRef) -> &'a ValueRef<'c> {
+        self.append(constant_float(self.location, value, ty))
+    }
+
+    /// Appends a binary operation on integers (see [integer_binary]).
+    ///
+    /// # Returns
+    /// Returns the result of the operation.
+    pub fn integer_binary(
+        &self,
+        op: IntegerBinaryOp,
+        lhs: &ValueRef,
+        rhs: &ValueRef,
+        overflow: IntegerOverflowFlags,
+    ) -> &'a ValueRef<'c> {
+        self.append(integer_binary(self.location, op, lhs, rhs, overflow))
+    }
+
+    /// Appends a binary operation on floating-point values (see [float_binary]).
+    ///
+    /// # Returns
+    /// Returns the result of the operation.
+    pub fn float_binary(
+        &self,
+        op: FloatBinaryOp,
+        lhs: &ValueRef,
+        rhs: &ValueRef,
+        fastmath: FastMathFlags,
+    ) -> &'a ValueRef<'c> {
+        self.append(float_binary(self.location, op, lhs, rhs, fastmath))
+    }
+
+    /// Appends an `arith.negf` operation (see [negf]).
+    ///
+    /// # Returns
+    /// Returns the result of the operation.
+    pub fn negf(&self, operand: &ValueRef, fastmath: FastMathFlags) -> &'a ValueRef<'c> {
+        self.append(negf(self.location, operand, fastmath))
+    }
+
+    /// Appends an `arith.cmpi` operation (see [cmpi]).
+    ///
+    /// # Returns
+    /// Returns the result of the operation.
+    pub fn cmpi(
+        &self,
+        predicate: CmpIPredicate,
+        lhs: &ValueRef,
+        rhs: &ValueRef,
+    ) -> &'a ValueRef<'c> {
+        self.append(cmpi(self.location, predicate, lhs, rhs))
+    }
+
+    /// Appends an `arith.cmpf` operation (see [cmpf]).
+    ///
+    /// # Returns
+    /// Returns the result of the operation.
+    pub fn cmpf(
+        &self,
+        predicate: CmpFPredicate,
+        lhs: &ValueRef,
+        rhs: &ValueRef,
+        fastmath: FastMathFlags,
+    ) -> &'a ValueRef<'c> {
+        self.append(cmpf(self.location, predicate, lhs, rhs, fastmath))
+    }
+
+    /// Appends an `arith.select` operation (see [select]).
+    ///
+    /// # Returns
+    /// Returns the result of the operation.
+    pub fn select(
+        &self,
+        condition: &ValueRef,
+        true_value: &ValueRef,
+        false_value: &ValueRef,
+    ) -> &'a ValueRef<'c> {
+        self.append(select(self.location, condition, true_value, false_value))
+    }
+
+    /// Appends a cast operation (see [cast]).
+    ///
+    /// # Returns
+    /// Returns the result of the operation.
+    pub fn cast(&self, op: CastOp, operand: &ValueRef, ty: &TypeRef) -> &'a ValueRef<'c> {
+        self.append(cast(self.location, op, operand, ty))
+    }
+}
+
+/// Adds the `fastmath` attribute to the builder of a floating-point operation, unless no flags
+/// are set.
+pub(super) fn with_fastmath<'c>(
+    builder: OperationBuilder<'c>,
+    location: &'c LocationRef,
+    fastmath: FastMathFlags,
+) -> OperationBuilder<'c> {
+    if fastmath == FastMathFlags::NONE {
+        builder
+    } else {
+        builder.add_attribute("fastmath", fastmath.to_attribute(location.context()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dialects::func,
+        ir::{Block, FunctionTypeRef, Module, SymbolVisibility},
+        Context, DialectRegistry,
+    };
+
+    fn context() -> Context {
+        let dialect_registry = DialectRegistry::default();
+        handle().insert_into_registry(&dialect_registry);
+        func::handle().insert_into_registry(&dialect_registry);
+        let context = Context::new(Some(&dialect_registry), false);
+        context.load_all_available_dialects();
+        context
+    }
+
+    #[test]
+    fn fastmath_attribute() {
+        let context = context();
+        let flags = FastMathFlags {
+            nnan: true,
+            contract: true,
+            ..Default::default()
+        };
+        assert_eq!(flags.names(), vec!["nnan", "contract"]);
+        assert_eq!(
+            flags.to_attribute(&context).to_string(),
+            "#arith.fastmath<nnan,contract>"
+        );
+        assert_eq!(
+            FastMathFlags::NONE.to_attribute(&context).to_string(),
+            "#arith.fastmath<none>"
+        );
+        assert_eq!(
+            FastMathFlags::FAST.to_attribute(&context).to_string(),
+            "#arith.fastmath<fast>"
+        );
+    }
+
+    #[test]
+    fn overflow_attribute() {
+        let context = context();
+        let flags = IntegerOverflowFlags {
+            nsw: true,
+            ..Default::default()
+        };
+        assert_eq!(flags.names(), vec!["nsw"]);
+        assert_eq!(
+            flags.to_attribute(&context).to_string(),
+            "#arith.overflow<nsw>"
+        );
+        assert_eq!(
+            IntegerOverflowFlags::NONE
+                .to_attribute(&context)
+                .to_string(),
+            "#arith.overflow<none>"
+        );
+        assert!(IntegerBinaryOp::ShLI.supports_overflow_flags());
+        assert!(!IntegerBinaryOp::DivSI.supports_overflow_flags());
+    }
+
+    #[test]
+    #[should_panic]
+    fn overflow_flags_unsupported() {
+        let context = context();
+        let loc = LocationRef::new_unknown(&context);
+        let i32_type = IntegerTypeRef::new_signless(&context, 32);
+        let block = Block::new(&[(i32_type, loc)]);
+        let flags = IntegerOverflowFlags {
+            nuw: true,
+            ..Default::default()
+        };
+        let x = block.argument(0);
+        integer_binary(loc, IntegerBinaryOp::DivUI, x, x, flags);
+    }
+
+    #[test]
+    fn arith_builder() {
+        let context = context();
+        let loc = LocationRef::new_unknown(&context);
+        let i32_type = IntegerTypeRef::new_signless(&context, 32);
+        let f32_type = FloatTypeRef::new_f32(&context);
+        let block = Block::new(&[(i32_type, loc)]);
+        let builder = ArithBuilder::new(&block, loc);
+        let x = block.argument(0);
+        let one = builder.constant_int(1, i32_type);
+        let sum = builder.integer_binary(IntegerBinaryOp::AddI, x, one, IntegerOverflowFlags::NONE);
+        let converted = builder.cast(CastOp::SIToFP, sum, f32_type);
+        let half = builder.constant_float(0.5, f32_type);
+        let product =
+            builder.float_binary(FloatBinaryOp::MulF, converted, half, FastMathFlags::NONE);
+        let negated = builder.negf(product, FastMathFlags::NONE);
+        let less = builder.cmpf(CmpFPredicate::Olt, negated, half, FastMathFlags::NONE);
+        let selected = builder.select(less, negated, half);
+        let index = builder.constant_index(2);
+        assert_eq!(
+            builder.cmpi(CmpIPredicate::Eq, x, one).r#type().to_string(),
+            "i1"
+        );
+        assert_eq!(selected.r#type(), f32_type.as_type());
+        assert_eq!(index.r#type().to_string(), "index");
+        assert_eq!(block.operations().count(), 10);
+    }
+
+    #[test]
+    fn build_scalar_code() {
+        let context = context();
+        let loc = LocationRef::new_unknown(&context);
+        let i32_type = IntegerTypeRef::new_signless(&context, 32);
+        let f32_type = FloatTypeRef::new_f32(&context);
+        let i1_type = IntegerTypeRef::new_signless(&context, 1);
+        let block = Block::new(&[(i32_type, loc), (f32_type, loc)]);
+        let x = block.argument(0);
+        let y = block.argument(1);
+
+        let one = block
+            .append_operation(constant_int(loc, 1, i32_type))
+            .result(0);
+        let sum = block.append_operation(integer_binary(
+            loc,
+            IntegerBinaryOp::AddI,
+            x,
+            one,
+            IntegerOverflowFlags::NONE,
+        ));
+        assert_eq!(sum.result(0).r#type(), i32_type.as_type());
+        let half = block
+            .append_operation(constant_float(loc, 0.5, f32_type))
+            .result(0);
+        let product = block.append_operation(float_binary(
+            loc,
+            FloatBinaryOp::MulF,
+            y,
+            half,
+            FastMathFlags::FAST,
+        ));
+        assert_eq!(
+            product.attribute("fastmath").unwrap(),
+            FastMathFlags::FAST.to_attribute(&context)
+        );
+        let negated = block.append_operation(negf(loc, product.result(0), FastMathFlags::NONE));
+        let converted = block.append_operation(cast(loc, CastOp::SIToFP, sum.result(0), f32_type));
+        let less = block.append_operation(cmpf(
+            loc,
+            CmpFPredicate::Olt,
+            negated.result(0),
+            converted.result(0),
+            FastMathFlags::NONE,
+        ));
+        assert_eq!(less.result(0).r#type(), i1_type.as_type());
+        let equal = block.append_operation(cmpi(loc, CmpIPredicate::Eq, x, one));
+        assert_eq!(equal.result(0).r#type(), i1_type.as_type());
+        let selected = block.append_operation(select(loc, less.result(0), half, y));
+        let index = block.append_operation(constant_index(loc, 4));
+        let index_sum = block.append_operation(integer_binary(
+            loc,
+            IntegerBinaryOp::AddI,
+            index.result(0),
+            index.result(0),
+            IntegerOverflowFlags {
+                nsw: true,
+                nuw: true,
+            },
+        ));
+        assert_eq!(
+            index_sum.attribute("overflowFlags").unwrap(),
+            IntegerOverflowFlags {
+                nsw: true,
+                nuw: true
+            }
+            .to_attribute(&context)
+        );
+        let index_cast =
+            block.append_operation(cast(loc, CastOp::IndexCast, index_sum.result(0), i32_type));
+        block.append_operation(func::r#return(
+            loc,
+            &[selected.result(0), index_cast.result(0)],
+        ));
+
+        let module = Module::new(loc);
+        let function_type =
+            FunctionTypeRef::new(&context, &[i32_type, f32_type], &[f32_type, i32_type]);
+        let function = module.body().append_operation(func::func(
+            loc,
+            "kernel",
+            function_type,
+            SymbolVisibility::Public,
+        ));
+        function.region(0).append_block(block);
+        assert!(module.as_operation().verify());
+    }
+}
@@ -0,0 +1,449 @@
+use super::impl_operation_variant;
+use crate::{
+    ir::{
+        symbol_attribute_name, ArrayAttributeRef, AttributeRef, Block, BlockArgumentRef, BlockRef,
+        DictionaryAttributeRef, FlatSymbolRefAttributeRef, FunctionTypeRef, LocationRef, Operation,
+        OperationBuilder, Region, RegionRef, StringAttributeRef, SymbolVisibility,
+        TypeAttributeRef, TypeRef, ValueRef,
+    },
+    support::binding::{impl_unowned_mlir_value, OwnedMlirValue, UnownedMlirValue},
+    DialectHandle, StringRef,
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{mlirGetDialectHandle__func__, MlirOperation};
+
+/// # Returns
+/// Returns the handle of the `func` dialect, e.g. to register it with a context.
+pub fn handle() -> DialectHandle {
+    unsafe { DialectHandle::from_raw(mlirGetDialectHandle__func__()) }
+}
+
+/// Builds a new `func.func` operation with an empty body, which makes it a declaration until its
+/// entry block is created (see [FuncOpRef::get_or_create_entry_block]).
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `name` - The name of the symbol defined by the function.
+/// * `function_type` - The type of the function.
+/// * `visibility` - The visibility of the symbol, which must be private for declarations.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn func<'c>(
+    location: &'c LocationRef,
+    name: &str,
+    function_type: &FunctionTypeRef,
+    visibility: SymbolVisibility,
+) -> Operation<'c> {
+    let context = location.context();
+    let operation = OperationBuilder::new(FuncOpRef::OPERATION_NAME, location)
+        .add_attribute(
+            symbol_attribute_name(),
+            StringAttributeRef::new(context, name),
+        )
+        .add_attribute("function_type", TypeAttributeRef::new(function_type))
+        .add_regions(vec![Region::new(context)])
+        .build()
+        .expect("func.func operation could not be built.");
+    operation.set_visibility(visibility);
+    operation
+}
+
+/// Builds a new `func.return` operation, which terminates the body of a function.
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `operands` - The values returned by the function.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn r#return<'c>(location: &'c LocationRef, operands: &[&ValueRef]) -> Operation<'c> {
+    OperationBuilder::new("func.return", location)
+        .add_operands(operands)
+        .build()
+        .expect("func.return operation could not be built.")
+}
+
+/// Builds a new `func.call` operation, which calls a function by the name of its symbol.
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `callee` - The name of the symbol of the called function.
+/// * `operands` - The arguments of the call.
+/// * `result_types` - The types of the results of the called function.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn call<'c>(
+    location: &'c LocationRef,
+    callee: &str,
+    operands: &[&ValueRef],
+    result_types: &[&TypeRef],
+) -> Operation<'c> {
+    let callee = FlatSymbolRefAttributeRef::new(location.context(), callee);
+    OperationBuilder::new(CallOpRef::OPERATION_NAME, location)
+        .add_attribute("callee", callee)
+        .add_operands(operands)
+        .add_results(result_types)
+        .build()
+        .expect("func.call operation could not be built.")
+}
+
+/// Builds a new `func.call` operation which calls the provided function, taking the types of the
+/// results from its function type.
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `function` - The called function.
+/// * `operands` - The arguments of the call.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn call_function<'c>(
+    location: &'c LocationRef,
+    function: &FuncOpRef,
+    operands: &[&ValueRef],
+) -> Operation<'c> {
+    let callee = function
+        .symbol_name()
+        .expect("func.func operation has no symbol name.");
+    let result_types = function.function_type().results().collect::<Vec<_>>();
+    call(location, callee.as_str(), operands, &result_types)
+}
+
+/// [FuncOpRef] is a reference to a `func.func` operation, which defines a function (or declares it,
+/// if its body is empty). It dereferences to [crate::ir::OperationRef].
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct FuncOpRef<'c> {
+    _context: PhantomData<&'c ()>,
+}
+
+impl_unowned_mlir_value!(context_ref, FuncOpRef, MlirOperation);
+impl_operation_variant!(FuncOpRef, "func.func");
+
+impl<'c> FuncOpRef<'c> {
+    /// # Returns
+    /// Returns the type of the function, panicking if the operation has no valid `function_type`
+    /// attribute.
+    pub fn function_type(&self) -> &'c FunctionTypeRef {
+        self.attribute("function_type")
+            .and_then(TypeAttributeRef::try_from_attribute)
+            .and_then(|attribute| FunctionTypeRef::try_from_type(attribute.value()))
+            .expect("func.func operation has no valid function_type attribute.")
+    }
+
+    /// # Returns
+    /// Returns the region holding the body of the function.
+    pub fn body(&self) -> &RegionRef<'c> {
+        self.region(0)
+    }
+
+    /// # Returns
+    /// Returns whether the function is a declaration, i.e. it has no body.
+    pub fn is_declaration(&self) -> bool {
+        self.body().first_block().is_none()
+    }
+
+    /// # Returns
+    /// Returns the entry block of the function, if it is not a declaration.
+    pub fn entry_block(&self) -> Option<&BlockRef<'c>> {
+        self.body().first_block()
+    }
+
+    /// Gets the entry block of the function, creating it with one argument per input of the
+    /// function type if the function is a declaration.
+    ///
+    /// # Arguments
+    /// * `loc` - The location of the entry block arguments, if the entry block is created.
+    ///
+    /// # Returns
+    /// Returns a reference to the entry block.
+    pub fn get_or_create_entry_block(&self, loc: &'c LocationRef) -> &BlockRef<'c> {
+        match self.entry_block() {
+            Some(block) => block,
+            None => {
+                let block = Block::for_function_type(self.function_type(), loc);
+                self.body().append_block(block)
+            }
+        }
+    }
+
+    /// Gets the argument at the provided index, panicking if the function is a declaration.
+    ///
+    /// # Arguments
+    /// * `idx` - The index of the argument to get.
+    ///
+    /// # Returns
+    /// Returns a reference to the entry block argument.
+    pub fn argument(&self, idx: isize) -> &ValueRef<'c> {
+        self.entry_block()
+            .expect("Cannot get the arguments of a function declaration.")
+            .argument(idx)
+    }
+
+    /// Inserts a new argument into the signature of the function at the provided index, updating
+    /// its function type, its argument attributes (if it has any), and the arguments of its entry
+    /// block (if it has a body) together.
+    ///
+    /// All checks are performed before the operation is modified, panicking if the entry block
+    /// does not match the function type, or the index is out of bounds (inserting at the end is
+    /// allowed).
+    ///
+    /// # Arguments
+    /// * `idx` - The index to insert the argument at.
+    /// * `ty` - The type of the new argument.
+    /// * `loc` - The location of the new entry block argument.
+    ///
+    /// # Returns
+    /// Returns the new entry block argument, if the function has a body.
+    pub fn insert_argument(
+        &self,
+        idx: isize,
+        ty: &'c TypeRef,
+        loc: &'c LocationRef,
+    ) -> Option<&BlockArgumentRef<'c>> {
+        let (function_type, entry_block) = self.checked_signature();
+        if idx < 0 || idx > function_type.num_inputs() {
+            panic!("Function argument index {} out of bounds.", idx);
+        }
+
+        let mut inputs = function_type.inputs().collect::<Vec<_>>();
+        inputs.insert(idx as usize, ty);
+        self.set_inputs(function_type, &inputs);
+        self.update_arg_attrs(|arg_attrs| {
+            let empty = DictionaryAttributeRef::new(self.context(), &[]);
+            arg_attrs.insert(idx as usize, empty);
+        });
+        entry_block.map(|block| {
+            let argument = block.insert_argument(idx, ty, loc);
+            BlockArgumentRef::try_from_value(argument).unwrap()
+        })
+    }
+
+    /// Removes the argument at the provided index from the signature of the function, updating
+    /// its function type, its argument attributes (if it has any), and the arguments of its entry
+    /// block (if it has a body) together.
+    ///
+    /// The entry block is replaced by a new block with the remaining arguments (see
+    /// `remove_entry_block_argument`), so references to the previous entry block and to its
+    /// arguments must not be used afterwards, and the remaining arguments are located at the
+    /// function.
+    ///
+    /// All checks are performed before the operation is modified, panicking if the entry block
+    /// does not match the function type, the index is out of bounds, or the entry block argument
+    /// being removed still has uses.
+    ///
+    /// # Arguments
+    /// * `idx` - The index of the argument to remove.
+    pub fn remove_argument(&self, idx: isize) {
+        let (function_type, entry_block) = self.checked_signature();
+        if idx < 0 || idx >= function_type.num_inputs() {
+            panic!("Function argument index {} out of bounds.", idx);
+        }
+        if let Some(block) = entry_block {
+            if block.argument(idx).first_use().is_some() {
+                panic!("Function argument {} still has uses.", idx);
+            }
+        }
+
+        let mut inputs = function_type.inputs().collect::<Vec<_>>();
+        inputs.remove(idx as usize);
+        self.set_inputs(function_type, &inputs);
+        self.update_arg_attrs(|arg_attrs| {
+            arg_attrs.remove(idx as usize);
+        });
+        if let Some(block) = entry_block {
+            self.remove_entry_block_argument(block, idx);
+        }
+    }
+
+    /// Gets the function type and entry block of the function, verifying that they are
+    /// consistent with each other.
+    fn checked_signature(&self) -> (&'c FunctionTypeRef, Option<&BlockRef<'c>>) {
+        let function_type = self.function_type();
+        let entry_block = self.entry_block();
+        if let Some(block) = entry_block {
+            let argument_types = block.arguments().map(|argument| argument.r#type());
+            if !argument_types.eq(function_type.inputs()) {
+                panic!("Entry block arguments do not match the function type.");
+            }
+        }
+        (function_type, entry_block)
+    }
+
+    fn set_inputs(&self, function_type: &FunctionTypeRef, inputs: &[&TypeRef]) {
+        let results = function_type.results().collect::<Vec<_>>();
+        let function_type = FunctionTypeRef::new(self.context(), inputs, &results);
+        self.set_inherent_attribute("function_type", TypeAttributeRef::new(function_type));
+    }
+
+    fn update_arg_attrs<'a, F>(&'a self, update: F)
+    where
+        F: FnOnce(&mut Vec<&'a AttributeRef>),
+    {
+        let Some(arg_attrs) = self
+            .inherent_attribute("arg_attrs")
+            .and_then(ArrayAttributeRef::try_from_attribute)
+        else {
+            return;
+        };
+        let mut elements = arg_attrs.iter().collect::<Vec<_>>();
+        update(&mut elements);
+        let arg_attrs = ArrayAttributeRef::new(self.context(), &elements);
+        self.set_inherent_attribute("arg_attrs", arg_attrs);
+    }
+
+    /// Removes an (unused) argument from the entry block. Block arguments cannot be erased through
+    /// the bindings of this crate, so the block is replaced with a new block with the remaining
+    /// arguments, which are located at the function (the C API cannot query the location of a
+    /// value).
+    fn remove_entry_block_argument(&self, block: &BlockRef<'c>, idx: isize) {
+        let arguments = block
+            .arguments()
+            .filter(|argument| argument.arg_number() != idx)
+            .collect::<Vec<_>>();
+        let new_block = Block::from_arguments(
+            arguments
+                .iter()
+                .map(|argument| (argument.r#type(), self.location())),
+        );
+        for (idx, argument) in arguments.iter().enumerate() {
+            argument.replace_all_uses_with(new_block.argument(idx as isize));
+        }
+        while let Some(operation) = block.first_operation() {
+            operation.remove_from_parent();
+            new_block.append_operation(unsafe { Operation::from_raw(operation.to_raw()) });
+        }
+        let region = block.parent_region().unwrap();
+        region.insert_block_before(block, new_block);
+        drop(block.detach().expect("Entry block has no parent region."));
+    }
+}
+
+/// [CallOpRef] is a reference to a `func.call` operation, which calls a function by the name of
+/// its symbol. It dereferences to [crate::ir::OperationRef].
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct CallOpRef<'c> {
+    _context: PhantomData<&'c ()>,
+}
+
+impl_unowned_mlir_value!(context_ref, CallOpRef, MlirOperation);
+impl_operation_variant!(CallOpRef, "func.call");
+
+impl<'c> CallOpRef<'c> {
+    /// # Returns
+    /// Returns the name of the symbol of the called function, panicking if the operation has no
+    /// valid `callee` attribute.
+    pub fn callee(&self) -> StringRef<'c> {
+        self.attribute("callee")
+            .and_then(FlatSymbolRefAttributeRef::try_from_attribute)
+            .expect("func.call operation has no valid callee attribute.")
+            .value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{IntegerTypeRef, Module},
+        Context, DialectRegistry,
+    };
+
+    fn context() -> Context {
+        let dialect_registry = DialectRegistry::default();
+        handle().insert_into_registry(&dialect_registry);
+        let context = Context::new(Some(&dialect_registry), false);
+        context.load_all_available_dialects();
+        context
+    }
+
+    #[test]
+    fn build_function() {
+        let context = context();
+        let loc = LocationRef::new_unknown(&context);
+        let i32_type = IntegerTypeRef::new_signless(&context, 32);
+        let module = Module::new(loc);
+        let function_type = FunctionTypeRef::new(&context, &[i32_type, i32_type], &[i32_type]);
+        let operation = module.body().append_operation(func(
+            loc,
+            "first",
+            function_type,
+            SymbolVisibility::Public,
+        ));
+        let function = FuncOpRef::try_from_operation(operation).unwrap();
+        assert!(function.is_declaration());
+        assert_eq!(function.function_type().as_type(), function_type.as_type());
+
+        let entry_block = function.get_or_create_entry_block(loc);
+        assert_eq!(entry_block.num_arguments(), 2);
+        assert_eq!(function.get_or_create_entry_block(loc), entry_block);
+        entry_block.append_operation(r#return(loc, &[function.argument(0)]));
+        assert!(module.as_operation().verify());
+        assert_eq!(
+            function.to_string(),
+            "func.func @first(%arg0: i32, %arg1: i32) -> i32 {\n  return %arg0 : i32\n}\n"
+        );
+    }
+
+    #[test]
+    fn build_call() {
+        let context = context();
+        let loc = LocationRef::new_unknown(&context);
+        let i32_type = IntegerTypeRef::new_signless(&context, 32);
+        let module = Module::new(loc);
+        let callee_type = FunctionTypeRef::new(&context, &[i32_type], &[i32_type]);
+        let callee = module.body().append_operation(func(
+            loc,
+            "callee",
+            callee_type,
+            SymbolVisibility::Private,
+        ));
+        let callee = FuncOpRef::try_from_operation(callee).unwrap();
+        assert_eq!(callee.visibility(), SymbolVisibility::Private);
+
+        let caller = module.body().append_operation(func(
+            loc,
+            "caller",
+            callee_type,
+            SymbolVisibility::Public,
+        ));
+        let caller = FuncOpRef::try_from_operation(caller).unwrap();
+        let entry_block = caller.get_or_create_entry_block(loc);
+        let call = entry_block.append_operation(call_function(loc, callee, &[caller.argument(0)]));
+        entry_block.append_operation(r#return(loc, &[call.result(0)]));
+        assert!(module.as_operation().verify());
+
+        let call = CallOpRef::try_from_operation(call).unwrap();
+        assert_eq!(call.callee().as_str(), "callee");
+        assert!(FuncOpRef::try_from_operation(call).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_func_op_ref() {
+        let _func_op_ref = FuncOpRef {
+            _context: PhantomData,
+        };
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_call_op_ref() {
+        let _call_op_ref = CallOpRef {
+            _context: PhantomData,
+        };
+    }
+}
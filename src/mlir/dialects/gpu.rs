@@ -0,0 +1,571 @@
+use super::impl_operation_variant;
+use crate::{
+    ir::{
+        symbol_attribute_name, ArrayAttributeRef, AttributeRef, Block, BlockRef,
+        DenseI32AttributeRef, FlatSymbolRefAttributeRef, FunctionTypeRef, GPUObjectAttributeRef,
+        IndexTypeRef, LocationRef, Operation, OperationBuilder, OperationRef, Region,
+        StringAttributeRef, SymbolRefAttributeRef, TypeAttributeRef, ValueRef,
+    },
+    support::binding::{impl_unowned_mlir_value, OwnedMlirValue, UnownedMlirValue},
+    ContextRef, DialectHandle,
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{mlirGetDialectHandle__gpu__, MlirOperation};
+
+/// The name of the unit attribute which must be set on the module holding both the host code and
+/// the `gpu.module` operations referenced by its `gpu.launch_func` operations.
+pub const CONTAINER_MODULE_ATTRIBUTE_NAME: &str = "gpu.container_module";
+
+/// The name of the unit attribute which marks a `gpu.func` operation as a kernel.
+pub const KERNEL_ATTRIBUTE_NAME: &str = "gpu.kernel";
+
+/// # Returns
+/// Returns the handle of the `gpu` dialect, e.g. to register it with a context.
+pub fn handle() -> DialectHandle {
+    unsafe { DialectHandle::from_raw(mlirGetDialectHandle__gpu__()) }
+}
+
+/// [Dimension] is one of the three dimensions of the grid of blocks, or of the threads of a block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dimension {
+    X,
+    Y,
+    Z,
+}
+
+impl Dimension {
+    /// # Returns
+    /// Returns the name of the dimension.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Dimension::X => "x",
+            Dimension::Y => "y",
+            Dimension::Z => "z",
+        }
+    }
+
+    /// Converts the dimension into a `#gpu<dim ...>` attribute. The `gpu` dialect must be loaded
+    /// in the context.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the attribute.
+    ///
+    /// # Returns
+    /// Returns the `#gpu<dim ...>` attribute of the dimension.
+    pub fn to_attribute<'a>(&self, context: &'a ContextRef) -> &'a AttributeRef {
+        AttributeRef::parse(context, &format!("#gpu<dim {}>", self.as_str()))
+            .expect("The gpu dialect is not loaded.")
+    }
+}
+
+/// [IdOp] is an operation of the `gpu` dialect which returns an index (or size) of the current
+/// thread or block along a [Dimension].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdOp {
+    /// The index of the current thread within its block.
+    ThreadId,
+    /// The index of the current block within the grid.
+    BlockId,
+    /// The number of threads of a block.
+    BlockDim,
+    /// The number of blocks of the grid.
+    GridDim,
+}
+
+impl IdOp {
+    /// # Returns
+    /// Returns the fully-qualified name of the operation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IdOp::ThreadId => "gpu.thread_id",
+            IdOp::BlockId => "gpu.block_id",
+            IdOp::BlockDim => "gpu.block_dim",
+            IdOp::GridDim => "gpu.grid_dim",
+        }
+    }
+}
+
+/// Builds a new `gpu.module` operation with an empty body, holding the kernels which are compiled
+/// for the device. The body is terminated by a `gpu.module_end` operation, so operations should be
+/// added with [GPUModuleOpRef::append_operation].
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `name` - The name of the symbol defined by the module.
+/// * `targets` - The target attributes (e.g. `#nvvm.target`) the module should be compiled for,
+///   which may be empty if the targets are attached later.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn module<'c>(
+    location: &'c LocationRef,
+    name: &str,
+    targets: &[&AttributeRef],
+) -> Operation<'c> {
+    let context = location.context();
+    let region = Region::new(context);
+    {
+        let block = region.append_block(Block::new(&[]));
+        block.append_operation(
+            OperationBuilder::new("gpu.module_end", location)
+                .build()
+                .expect("gpu.module_end operation could not be built."),
+        );
+    }
+    let mut builder = OperationBuilder::new(GPUModuleOpRef::OPERATION_NAME, location)
+        .add_attribute(
+            symbol_attribute_name(),
+            StringAttributeRef::new(context, name),
+        )
+        .add_regions(vec![region]);
+    if !targets.is_empty() {
+        builder = builder.add_attribute("targets", ArrayAttributeRef::new(context, targets));
+    }
+    builder
+        .build()
+        .expect("gpu.module operation could not be built.")
+}
+
+/// Builds a new `gpu.func` operation, whose entry block is created with one argument per input of
+/// the function type.
+///
+/// # Arguments
+/// * `location` - The location of the operation and of the entry block arguments.
+/// * `name` - The name of the symbol defined by the function.
+/// * `function_type` - The type of the function, which must have no results if it is a kernel.
+/// * `kernel` - Whether the function is a kernel, which can be launched from the host.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn func<'c>(
+    location: &'c LocationRef,
+    name: &str,
+    function_type: &'c FunctionTypeRef,
+    kernel: bool,
+) -> Operation<'c> {
+    let context = location.context();
+    let region = Region::new(context);
+    region.append_block(Block::for_function_type(function_type, location));
+    let mut builder = OperationBuilder::new(GPUFuncOpRef::OPERATION_NAME, location)
+        .add_attribute(
+            symbol_attribute_name(),
+            StringAttributeRef::new(context, name),
+        )
+        .add_attribute("function_type", TypeAttributeRef::new(function_type))
+        .add_regions(vec![region]);
+    if kernel {
+        builder = builder.add_attribute(KERNEL_ATTRIBUTE_NAME, AttributeRef::unit(context));
+    }
+    builder
+        .build()
+        .expect("gpu.func operation could not be built.")
+}
+
+/// Builds a new `gpu.return` operation, which terminates the body of a `gpu.func` operation.
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `operands` - The values returned by the function, which must be empty for kernels.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn r#return<'c>(location: &'c LocationRef, operands: &[&ValueRef]) -> Operation<'c> {
+    OperationBuilder::new("gpu.return", location)
+        .add_operands(operands)
+        .build()
+        .expect("gpu.return operation could not be built.")
+}
+
+/// Builds a new operation returning an index (or size) of the current thread or block along the
+/// provided dimension.
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `op` - The operation to build.
+/// * `dimension` - The dimension along which the index (or size) is returned.
+///
+/// # Returns
+/// Returns the new operation, whose only result has the `index` type.
+pub fn id<'c>(location: &'c LocationRef, op: IdOp, dimension: Dimension) -> Operation<'c> {
+    let context = location.context();
+    OperationBuilder::new(op.as_str(), location)
+        .add_attribute("dimension", dimension.to_attribute(context))
+        .add_results(&[IndexTypeRef::new(context)])
+        .build()
+        .unwrap_or_else(|_| panic!("{} operation could not be built.", op.as_str()))
+}
+
+/// Builds a new synchronous `gpu.launch_func` operation, which launches a kernel on a grid of
+/// blocks of threads. The operation must be nested in a module with the
+/// [CONTAINER_MODULE_ATTRIBUTE_NAME] attribute, which also holds the `gpu.module` of the kernel.
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `module` - The name of the symbol of the `gpu.module` operation holding the kernel.
+/// * `kernel` - The name of the symbol of the kernel within the `gpu.module` operation.
+/// * `grid_size` - The number of blocks along each dimension, as `index` (or `i32`/`i64`) values.
+/// * `block_size` - The number of threads of each block along each dimension, of the same type.
+/// * `dynamic_shared_memory_size` - The `i32` number of bytes of dynamic shared memory of each
+///   block, if any.
+/// * `operands` - The arguments of the kernel.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn launch_func<'c>(
+    location: &'c LocationRef,
+    module: &str,
+    kernel: &str,
+    grid_size: [&ValueRef; 3],
+    block_size: [&ValueRef; 3],
+    dynamic_shared_memory_size: Option<&ValueRef>,
+    operands: &[&ValueRef],
+) -> Operation<'c> {
+    let context = location.context();
+    let kernel = SymbolRefAttributeRef::new(
+        context,
+        module,
+        &[FlatSymbolRefAttributeRef::new(context, kernel)],
+    );
+    // The operand segments are: the async dependencies, the grid and block sizes, the (optional)
+    // cluster sizes, the (optional) dynamic shared memory size, the kernel operands, and the
+    // (optional) async object.
+    let segment_sizes = [
+        0,
+        1,
+        1,
+        1,
+        1,
+        1,
+        1,
+        0,
+        0,
+        0,
+        dynamic_shared_memory_size.is_some() as i32,
+        operands.len() as i32,
+        0,
+    ];
+    let all_operands = grid_size
+        .into_iter()
+        .chain(block_size)
+        .chain(dynamic_shared_memory_size)
+        .chain(operands.iter().copied())
+        .collect::<Vec<_>>();
+    OperationBuilder::new(LaunchFuncOpRef::OPERATION_NAME, location)
+        .add_attribute("kernel", kernel)
+        .add_attribute(
+            "operandSegmentSizes",
+            DenseI32AttributeRef::new(context, &segment_sizes),
+        )
+        .add_operands(&all_operands)
+        .build()
+        .expect("gpu.launch_func operation could not be built.")
+}
+
+/// Builds a new `gpu.binary` operation, which holds the objects resulting from compiling a
+/// `gpu.module` operation, and replaces it as the target of `gpu.launch_func` operations.
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `name` - The name of the symbol defined by the binary.
+/// * `offloading_handler` - The attribute selecting the object to embed and launch on the host
+///   (e.g. [select_object]), or `None` for the default handler.
+/// * `objects` - The objects of the binary, which must not be empty.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn binary<'c>(
+    location: &'c LocationRef,
+    name: &str,
+    offloading_handler: Option<&AttributeRef>,
+    objects: &[&GPUObjectAttributeRef],
+) -> Operation<'c> {
+    let context = location.context();
+    let objects = objects
+        .iter()
+        .map(|object| object.as_attribute())
+        .collect::<Vec<_>>();
+    let mut builder = OperationBuilder::new("gpu.binary", location)
+        .add_attribute(
+            symbol_attribute_name(),
+            StringAttributeRef::new(context, name),
+        )
+        .add_attribute("objects", ArrayAttributeRef::new(context, &objects));
+    if let Some(offloading_handler) = offloading_handler {
+        builder = builder.add_attribute("offloadingHandler", offloading_handler);
+    }
+    builder
+        .build()
+        .expect("gpu.binary operation could not be built.")
+}
+
+/// Constructs a `#gpu.select_object` offloading handler attribute, which embeds a single object of
+/// a `gpu.binary` operation in the host code. The `gpu` dialect must be loaded in the context.
+///
+/// # Arguments
+/// * `context` - The context that should own the attribute.
+/// * `target` - The index of the object to select as an integer attribute, or the target attribute
+///   of the object to select, or `None` to select the first object.
+///
+/// # Returns
+/// Returns the `#gpu.select_object` attribute.
+pub fn select_object<'a>(
+    context: &'a ContextRef,
+    target: Option<&AttributeRef>,
+) -> &'a AttributeRef {
+    let source = match target {
+        Some(target) => format!("#gpu.select_object<{}>", target),
+        None => "#gpu.select_object".to_string(),
+    };
+    AttributeRef::parse(context, &source).expect("The gpu dialect is not loaded.")
+}
+
+/// [GPUModuleOpRef] is a reference to a `gpu.module` operation, which holds the kernels which are
+/// compiled for the device. It dereferences to [crate::ir::OperationRef].
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct GPUModuleOpRef<'c> {
+    _context: PhantomData<&'c ()>,
+}
+
+impl_unowned_mlir_value!(context_ref, GPUModuleOpRef, MlirOperation);
+impl_operation_variant!(GPUModuleOpRef, "gpu.module");
+
+impl<'c> GPUModuleOpRef<'c> {
+    /// # Returns
+    /// Returns the block holding the body of the module, panicking if it has none.
+    pub fn body(&self) -> &BlockRef<'c> {
+        self.region(0)
+            .first_block()
+            .expect("gpu.module operation has no body.")
+    }
+
+    /// Appends the provided operation to the body of the module, before its terminator.
+    ///
+    /// # Arguments
+    /// * `operation` - The operation to append.
+    ///
+    /// # Returns
+    /// Returns a reference to the appended operation.
+    pub fn append_operation(&self, operation: Operation<'c>) -> &OperationRef<'c> {
+        let body = self.body();
+        match body.terminator() {
+            Some(terminator) => body.insert_operation_before(terminator, operation),
+            None => body.append_operation(operation),
+        }
+    }
+}
+
+/// [GPUFuncOpRef] is a reference to a `gpu.func` operation, which defines a function executed on
+/// the device, e.g. a kernel. It dereferences to [crate::ir::OperationRef].
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct GPUFuncOpRef<'c> {
+    _context: PhantomData<&'c ()>,
+}
+
+impl_unowned_mlir_value!(context_ref, GPUFuncOpRef, MlirOperation);
+impl_operation_variant!(GPUFuncOpRef, "gpu.func");
+
+impl<'c> GPUFuncOpRef<'c> {
+    /// # Returns
+    /// Returns the type of the function, panicking if the operation has no valid `function_type`
+    /// attribute.
+    pub fn function_type(&self) -> &'c FunctionTypeRef {
+        self.attribute("function_type")
+            .and_then(TypeAttributeRef::try_from_attribute)
+            .and_then(|attribute| FunctionTypeRef::try_from_type(attribute.value()))
+            .expect("gpu.func operation has no valid function_type attribute.")
+    }
+
+    /// # Returns
+    /// Returns whether the function is a kernel.
+    pub fn is_kernel(&self) -> bool {
+        self.attribute(KERNEL_ATTRIBUTE_NAME).is_some()
+    }
+
+    /// # Returns
+    /// Returns the entry block of the function, panicking if it has none.
+    pub fn entry_block(&self) -> &BlockRef<'c> {
+        self.region(0)
+            .first_block()
+            .expect("gpu.func operation has no entry block.")
+    }
+
+    /// Gets the argument at the provided index.
+    ///
+    /// # Arguments
+    /// * `idx` - The index of the argument to get.
+    ///
+    /// # Returns
+    /// Returns a reference to the entry block argument.
+    pub fn argument(&self, idx: isize) -> &ValueRef<'c> {
+        self.entry_block().argument(idx)
+    }
+}
+
+/// [LaunchFuncOpRef] is a reference to a `gpu.launch_func` operation, which launches a kernel on
+/// a grid of blocks of threads. It dereferences to [crate::ir::OperationRef].
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct LaunchFuncOpRef<'c> {
+    _context: PhantomData<&'c ()>,
+}
+
+impl_unowned_mlir_value!(context_ref, LaunchFuncOpRef, MlirOperation);
+impl_operation_variant!(LaunchFuncOpRef, "gpu.launch_func");
+
+impl<'c> LaunchFuncOpRef<'c> {
+    /// # Returns
+    /// Returns the reference to the launched kernel, nested in its `gpu.module` (or `gpu.binary`)
+    /// operation, panicking if the operation has no valid `kernel` attribute.
+    pub fn kernel(&self) -> &'c SymbolRefAttributeRef {
+        self.attribute("kernel")
+            .and_then(SymbolRefAttributeRef::try_from_attribute)
+            .expect("gpu.launch_func operation has no valid kernel attribute.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dialects::{arith, func, nvvm},
+        ir::{CompilationTarget, Module, SymbolVisibility},
+        Context, DialectRegistry,
+    };
+
+    fn context() -> Context {
+        // mlir-sys binds no handle of the `nvvm` dialect, which is only registered with all others.
+        let dialect_registry = DialectRegistry::default();
+        dialect_registry.register_all_dialects();
+        let context = Context::new(Some(&dialect_registry), false);
+        context.load_all_available_dialects();
+        context
+    }
+
+    #[test]
+    fn build_kernel() {
+        let context = context();
+        let loc = LocationRef::new_unknown(&context);
+        let index_type = IndexTypeRef::new(&context);
+        let module = Module::new(loc);
+        let module_op = module.as_operation();
+        module_op.set_attribute(
+            CONTAINER_MODULE_ATTRIBUTE_NAME,
+            AttributeRef::unit(&context),
+        );
+
+        let target = nvvm::target(&context, "sm_70", "+ptx60", 2);
+        let gpu_module = module
+            .body()
+            .append_operation(super::module(loc, "kernels", &[target]));
+        let gpu_module = GPUModuleOpRef::try_from_operation(gpu_module).unwrap();
+        let kernel_type = FunctionTypeRef::new(&context, &[index_type], &[]);
+        let kernel = gpu_module.append_operation(func(loc, "kernel", kernel_type, true));
+        let kernel = GPUFuncOpRef::try_from_operation(kernel).unwrap();
+        assert!(kernel.is_kernel());
+        assert_eq!(kernel.function_type().as_type(), kernel_type.as_type());
+        let entry_block = kernel.entry_block();
+        let thread_id = entry_block.append_operation(id(loc, IdOp::ThreadId, Dimension::X));
+        assert_eq!(thread_id.result(0).r#type(), index_type.as_type());
+        entry_block.append_operation(id(loc, IdOp::BlockDim, Dimension::Y));
+        entry_block.append_operation(r#return(loc, &[]));
+
+        let host_type = FunctionTypeRef::new(&context, &[index_type], &[]);
+        let host = module.body().append_operation(func::func(
+            loc,
+            "host",
+            host_type,
+            SymbolVisibility::Public,
+        ));
+        let host = func::FuncOpRef::try_from_operation(host).unwrap();
+        let host_block = host.get_or_create_entry_block(loc);
+        let one = host_block.append_operation(arith::constant_index(loc, 1));
+        let one = one.result(0);
+        let launch = host_block.append_operation(launch_func(
+            loc,
+            "kernels",
+            "kernel",
+            [one, one, one],
+            [one, one, one],
+            None,
+            &[host.argument(0)],
+        ));
+        host_block.append_operation(func::r#return(loc, &[]));
+        assert!(module_op.verify());
+
+        let launch = LaunchFuncOpRef::try_from_operation(launch).unwrap();
+        assert_eq!(
+            launch.kernel().as_attribute(),
+            AttributeRef::parse(&context, "@kernels::@kernel").unwrap()
+        );
+        assert_eq!(launch.num_operands(), 7);
+    }
+
+    #[test]
+    fn build_binary() {
+        let context = context();
+        let loc = LocationRef::new_unknown(&context);
+        let module = Module::new(loc);
+        let target = nvvm::target(&context, "sm_70", "+ptx60", 2);
+        let object =
+            GPUObjectAttributeRef::new(&context, target, CompilationTarget::Assembly, b"ptx", None);
+        let handler = select_object(&context, Some(target));
+        let operation =
+            module
+                .body()
+                .append_operation(binary(loc, "kernels", Some(handler), &[object]));
+        assert!(module.as_operation().verify());
+        assert_eq!(operation.attribute("offloadingHandler"), Some(handler));
+        assert!(select_object(&context, None)
+            .to_string()
+            .starts_with("#gpu.select_object"));
+    }
+
+    #[test]
+    fn dimension_attribute() {
+        let context = context();
+        assert_eq!(
+            Dimension::Z.to_attribute(&context),
+            AttributeRef::parse(&context, "#gpu<dim z>").unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_gpu_module_op_ref() {
+        let _gpu_module_op_ref = GPUModuleOpRef {
+            _context: PhantomData,
+        };
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_gpu_func_op_ref() {
+        let _gpu_func_op_ref = GPUFuncOpRef {
+            _context: PhantomData,
+        };
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_launch_func_op_ref() {
+        let _launch_func_op_ref = LaunchFuncOpRef {
+            _context: PhantomData,
+        };
+    }
+}
@@ -0,0 +1,373 @@
+use super::arith::{with_fastmath, FastMathFlags};
+use crate::{
+    ir::{LocationRef, Operation, OperationBuilder, ValueRef},
+    support::binding::OwnedMlirValue,
+    DialectHandle,
+};
+
+use mlir_sys::mlirGetDialectHandle__math__;
+
+/// # Returns
+/// Returns the handle of the `math` dialect, e.g. to register it with a context.
+pub fn handle() -> DialectHandle {
+    unsafe { DialectHandle::from_raw(mlirGetDialectHandle__math__()) }
+}
+
+/// [UnaryOp] is an elementwise unary operation of the `math` dialect on floating-point values,
+/// whose operand and result have the same type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnaryOp {
+    /// Absolute value.
+    AbsF,
+    /// Arcus tangent.
+    Atan,
+    /// Cubic root.
+    Cbrt,
+    /// Rounding up to an integer value.
+    Ceil,
+    /// Cosine.
+    Cos,
+    /// Error function.
+    Erf,
+    /// Base-e exponential.
+    Exp,
+    /// Base-2 exponential.
+    Exp2,
+    /// Base-e exponential minus one.
+    ExpM1,
+    /// Rounding down to an integer value.
+    Floor,
+    /// Natural logarithm.
+    Log,
+    /// Base-10 logarithm.
+    Log10,
+    /// Natural logarithm of one plus the operand.
+    Log1p,
+    /// Base-2 logarithm.
+    Log2,
+    /// Rounding to the nearest integer value, with ties away from zero.
+    Round,
+    /// Rounding to the nearest integer value, with ties to even.
+    RoundEven,
+    /// Reciprocal of the square root.
+    Rsqrt,
+    /// Sine.
+    Sin,
+    /// Square root.
+    Sqrt,
+    /// Tangent.
+    Tan,
+    /// Hyperbolic tangent.
+    Tanh,
+    /// Rounding towards zero to an integer value.
+    Trunc,
+}
+
+impl UnaryOp {
+    /// # Returns
+    /// Returns the fully-qualified name of the operation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UnaryOp::AbsF => "math.absf",
+            UnaryOp::Atan => "math.atan",
+            UnaryOp::Cbrt => "math.cbrt",
+            UnaryOp::Ceil => "math.ceil",
+            UnaryOp::Cos => "math.cos",
+            UnaryOp::Erf => "math.erf",
+            UnaryOp::Exp => "math.exp",
+            UnaryOp::Exp2 => "math.exp2",
+            UnaryOp::ExpM1 => "math.expm1",
+            UnaryOp::Floor => "math.floor",
+            UnaryOp::Log => "math.log",
+            UnaryOp::Log10 => "math.log10",
+            UnaryOp::Log1p => "math.log1p",
+            UnaryOp::Log2 => "math.log2",
+            UnaryOp::Round => "math.round",
+            UnaryOp::RoundEven => "math.roundeven",
+            UnaryOp::Rsqrt => "math.rsqrt",
+            UnaryOp::Sin => "math.sin",
+            UnaryOp::Sqrt => "math.sqrt",
+            UnaryOp::Tan => "math.tan",
+            UnaryOp::Tanh => "math.tanh",
+            UnaryOp::Trunc => "math.trunc",
+        }
+    }
+}
+
+/// [BinaryOp] is an elementwise binary operation of the `math` dialect on floating-point values,
+/// whose operands and result have the same type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    /// Arcus tangent of the quotient of the operands, using their signs to pick the quadrant.
+    Atan2,
+    /// The magnitude of the left-hand side with the sign of the right-hand side.
+    CopySign,
+    /// The left-hand side raised to the power of the right-hand side.
+    PowF,
+}
+
+impl BinaryOp {
+    /// # Returns
+    /// Returns the fully-qualified name of the operation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BinaryOp::Atan2 => "math.atan2",
+            BinaryOp::CopySign => "math.copysign",
+            BinaryOp::PowF => "math.powf",
+        }
+    }
+}
+
+/// [IntegerUnaryOp] is an elementwise unary operation of the `math` dialect on signless integers,
+/// whose operand and result have the same type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegerUnaryOp {
+    /// Absolute value.
+    AbsI,
+    /// Number of leading zero bits.
+    CountLeadingZeros,
+    /// Number of set bits.
+    CountOnes,
+    /// Number of trailing zero bits.
+    CountTrailingZeros,
+}
+
+impl IntegerUnaryOp {
+    /// # Returns
+    /// Returns the fully-qualified name of the operation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IntegerUnaryOp::AbsI => "math.absi",
+            IntegerUnaryOp::CountLeadingZeros => "math.ctlz",
+            IntegerUnaryOp::CountOnes => "math.ctpop",
+            IntegerUnaryOp::CountTrailingZeros => "math.cttz",
+        }
+    }
+}
+
+/// Builds a new elementwise unary operation on floating-point values.
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `op` - The operation to build.
+/// * `operand` - The operand of the operation, a float or a vector or tensor of floats.
+/// * `fastmath` - The fast-math flags of the operation.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn unary<'c>(
+    location: &'c LocationRef,
+    op: UnaryOp,
+    operand: &ValueRef,
+    fastmath: FastMathFlags,
+) -> Operation<'c> {
+    elementwise(location, op.as_str(), &[operand], Some(fastmath))
+}
+
+/// Builds a new elementwise binary operation on floating-point values.
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `op` - The operation to build.
+/// * `lhs` - The left-hand side of the operation.
+/// * `rhs` - The right-hand side of the operation, which must have the same type.
+/// * `fastmath` - The fast-math flags of the operation.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn binary<'c>(
+    location: &'c LocationRef,
+    op: BinaryOp,
+    lhs: &ValueRef,
+    rhs: &ValueRef,
+    fastmath: FastMathFlags,
+) -> Operation<'c> {
+    elementwise(location, op.as_str(), &[lhs, rhs], Some(fastmath))
+}
+
+/// Builds a new `math.fma` operation, which computes `a * b + c` with a single rounding.
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `a` - The first factor.
+/// * `b` - The second factor, which must have the same type.
+/// * `c` - The addend, which must have the same type.
+/// * `fastmath` - The fast-math flags of the operation.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn fma<'c>(
+    location: &'c LocationRef,
+    a: &ValueRef,
+    b: &ValueRef,
+    c: &ValueRef,
+    fastmath: FastMathFlags,
+) -> Operation<'c> {
+    elementwise(location, "math.fma", &[a, b, c], Some(fastmath))
+}
+
+/// Builds a new `math.fpowi` operation, which raises a floating-point value to an integer power.
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `base` - The floating-point base, whose type is the type of the result.
+/// * `exponent` - The integer exponent, with the same shape as the base.
+/// * `fastmath` - The fast-math flags of the operation.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn fpowi<'c>(
+    location: &'c LocationRef,
+    base: &ValueRef,
+    exponent: &ValueRef,
+    fastmath: FastMathFlags,
+) -> Operation<'c> {
+    elementwise(location, "math.fpowi", &[base, exponent], Some(fastmath))
+}
+
+/// Builds a new elementwise unary operation on integers.
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `op` - The operation to build.
+/// * `operand` - The operand of the operation, an integer or a vector or tensor of integers.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn integer_unary<'c>(
+    location: &'c LocationRef,
+    op: IntegerUnaryOp,
+    operand: &ValueRef,
+) -> Operation<'c> {
+    elementwise(location, op.as_str(), &[operand], None)
+}
+
+/// Builds a new `math.ipowi` operation, which raises an integer to an integer power.
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `base` - The base.
+/// * `exponent` - The exponent, which must have the same type.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn ipowi<'c>(location: &'c LocationRef, base: &ValueRef, exponent: &ValueRef) -> Operation<'c> {
+    elementwise(location, "math.ipowi", &[base, exponent], None)
+}
+
+/// Builds an elementwise operation whose result has the type of its first operand, adding the
+/// `fastmath` attribute if the operation is on floating-point values.
+fn elementwise<'c>(
+    location: &'c LocationRef,
+    name: &str,
+    operands: &[&ValueRef],
+    fastmath: Option<FastMathFlags>,
+) -> Operation<'c> {
+    let mut builder = OperationBuilder::new(name, location);
+    if let Some(fastmath) = fastmath {
+        builder = with_fastmath(builder, location, fastmath);
+    }
+    builder
+        .add_operands(operands)
+        .add_results(&[operands[0].r#type()])
+        .build()
+        .unwrap_or_else(|_| panic!("{} operation could not be built.", name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dialects::func::{self, FuncOpRef},
+        ir::{Block, FloatTypeRef, FunctionTypeRef, IntegerTypeRef, Module, SymbolVisibility},
+        pass::{Pass, PassManager},
+        Context, DialectRegistry, ExecutionEngine,
+    };
+
+    fn context() -> Context {
+        let dialect_registry = DialectRegistry::default();
+        dialect_registry.register_all_dialects();
+        let context = Context::new(Some(&dialect_registry), false);
+        context.load_all_available_dialects();
+        context
+    }
+
+    /// Builds a function computing `fma(sqrt(x), powf(x, y), x)`.
+    fn build_module(context: &Context) -> Module {
+        let loc = LocationRef::new_unknown(context);
+        let f64_type = FloatTypeRef::new_f64(context);
+        let module = Module::new(loc);
+        let function_type = FunctionTypeRef::new(context, &[f64_type, f64_type], &[f64_type]);
+        let function = module.body().append_operation(func::func(
+            loc,
+            "kernel",
+            function_type,
+            SymbolVisibility::Public,
+        ));
+        let function = FuncOpRef::try_from_operation(function).unwrap();
+        let block = function.get_or_create_entry_block(loc);
+        let (x, y) = (function.argument(0), function.argument(1));
+
+        let root = block.append_operation(unary(loc, UnaryOp::Sqrt, x, FastMathFlags::NONE));
+        let power = block.append_operation(binary(loc, BinaryOp::PowF, x, y, FastMathFlags::FAST));
+        let sum = block.append_operation(fma(
+            loc,
+            root.result(0),
+            power.result(0),
+            x,
+            FastMathFlags::NONE,
+        ));
+        block.append_operation(func::r#return(loc, &[sum.result(0)]));
+        module
+    }
+
+    #[test]
+    fn build_elementwise() {
+        let context = context();
+        let module = build_module(&context);
+        assert!(module.as_operation().verify());
+        let printed = module.to_string();
+        assert!(printed.contains("math.powf %arg0, %arg1 fastmath<fast> : f64"));
+        assert!(printed.contains("math.fma"));
+    }
+
+    #[test]
+    fn build_integer_elementwise() {
+        let context = context();
+        let loc = LocationRef::new_unknown(&context);
+        let f32_type = FloatTypeRef::new_f32(&context);
+        let i32_type = IntegerTypeRef::new_signless(&context, 32);
+        let block = Block::new(&[(f32_type, loc), (i32_type, loc)]);
+        let (x, n) = (block.argument(0), block.argument(1));
+        let ones = block.append_operation(integer_unary(loc, IntegerUnaryOp::CountOnes, n));
+        let power = block.append_operation(ipowi(loc, n, ones.result(0)));
+        assert_eq!(power.result(0).r#type(), i32_type.as_type());
+        let scaled = block.append_operation(fpowi(loc, x, power.result(0), FastMathFlags::NONE));
+        assert_eq!(scaled.result(0).r#type(), f32_type.as_type());
+        assert!(scaled.verify());
+    }
+
+    #[test]
+    fn run_elementwise() {
+        let context = context();
+        let module = build_module(&context);
+        let pass_manager = PassManager::new(&context);
+        pass_manager.add_owned_pass(Pass::math_to_llvm());
+        pass_manager.add_owned_pass(Pass::arith_to_llvm());
+        pass_manager.add_owned_pass(Pass::func_to_llvm());
+        pass_manager.add_owned_pass(Pass::reconcile_unrealized_casts());
+        pass_manager.run(module.as_operation()).unwrap();
+        let engine = ExecutionEngine::new(&module, 0, &[], false).unwrap();
+
+        // sqrt(4) * 4 ** 0.5 + 4 = 2 * 2 + 4 = 8
+        let result: f64 = unsafe { engine.invoke("kernel", (4f64, 0.5f64)) }.unwrap();
+        assert_eq!(result, 8.0);
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(UnaryOp::RoundEven.as_str(), "math.roundeven");
+        assert_eq!(BinaryOp::CopySign.as_str(), "math.copysign");
+        assert_eq!(IntegerUnaryOp::CountTrailingZeros.as_str(), "math.cttz");
+    }
+}
@@ -0,0 +1,22 @@
+use crate::{support::binding::OwnedMlirValue, DialectHandle};
+
+use mlir_sys::mlirGetDialectHandle__nvgpu__;
+
+/// # Returns
+/// Returns the handle of the `nvgpu` dialect, e.g. to register it with a context.
+pub fn handle() -> DialectHandle {
+    unsafe { DialectHandle::from_raw(mlirGetDialectHandle__nvgpu__()) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Context;
+
+    #[test]
+    fn load_dialect() {
+        let context = Context::new(None, false);
+        let dialect = handle().load_into_context(&context);
+        assert_eq!(dialect.namespace().as_str(), "nvgpu");
+    }
+}
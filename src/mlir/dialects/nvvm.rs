@@ -0,0 +1,49 @@
+use crate::{ir::AttributeRef, ContextRef};
+
+/// Constructs a `#nvvm.target` attribute, which describes how a `gpu.module` operation is
+/// compiled for an NVIDIA GPU (see [super::gpu::module]). The `nvvm` dialect must be loaded in the
+/// context. The C API has no handle of the dialect, so it is only registered by
+/// [crate::DialectRegistry::register_all_dialects].
+///
+/// # Arguments
+/// * `context` - The context that should own the attribute.
+/// * `chip` - The target architecture, e.g. `sm_70`.
+/// * `features` - The target features, e.g. `+ptx60`.
+/// * `opt_level` - The optimization level, from 0 to 3.
+///
+/// # Returns
+/// Returns the `#nvvm.target` attribute.
+pub fn target<'a>(
+    context: &'a ContextRef,
+    chip: &str,
+    features: &str,
+    opt_level: u32,
+) -> &'a AttributeRef {
+    let source = format!(
+        "#nvvm.target<O = {}, chip = \"{}\", features = \"{}\">",
+        opt_level, chip, features
+    );
+    AttributeRef::parse(context, &source).expect("The nvvm dialect is not loaded.")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Context, DialectRegistry};
+
+    #[test]
+    fn target_attribute() {
+        let dialect_registry = DialectRegistry::default();
+        dialect_registry.register_all_dialects();
+        let context = Context::new(Some(&dialect_registry), false);
+        context.load_all_available_dialects();
+        assert_eq!(
+            target(&context, "sm_70", "+ptx60", 3),
+            AttributeRef::parse(
+                &context,
+                "#nvvm.target<O = 3, chip = \"sm_70\", features = \"+ptx60\">"
+            )
+            .unwrap()
+        );
+    }
+}
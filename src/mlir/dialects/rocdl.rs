@@ -0,0 +1,61 @@
+use crate::{ir::AttributeRef, support::binding::OwnedMlirValue, ContextRef, DialectHandle};
+
+use mlir_sys::mlirGetDialectHandle__rocdl__;
+
+/// # Returns
+/// Returns the handle of the `rocdl` dialect, e.g. to register it with a context.
+pub fn handle() -> DialectHandle {
+    unsafe { DialectHandle::from_raw(mlirGetDialectHandle__rocdl__()) }
+}
+
+/// Constructs a `#rocdl.target` attribute, which describes how a `gpu.module` operation is
+/// compiled for an AMD GPU (see [super::gpu::module]). The `rocdl` dialect must be loaded in the
+/// context.
+///
+/// # Arguments
+/// * `context` - The context that should own the attribute.
+/// * `chip` - The target architecture, e.g. `gfx90a`.
+/// * `features` - The target features, e.g. `+sramecc,-xnack`.
+/// * `opt_level` - The optimization level, from 0 to 3.
+///
+/// # Returns
+/// Returns the `#rocdl.target` attribute.
+pub fn target<'a>(
+    context: &'a ContextRef,
+    chip: &str,
+    features: &str,
+    opt_level: u32,
+) -> &'a AttributeRef {
+    let source = format!(
+        "#rocdl.target<O = {}, chip = \"{}\", features = \"{}\">",
+        opt_level, chip, features
+    );
+    AttributeRef::parse(context, &source).expect("The rocdl dialect is not loaded.")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Context;
+
+    #[test]
+    fn load_dialect() {
+        let context = Context::new(None, false);
+        let dialect = handle().load_into_context(&context);
+        assert_eq!(dialect.namespace().as_str(), "rocdl");
+    }
+
+    #[test]
+    fn target_attribute() {
+        let context = Context::new(None, false);
+        handle().load_into_context(&context);
+        assert_eq!(
+            target(&context, "gfx90a", "+sramecc,-xnack", 3),
+            AttributeRef::parse(
+                &context,
+                "#rocdl.target<O = 3, chip = \"gfx90a\", features = \"+sramecc,-xnack\">"
+            )
+            .unwrap()
+        );
+    }
+}
@@ -0,0 +1,396 @@
+use crate::{
+    ir::{Block, BlockRef, LocationRef, Operation, OperationBuilder, Region, TypeRef, ValueRef},
+    support::binding::OwnedMlirValue,
+    DialectHandle,
+};
+
+use mlir_sys::mlirGetDialectHandle__scf__;
+
+/// # Returns
+/// Returns the handle of the `scf` dialect, e.g. to register it with a context.
+pub fn handle() -> DialectHandle {
+    unsafe { DialectHandle::from_raw(mlirGetDialectHandle__scf__()) }
+}
+
+/// Builds a new `scf.for` operation, which iterates an induction variable from a lower bound
+/// (inclusive) to an upper bound (exclusive) by a step, carrying loop-carried values (the
+/// `iter_args`) from each iteration to the next. The results of the operation are the values
+/// carried out of the last iteration (or the initial values, if there is no iteration).
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `lower_bound` - The lower bound of the induction variable.
+/// * `upper_bound` - The upper bound of the induction variable, of the same type.
+/// * `step` - The step of the induction variable, of the same type.
+/// * `init_args` - The initial values of the loop-carried values.
+/// * `body` - Populates the body of the loop, given its block, the induction variable, and the
+///   loop-carried values. Returns the values carried to the next iteration, one per initial value,
+///   which are yielded by the `scf.yield` operation appended after the body.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn r#for<'c, F>(
+    location: &'c LocationRef,
+    lower_bound: &ValueRef<'c>,
+    upper_bound: &ValueRef<'c>,
+    step: &ValueRef<'c>,
+    init_args: &[&ValueRef<'c>],
+    body: F,
+) -> Operation<'c>
+where
+    F: for<'a> FnOnce(
+        &'a BlockRef<'c>,
+        &'a ValueRef<'c>,
+        &[&'a ValueRef<'c>],
+    ) -> Vec<&'a ValueRef<'c>>,
+{
+    let types = init_args
+        .iter()
+        .map(|value| value.r#type())
+        .collect::<Vec<_>>();
+    let region = Region::new(location.context());
+    {
+        let argument_types = std::iter::once(lower_bound.r#type()).chain(types.iter().copied());
+        let block = region.append_block(Block::from_arguments(
+            argument_types.map(|ty| (ty, location)),
+        ));
+        let arguments = (1..block.num_arguments())
+            .map(|idx| block.argument(idx))
+            .collect::<Vec<_>>();
+        let yielded = body(block, block.argument(0), &arguments);
+        block.append_operation(r#yield(location, &yielded));
+    }
+    let operands = [lower_bound, upper_bound, step]
+        .into_iter()
+        .chain(init_args.iter().copied())
+        .collect::<Vec<_>>();
+    OperationBuilder::new("scf.for", location)
+        .add_operands(&operands)
+        .add_results(&types)
+        .add_regions(vec![region])
+        .build()
+        .expect("scf.for operation could not be built.")
+}
+
+/// Builds a new `scf.if` operation with both a `then` and an `else` branch, whose results are the
+/// values yielded by the branch which is taken.
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `condition` - The `i1` condition.
+/// * `result_types` - The types of the results of the operation.
+/// * `then_body` - Populates the block of the `then` branch, returning the values it yields.
+/// * `else_body` - Populates the block of the `else` branch, returning the values it yields.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn r#if<'c, F, G>(
+    location: &'c LocationRef,
+    condition: &ValueRef<'c>,
+    result_types: &[&TypeRef],
+    then_body: F,
+    else_body: G,
+) -> Operation<'c>
+where
+    F: for<'a> FnOnce(&'a BlockRef<'c>) -> Vec<&'a ValueRef<'c>>,
+    G: for<'a> FnOnce(&'a BlockRef<'c>) -> Vec<&'a ValueRef<'c>>,
+{
+    let then_region = Region::new(location.context());
+    {
+        let block = then_region.append_block(Block::new(&[]));
+        let yielded = then_body(block);
+        block.append_operation(r#yield(location, &yielded));
+    }
+    let else_region = Region::new(location.context());
+    {
+        let block = else_region.append_block(Block::new(&[]));
+        let yielded = else_body(block);
+        block.append_operation(r#yield(location, &yielded));
+    }
+    OperationBuilder::new("scf.if", location)
+        .add_operands(&[condition])
+        .add_results(result_types)
+        .add_regions(vec![then_region, else_region])
+        .build()
+        .expect("scf.if operation could not be built.")
+}
+
+/// Builds a new `scf.if` operation without results and without an `else` branch.
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `condition` - The `i1` condition.
+/// * `then_body` - Populates the block of the `then` branch, which is terminated by an
+///   `scf.yield` operation without operands.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn if_then<'c, F>(
+    location: &'c LocationRef,
+    condition: &ValueRef<'c>,
+    then_body: F,
+) -> Operation<'c>
+where
+    F: for<'a> FnOnce(&'a BlockRef<'c>),
+{
+    let then_region = Region::new(location.context());
+    {
+        let block = then_region.append_block(Block::new(&[]));
+        then_body(block);
+        block.append_operation(r#yield(location, &[]));
+    }
+    OperationBuilder::new("scf.if", location)
+        .add_operands(&[condition])
+        .add_regions(vec![then_region, Region::new(location.context())])
+        .build()
+        .expect("scf.if operation could not be built.")
+}
+
+/// Builds a new `scf.while` operation, which repeatedly runs its `before` region to compute a
+/// condition and, while the condition holds, its `after` region. The results of the operation are
+/// the values forwarded by the `before` region when the condition does not hold.
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `init_args` - The initial values passed to the `before` region.
+/// * `result_types` - The types of the values forwarded from the `before` region to the `after`
+///   region (and of the results of the operation).
+/// * `before_body` - Populates the `before` region, given its block and its arguments (of the
+///   types of the initial values). Returns the `i1` condition and the forwarded values, which are
+///   passed to the `scf.condition` operation appended after the body.
+/// * `after_body` - Populates the `after` region, given its block and its arguments (of the result
+///   types). Returns the values passed to the next iteration of the `before` region, one per
+///   initial value.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn r#while<'c, F, G>(
+    location: &'c LocationRef,
+    init_args: &[&ValueRef<'c>],
+    result_types: &[&'c TypeRef],
+    before_body: F,
+    after_body: G,
+) -> Operation<'c>
+where
+    F: for<'a> FnOnce(
+        &'a BlockRef<'c>,
+        &[&'a ValueRef<'c>],
+    ) -> (&'a ValueRef<'c>, Vec<&'a ValueRef<'c>>),
+    G: for<'a> FnOnce(&'a BlockRef<'c>, &[&'a ValueRef<'c>]) -> Vec<&'a ValueRef<'c>>,
+{
+    let before_region = Region::new(location.context());
+    {
+        let argument_types = init_args.iter().map(|value| (value.r#type(), location));
+        let block = before_region.append_block(Block::from_arguments(argument_types));
+        let arguments = block_arguments(block);
+        let (condition_value, forwarded) = before_body(block, &arguments);
+        block.append_operation(condition(location, condition_value, &forwarded));
+    }
+    let after_region = Region::new(location.context());
+    {
+        let argument_types = result_types.iter().map(|ty| (*ty, location));
+        let block = after_region.append_block(Block::from_arguments(argument_types));
+        let arguments = block_arguments(block);
+        let yielded = after_body(block, &arguments);
+        block.append_operation(r#yield(location, &yielded));
+    }
+    OperationBuilder::new("scf.while", location)
+        .add_operands(init_args)
+        .add_results(result_types)
+        .add_regions(vec![before_region, after_region])
+        .build()
+        .expect("scf.while operation could not be built.")
+}
+
+/// Builds a new `scf.yield` operation, which terminates the regions of `scf.for`, `scf.if` and the
+/// `after` region of `scf.while`.
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `operands` - The values yielded to the parent operation.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn r#yield<'c>(location: &'c LocationRef, operands: &[&ValueRef]) -> Operation<'c> {
+    OperationBuilder::new("scf.yield", location)
+        .add_operands(operands)
+        .build()
+        .expect("scf.yield operation could not be built.")
+}
+
+/// Builds a new `scf.condition` operation, which terminates the `before` region of `scf.while`.
+///
+/// # Arguments
+/// * `location` - The location of the operation.
+/// * `condition` - The `i1` condition of the loop.
+/// * `forwarded` - The values forwarded to the `after` region, or returned from the loop.
+///
+/// # Returns
+/// Returns the new operation.
+pub fn condition<'c>(
+    location: &'c LocationRef,
+    condition: &ValueRef,
+    forwarded: &[&ValueRef],
+) -> Operation<'c> {
+    let operands = std::iter::once(condition)
+        .chain(forwarded.iter().copied())
+        .collect::<Vec<_>>();
+    OperationBuilder::new("scf.condition", location)
+        .add_operands(&operands)
+        .build()
+        .expect("scf.condition operation could not be built.")
+}
+
+fn block_arguments<'a, 'c>(block: &'a BlockRef<'c>) -> Vec<&'a ValueRef<'c>> {
+    (0..block.num_arguments())
+        .map(|idx| block.argument(idx))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dialects::{
+            arith::{self, CmpIPredicate, IntegerBinaryOp, IntegerOverflowFlags},
+            func::{self, FuncOpRef},
+        },
+        ir::{FunctionTypeRef, IndexTypeRef, IntegerTypeRef, Module, SymbolVisibility},
+        pass::{Pass, PassManager},
+        Context, DialectRegistry, ExecutionEngine,
+    };
+
+    fn context() -> Context {
+        let dialect_registry = DialectRegistry::default();
+        dialect_registry.register_all_dialects();
+        let context = Context::new(Some(&dialect_registry), false);
+        context.load_all_available_dialects();
+        context
+    }
+
+    /// Builds a function computing the sum of `0..n` with `scf.for`, and then halving it with
+    /// `scf.while` until it is below `n` (if `n` is positive).
+    fn build_module(context: &Context) -> Module {
+        let loc = LocationRef::new_unknown(context);
+        let index_type = IndexTypeRef::new(context);
+        let module = Module::new(loc);
+        let function_type = FunctionTypeRef::new(context, &[index_type], &[index_type]);
+        let function = module.body().append_operation(func::func(
+            loc,
+            "sum",
+            function_type,
+            SymbolVisibility::Public,
+        ));
+        let function = FuncOpRef::try_from_operation(function).unwrap();
+        let block = function.get_or_create_entry_block(loc);
+        let n = function.argument(0);
+
+        let zero = block
+            .append_operation(arith::constant_index(loc, 0))
+            .result(0);
+        let one = block
+            .append_operation(arith::constant_index(loc, 1))
+            .result(0);
+        let two = block
+            .append_operation(arith::constant_index(loc, 2))
+            .result(0);
+        let sum = block.append_operation(r#for(loc, zero, n, one, &[zero], |body, iv, args| {
+            let add = arith::integer_binary(
+                loc,
+                IntegerBinaryOp::AddI,
+                args[0],
+                iv,
+                IntegerOverflowFlags::NONE,
+            );
+            vec![body.append_operation(add).result(0)]
+        }));
+        let positive = block.append_operation(arith::cmpi(loc, CmpIPredicate::Sgt, n, zero));
+        let result = block.append_operation(r#if(
+            loc,
+            positive.result(0),
+            &[index_type],
+            |then_block| {
+                let halved = r#while(
+                    loc,
+                    &[sum.result(0)],
+                    &[index_type],
+                    |before, args| {
+                        let cmp = arith::cmpi(loc, CmpIPredicate::Sge, args[0], n);
+                        (before.append_operation(cmp).result(0), vec![args[0]])
+                    },
+                    |after, args| {
+                        let div = arith::integer_binary(
+                            loc,
+                            IntegerBinaryOp::DivSI,
+                            args[0],
+                            two,
+                            IntegerOverflowFlags::NONE,
+                        );
+                        vec![after.append_operation(div).result(0)]
+                    },
+                );
+                vec![then_block.append_operation(halved).result(0)]
+            },
+            |_| vec![zero],
+        ));
+        block.append_operation(if_then(loc, positive.result(0), |_| {}));
+        block.append_operation(func::r#return(loc, &[result.result(0)]));
+        module
+    }
+
+    #[test]
+    fn build_loops() {
+        let context = context();
+        let module = build_module(&context);
+        assert!(module.as_operation().verify());
+        let printed = module.to_string();
+        assert!(printed.contains("scf.for %arg1 = %c0 to %arg0 step %c1 iter_args("));
+        assert!(printed.contains("scf.while"));
+        assert!(printed.contains("scf.condition"));
+    }
+
+    #[test]
+    fn run_loops() {
+        let context = context();
+        let module = build_module(&context);
+        let pass_manager = PassManager::new(&context);
+        pass_manager.add_owned_pass(Pass::scf_to_cf());
+        pass_manager.add_owned_pass(Pass::cf_to_llvm());
+        pass_manager.add_owned_pass(Pass::arith_to_llvm());
+        pass_manager.add_owned_pass(Pass::func_to_llvm());
+        pass_manager.add_owned_pass(Pass::reconcile_unrealized_casts());
+        pass_manager.run(module.as_operation()).unwrap();
+        let engine = ExecutionEngine::new(&module, 0, &[], false).unwrap();
+
+        // The sum of 0..10 is 45, which is halved to 22 and then 11 and then 5.
+        let result: i64 = unsafe { engine.invoke("sum", (10i64,)) }.unwrap();
+        assert_eq!(result, 5);
+        let result: i64 = unsafe { engine.invoke("sum", (0i64,)) }.unwrap();
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn typed_block_arguments() {
+        let context = context();
+        let loc = LocationRef::new_unknown(&context);
+        let index_type = IndexTypeRef::new(&context);
+        let i8_type = IntegerTypeRef::new_signless(&context, 8);
+        let block = Block::new(&[(index_type, loc), (i8_type, loc)]);
+        let bound = block.argument(0);
+        let init = block.argument(1);
+        let for_op = block.append_operation(r#for(
+            loc,
+            bound,
+            bound,
+            bound,
+            &[init],
+            |body, iv, args| {
+                assert_eq!(body.num_arguments(), 2);
+                assert_eq!(iv.r#type(), index_type.as_type());
+                assert_eq!(args[0].r#type(), i8_type.as_type());
+                vec![args[0]]
+            },
+        ));
+        assert_eq!(for_op.result(0).r#type(), i8_type.as_type());
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{support::binding::OwnedMlirValue, DialectHandle};
+
+use mlir_sys::mlirGetDialectHandle__spirv__;
+
+/// # Returns
+/// Returns the handle of the `spirv` dialect, e.g. to register it with a context.
+///
+/// The MLIR C API provides no constructors for the types of the `spirv` dialect (e.g.
+/// `!spirv.ptr`), which can be parsed instead (see [crate::ir::TypeRef::parse]).
+pub fn handle() -> DialectHandle {
+    unsafe { DialectHandle::from_raw(mlirGetDialectHandle__spirv__()) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{Module, TypeRef},
+        Context, DialectRegistry,
+    };
+
+    fn context() -> Context {
+        let dialect_registry = DialectRegistry::default();
+        handle().insert_into_registry(&dialect_registry);
+        let context = Context::new(Some(&dialect_registry), false);
+        context.load_all_available_dialects();
+        context
+    }
+
+    #[test]
+    fn load_dialect() {
+        let context = Context::new(None, false);
+        let dialect = handle().load_into_context(&context);
+        assert_eq!(dialect.namespace().as_str(), "spirv");
+    }
+
+    #[test]
+    fn parse_types() {
+        let context = context();
+        let ty = TypeRef::parse(&context, "!spirv.ptr<f32, StorageBuffer>").unwrap();
+        assert_eq!(ty.dialect().namespace().as_str(), "spirv");
+        assert!(TypeRef::parse(&context, "!spirv.array<4 x i32>").is_some());
+    }
+
+    #[test]
+    fn parse_module() {
+        let context = context();
+        let module_source = r#"
+spirv.module Logical GLSL450 requires #spirv.vce<v1.0, [Shader], []> {
+  spirv.func @add(%arg0: i32, %arg1: i32) -> i32 "None" {
+    %0 = spirv.IAdd %arg0, %arg1 : i32
+    spirv.ReturnValue %0 : i32
+  }
+}
+"#;
+        let module = Module::parse(&context, module_source).unwrap();
+        assert!(module.as_operation().verify());
+        let spirv_module = module.body().first_operation().unwrap();
+        assert_eq!(spirv_module.name().value().as_str(), "spirv.module");
+    }
+}
@@ -0,0 +1,332 @@
+mod invoke;
+mod memref;
+
+pub use self::{
+    invoke::{JitArguments, JitResult, JitValue},
+    memref::{StridedMemRefDescriptor, UnrankedMemRefDescriptor},
+};
+use crate::{
+    ir::ModuleRef,
+    support::binding::{impl_owned_mlir_value, impl_unowned_mlir_value, UnownedMlirValue},
+    Diagnostic, StringRef,
+};
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    marker::PhantomData,
+    os::raw::c_void,
+    path::Path,
+};
+
+use mlir_sys::{
+    mlirExecutionEngineCreate, mlirExecutionEngineDestroy, mlirExecutionEngineDumpToObjectFile,
+    mlirExecutionEngineInvokePacked, mlirExecutionEngineLookup, mlirExecutionEngineLookupPacked,
+    mlirExecutionEngineRegisterSymbol, MlirExecutionEngine,
+};
+
+/// [ExecutionEngine] wraps the `mlir::ExecutionEngine` class, which JIT-compiles a module lowered
+/// to the LLVM dialect so that its functions can be called from Rust.
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirExecutionEngineCreate`
+/// - `mlirExecutionEngineDestroy`
+/// - `mlirExecutionEngineDumpToObjectFile`
+/// - `mlirExecutionEngineInvokePacked`
+/// - `mlirExecutionEngineLookup`
+/// - `mlirExecutionEngineLookupPacked`
+/// - `mlirExecutionEngineRegisterSymbol`
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct ExecutionEngine {
+    raw: MlirExecutionEngine,
+}
+
+impl_owned_mlir_value!(no_refs, ExecutionEngine, MlirExecutionEngine);
+
+impl ExecutionEngine {
+    /// Creates a new execution engine, JIT-compiling the provided module. The translations of the
+    /// builtin and LLVM dialects to LLVM IR are registered in the context of the module as needed.
+    ///
+    /// # Arguments
+    /// * `module` - The module to compile, which must only contain operations of the LLVM dialect
+    ///   (e.g. after running [crate::pass::Pass::func_to_llvm] and the other conversion passes).
+    /// * `opt_level` - The LLVM optimization level to compile the module with, from 0 to 3.
+    /// * `shared_library_paths` - The paths of shared libraries to load, whose symbols can be
+    ///   called from the module (e.g. the MLIR C runner utilities).
+    /// * `enable_object_dump` - Whether the compiled object code is kept, so that it can be
+    ///   written to a file using [ExecutionEngineRef::dump_to_object_file].
+    ///
+    /// # Returns
+    /// Returns a new [ExecutionEngine] instance, or the diagnostics emitted while compiling the
+    /// module if it could not be compiled.
+    pub fn new(
+        module: &ModuleRef,
+        opt_level: usize,
+        shared_library_paths: &[&Path],
+        enable_object_dump: bool,
+    ) -> Result<Self, Vec<Diagnostic>> {
+        if opt_level > 3 {
+            panic!("Optimization level {} is not between 0 and 3", opt_level);
+        }
+        let paths = shared_library_paths
+            .iter()
+            .map(|path| path.to_string_lossy())
+            .collect::<Vec<_>>();
+        let raw_paths = paths
+            .iter()
+            .map(|path| StringRef::from(path).to_raw())
+            .collect::<Vec<_>>();
+
+        let capture = module.context().capture_diagnostics();
+        let raw = unsafe {
+            mlirExecutionEngineCreate(
+                module.to_raw(),
+                opt_level as i32,
+                raw_paths.len() as i32,
+                raw_paths.as_ptr(),
+                enable_object_dump,
+            )
+        };
+        if raw.ptr.is_null() {
+            Err(capture.take())
+        } else {
+            Ok(Self { raw })
+        }
+    }
+}
+
+impl Drop for ExecutionEngine {
+    fn drop(&mut self) {
+        unsafe { mlirExecutionEngineDestroy(self.raw) }
+    }
+}
+
+/// [ExecutionEngineRef] is a reference to an instance of the `mlir::ExecutionEngine` class.
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct ExecutionEngineRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(
+    no_refs,
+    ExecutionEngine,
+    ExecutionEngineRef,
+    MlirExecutionEngine
+);
+
+impl ExecutionEngineRef {
+    /// Invokes a function of the compiled module through the packed interface the engine generates
+    /// for it, which takes a single array of pointers to the arguments and results. Memref
+    /// arguments are passed as their expanded fields, unless the function has the
+    /// `llvm.emit_c_interface` attribute and its `_mlir_ciface_` wrapper is invoked instead.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the function to invoke.
+    /// * `arguments` - Pointers to each argument of the function, followed by pointers to where
+    ///   each of its results is written.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the function was invoked, or an [InvokeError] if the module has no
+    /// function with the provided name.
+    ///
+    /// # Safety
+    /// The caller of this function is responsible for ensuring that `arguments` holds a valid
+    /// pointer of the right type for each argument and result of the function.
+    pub unsafe fn invoke_packed(
+        &self,
+        name: &str,
+        arguments: &mut [*mut c_void],
+    ) -> Result<(), InvokeError> {
+        let result = mlirExecutionEngineInvokePacked(
+            self.to_raw(),
+            StringRef::from(&name).to_raw(),
+            arguments.as_mut_ptr(),
+        );
+        if result.value != 0 {
+            Ok(())
+        } else {
+            Err(InvokeError {
+                name: name.to_string(),
+            })
+        }
+    }
+
+    /// # Arguments
+    /// * `name` - The name of the symbol to look up.
+    ///
+    /// # Returns
+    /// Returns the address of the symbol with the provided name in the compiled module (e.g. of a
+    /// function, to transmute into an `extern "C"` function pointer), if there is one.
+    pub fn lookup(&self, name: &str) -> Option<*mut c_void> {
+        let address =
+            unsafe { mlirExecutionEngineLookup(self.to_raw(), StringRef::from(&name).to_raw()) };
+        (!address.is_null()).then_some(address)
+    }
+
+    /// # Arguments
+    /// * `name` - The name of the function to look up.
+    ///
+    /// # Returns
+    /// Returns the address of the packed interface of the function with the provided name (see
+    /// [ExecutionEngineRef::invoke_packed]), if there is one.
+    pub fn lookup_packed(&self, name: &str) -> Option<*mut c_void> {
+        let address = unsafe {
+            mlirExecutionEngineLookupPacked(self.to_raw(), StringRef::from(&name).to_raw())
+        };
+        (!address.is_null()).then_some(address)
+    }
+
+    /// Makes a symbol available to the compiled module, e.g. a Rust function declared as an
+    /// external `llvm.func` in the module.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the symbol.
+    /// * `address` - The address of the symbol.
+    ///
+    /// # Safety
+    /// The caller of this function is responsible for ensuring that `address` stays valid for as
+    /// long as the engine may use it, and that it matches the declaration of the symbol.
+    pub unsafe fn register_symbol(&self, name: &str, address: *mut c_void) {
+        mlirExecutionEngineRegisterSymbol(self.to_raw(), StringRef::from(&name).to_raw(), address)
+    }
+
+    /// Writes the compiled object code to a file, e.g. to inspect it using `objdump` or to link it
+    /// ahead of time. Nothing is written if the engine was created without enabling object dumps
+    /// (see [ExecutionEngine::new]), in which case MLIR prints an error to the standard error
+    /// stream.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the object file to write.
+    pub fn dump_to_object_file(&self, path: impl AsRef<Path>) {
+        let path = path.as_ref().to_string_lossy();
+        unsafe {
+            mlirExecutionEngineDumpToObjectFile(self.to_raw(), StringRef::from(&path).to_raw())
+        }
+    }
+}
+
+/// [InvokeError] is returned by [ExecutionEngineRef::invoke_packed] when the compiled module has no
+/// function with the provided name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvokeError {
+    /// The name of the function which could not be invoked.
+    pub name: String,
+}
+
+impl Display for InvokeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "failed to invoke function '{}', which is not in the module",
+            self.name
+        )
+    }
+}
+
+impl Error for InvokeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::Module,
+        pass::{Pass, PassManager},
+        Context, DialectRegistry,
+    };
+
+    fn context() -> Context {
+        let dialect_registry = DialectRegistry::default();
+        dialect_registry.register_all_dialects();
+        let context = Context::new(Some(&dialect_registry), false);
+        context.load_all_available_dialects();
+        context
+    }
+
+    fn lower_to_llvm(module: &Module) {
+        let pass_manager = PassManager::new(module.context());
+        pass_manager.add_owned_pass(Pass::arith_to_llvm());
+        pass_manager.add_owned_pass(Pass::func_to_llvm());
+        pass_manager.add_owned_pass(Pass::reconcile_unrealized_casts());
+        pass_manager.run(module.as_operation()).unwrap();
+    }
+
+    fn add_module(context: &Context) -> Module {
+        let module_source = r#"
+func.func @add(%arg0: i32, %arg1: i32) -> i32 attributes { llvm.emit_c_interface } {
+  %0 = arith.addi %arg0, %arg1 : i32
+  return %0 : i32
+}
+"#;
+        let module = Module::parse(context, module_source).unwrap();
+        lower_to_llvm(&module);
+        module
+    }
+
+    #[test]
+    fn invoke_packed() {
+        let context = context();
+        let module = add_module(&context);
+        let engine = ExecutionEngine::new(&module, 2, &[], false).unwrap();
+
+        let mut lhs = 40i32;
+        let mut rhs = 2i32;
+        let mut result = 0i32;
+        unsafe {
+            engine
+                .invoke_packed(
+                    "add",
+                    &mut [
+                        &mut lhs as *mut i32 as *mut c_void,
+                        &mut rhs as *mut i32 as *mut c_void,
+                        &mut result as *mut i32 as *mut c_void,
+                    ],
+                )
+                .unwrap();
+        }
+        assert_eq!(result, 42);
+        assert!(unsafe { engine.invoke_packed("missing", &mut []) }.is_err());
+    }
+
+    #[test]
+    fn lookup() {
+        let context = context();
+        let module = add_module(&context);
+        let engine = ExecutionEngine::new(&module, 0, &[], false).unwrap();
+
+        let address = engine.lookup("add").unwrap();
+        let add: extern "C" fn(i32, i32) -> i32 = unsafe { std::mem::transmute(address) };
+        assert_eq!(add(1, 2), 3);
+        assert!(engine.lookup_packed("add").is_some());
+        assert!(engine.lookup("missing").is_none());
+    }
+
+    #[test]
+    fn dump_to_object_file() {
+        let context = context();
+        let module = add_module(&context);
+        let engine = ExecutionEngine::new(&module, 2, &[], true).unwrap();
+
+        let path = std::env::temp_dir().join("mlir-rs-dump-to-object-file.o");
+        let _ = std::fs::remove_file(&path);
+        engine.dump_to_object_file(&path);
+        let object = std::fs::read(&path).unwrap();
+        assert!(!object.is_empty());
+        #[cfg(target_os = "linux")]
+        assert!(object.starts_with(b"\x7fELF"));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_execution_engine_ref() {
+        let _execution_engine_ref = ExecutionEngineRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
@@ -0,0 +1,160 @@
+use super::{ExecutionEngineRef, InvokeError};
+
+use std::{mem::MaybeUninit, os::raw::c_void};
+
+/// [JitValue] is implemented by the Rust types which can be passed to, or returned from, a
+/// function invoked using [ExecutionEngineRef::invoke], as they have the same layout as the LLVM
+/// type a builtin MLIR type is lowered to:
+/// - `bool` for `i1`;
+/// - `i8`, `i16`, `i32` and `i64` (or their unsigned counterparts) for the integer types;
+/// - `isize` and `usize` for `index` (on 64-bit targets);
+/// - `f32` and `f64` for `f32` and `f64`;
+/// - raw pointers for `!llvm.ptr`, e.g. to pass memref descriptors to functions with the
+///   `llvm.emit_c_interface` attribute.
+///
+/// # Safety
+/// Implementations must have the same size and alignment as the LLVM type they are passed as.
+pub unsafe trait JitValue: Copy {}
+
+unsafe impl JitValue for bool {}
+unsafe impl JitValue for i8 {}
+unsafe impl JitValue for i16 {}
+unsafe impl JitValue for i32 {}
+unsafe impl JitValue for i64 {}
+unsafe impl JitValue for isize {}
+unsafe impl JitValue for u8 {}
+unsafe impl JitValue for u16 {}
+unsafe impl JitValue for u32 {}
+unsafe impl JitValue for u64 {}
+unsafe impl JitValue for usize {}
+unsafe impl JitValue for f32 {}
+unsafe impl JitValue for f64 {}
+unsafe impl<T> JitValue for *const T {}
+unsafe impl<T> JitValue for *mut T {}
+
+/// [JitResult] is implemented by the types a function invoked using [ExecutionEngineRef::invoke]
+/// can return: `()` for functions without results, and any [JitValue] for functions with a
+/// single result.
+///
+/// # Safety
+/// Implementations must either be zero-sized (for functions without results), or have the same
+/// size and alignment as the LLVM type of the single result.
+pub unsafe trait JitResult {}
+
+unsafe impl JitResult for () {}
+unsafe impl<T: JitValue> JitResult for T {}
+
+/// [JitArguments] is implemented by tuples of up to 8 [JitValue]s, which hold the arguments of a
+/// function invoked using [ExecutionEngineRef::invoke].
+pub trait JitArguments {
+    /// # Returns
+    /// Returns a pointer to each argument, in order.
+    fn pointers(&mut self) -> Vec<*mut c_void>;
+}
+
+macro_rules! impl_jit_arguments {
+    ($($name:ident: $idx:tt),*) => {
+        impl<$($name: JitValue),*> JitArguments for ($($name,)*) {
+            fn pointers(&mut self) -> Vec<*mut c_void> {
+                vec![$(&mut self.$idx as *mut $name as *mut c_void),*]
+            }
+        }
+    };
+}
+
+impl_jit_arguments!();
+impl_jit_arguments!(A: 0);
+impl_jit_arguments!(A: 0, B: 1);
+impl_jit_arguments!(A: 0, B: 1, C: 2);
+impl_jit_arguments!(A: 0, B: 1, C: 2, D: 3);
+impl_jit_arguments!(A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_jit_arguments!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+impl_jit_arguments!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6);
+impl_jit_arguments!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7);
+
+/// Typed invocation of the functions of a compiled module, which builds the array of pointers of
+/// the packed interface (see [ExecutionEngineRef::invoke_packed]) from the Rust types of the
+/// arguments and result.
+impl ExecutionEngineRef {
+    /// Invokes a function of the compiled module with the provided arguments, e.g.
+    /// `engine.invoke::<_, f32>("scale", (2i64, 1.5f32))` for a function of type
+    /// `(i64, f32) -> f32`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the function to invoke.
+    /// * `arguments` - A tuple holding the arguments of the function.
+    ///
+    /// # Returns
+    /// Returns the result of the function (or `()` if it has none), or an [InvokeError] if the
+    /// module has no function with the provided name.
+    ///
+    /// # Safety
+    /// The caller of this function is responsible for ensuring that the types of the arguments and
+    /// result match the type of the function, which cannot be checked through the packed interface.
+    pub unsafe fn invoke<A, R>(&self, name: &str, mut arguments: A) -> Result<R, InvokeError>
+    where
+        A: JitArguments,
+        R: JitResult,
+    {
+        let mut result = MaybeUninit::<R>::uninit();
+        let mut pointers = arguments.pointers();
+        if std::mem::size_of::<R>() != 0 {
+            pointers.push(result.as_mut_ptr() as *mut c_void);
+        }
+        self.invoke_packed(name, &mut pointers)?;
+        Ok(result.assume_init())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::Module,
+        pass::{Pass, PassManager},
+        Context, DialectRegistry, ExecutionEngine,
+    };
+
+    fn engine() -> ExecutionEngine {
+        let dialect_registry = DialectRegistry::default();
+        dialect_registry.register_all_dialects();
+        let context = Context::new(Some(&dialect_registry), false);
+        context.load_all_available_dialects();
+        let module_source = r#"
+func.func @scale(%arg0: i64, %arg1: f32) -> f32 {
+  %0 = arith.sitofp %arg0 : i64 to f32
+  %1 = arith.mulf %0, %arg1 : f32
+  return %1 : f32
+}
+
+func.func @nothing() {
+  return
+}
+"#;
+        let module = Module::parse(&context, module_source).unwrap();
+        let pass_manager = PassManager::new(&context);
+        pass_manager.add_owned_pass(Pass::arith_to_llvm());
+        pass_manager.add_owned_pass(Pass::func_to_llvm());
+        pass_manager.add_owned_pass(Pass::reconcile_unrealized_casts());
+        pass_manager.run(module.as_operation()).unwrap();
+        ExecutionEngine::new(&module, 0, &[], false).unwrap()
+    }
+
+    #[test]
+    fn invoke() {
+        let engine = engine();
+        let result: f32 = unsafe { engine.invoke("scale", (4i64, 1.5f32)) }.unwrap();
+        assert_eq!(result, 6.0);
+        unsafe { engine.invoke::<_, ()>("nothing", ()) }.unwrap();
+        assert!(unsafe { engine.invoke::<_, ()>("missing", ()) }.is_err());
+    }
+
+    #[test]
+    fn argument_pointers() {
+        let mut arguments = (1i8, 2i64, 3.0f64);
+        let pointers = arguments.pointers();
+        assert_eq!(pointers.len(), 3);
+        assert_eq!(unsafe { *(pointers[1] as *const i64) }, 2);
+        assert!(().pointers().is_empty());
+    }
+}
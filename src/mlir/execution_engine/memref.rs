@@ -0,0 +1,280 @@
+use super::JitResult;
+
+use std::os::raw::c_void;
+
+/// [StridedMemRefDescriptor] has the layout of the descriptor a ranked memref type is lowered to
+/// by the conversion to the LLVM dialect, as `StridedMemRefType` in the MLIR C runner utilities.
+///
+/// Functions with the `llvm.emit_c_interface` attribute take a pointer to the descriptor for each
+/// memref argument, and return descriptors of memref results the same way, so they can be invoked
+/// using [super::ExecutionEngineRef::invoke] on their `_mlir_ciface_` wrapper. The packed
+/// interface of other functions takes each field of the descriptor as a separate argument, but
+/// returns memref results as a whole descriptor.
+///
+/// The descriptor does not own the data it points to, which must outlive any use of the
+/// descriptor.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StridedMemRefDescriptor<T, const RANK: usize> {
+    /// The pointer to the allocated data, which is freed when the memref is deallocated.
+    pub allocated: *mut T,
+    /// The pointer to the data, aligned as required by the memref.
+    pub aligned: *mut T,
+    /// The offset of the first element from `aligned`, in elements.
+    pub offset: i64,
+    /// The size of each dimension, in elements.
+    pub sizes: [i64; RANK],
+    /// The stride of each dimension, in elements.
+    pub strides: [i64; RANK],
+}
+
+unsafe impl<T, const RANK: usize> JitResult for StridedMemRefDescriptor<T, RANK> {}
+
+impl<T, const RANK: usize> StridedMemRefDescriptor<T, RANK> {
+    /// Creates a new descriptor for the data of a slice, laid out in row-major order with the
+    /// identity layout (i.e. as a memref type without a layout attribute).
+    ///
+    /// # Arguments
+    /// * `data` - The slice holding the elements of the memref.
+    /// * `sizes` - The size of each dimension of the memref, whose product must be the length of
+    ///   the slice.
+    ///
+    /// # Returns
+    /// Returns a new [StridedMemRefDescriptor] instance, which must not be used after the slice
+    /// is dropped.
+    pub fn from_slice(data: &mut [T], sizes: [i64; RANK]) -> Self {
+        if sizes.iter().any(|size| *size < 0) {
+            panic!("Memref sizes {:?} must not be negative", sizes);
+        }
+        if sizes.iter().product::<i64>() != data.len() as i64 {
+            panic!(
+                "Memref sizes {:?} do not match the {} elements of the slice",
+                sizes,
+                data.len()
+            );
+        }
+        let mut strides = [1; RANK];
+        for dim in (0..RANK.saturating_sub(1)).rev() {
+            strides[dim] = strides[dim + 1] * sizes[dim + 1];
+        }
+        StridedMemRefDescriptor {
+            allocated: data.as_mut_ptr(),
+            aligned: data.as_mut_ptr(),
+            offset: 0,
+            sizes,
+            strides,
+        }
+    }
+
+    /// # Returns
+    /// Returns the number of elements of the memref.
+    pub fn len(&self) -> usize {
+        self.sizes.iter().product::<i64>() as usize
+    }
+
+    /// # Returns
+    /// Returns whether the memref has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Copies the elements of the memref in row-major order, following its offset and strides,
+    /// e.g. to read a memref returned by a compiled function.
+    ///
+    /// # Returns
+    /// Returns the elements of the memref.
+    ///
+    /// # Safety
+    /// The caller of this function is responsible for ensuring that the descriptor points to
+    /// valid data for every element of the memref.
+    pub unsafe fn to_vec(&self) -> Vec<T>
+    where
+        T: Copy,
+    {
+        let mut elements = Vec::with_capacity(self.len());
+        if self.is_empty() {
+            return elements;
+        }
+        let mut indices = [0i64; RANK];
+        loop {
+            let position = self.offset
+                + indices
+                    .iter()
+                    .zip(self.strides.iter())
+                    .map(|(index, stride)| index * stride)
+                    .sum::<i64>();
+            elements.push(*self.aligned.offset(position as isize));
+            // Increments the indices like an odometer, starting from the innermost dimension.
+            let mut dim = RANK;
+            loop {
+                if dim == 0 {
+                    return elements;
+                }
+                dim -= 1;
+                indices[dim] += 1;
+                if indices[dim] < self.sizes[dim] {
+                    break;
+                }
+                indices[dim] = 0;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<T, const RANK: usize> StridedMemRefDescriptor<T, RANK>
+where
+    ndarray::Dim<[usize; RANK]>: ndarray::Dimension,
+{
+    /// Creates a new descriptor for the data of an `ndarray` view, keeping its strides.
+    ///
+    /// # Arguments
+    /// * `array` - The view of the elements of the memref.
+    ///
+    /// # Returns
+    /// Returns a new [StridedMemRefDescriptor] instance, which must not be used after the view
+    /// is dropped.
+    pub fn from_array(
+        array: &mut ndarray::ArrayViewMut<'_, T, ndarray::Dim<[usize; RANK]>>,
+    ) -> Self {
+        let mut sizes = [0; RANK];
+        let mut strides = [0; RANK];
+        for (dim, (size, stride)) in array.shape().iter().zip(array.strides()).enumerate() {
+            sizes[dim] = *size as i64;
+            strides[dim] = *stride as i64;
+        }
+        StridedMemRefDescriptor {
+            allocated: array.as_mut_ptr(),
+            aligned: array.as_mut_ptr(),
+            offset: 0,
+            sizes,
+            strides,
+        }
+    }
+}
+
+/// [UnrankedMemRefDescriptor] has the layout of the descriptor an unranked memref type is lowered
+/// to by the conversion to the LLVM dialect, as `UnrankedMemRefType` in the MLIR C runner
+/// utilities. It pairs the rank of the memref with a pointer to its ranked descriptor.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnrankedMemRefDescriptor {
+    /// The rank of the memref.
+    pub rank: i64,
+    /// The pointer to the [StridedMemRefDescriptor] of the memref.
+    pub descriptor: *mut c_void,
+}
+
+impl UnrankedMemRefDescriptor {
+    /// Creates a new unranked descriptor for a ranked descriptor.
+    ///
+    /// # Arguments
+    /// * `descriptor` - The ranked descriptor of the memref.
+    ///
+    /// # Returns
+    /// Returns a new [UnrankedMemRefDescriptor] instance, which must not be used after the ranked
+    /// descriptor is dropped.
+    pub fn new<T, const RANK: usize>(descriptor: &mut StridedMemRefDescriptor<T, RANK>) -> Self {
+        UnrankedMemRefDescriptor {
+            rank: RANK as i64,
+            descriptor: descriptor as *mut StridedMemRefDescriptor<T, RANK> as *mut c_void,
+        }
+    }
+
+    /// # Returns
+    /// Returns the ranked descriptor of the memref, if it has the provided rank.
+    ///
+    /// # Safety
+    /// The caller of this function is responsible for ensuring that the descriptor points to a
+    /// valid [StridedMemRefDescriptor] with elements of type `T`.
+    pub unsafe fn as_ranked<T, const RANK: usize>(
+        &self,
+    ) -> Option<&StridedMemRefDescriptor<T, RANK>> {
+        (self.rank == RANK as i64)
+            .then(|| &*(self.descriptor as *const StridedMemRefDescriptor<T, RANK>))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::Module,
+        pass::{Pass, PassManager},
+        Context, DialectRegistry, ExecutionEngine,
+    };
+
+    #[test]
+    fn from_slice() {
+        let mut data = [0f32; 24];
+        let descriptor = StridedMemRefDescriptor::from_slice(&mut data, [2, 3, 4]);
+        assert_eq!(descriptor.strides, [12, 4, 1]);
+        assert_eq!(descriptor.len(), 24);
+        assert_eq!(descriptor.aligned, data.as_mut_ptr());
+
+        let mut scalar = [1i64];
+        let descriptor = StridedMemRefDescriptor::from_slice(&mut scalar, []);
+        assert_eq!(unsafe { descriptor.to_vec() }, vec![1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_slice_size_mismatch() {
+        let mut data = [0f32; 5];
+        StridedMemRefDescriptor::from_slice(&mut data, [2, 3]);
+    }
+
+    #[test]
+    fn to_vec_strided() {
+        let mut data = [0, 1, 2, 3, 4, 5];
+        let mut descriptor = StridedMemRefDescriptor::from_slice(&mut data, [2, 3]);
+        // Transposes the memref, and then skips its first row.
+        descriptor.sizes = [2, 2];
+        descriptor.strides = [1, 3];
+        descriptor.offset = 1;
+        assert_eq!(unsafe { descriptor.to_vec() }, vec![1, 4, 2, 5]);
+    }
+
+    #[test]
+    fn unranked() {
+        let mut data = [0u8; 6];
+        let mut descriptor = StridedMemRefDescriptor::from_slice(&mut data, [2, 3]);
+        let unranked = UnrankedMemRefDescriptor::new(&mut descriptor);
+        assert_eq!(unranked.rank, 2);
+        assert!(unsafe { unranked.as_ranked::<u8, 1>() }.is_none());
+        assert_eq!(
+            unsafe { unranked.as_ranked::<u8, 2>() }.unwrap().sizes,
+            [2, 3]
+        );
+    }
+
+    #[test]
+    fn invoke_with_memref() {
+        let dialect_registry = DialectRegistry::default();
+        dialect_registry.register_all_dialects();
+        let context = Context::new(Some(&dialect_registry), false);
+        context.load_all_available_dialects();
+        let module_source = r#"
+func.func @store(%arg0: memref<2x3xf32>, %arg1: f32) attributes { llvm.emit_c_interface } {
+  %c1 = arith.constant 1 : index
+  %c2 = arith.constant 2 : index
+  memref.store %arg1, %arg0[%c1, %c2] : memref<2x3xf32>
+  return
+}
+"#;
+        let module = Module::parse(&context, module_source).unwrap();
+        let pass_manager = PassManager::new(&context);
+        pass_manager.add_owned_pass(Pass::finalize_memref_to_llvm());
+        pass_manager.add_owned_pass(Pass::arith_to_llvm());
+        pass_manager.add_owned_pass(Pass::func_to_llvm());
+        pass_manager.add_owned_pass(Pass::reconcile_unrealized_casts());
+        pass_manager.run(module.as_operation()).unwrap();
+        let engine = ExecutionEngine::new(&module, 0, &[], false).unwrap();
+
+        let mut data = [0f32; 6];
+        let mut descriptor = StridedMemRefDescriptor::from_slice(&mut data, [2, 3]);
+        let pointer = &mut descriptor as *mut StridedMemRefDescriptor<f32, 2>;
+        unsafe { engine.invoke::<_, ()>("_mlir_ciface_store", (pointer, 7.0f32)) }.unwrap();
+        assert_eq!(data, [0.0, 0.0, 0.0, 0.0, 0.0, 7.0]);
+    }
+}
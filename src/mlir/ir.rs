@@ -1,13 +1,18 @@
+mod affine_expr;
+mod affine_map;
+mod asm_state;
 mod attribute;
 mod block;
 mod identifier;
 mod location;
+mod module;
+mod op_operand;
 mod operation;
 mod region;
 mod r#type;
 mod value;
 
 pub use self::{
-    attribute::*, block::*, identifier::*, location::*, operation::*, r#type::*, region::*,
-    value::*,
+    affine_expr::*, affine_map::*, asm_state::*, attribute::*, block::*, identifier::*,
+    location::*, module::*, op_operand::*, operation::*, r#type::*, region::*, value::*,
 };
@@ -0,0 +1,416 @@
+use crate::{
+    ir::AffineMapRef,
+    support::{
+        binding::{impl_unowned_mlir_value, UnownedMlirValue},
+        string_reader::StringReader,
+    },
+    ContextRef,
+};
+
+use std::{
+    fmt::{Display, Formatter},
+    marker::PhantomData,
+};
+
+use mlir_sys::{
+    mlirAffineAddExprGet, mlirAffineBinaryOpExprGetLHS, mlirAffineBinaryOpExprGetRHS,
+    mlirAffineCeilDivExprGet, mlirAffineConstantExprGet, mlirAffineConstantExprGetValue,
+    mlirAffineDimExprGet, mlirAffineDimExprGetPosition, mlirAffineExprCompose, mlirAffineExprEqual,
+    mlirAffineExprGetContext, mlirAffineExprGetLargestKnownDivisor, mlirAffineExprIsAAdd,
+    mlirAffineExprIsACeilDiv, mlirAffineExprIsAConstant, mlirAffineExprIsADim,
+    mlirAffineExprIsAFloorDiv, mlirAffineExprIsAMod, mlirAffineExprIsAMul, mlirAffineExprIsASymbol,
+    mlirAffineExprIsFunctionOfDim, mlirAffineExprIsMultipleOf, mlirAffineExprIsPureAffine,
+    mlirAffineExprIsSymbolicOrConstant, mlirAffineExprPrint, mlirAffineFloorDivExprGet,
+    mlirAffineModExprGet, mlirAffineMulExprGet, mlirAffineSymbolExprGet,
+    mlirAffineSymbolExprGetPosition, MlirAffineExpr,
+};
+
+/// [AffineExprKind] is the kind of an affine expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AffineExprKind {
+    /// A dimension, e.g. `d0`.
+    Dim,
+    /// A symbol, e.g. `s0`.
+    Symbol,
+    /// An integer constant.
+    Constant,
+    /// The sum of two expressions.
+    Add,
+    /// The product of two expressions.
+    Mul,
+    /// The remainder of the division of two expressions.
+    Mod,
+    /// The quotient of the division of two expressions, rounded down.
+    FloorDiv,
+    /// The quotient of the division of two expressions, rounded up.
+    CeilDiv,
+}
+
+/// [AffineExprRef] is a reference to an instance of the `mlir::AffineExpr` class, which represents
+/// an affine expression of dimensions, symbols and constants, such as a result of an affine map.
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirAffineAddExprGet`
+/// - `mlirAffineBinaryOpExprGetLHS`
+/// - `mlirAffineBinaryOpExprGetRHS`
+/// - `mlirAffineCeilDivExprGet`
+/// - `mlirAffineConstantExprGet`
+/// - `mlirAffineConstantExprGetValue`
+/// - `mlirAffineDimExprGet`
+/// - `mlirAffineDimExprGetPosition`
+/// - `mlirAffineExprCompose`
+/// - `mlirAffineExprEqual`
+/// - `mlirAffineExprGetContext`
+/// - `mlirAffineExprGetLargestKnownDivisor`
+/// - `mlirAffineExprIsAAdd`
+/// - `mlirAffineExprIsACeilDiv`
+/// - `mlirAffineExprIsAConstant`
+/// - `mlirAffineExprIsADim`
+/// - `mlirAffineExprIsAFloorDiv`
+/// - `mlirAffineExprIsAMod`
+/// - `mlirAffineExprIsAMul`
+/// - `mlirAffineExprIsASymbol`
+/// - `mlirAffineExprIsFunctionOfDim`
+/// - `mlirAffineExprIsMultipleOf`
+/// - `mlirAffineExprIsPureAffine`
+/// - `mlirAffineExprIsSymbolicOrConstant`
+/// - `mlirAffineExprPrint`
+/// - `mlirAffineFloorDivExprGet`
+/// - `mlirAffineModExprGet`
+/// - `mlirAffineMulExprGet`
+/// - `mlirAffineSymbolExprGet`
+/// - `mlirAffineSymbolExprGetPosition`
+///
+/// The following bindings are not used/supported:
+/// - `mlirAffineExprDump`
+/// - `mlirAffineExprIsABinary`
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct AffineExprRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, AffineExprRef, MlirAffineExpr);
+
+impl AffineExprRef {
+    /// Constructs a new affine expression referring to a dimension.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the affine expression.
+    /// * `position` - The position of the dimension.
+    ///
+    /// # Returns
+    /// Returns a new [AffineExprRef] reference.
+    pub fn new_dim(context: &ContextRef, position: isize) -> &Self {
+        unsafe { Self::from_raw(mlirAffineDimExprGet(context.to_raw(), position)) }
+    }
+
+    /// Constructs a new affine expression referring to a symbol.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the affine expression.
+    /// * `position` - The position of the symbol.
+    ///
+    /// # Returns
+    /// Returns a new [AffineExprRef] reference.
+    pub fn new_symbol(context: &ContextRef, position: isize) -> &Self {
+        unsafe { Self::from_raw(mlirAffineSymbolExprGet(context.to_raw(), position)) }
+    }
+
+    /// Constructs a new constant affine expression.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the affine expression.
+    /// * `value` - The value of the constant.
+    ///
+    /// # Returns
+    /// Returns a new [AffineExprRef] reference.
+    pub fn new_constant(context: &ContextRef, value: i64) -> &Self {
+        unsafe { Self::from_raw(mlirAffineConstantExprGet(context.to_raw(), value)) }
+    }
+
+    /// Constructs a new affine expression combining two expressions with the provided kind of
+    /// binary operation. The expression is simplified where possible, so it may not be of the
+    /// provided kind (e.g. `d0 * 1` is simplified to `d0`).
+    ///
+    /// # Arguments
+    /// * `kind` - The kind of the binary operation, which must not be [AffineExprKind::Dim],
+    ///   [AffineExprKind::Symbol] or [AffineExprKind::Constant].
+    /// * `lhs` - The left-hand side of the operation.
+    /// * `rhs` - The right-hand side of the operation.
+    ///
+    /// # Returns
+    /// Returns a new [AffineExprRef] reference.
+    pub fn new_binary<'a>(kind: AffineExprKind, lhs: &'a Self, rhs: &Self) -> &'a Self {
+        let get = match kind {
+            AffineExprKind::Add => mlirAffineAddExprGet,
+            AffineExprKind::Mul => mlirAffineMulExprGet,
+            AffineExprKind::Mod => mlirAffineModExprGet,
+            AffineExprKind::FloorDiv => mlirAffineFloorDivExprGet,
+            AffineExprKind::CeilDiv => mlirAffineCeilDivExprGet,
+            _ => panic!("{:?} is not a binary affine expression kind", kind),
+        };
+        unsafe { Self::from_raw(get(lhs.to_raw(), rhs.to_raw())) }
+    }
+
+    /// # Returns
+    /// Returns a reference to the context that owns the affine expression.
+    pub fn context(&self) -> &ContextRef {
+        unsafe { ContextRef::from_raw(mlirAffineExprGetContext(self.to_raw())) }
+    }
+
+    /// # Returns
+    /// Returns the kind of the affine expression.
+    pub fn kind(&self) -> AffineExprKind {
+        let raw = self.to_raw();
+        unsafe {
+            if mlirAffineExprIsADim(raw) {
+                AffineExprKind::Dim
+            } else if mlirAffineExprIsASymbol(raw) {
+                AffineExprKind::Symbol
+            } else if mlirAffineExprIsAConstant(raw) {
+                AffineExprKind::Constant
+            } else if mlirAffineExprIsAAdd(raw) {
+                AffineExprKind::Add
+            } else if mlirAffineExprIsAMul(raw) {
+                AffineExprKind::Mul
+            } else if mlirAffineExprIsAMod(raw) {
+                AffineExprKind::Mod
+            } else if mlirAffineExprIsAFloorDiv(raw) {
+                AffineExprKind::FloorDiv
+            } else if mlirAffineExprIsACeilDiv(raw) {
+                AffineExprKind::CeilDiv
+            } else {
+                unreachable!("Unknown kind of affine expression {}", self)
+            }
+        }
+    }
+
+    /// # Returns
+    /// Returns the position of the dimension or symbol the affine expression refers to, if it is
+    /// a dimension or symbol.
+    pub fn position(&self) -> Option<isize> {
+        match self.kind() {
+            AffineExprKind::Dim => Some(unsafe { mlirAffineDimExprGetPosition(self.to_raw()) }),
+            AffineExprKind::Symbol => {
+                Some(unsafe { mlirAffineSymbolExprGetPosition(self.to_raw()) })
+            }
+            _ => None,
+        }
+    }
+
+    /// # Returns
+    /// Returns the value of the affine expression, if it is a constant.
+    pub fn constant_value(&self) -> Option<i64> {
+        (self.kind() == AffineExprKind::Constant)
+            .then(|| unsafe { mlirAffineConstantExprGetValue(self.to_raw()) })
+    }
+
+    /// # Returns
+    /// Returns the left-hand and right-hand sides of the affine expression, if it is a binary
+    /// operation.
+    pub fn operands(&self) -> Option<(&Self, &Self)> {
+        match self.kind() {
+            AffineExprKind::Dim | AffineExprKind::Symbol | AffineExprKind::Constant => None,
+            _ => unsafe {
+                Some((
+                    Self::from_raw(mlirAffineBinaryOpExprGetLHS(self.to_raw())),
+                    Self::from_raw(mlirAffineBinaryOpExprGetRHS(self.to_raw())),
+                ))
+            },
+        }
+    }
+
+    /// # Returns
+    /// Returns whether the affine expression only refers to symbols and constants.
+    pub fn is_symbolic_or_constant(&self) -> bool {
+        unsafe { mlirAffineExprIsSymbolicOrConstant(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns whether the affine expression is pure affine, i.e. it only multiplies by, and
+    /// divides by, constants.
+    pub fn is_pure_affine(&self) -> bool {
+        unsafe { mlirAffineExprIsPureAffine(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns the largest constant the affine expression is known to be a multiple of.
+    pub fn largest_known_divisor(&self) -> i64 {
+        unsafe { mlirAffineExprGetLargestKnownDivisor(self.to_raw()) }
+    }
+
+    /// # Arguments
+    /// * `factor` - The factor to check.
+    ///
+    /// # Returns
+    /// Returns whether the affine expression is known to be a multiple of the provided factor.
+    pub fn is_multiple_of(&self, factor: i64) -> bool {
+        unsafe { mlirAffineExprIsMultipleOf(self.to_raw(), factor) }
+    }
+
+    /// # Arguments
+    /// * `position` - The position of the dimension.
+    ///
+    /// # Returns
+    /// Returns whether the affine expression refers to the dimension at the provided position.
+    pub fn is_function_of_dim(&self, position: isize) -> bool {
+        unsafe { mlirAffineExprIsFunctionOfDim(self.to_raw(), position) }
+    }
+
+    /// Replaces the dimensions of the affine expression with the results of an affine map.
+    ///
+    /// # Arguments
+    /// * `affine_map` - The affine map whose results replace the dimensions.
+    ///
+    /// # Returns
+    /// Returns the new affine expression.
+    pub fn compose(&self, affine_map: &AffineMapRef) -> &Self {
+        unsafe { Self::from_raw(mlirAffineExprCompose(self.to_raw(), affine_map.to_raw())) }
+    }
+
+    /// Replaces the dimensions and symbols of the affine expression with other expressions. The
+    /// dimensions and symbols without a replacement are left unchanged.
+    ///
+    /// # Arguments
+    /// * `dims` - The replacement of each dimension, by position.
+    /// * `symbols` - The replacement of each symbol, by position.
+    ///
+    /// # Returns
+    /// Returns the new affine expression.
+    pub fn replace_dims_and_symbols<'a>(
+        &'a self,
+        dims: &[&'a AffineExprRef],
+        symbols: &[&'a AffineExprRef],
+    ) -> &'a Self {
+        let kind = self.kind();
+        match (kind, self.position()) {
+            (AffineExprKind::Dim, Some(position)) => dims.get(position as usize).unwrap_or(&self),
+            (AffineExprKind::Symbol, Some(position)) => {
+                symbols.get(position as usize).unwrap_or(&self)
+            }
+            _ => match self.operands() {
+                Some((lhs, rhs)) => Self::new_binary(
+                    kind,
+                    lhs.replace_dims_and_symbols(dims, symbols),
+                    rhs.replace_dims_and_symbols(dims, symbols),
+                ),
+                None => self,
+            },
+        }
+    }
+}
+
+impl PartialEq for AffineExprRef {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { mlirAffineExprEqual(self.to_raw(), other.to_raw()) }
+    }
+}
+
+impl Eq for AffineExprRef {}
+
+impl Display for AffineExprRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut reader = StringReader::new(f);
+        unsafe { mlirAffineExprPrint(self.to_raw(), reader.callback(), reader.as_raw_mut()) }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Context;
+
+    #[test]
+    fn new_binary() {
+        let context = Context::new(None, false);
+        let d0 = AffineExprRef::new_dim(&context, 0);
+        let s0 = AffineExprRef::new_symbol(&context, 0);
+        let two = AffineExprRef::new_constant(&context, 2);
+        let sum = AffineExprRef::new_binary(AffineExprKind::Add, d0, s0);
+        assert_eq!(sum.kind(), AffineExprKind::Add);
+        assert_eq!(sum.to_string(), "d0 + s0");
+        assert_eq!(sum.operands(), Some((d0, s0)));
+        let product = AffineExprRef::new_binary(AffineExprKind::Mul, d0, two);
+        assert!(product.is_multiple_of(2));
+        assert_eq!(product.largest_known_divisor(), 2);
+        assert!(product.is_pure_affine());
+        assert!(product.is_function_of_dim(0));
+        assert!(!product.is_symbolic_or_constant());
+        assert_eq!(product.context(), &context);
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_binary_invalid_kind() {
+        let context = Context::new(None, false);
+        let d0 = AffineExprRef::new_dim(&context, 0);
+        AffineExprRef::new_binary(AffineExprKind::Dim, d0, d0);
+    }
+
+    #[test]
+    fn leaves() {
+        let context = Context::new(None, false);
+        let d1 = AffineExprRef::new_dim(&context, 1);
+        assert_eq!(d1.kind(), AffineExprKind::Dim);
+        assert_eq!(d1.position(), Some(1));
+        assert_eq!(d1.constant_value(), None);
+        assert_eq!(d1.operands(), None);
+        let s2 = AffineExprRef::new_symbol(&context, 2);
+        assert_eq!(s2.kind(), AffineExprKind::Symbol);
+        assert_eq!(s2.position(), Some(2));
+        let constant = AffineExprRef::new_constant(&context, 7);
+        assert_eq!(constant.kind(), AffineExprKind::Constant);
+        assert_eq!(constant.constant_value(), Some(7));
+        assert!(constant.is_symbolic_or_constant());
+    }
+
+    #[test]
+    fn replace_dims_and_symbols() {
+        let context = Context::new(None, false);
+        let d0 = AffineExprRef::new_dim(&context, 0);
+        let d1 = AffineExprRef::new_dim(&context, 1);
+        let s0 = AffineExprRef::new_symbol(&context, 0);
+        let four = AffineExprRef::new_constant(&context, 4);
+        let expr = AffineExprRef::new_binary(
+            AffineExprKind::FloorDiv,
+            AffineExprRef::new_binary(AffineExprKind::Add, d0, s0),
+            four,
+        );
+        // Swapping two dimensions must not replace the result of the first replacement again.
+        let swapped = AffineExprRef::new_binary(AffineExprKind::Add, d0, d1)
+            .replace_dims_and_symbols(&[d1, d0], &[]);
+        assert_eq!(
+            swapped,
+            AffineExprRef::new_binary(AffineExprKind::Add, d1, d0)
+        );
+        let replaced = expr.replace_dims_and_symbols(&[d1], &[four]);
+        let expected = AffineExprRef::new_binary(
+            AffineExprKind::FloorDiv,
+            AffineExprRef::new_binary(AffineExprKind::Add, d1, four),
+            four,
+        );
+        assert_eq!(replaced, expected);
+    }
+
+    #[test]
+    fn compose() {
+        let context = Context::new(None, false);
+        let d0 = AffineExprRef::new_dim(&context, 0);
+        let affine_map = AffineMapRef::parse(&context, "(d0, d1) -> (d1 * 2)").unwrap();
+        assert_eq!(d0.compose(affine_map).to_string(), "d1 * 2");
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_affine_expr_ref() {
+        let _affine_expr_ref = AffineExprRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
@@ -0,0 +1,444 @@
+use crate::{
+    ir::AffineExprRef,
+    support::{
+        binding::{impl_unowned_mlir_value, UnownedMlirValue},
+        string_reader::StringReader,
+    },
+    ContextRef, StringRef,
+};
+
+use std::{
+    fmt::{Display, Formatter},
+    marker::PhantomData,
+};
+
+use mlir_sys::{
+    mlirAffineMapAttrGetValue, mlirAffineMapEqual, mlirAffineMapGet, mlirAffineMapGetContext,
+    mlirAffineMapGetNumDims, mlirAffineMapGetNumResults, mlirAffineMapGetNumSymbols,
+    mlirAffineMapGetResult, mlirAffineMapIsIdentity, mlirAffineMapIsPermutation,
+    mlirAffineMapIsProjectedPermutation, mlirAffineMapMultiDimIdentityGet,
+    mlirAffineMapPermutationGet, mlirAffineMapPrint, mlirAffineMapReplace,
+    mlirAttributeIsAAffineMap, mlirAttributeParseGet, MlirAffineMap,
+};
+
+/// [AffineMapRef] is a reference to an instance of the `mlir::AffineMap` class, which represents
+/// a mapping from a list of dimensions and symbols to a list of affine expressions.
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirAffineMapEqual`
+/// - `mlirAffineMapGet`
+/// - `mlirAffineMapGetContext`
+/// - `mlirAffineMapGetNumDims`
+/// - `mlirAffineMapGetNumResults`
+/// - `mlirAffineMapGetNumSymbols`
+/// - `mlirAffineMapGetResult`
+/// - `mlirAffineMapIsIdentity`
+/// - `mlirAffineMapIsPermutation`
+/// - `mlirAffineMapIsProjectedPermutation`
+/// - `mlirAffineMapMultiDimIdentityGet`
+/// - `mlirAffineMapPermutationGet`
+/// - `mlirAffineMapPrint`
+/// - `mlirAffineMapReplace`
+///
+/// The following bindings are not used/supported:
+/// - `mlirAffineMapCompressUnusedSymbols`
+/// - `mlirAffineMapConstantGet`
+/// - `mlirAffineMapDump`
+/// - `mlirAffineMapEmptyGet`
+/// - `mlirAffineMapGetMajorSubMap`
+/// - `mlirAffineMapGetMinorSubMap`
+/// - `mlirAffineMapGetNumInputs`
+/// - `mlirAffineMapGetSingleConstantResult`
+/// - `mlirAffineMapGetSubMap`
+/// - `mlirAffineMapIsEmpty`
+/// - `mlirAffineMapIsMinorIdentity`
+/// - `mlirAffineMapIsSingleConstant`
+/// - `mlirAffineMapMinorIdentityGet`
+/// - `mlirAffineMapZeroResultGet`
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct AffineMapRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, AffineMapRef, MlirAffineMap);
+
+impl AffineMapRef {
+    /// Attempts to parse an affine map from the provided string, e.g. `(d0, d1) -> (d1, d0)`.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the affine map.
+    /// * `affine_map` - The string to parse the affine map from.
+    ///
+    /// # Returns
+    /// Returns a new [AffineMapRef] reference if the affine map could be parsed, otherwise
+    /// `None`.
+    pub fn parse<'a>(context: &'a ContextRef, affine_map: &str) -> Option<&'a Self> {
+        let source = format!("affine_map<{}>", affine_map);
+        unsafe {
+            let attribute =
+                mlirAttributeParseGet(context.to_raw(), StringRef::from(&source).to_raw());
+            if attribute.ptr.is_null() || !mlirAttributeIsAAffineMap(attribute) {
+                return None;
+            }
+            Some(Self::from_raw(mlirAffineMapAttrGetValue(attribute)))
+        }
+    }
+
+    /// Constructs a new affine map from its results.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the affine map.
+    /// * `num_dims` - The number of dimensions of the affine map.
+    /// * `num_symbols` - The number of symbols of the affine map.
+    /// * `results` - The results of the affine map, which may only refer to its dimensions and
+    ///   symbols.
+    ///
+    /// # Returns
+    /// Returns a new [AffineMapRef] reference.
+    pub fn new<'a>(
+        context: &'a ContextRef,
+        num_dims: isize,
+        num_symbols: isize,
+        results: &[&AffineExprRef],
+    ) -> &'a Self {
+        let mut raw_results = results
+            .iter()
+            .map(|result| result.to_raw())
+            .collect::<Vec<_>>();
+        unsafe {
+            Self::from_raw(mlirAffineMapGet(
+                context.to_raw(),
+                num_dims,
+                num_symbols,
+                raw_results.len() as isize,
+                raw_results.as_mut_ptr(),
+            ))
+        }
+    }
+
+    /// Constructs a new identity affine map with the provided number of dimensions.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the affine map.
+    /// * `num_dims` - The number of dimensions of the affine map.
+    ///
+    /// # Returns
+    /// Returns a new [AffineMapRef] reference.
+    pub fn new_identity(context: &ContextRef, num_dims: isize) -> &Self {
+        unsafe { Self::from_raw(mlirAffineMapMultiDimIdentityGet(context.to_raw(), num_dims)) }
+    }
+
+    /// Constructs a new affine map that permutes its dimensions.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the affine map.
+    /// * `permutation` - The dimension that each result of the affine map refers to. Must be a
+    ///   permutation of `0..permutation.len()`.
+    ///
+    /// # Returns
+    /// Returns a new [AffineMapRef] reference.
+    pub fn new_permutation<'a>(context: &'a ContextRef, permutation: &[u32]) -> &'a Self {
+        let mut sorted = permutation.to_vec();
+        sorted.sort_unstable();
+        if !sorted
+            .iter()
+            .enumerate()
+            .all(|(idx, &dim)| idx as u32 == dim)
+        {
+            panic!("{:?} is not a permutation.", permutation);
+        }
+        unsafe {
+            Self::from_raw(mlirAffineMapPermutationGet(
+                context.to_raw(),
+                permutation.len() as isize,
+                permutation.as_ptr() as *mut u32,
+            ))
+        }
+    }
+
+    /// # Returns
+    /// Returns a reference to the context that owns the affine map.
+    pub fn context(&self) -> &ContextRef {
+        unsafe { ContextRef::from_raw(mlirAffineMapGetContext(self.to_raw())) }
+    }
+
+    /// # Returns
+    /// Returns the number of dimensions of the affine map.
+    pub fn num_dims(&self) -> isize {
+        unsafe { mlirAffineMapGetNumDims(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns the number of symbols of the affine map.
+    pub fn num_symbols(&self) -> isize {
+        unsafe { mlirAffineMapGetNumSymbols(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns the number of results of the affine map.
+    pub fn num_results(&self) -> isize {
+        unsafe { mlirAffineMapGetNumResults(self.to_raw()) }
+    }
+
+    /// # Arguments
+    /// * `pos` - The position of the result.
+    ///
+    /// # Returns
+    /// Returns the result of the affine map at the provided position, if there is one.
+    pub fn result(&self, pos: isize) -> Option<&AffineExprRef> {
+        (0..self.num_results())
+            .contains(&pos)
+            .then(|| unsafe { AffineExprRef::from_raw(mlirAffineMapGetResult(self.to_raw(), pos)) })
+    }
+
+    /// # Returns
+    /// Returns the results of the affine map.
+    pub fn results(&self) -> Vec<&AffineExprRef> {
+        (0..self.num_results())
+            .map(|pos| unsafe {
+                AffineExprRef::from_raw(mlirAffineMapGetResult(self.to_raw(), pos))
+            })
+            .collect()
+    }
+
+    /// # Returns
+    /// Returns whether the affine map is an identity map.
+    pub fn is_identity(&self) -> bool {
+        unsafe { mlirAffineMapIsIdentity(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns whether the affine map is a permutation of its dimensions, without symbols.
+    pub fn is_permutation(&self) -> bool {
+        unsafe { mlirAffineMapIsPermutation(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns whether each result of the affine map is a distinct dimension, e.g.
+    /// `(d0, d1, d2) -> (d2, d0)`.
+    pub fn is_projected_permutation(&self) -> bool {
+        unsafe { mlirAffineMapIsProjectedPermutation(self.to_raw()) }
+    }
+
+    /// Composes the affine map with another affine map, i.e. constructs the affine map which
+    /// applies the other affine map and then this one. The symbols of the other affine map come
+    /// first in the new affine map, followed by the symbols of this one.
+    ///
+    /// # Arguments
+    /// * `other` - The affine map to apply first, which must have as many results as this affine
+    ///   map has dimensions.
+    ///
+    /// # Returns
+    /// Returns the new affine map.
+    pub fn compose<'a>(&'a self, other: &'a AffineMapRef) -> &'a Self {
+        if self.num_dims() != other.num_results() {
+            panic!(
+                "Cannot compose {} with {}, which does not have {} results",
+                self,
+                other,
+                self.num_dims()
+            );
+        }
+        let context = self.context();
+        let dims = other.results();
+        let symbols = (0..self.num_symbols())
+            .map(|pos| AffineExprRef::new_symbol(context, other.num_symbols() + pos))
+            .collect::<Vec<_>>();
+        let results = self
+            .results()
+            .into_iter()
+            .map(|result| result.replace_dims_and_symbols(&dims, &symbols))
+            .collect::<Vec<_>>();
+        Self::new(
+            context,
+            other.num_dims(),
+            other.num_symbols() + self.num_symbols(),
+            &results,
+        )
+    }
+
+    /// Replaces every occurrence of an affine expression in the results of the affine map.
+    ///
+    /// # Arguments
+    /// * `expression` - The affine expression to replace.
+    /// * `replacement` - The affine expression to replace it with.
+    /// * `num_dims` - The number of dimensions of the new affine map.
+    /// * `num_symbols` - The number of symbols of the new affine map.
+    ///
+    /// # Returns
+    /// Returns the new affine map.
+    pub fn replace(
+        &self,
+        expression: &AffineExprRef,
+        replacement: &AffineExprRef,
+        num_dims: isize,
+        num_symbols: isize,
+    ) -> &Self {
+        unsafe {
+            Self::from_raw(mlirAffineMapReplace(
+                self.to_raw(),
+                expression.to_raw(),
+                replacement.to_raw(),
+                num_dims,
+                num_symbols,
+            ))
+        }
+    }
+
+    /// Replaces the dimensions and symbols in the results of the affine map with other affine
+    /// expressions (see [AffineExprRef::replace_dims_and_symbols]).
+    ///
+    /// # Arguments
+    /// * `dims` - The replacement of each dimension, by position.
+    /// * `symbols` - The replacement of each symbol, by position.
+    /// * `num_dims` - The number of dimensions of the new affine map.
+    /// * `num_symbols` - The number of symbols of the new affine map.
+    ///
+    /// # Returns
+    /// Returns the new affine map.
+    pub fn replace_dims_and_symbols<'a>(
+        &'a self,
+        dims: &[&'a AffineExprRef],
+        symbols: &[&'a AffineExprRef],
+        num_dims: isize,
+        num_symbols: isize,
+    ) -> &'a Self {
+        let results = self
+            .results()
+            .into_iter()
+            .map(|result| result.replace_dims_and_symbols(dims, symbols))
+            .collect::<Vec<_>>();
+        Self::new(self.context(), num_dims, num_symbols, &results)
+    }
+}
+
+impl PartialEq for AffineMapRef {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { mlirAffineMapEqual(self.to_raw(), other.to_raw()) }
+    }
+}
+
+impl Eq for AffineMapRef {}
+
+impl Display for AffineMapRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut reader = StringReader::new(f);
+        unsafe { mlirAffineMapPrint(self.to_raw(), reader.callback(), reader.as_raw_mut()) }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Context;
+
+    #[test]
+    fn parse() {
+        let context = Context::new(None, false);
+        let affine_map = AffineMapRef::parse(&context, "(d0, d1)[s0] -> (d1 + s0)").unwrap();
+        assert_eq!(affine_map.num_dims(), 2);
+        assert_eq!(affine_map.num_symbols(), 1);
+        assert_eq!(affine_map.num_results(), 1);
+        assert_eq!(affine_map.context(), &context);
+        assert!(AffineMapRef::parse(&context, "z").is_none());
+    }
+
+    #[test]
+    fn new_identity() {
+        let context = Context::new(None, false);
+        let affine_map = AffineMapRef::new_identity(&context, 2);
+        assert!(affine_map.is_identity());
+        assert_eq!(affine_map.to_string(), "(d0, d1) -> (d0, d1)");
+        assert_eq!(
+            Some(affine_map),
+            AffineMapRef::parse(&context, "(d0, d1) -> (d0, d1)")
+        );
+    }
+
+    #[test]
+    fn new_permutation() {
+        let context = Context::new(None, false);
+        let affine_map = AffineMapRef::new_permutation(&context, &[1, 0]);
+        assert!(!affine_map.is_identity());
+        assert_eq!(affine_map.to_string(), "(d0, d1) -> (d1, d0)");
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_permutation_invalid() {
+        let context = Context::new(None, false);
+        AffineMapRef::new_permutation(&context, &[1, 1]);
+    }
+
+    #[test]
+    fn results() {
+        let context = Context::new(None, false);
+        let d0 = AffineExprRef::new_dim(&context, 0);
+        let s0 = AffineExprRef::new_symbol(&context, 0);
+        let affine_map = AffineMapRef::new(&context, 2, 1, &[s0, d0]);
+        assert_eq!(affine_map.to_string(), "(d0, d1)[s0] -> (s0, d0)");
+        assert_eq!(affine_map.results(), vec![s0, d0]);
+        assert_eq!(affine_map.result(1), Some(d0));
+        assert_eq!(affine_map.result(2), None);
+        assert!(!affine_map.is_projected_permutation());
+    }
+
+    #[test]
+    fn permutations() {
+        let context = Context::new(None, false);
+        assert!(AffineMapRef::new_permutation(&context, &[2, 0, 1]).is_permutation());
+        let projection = AffineMapRef::parse(&context, "(d0, d1, d2) -> (d2, d0)").unwrap();
+        assert!(!projection.is_permutation());
+        assert!(projection.is_projected_permutation());
+    }
+
+    #[test]
+    fn compose() {
+        let context = Context::new(None, false);
+        let affine_map = AffineMapRef::parse(&context, "(d0, d1)[s0] -> (d0 + s0, d1)").unwrap();
+        let other = AffineMapRef::parse(&context, "(d0)[s0] -> (d0 * 2, s0)").unwrap();
+        assert_eq!(
+            affine_map.compose(other),
+            AffineMapRef::parse(&context, "(d0)[s0, s1] -> (d0 * 2 + s1, s0)").unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn compose_mismatch() {
+        let context = Context::new(None, false);
+        let affine_map = AffineMapRef::new_identity(&context, 2);
+        affine_map.compose(AffineMapRef::new_identity(&context, 3));
+    }
+
+    #[test]
+    fn replace() {
+        let context = Context::new(None, false);
+        let affine_map = AffineMapRef::parse(&context, "(d0, d1) -> (d0 + d1, d1)").unwrap();
+        let d0 = AffineExprRef::new_dim(&context, 0);
+        let d1 = AffineExprRef::new_dim(&context, 1);
+        let s0 = AffineExprRef::new_symbol(&context, 0);
+        assert_eq!(
+            affine_map.replace(d1, s0, 1, 1),
+            AffineMapRef::parse(&context, "(d0)[s0] -> (d0 + s0, s0)").unwrap()
+        );
+        assert_eq!(
+            affine_map.replace_dims_and_symbols(&[d1, d0], &[], 2, 0),
+            AffineMapRef::parse(&context, "(d0, d1) -> (d1 + d0, d0)").unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_affine_map_ref() {
+        let _affine_map_ref = AffineMapRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
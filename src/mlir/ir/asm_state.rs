@@ -0,0 +1,70 @@
+use crate::{
+    ir::{OperationRef, ValueRef},
+    support::binding::{impl_owned_mlir_value, OwnedMlirValue, UnownedMlirValue},
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{
+    mlirAsmStateCreateForOperation, mlirAsmStateCreateForValue, mlirAsmStateDestroy,
+    mlirOpPrintingFlagsCreate, mlirOpPrintingFlagsDestroy, MlirAsmState,
+};
+
+/// [AsmState] wraps the `mlir::AsmState` class, which holds the state (such as the names assigned
+/// to values) used when printing IR. Creating it walks the IR it is created for, so a single
+/// [AsmState] should be reused when printing many values nested in the same operation.
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirAsmStateCreateForOperation`
+/// - `mlirAsmStateCreateForValue`
+/// - `mlirAsmStateDestroy`
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct AsmState<'c> {
+    raw: MlirAsmState,
+    _context: PhantomData<&'c ()>,
+}
+
+impl_owned_mlir_value!(context_ref, AsmState, MlirAsmState);
+
+impl<'c> AsmState<'c> {
+    /// Creates a new printing state for the provided operation and the IR nested within it, using
+    /// the default printing flags.
+    ///
+    /// # Arguments
+    /// * `operation` - The operation to create the printing state for.
+    ///
+    /// # Returns
+    /// Returns a new [AsmState] instance.
+    pub fn for_operation(operation: &OperationRef<'c>) -> Self {
+        unsafe {
+            let flags = mlirOpPrintingFlagsCreate();
+            let raw = mlirAsmStateCreateForOperation(operation.to_raw(), flags);
+            mlirOpPrintingFlagsDestroy(flags);
+            Self::from_raw(raw)
+        }
+    }
+
+    /// Creates a new printing state for the IR surrounding the provided value, using the default
+    /// printing flags.
+    ///
+    /// # Arguments
+    /// * `value` - The value to create the printing state for.
+    ///
+    /// # Returns
+    /// Returns a new [AsmState] instance.
+    pub fn for_value(value: &ValueRef<'c>) -> Self {
+        unsafe {
+            let flags = mlirOpPrintingFlagsCreate();
+            let raw = mlirAsmStateCreateForValue(value.to_raw(), flags);
+            mlirOpPrintingFlagsDestroy(flags);
+            Self::from_raw(raw)
+        }
+    }
+}
+
+impl<'c> Drop for AsmState<'c> {
+    fn drop(&mut self) {
+        unsafe { mlirAsmStateDestroy(self.raw) }
+    }
+}
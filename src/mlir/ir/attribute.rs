@@ -1,35 +1,55 @@
+mod array;
 mod bool;
+mod dense_array;
 mod dense_bool;
-mod dense_i32;
+mod dense_elements;
+mod dense_fp_elements;
+mod dense_int_elements;
+mod dense_resource_elements;
 mod dense_string;
+mod dictionary;
+mod elements;
 mod float;
+mod gpu_object;
 mod integer;
+mod opaque;
+mod sparse_tensor_encoding;
+mod strided_layout;
 mod string;
+mod symbol_ref;
+mod r#type;
+mod unit;
 
 pub use self::{
-    bool::*, dense_bool::*, dense_i32::*, dense_string::*, float::*, integer::*, string::*,
+    array::*, bool::*, dense_array::*, dense_bool::*, dense_elements::*, dense_fp_elements::*,
+    dense_int_elements::*, dense_resource_elements::*, dense_string::*, dictionary::*, elements::*,
+    float::*, gpu_object::*, integer::*, opaque::*, r#type::*, sparse_tensor_encoding::*,
+    strided_layout::*, string::*, symbol_ref::*, unit::*,
 };
 use crate::{
-    ir::{IdentifierRef, TypeRef},
+    ir::{FloatKind, FloatTypeRef, IdentifierRef, IntegerTypeRef, TypeRef},
     support::{
         binding::{impl_unowned_mlir_value, UnownedMlirValue},
         string_reader::StringReader,
     },
-    ContextRef, DialectRef, StringRef,
+    ContextRef, Diagnostic, DialectRef, StringRef,
 };
 
 use std::{
+    error::Error,
     fmt::{Display, Formatter},
     marker::PhantomData,
 };
 
 use mlir_sys::{
     mlirAttributeEqual, mlirAttributeGetContext, mlirAttributeGetDialect, mlirAttributeGetType,
-    mlirAttributeParseGet, mlirAttributePrint, MlirAttribute, MlirIdentifier, MlirNamedAttribute,
+    mlirAttributeParseGet, mlirAttributePrint, mlirDisctinctAttrCreate, MlirAttribute,
+    MlirIdentifier, MlirNamedAttribute,
 };
 
 /// [AttributeRef] is a reference to an instance of the `mlir::Attribute` class, which represents a
-/// constant value in the MLIR IR.
+/// constant value in the MLIR IR. The most common builtin attributes can be constructed directly
+/// through it, without first looking up the matching variant type.
 ///
 /// The following bindings into the MLIR C API are used/supported:
 /// - `mlirAttributeEqual`
@@ -38,6 +58,7 @@ use mlir_sys::{
 /// - `mlirAttributeGetType`
 /// - `mlirAttributeParseGet`
 /// - `mlirAttributePrint`
+/// - `mlirDisctinctAttrCreate`
 ///
 /// The following bindings are not used/supported:
 /// - `mlirAttributeDump`
@@ -73,6 +94,32 @@ impl AttributeRef {
         }
     }
 
+    /// Attempts to parse an attribute from the provided string and to downcast it to the
+    /// requested attribute variant, capturing the diagnostics emitted while parsing.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the attribute.
+    /// * `attribute` - The string to parse the attribute from.
+    ///
+    /// # Returns
+    /// Returns a reference to the parsed attribute as the requested variant, or a
+    /// [ParseAttributeError] describing why the attribute could not be parsed or downcast.
+    pub fn parse_as<'a, T: AttributeVariant>(
+        context: &'a ContextRef,
+        attribute: &str,
+    ) -> Result<&'a T, ParseAttributeError> {
+        let capture = context.capture_diagnostics();
+        let parsed =
+            Self::parse(context, attribute).ok_or_else(|| ParseAttributeError::Invalid {
+                source: attribute.to_string(),
+                diagnostics: capture.take(),
+            })?;
+        T::try_from_attribute(parsed).ok_or_else(|| ParseAttributeError::Mismatch {
+            expected: T::NAME,
+            attribute: parsed.to_string(),
+        })
+    }
+
     /// # Returns
     /// Returns a reference to the context that owns the attribute.
     pub fn context(&self) -> &ContextRef {
@@ -94,8 +141,179 @@ impl AttributeRef {
     /// # Returns
     /// Returns a named attribute with the provided name.
     pub fn with_name(&self, name: &str) -> NamedAttribute {
-        let identifier = IdentifierRef::new(self.context(), name);
-        unsafe { NamedAttribute::from_raw(identifier.to_raw(), self.to_raw()) }
+        NamedAttribute::new(self.context(), name, self)
+    }
+
+    /// Classifies the attribute by checking it against each of the supported attribute variants,
+    /// so that it can be matched on directly.
+    ///
+    /// # Returns
+    /// Returns the [Attribute] variant that the attribute belongs to.
+    pub fn classify(&self) -> Attribute {
+        if let Some(attr) = UnitAttributeRef::try_from_attribute(self) {
+            Attribute::Unit(attr)
+        } else if let Some(attr) = BoolAttributeRef::try_from_attribute(self) {
+            Attribute::Bool(attr)
+        } else if let Some(attr) = IntegerAttributeRef::try_from_attribute(self) {
+            Attribute::Integer(attr)
+        } else if let Some(attr) = FloatAttributeRef::try_from_attribute(self) {
+            Attribute::Float(attr)
+        } else if let Some(attr) = StringAttributeRef::try_from_attribute(self) {
+            Attribute::String(attr)
+        } else if let Some(attr) = ArrayAttributeRef::try_from_attribute(self) {
+            Attribute::Array(attr)
+        } else if let Some(attr) = DictionaryAttributeRef::try_from_attribute(self) {
+            Attribute::Dictionary(attr)
+        } else if let Some(attr) = TypeAttributeRef::try_from_attribute(self) {
+            Attribute::Type(attr)
+        } else if let Some(attr) = FlatSymbolRefAttributeRef::try_from_attribute(self) {
+            Attribute::FlatSymbolRef(attr)
+        } else if let Some(attr) = SymbolRefAttributeRef::try_from_attribute(self) {
+            Attribute::SymbolRef(attr)
+        } else if let Some(attr) = DenseBoolAttributeRef::try_from_attribute(self) {
+            Attribute::DenseBoolArray(attr)
+        } else if let Some(attr) = DenseI8AttributeRef::try_from_attribute(self) {
+            Attribute::DenseI8Array(attr)
+        } else if let Some(attr) = DenseI16AttributeRef::try_from_attribute(self) {
+            Attribute::DenseI16Array(attr)
+        } else if let Some(attr) = DenseI32AttributeRef::try_from_attribute(self) {
+            Attribute::DenseI32Array(attr)
+        } else if let Some(attr) = DenseI64AttributeRef::try_from_attribute(self) {
+            Attribute::DenseI64Array(attr)
+        } else if let Some(attr) = DenseF32AttributeRef::try_from_attribute(self) {
+            Attribute::DenseF32Array(attr)
+        } else if let Some(attr) = DenseF64AttributeRef::try_from_attribute(self) {
+            Attribute::DenseF64Array(attr)
+        } else if let Some(attr) = DenseIntElementsAttributeRef::try_from_attribute(self) {
+            Attribute::DenseIntElements(attr)
+        } else if let Some(attr) = DenseFPElementsAttributeRef::try_from_attribute(self) {
+            Attribute::DenseFPElements(attr)
+        } else if let Some(attr) = DenseElementsAttributeRef::try_from_attribute(self) {
+            Attribute::DenseElements(attr)
+        } else if let Some(attr) = DenseResourceElementsAttributeRef::try_from_attribute(self) {
+            Attribute::DenseResourceElements(attr)
+        } else if let Some(attr) = ElementsAttributeRef::try_from_attribute(self) {
+            Attribute::Elements(attr)
+        } else if let Some(attr) = StridedLayoutAttributeRef::try_from_attribute(self) {
+            Attribute::StridedLayout(attr)
+        } else if let Some(attr) = OpaqueAttributeRef::try_from_attribute(self) {
+            Attribute::Opaque(attr)
+        } else {
+            Attribute::Other(self)
+        }
+    }
+
+    /// Constructs a new signless integer attribute with the provided value and bitwidth.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the attribute.
+    /// * `value` - The integer value to hold in the attribute.
+    /// * `bitwidth` - The bitwidth of the integer type of the attribute.
+    ///
+    /// # Returns
+    /// Returns a reference to a new integer attribute.
+    pub fn integer(context: &ContextRef, value: i64, bitwidth: u32) -> &AttributeRef {
+        let ty = IntegerTypeRef::new_signless(context, bitwidth);
+        IntegerAttributeRef::new(ty, value).as_attribute()
+    }
+
+    /// Constructs a new float attribute of the provided kind with the provided value.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the attribute.
+    /// * `value` - The float value to hold in the attribute.
+    /// * `kind` - The kind of the float type of the attribute.
+    ///
+    /// # Returns
+    /// Returns a reference to a new float attribute.
+    pub fn float(context: &ContextRef, value: f64, kind: FloatKind) -> &AttributeRef {
+        let ty = FloatTypeRef::new(context, kind);
+        FloatAttributeRef::new(context, ty, value).as_attribute()
+    }
+
+    /// Constructs a new string attribute with the provided value.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the attribute.
+    /// * `value` - The string value to hold in the attribute.
+    ///
+    /// # Returns
+    /// Returns a reference to a new string attribute.
+    pub fn string<'a>(context: &'a ContextRef, value: &str) -> &'a AttributeRef {
+        StringAttributeRef::new(context, value).as_attribute()
+    }
+
+    /// Constructs a new bool attribute with the provided value.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the attribute.
+    /// * `value` - The bool value to hold in the attribute.
+    ///
+    /// # Returns
+    /// Returns a reference to a new bool attribute.
+    pub fn bool(context: &ContextRef, value: bool) -> &AttributeRef {
+        BoolAttributeRef::new(context, value).as_attribute()
+    }
+
+    /// # Arguments
+    /// * `context` - The context that should own the attribute.
+    ///
+    /// # Returns
+    /// Returns a reference to the unit attribute.
+    pub fn unit(context: &ContextRef) -> &AttributeRef {
+        UnitAttributeRef::new(context).as_attribute()
+    }
+
+    /// Constructs a new array attribute with the provided elements.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the attribute.
+    /// * `elements` - The elements of the array.
+    ///
+    /// # Returns
+    /// Returns a reference to a new array attribute.
+    pub fn array<'a>(context: &'a ContextRef, elements: &[&AttributeRef]) -> &'a AttributeRef {
+        ArrayAttributeRef::new(context, elements).as_attribute()
+    }
+
+    /// Constructs a new dictionary attribute with the provided elements.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the attribute.
+    /// * `elements` - The named attributes to hold in the dictionary.
+    ///
+    /// # Returns
+    /// Returns a reference to a new dictionary attribute.
+    pub fn dictionary<'a>(
+        context: &'a ContextRef,
+        elements: &[NamedAttribute],
+    ) -> &'a AttributeRef {
+        DictionaryAttributeRef::new(context, elements).as_attribute()
+    }
+
+    /// Constructs a new flat symbol reference attribute referring to the provided symbol.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the attribute.
+    /// * `symbol` - The name of the referenced symbol.
+    ///
+    /// # Returns
+    /// Returns a reference to a new flat symbol reference attribute.
+    pub fn symbol_ref<'a>(context: &'a ContextRef, symbol: &str) -> &'a AttributeRef {
+        FlatSymbolRefAttributeRef::new(context, symbol).as_attribute()
+    }
+
+    /// Constructs a new distinct attribute referencing the provided attribute. Unlike all other
+    /// attributes, distinct attributes are not uniqued, so every call returns a new attribute that
+    /// is only equal to itself (as required e.g. for LLVM debug info metadata).
+    ///
+    /// # Arguments
+    /// * `referenced` - The attribute referenced by the distinct attribute.
+    ///
+    /// # Returns
+    /// Returns a reference to a new distinct attribute.
+    pub fn distinct(referenced: &AttributeRef) -> &AttributeRef {
+        unsafe { AttributeRef::from_raw(mlirDisctinctAttrCreate(referenced.to_raw())) }
     }
 }
 
@@ -107,6 +325,12 @@ impl PartialEq for AttributeRef {
 
 impl Eq for AttributeRef {}
 
+impl AsRef<AttributeRef> for AttributeRef {
+    fn as_ref(&self) -> &AttributeRef {
+        self
+    }
+}
+
 impl Display for AttributeRef {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let mut reader = StringReader::new(f);
@@ -124,6 +348,26 @@ pub struct NamedAttribute<'a> {
 }
 
 impl<'a> NamedAttribute<'a> {
+    /// Constructs a new [NamedAttribute] with the provided name and attribute.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the identifier of the name.
+    /// * `name` - The name of the attribute.
+    /// * `attribute` - The attribute value.
+    ///
+    /// # Returns
+    /// Returns a new [NamedAttribute] instance.
+    pub fn new(
+        context: &'a ContextRef,
+        name: &str,
+        attribute: &'a (impl AsRef<AttributeRef> + ?Sized),
+    ) -> Self {
+        NamedAttribute {
+            name: IdentifierRef::new(context, name),
+            attribute: attribute.as_ref(),
+        }
+    }
+
     /// Constructs a new [NamedAttribute] from the provided raw identifier and attribute.
     ///
     /// # Safety
@@ -169,8 +413,162 @@ impl<'a> NamedAttribute<'a> {
     }
 }
 
+impl<'a, A: AsRef<AttributeRef> + ?Sized> From<(&str, &'a A)> for NamedAttribute<'a> {
+    /// Constructs a new [NamedAttribute] from a name-attribute pair, constructing the identifier
+    /// of the name in the context of the attribute.
+    fn from((name, attribute): (&str, &'a A)) -> Self {
+        let attribute = attribute.as_ref();
+        NamedAttribute::new(attribute.context(), name, attribute)
+    }
+}
+
+impl<'a, A: AsRef<AttributeRef> + ?Sized> From<(&'a IdentifierRef, &'a A)> for NamedAttribute<'a> {
+    fn from((name, attribute): (&'a IdentifierRef, &'a A)) -> Self {
+        NamedAttribute {
+            name,
+            attribute: attribute.as_ref(),
+        }
+    }
+}
+
+/// [Attribute] is the result of classifying an [AttributeRef] into one of the supported attribute
+/// variants, which allows matching on an attribute instead of attempting each variant in turn.
+#[derive(Debug, Clone, Copy)]
+pub enum Attribute<'a> {
+    Unit(&'a UnitAttributeRef),
+    Bool(&'a BoolAttributeRef),
+    Integer(&'a IntegerAttributeRef),
+    Float(&'a FloatAttributeRef),
+    String(&'a StringAttributeRef),
+    Array(&'a ArrayAttributeRef),
+    Dictionary(&'a DictionaryAttributeRef),
+    Type(&'a TypeAttributeRef),
+    FlatSymbolRef(&'a FlatSymbolRefAttributeRef),
+    /// A symbol reference with nested references.
+    SymbolRef(&'a SymbolRefAttributeRef),
+    DenseBoolArray(&'a DenseBoolAttributeRef),
+    DenseI8Array(&'a DenseI8AttributeRef),
+    DenseI16Array(&'a DenseI16AttributeRef),
+    DenseI32Array(&'a DenseI32AttributeRef),
+    DenseI64Array(&'a DenseI64AttributeRef),
+    DenseF32Array(&'a DenseF32AttributeRef),
+    DenseF64Array(&'a DenseF64AttributeRef),
+    DenseIntElements(&'a DenseIntElementsAttributeRef),
+    DenseFPElements(&'a DenseFPElementsAttributeRef),
+    /// Any other dense elements attribute, such as a dense array of strings.
+    DenseElements(&'a DenseElementsAttributeRef),
+    DenseResourceElements(&'a DenseResourceElementsAttributeRef),
+    /// Any other elements attribute, such as a sparse elements attribute.
+    Elements(&'a ElementsAttributeRef),
+    StridedLayout(&'a StridedLayoutAttributeRef),
+    Opaque(&'a OpaqueAttributeRef),
+    /// An attribute that does not belong to any of the supported variants.
+    Other(&'a AttributeRef),
+}
+
+impl<'a> Attribute<'a> {
+    /// # Returns
+    /// Returns the classified attribute as an [AttributeRef].
+    pub fn as_attribute(&self) -> &'a AttributeRef {
+        match *self {
+            Self::Unit(attr) => attr.as_attribute(),
+            Self::Bool(attr) => attr.as_attribute(),
+            Self::Integer(attr) => attr.as_attribute(),
+            Self::Float(attr) => attr.as_attribute(),
+            Self::String(attr) => attr.as_attribute(),
+            Self::Array(attr) => attr.as_attribute(),
+            Self::Dictionary(attr) => attr.as_attribute(),
+            Self::Type(attr) => attr.as_attribute(),
+            Self::FlatSymbolRef(attr) => attr.as_attribute(),
+            Self::SymbolRef(attr) => attr.as_attribute(),
+            Self::DenseBoolArray(attr) => attr.as_attribute(),
+            Self::DenseI8Array(attr) => attr.as_attribute(),
+            Self::DenseI16Array(attr) => attr.as_attribute(),
+            Self::DenseI32Array(attr) => attr.as_attribute(),
+            Self::DenseI64Array(attr) => attr.as_attribute(),
+            Self::DenseF32Array(attr) => attr.as_attribute(),
+            Self::DenseF64Array(attr) => attr.as_attribute(),
+            Self::DenseIntElements(attr) => attr.as_attribute(),
+            Self::DenseFPElements(attr) => attr.as_attribute(),
+            Self::DenseElements(attr) => attr.as_attribute(),
+            Self::DenseResourceElements(attr) => attr.as_attribute(),
+            Self::Elements(attr) => attr.as_attribute(),
+            Self::StridedLayout(attr) => attr.as_attribute(),
+            Self::Opaque(attr) => attr.as_attribute(),
+            Self::Other(attr) => attr,
+        }
+    }
+}
+
+/// [AttributeVariant] is implemented by every attribute variant, allowing code to be generic
+/// over the variant it downcasts an [AttributeRef] to.
+pub trait AttributeVariant {
+    /// The name of the attribute variant.
+    const NAME: &'static str;
+
+    /// Attempts to downcast the provided attribute to this variant.
+    ///
+    /// # Arguments
+    /// * `attr` - The attribute to downcast.
+    ///
+    /// # Returns
+    /// Returns a reference to the attribute as this variant, or `None` if it is a different
+    /// attribute.
+    fn try_from_attribute(attr: &AttributeRef) -> Option<&Self>;
+}
+
+impl AttributeVariant for AttributeRef {
+    const NAME: &'static str = "AttributeRef";
+
+    fn try_from_attribute(attr: &AttributeRef) -> Option<&Self> {
+        Some(attr)
+    }
+}
+
+/// [ParseAttributeError] is returned by [AttributeRef::parse_as] when an attribute could not be
+/// parsed or is not of the requested variant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseAttributeError {
+    /// The source could not be parsed as an attribute.
+    Invalid {
+        source: String,
+        diagnostics: Vec<Diagnostic>,
+    },
+    /// The parsed attribute is not of the requested variant.
+    Mismatch {
+        expected: &'static str,
+        attribute: String,
+    },
+}
+
+impl Display for ParseAttributeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Invalid {
+                source,
+                diagnostics,
+            } => {
+                write!(f, "failed to parse attribute '{}'", source)?;
+                for diagnostic in diagnostics {
+                    write!(f, "\n{}", diagnostic)?;
+                }
+                Ok(())
+            }
+            Self::Mismatch {
+                expected,
+                attribute,
+            } => write!(f, "expected {}, got '{}'", expected, attribute),
+        }
+    }
+}
+
+impl Error for ParseAttributeError {}
+
 macro_rules! impl_attribute_variant {
     ($variant_type:ident, $verify_fn:ident) => {
+        impl_attribute_variant!($variant_type, $verify_fn, $crate::ir::AttributeRef);
+    };
+    ($variant_type:ident, $verify_fn:ident, $target_type:ty) => {
         impl $variant_type {
             pub fn try_from_attribute(attr: &$crate::ir::AttributeRef) -> Option<&Self> {
                 let types_match = unsafe { $verify_fn(attr.to_raw()) };
@@ -186,10 +584,24 @@ macro_rules! impl_attribute_variant {
             }
         }
 
+        impl $crate::ir::AttributeVariant for $variant_type {
+            const NAME: &'static str = stringify!($variant_type);
+
+            fn try_from_attribute(attr: &$crate::ir::AttributeRef) -> Option<&Self> {
+                $variant_type::try_from_attribute(attr)
+            }
+        }
+
         impl std::ops::Deref for $variant_type {
-            type Target = $crate::ir::AttributeRef;
+            type Target = $target_type;
 
             fn deref(&self) -> &Self::Target {
+                unsafe { <$target_type>::from_raw(self.to_raw()) }
+            }
+        }
+
+        impl AsRef<$crate::ir::AttributeRef> for $variant_type {
+            fn as_ref(&self) -> &$crate::ir::AttributeRef {
                 self.as_attribute()
             }
         }
@@ -201,7 +613,10 @@ use impl_attribute_variant;
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{ir::NoneTypeRef, Context};
+    use crate::{
+        ir::{NoneTypeRef, RankedTensorTypeRef},
+        Context,
+    };
 
     #[test]
     fn parse_attribute() {
@@ -245,6 +660,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn named_attribute() {
+        let context = Context::new(None, false);
+        let integer = IntegerAttributeRef::new(IntegerTypeRef::new_signless(&context, 32), 1);
+        let named_attribute = NamedAttribute::new(&context, "a", integer);
+        assert_eq!(named_attribute.name().value(), "a");
+        assert_eq!(named_attribute.attribute(), integer.as_attribute());
+
+        let named_attribute = NamedAttribute::from(("b", integer));
+        assert_eq!(named_attribute.name().value(), "b");
+        assert_eq!(named_attribute.attribute(), integer.as_attribute());
+
+        let name = IdentifierRef::new(&context, "c");
+        let named_attribute: NamedAttribute = (name, integer.as_attribute()).into();
+        assert_eq!(named_attribute.name().value(), "c");
+    }
+
     #[test]
     fn compare_attributes() {
         let context = Context::new(None, false);
@@ -260,6 +692,133 @@ mod tests {
         assert_ne!(attribute3, attribute2);
     }
 
+    #[test]
+    fn classify() {
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let classify = |source| AttributeRef::parse(&context, source).unwrap().classify();
+        assert!(matches!(classify("unit"), Attribute::Unit(_)));
+        assert!(matches!(classify("true"), Attribute::Bool(_)));
+        assert!(matches!(classify("1 : i32"), Attribute::Integer(_)));
+        assert!(matches!(classify("1.0 : f32"), Attribute::Float(_)));
+        assert!(matches!(classify(r#""x""#), Attribute::String(_)));
+        assert!(matches!(classify("[1 : i32]"), Attribute::Array(_)));
+        assert!(matches!(classify("{a}"), Attribute::Dictionary(_)));
+        assert!(matches!(classify("i32"), Attribute::Type(_)));
+        assert!(matches!(classify("@f"), Attribute::FlatSymbolRef(_)));
+        assert!(matches!(classify("@f::@g"), Attribute::SymbolRef(_)));
+        let bool_array = classify("array<i1: true, false>");
+        assert!(matches!(bool_array, Attribute::DenseBoolArray(_)));
+        assert!(matches!(
+            classify("array<i8: 1>"),
+            Attribute::DenseI8Array(_)
+        ));
+        assert!(matches!(
+            classify("array<i16: 1>"),
+            Attribute::DenseI16Array(_)
+        ));
+        assert!(matches!(
+            classify("array<i32: 1>"),
+            Attribute::DenseI32Array(_)
+        ));
+        assert!(matches!(
+            classify("array<i64: 1>"),
+            Attribute::DenseI64Array(_)
+        ));
+        assert!(matches!(
+            classify("array<f32: 1.0>"),
+            Attribute::DenseF32Array(_)
+        ));
+        assert!(matches!(
+            classify("array<f64: 1.0>"),
+            Attribute::DenseF64Array(_)
+        ));
+        let int_elements = classify("dense<1> : tensor<2xi8>");
+        assert!(matches!(int_elements, Attribute::DenseIntElements(_)));
+        let fp_elements = classify("dense<1.0> : tensor<2xf32>");
+        assert!(matches!(fp_elements, Attribute::DenseFPElements(_)));
+        let string_elements = classify(r#"dense<["a"]> : tensor<1x!dialect.string>"#);
+        assert!(matches!(string_elements, Attribute::DenseElements(_)));
+        let i8_type = IntegerTypeRef::new_signless(&context, 8);
+        let tensor_type = RankedTensorTypeRef::new(&[1], i8_type, None);
+        let data = vec![1i8].into_boxed_slice();
+        let resource_elements = DenseResourceElementsAttributeRef::new(tensor_type, "blob", data);
+        assert!(matches!(
+            resource_elements.classify(),
+            Attribute::DenseResourceElements(_)
+        ));
+        let sparse_elements = classify("sparse<[[0]], [1]> : tensor<2xi32>");
+        assert!(matches!(sparse_elements, Attribute::Elements(_)));
+        assert!(matches!(
+            classify("strided<[1]>"),
+            Attribute::StridedLayout(_)
+        ));
+        let opaque = classify(r#"#dialect<"attr">"#);
+        assert!(matches!(opaque, Attribute::Opaque(_)));
+        let affine_map = classify("affine_map<(d0) -> (d0)>");
+        assert!(matches!(affine_map, Attribute::Other(_)));
+        assert_eq!(classify("@f").as_attribute().to_string(), "@f");
+    }
+
+    #[test]
+    fn parse_as() {
+        let context = Context::new(None, false);
+        let attribute = AttributeRef::parse_as::<StringAttributeRef>(&context, r#""x""#).unwrap();
+        assert_eq!(attribute.value(), "x");
+        let attribute = AttributeRef::parse_as::<AttributeRef>(&context, "unit").unwrap();
+        assert_eq!(attribute.to_string(), "unit");
+
+        let error = AttributeRef::parse_as::<StringAttributeRef>(&context, "1 : i32").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "expected StringAttributeRef, got '1 : i32'"
+        );
+        let error = AttributeRef::parse_as::<StringAttributeRef>(&context, "z").unwrap_err();
+        let ParseAttributeError::Invalid { diagnostics, .. } = error else {
+            panic!("Expected an invalid attribute error.");
+        };
+        assert_eq!(diagnostics.len(), 1);
+    }
+
+    #[test]
+    fn constructors() {
+        let context = Context::new(None, false);
+        let integer = AttributeRef::integer(&context, 42, 32);
+        assert_eq!(integer.to_string(), "42 : i32");
+        assert!(IntegerAttributeRef::try_from_attribute(integer).is_some());
+        let float = AttributeRef::float(&context, 1.5, FloatKind::F32);
+        assert_eq!(float.to_string(), "1.500000e+00 : f32");
+        assert!(FloatAttributeRef::try_from_attribute(float).is_some());
+        let string = AttributeRef::string(&context, "x");
+        assert_eq!(string.to_string(), r#""x""#);
+        assert!(StringAttributeRef::try_from_attribute(string).is_some());
+        let bool = AttributeRef::bool(&context, true);
+        assert_eq!(bool.to_string(), "true");
+        assert!(BoolAttributeRef::try_from_attribute(bool).is_some());
+        assert_eq!(AttributeRef::unit(&context).to_string(), "unit");
+        let array = AttributeRef::array(&context, &[integer, string]);
+        assert_eq!(array.to_string(), r#"[42 : i32, "x"]"#);
+        assert!(ArrayAttributeRef::try_from_attribute(array).is_some());
+        let dictionary = AttributeRef::dictionary(&context, &[integer.with_name("a")]);
+        assert_eq!(dictionary.to_string(), "{a = 42 : i32}");
+        assert!(DictionaryAttributeRef::try_from_attribute(dictionary).is_some());
+        let symbol_ref = AttributeRef::symbol_ref(&context, "f");
+        assert_eq!(symbol_ref.to_string(), "@f");
+        assert!(FlatSymbolRefAttributeRef::try_from_attribute(symbol_ref).is_some());
+    }
+
+    #[test]
+    fn distinct() {
+        let context = Context::new(None, false);
+        let unit = AttributeRef::unit(&context);
+        let distinct1 = AttributeRef::distinct(unit);
+        let distinct2 = AttributeRef::distinct(unit);
+        assert_eq!(distinct1, distinct1);
+        assert_ne!(distinct1, distinct2);
+        assert!(distinct1.to_string().starts_with("distinct["));
+        assert!(distinct1.to_string().ends_with("]<unit>"));
+    }
+
     #[test]
     #[should_panic]
     fn no_owned_attribute_ref() {
@@ -0,0 +1,142 @@
+use super::impl_attribute_variant;
+use crate::{
+    ir::AttributeRef,
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+    ContextRef,
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{
+    mlirArrayAttrGet, mlirArrayAttrGetElement, mlirArrayAttrGetNumElements, mlirAttributeIsAArray,
+    MlirAttribute,
+};
+
+/// [ArrayAttributeRef] is a reference to an instance of the `mlir::ArrayAttr` class, which
+/// represents an ordered list of attributes in the MLIR IR.
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirArrayAttrGet`
+/// - `mlirArrayAttrGetElement`
+/// - `mlirArrayAttrGetNumElements`
+/// - `mlirAttributeIsAArray`
+///
+/// The following bindings are not used/supported:
+/// - `mlirArrayAttrGetTypeID`
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct ArrayAttributeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, ArrayAttributeRef, MlirAttribute);
+impl_attribute_variant!(ArrayAttributeRef, mlirAttributeIsAArray);
+
+impl ArrayAttributeRef {
+    /// Constructs a new array attribute with the provided elements.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the attribute.
+    /// * `elements` - The attributes to hold in the array.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [ArrayAttributeRef] instance.
+    pub fn new<'a>(context: &'a ContextRef, elements: &[&AttributeRef]) -> &'a Self {
+        unsafe {
+            Self::from_raw(mlirArrayAttrGet(
+                context.to_raw(),
+                elements.len() as isize,
+                elements.as_ptr() as *const MlirAttribute,
+            ))
+        }
+    }
+
+    /// # Returns
+    /// Returns the length of the array.
+    pub fn len(&self) -> isize {
+        unsafe { mlirArrayAttrGetNumElements(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns whether the array is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the element at the provided index, verifying that the index is within bounds.
+    ///
+    /// # Arguments
+    /// * `idx` - The index of the element to get.
+    ///
+    /// # Returns
+    /// Returns a reference to the element at the provided index.
+    pub fn get(&self, idx: isize) -> &AttributeRef {
+        if idx < 0 || idx >= self.len() {
+            panic!("Element index {} out of bounds.", idx);
+        }
+        unsafe { AttributeRef::from_raw(mlirArrayAttrGetElement(self.to_raw(), idx)) }
+    }
+
+    /// # Returns
+    /// Returns an iterator over the elements of the array.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &AttributeRef> {
+        (0..self.len()).map(|idx| self.get(idx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Context;
+
+    #[test]
+    fn new() {
+        let context = Context::new(None, false);
+        let unit = AttributeRef::parse(&context, "unit").unwrap();
+        let string = AttributeRef::parse(&context, r#""foo""#).unwrap();
+        let array = ArrayAttributeRef::new(&context, &[unit, string]);
+        assert_eq!(array.to_string(), r#"[unit, "foo"]"#);
+        assert_eq!(array.len(), 2);
+        assert!(!array.is_empty());
+        assert_eq!(array.get(1), string);
+        assert_eq!(array.iter().collect::<Vec<_>>(), vec![unit, string]);
+    }
+
+    #[test]
+    fn empty() {
+        let context = Context::new(None, false);
+        let array = ArrayAttributeRef::new(&context, &[]);
+        assert_eq!(array.to_string(), "[]");
+        assert!(array.is_empty());
+        assert_eq!(array.iter().count(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_out_of_bounds() {
+        let context = Context::new(None, false);
+        ArrayAttributeRef::new(&context, &[]).get(0);
+    }
+
+    #[test]
+    fn from_attribute() {
+        let context = Context::new(None, false);
+        let erased_array = AttributeRef::parse(&context, "[1 : i32, 2 : i32]").unwrap();
+        let erased_unit = AttributeRef::parse(&context, "unit").unwrap();
+        assert_eq!(
+            ArrayAttributeRef::try_from_attribute(erased_array)
+                .unwrap()
+                .len(),
+            2
+        );
+        assert!(ArrayAttributeRef::try_from_attribute(erased_unit).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_array_attribute_ref() {
+        let _array_attribute_ref = ArrayAttributeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
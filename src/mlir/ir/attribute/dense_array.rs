@@ -0,0 +1,219 @@
+use super::impl_attribute_variant;
+use crate::{
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+    ContextRef,
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{
+    mlirAttributeIsADenseF32Array, mlirAttributeIsADenseF64Array, mlirAttributeIsADenseI16Array,
+    mlirAttributeIsADenseI32Array, mlirAttributeIsADenseI64Array, mlirAttributeIsADenseI8Array,
+    mlirDenseArrayGetNumElements, mlirDenseF32ArrayGet, mlirDenseF32ArrayGetElement,
+    mlirDenseF64ArrayGet, mlirDenseF64ArrayGetElement, mlirDenseI16ArrayGet,
+    mlirDenseI16ArrayGetElement, mlirDenseI32ArrayGet, mlirDenseI32ArrayGetElement,
+    mlirDenseI64ArrayGet, mlirDenseI64ArrayGetElement, mlirDenseI8ArrayGet,
+    mlirDenseI8ArrayGetElement, MlirAttribute,
+};
+
+/// Defines a reference type for one of the `mlir::DenseArrayAttr` instantiations, which all share
+/// the same interface apart from their element type.
+macro_rules! impl_dense_array_attribute {
+    (
+        $attribute_type:ident,
+        $element_type:ty,
+        $class_name:literal,
+        $description:literal,
+        $is_a_fn:ident,
+        $get_fn:ident,
+        $get_element_fn:ident
+    ) => {
+        #[doc = concat!(
+            "[", stringify!($attribute_type), "] is a reference to an instance of the `mlir::",
+            $class_name, "`, which\nrepresents a constant array of ", $description,
+            " in the MLIR IR.\n\n",
+            "The following bindings into the MLIR C API are used/supported:\n",
+            "- `", stringify!($is_a_fn), "`\n",
+            "- `mlirDenseArrayGetNumElements`\n",
+            "- `", stringify!($get_element_fn), "`\n",
+            "- `", stringify!($get_fn), "`\n\n",
+            "The following bindings are not used/supported:\n",
+            "- `mlirDenseArrayAttrGetTypeID`",
+        )]
+        #[repr(transparent)]
+        #[derive(Debug)]
+        pub struct $attribute_type {
+            _prevent_external_instantiation: PhantomData<()>,
+        }
+
+        impl_unowned_mlir_value!(no_refs, $attribute_type, MlirAttribute);
+        impl_attribute_variant!($attribute_type, $is_a_fn);
+
+        impl $attribute_type {
+            #[doc = concat!(
+                "Constructs a new dense array of ", $description,
+                " attribute with the provided values.\n\n",
+                "# Arguments\n",
+                "* `context` - The context that should own the attribute.\n",
+                "* `values` - The ", $description, " to hold in the attribute.\n\n",
+                "# Returns\n",
+                "Returns a reference to a new [", stringify!($attribute_type), "] instance.",
+            )]
+            pub fn new<'a>(context: &'a ContextRef, values: &[$element_type]) -> &'a Self {
+                unsafe {
+                    Self::from_raw($get_fn(
+                        context.to_raw(),
+                        values.len() as isize,
+                        values.as_ptr(),
+                    ))
+                }
+            }
+
+            /// # Returns
+            /// Returns the length of the array.
+            pub fn len(&self) -> isize {
+                unsafe { mlirDenseArrayGetNumElements(self.to_raw()) }
+            }
+
+            /// # Returns
+            /// Returns whether the array is empty.
+            pub fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            /// Gets the element at the provided index, verifying that the index is within bounds.
+            ///
+            /// # Arguments
+            /// * `index` - The index of the element to get.
+            ///
+            /// # Returns
+            /// Returns the element at the provided index.
+            pub fn get(&self, index: isize) -> $element_type {
+                assert!(index >= 0 && index < self.len());
+                unsafe { $get_element_fn(self.to_raw(), index) }
+            }
+        }
+    };
+}
+
+impl_dense_array_attribute!(
+    DenseI8AttributeRef,
+    i8,
+    "DenseI8ArrayAttr",
+    "8-bit integers",
+    mlirAttributeIsADenseI8Array,
+    mlirDenseI8ArrayGet,
+    mlirDenseI8ArrayGetElement
+);
+impl_dense_array_attribute!(
+    DenseI16AttributeRef,
+    i16,
+    "DenseI16ArrayAttr",
+    "16-bit integers",
+    mlirAttributeIsADenseI16Array,
+    mlirDenseI16ArrayGet,
+    mlirDenseI16ArrayGetElement
+);
+impl_dense_array_attribute!(
+    DenseI32AttributeRef,
+    i32,
+    "DenseI32ArrayAttr",
+    "32-bit integers",
+    mlirAttributeIsADenseI32Array,
+    mlirDenseI32ArrayGet,
+    mlirDenseI32ArrayGetElement
+);
+impl_dense_array_attribute!(
+    DenseI64AttributeRef,
+    i64,
+    "DenseI64ArrayAttr",
+    "64-bit integers",
+    mlirAttributeIsADenseI64Array,
+    mlirDenseI64ArrayGet,
+    mlirDenseI64ArrayGetElement
+);
+impl_dense_array_attribute!(
+    DenseF32AttributeRef,
+    f32,
+    "DenseF32ArrayAttr",
+    "32-bit floats",
+    mlirAttributeIsADenseF32Array,
+    mlirDenseF32ArrayGet,
+    mlirDenseF32ArrayGetElement
+);
+impl_dense_array_attribute!(
+    DenseF64AttributeRef,
+    f64,
+    "DenseF64ArrayAttr",
+    "64-bit floats",
+    mlirAttributeIsADenseF64Array,
+    mlirDenseF64ArrayGet,
+    mlirDenseF64ArrayGetElement
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::AttributeRef, Context};
+
+    #[test]
+    fn integer_arrays() {
+        let context = Context::new(None, false);
+        let i8_attr = DenseI8AttributeRef::new(&context, &[1, -2]);
+        assert_eq!(i8_attr.to_string(), "array<i8: 1, -2>");
+        assert_eq!(i8_attr.get(1), -2);
+        let i16_attr = DenseI16AttributeRef::new(&context, &[300]);
+        assert_eq!(i16_attr.to_string(), "array<i16: 300>");
+        assert_eq!(i16_attr.get(0), 300);
+        let i32_attr = DenseI32AttributeRef::new(&context, &[1, 2, 3]);
+        assert_eq!(i32_attr.len(), 3);
+        assert_eq!(i32_attr.get(2), 3);
+        let i64_attr = DenseI64AttributeRef::new(&context, &[i64::MAX]);
+        assert_eq!(i64_attr.get(0), i64::MAX);
+    }
+
+    #[test]
+    fn float_arrays() {
+        let context = Context::new(None, false);
+        let f32_attr = DenseF32AttributeRef::new(&context, &[0.5, 1.5]);
+        assert_eq!(f32_attr.len(), 2);
+        assert_eq!(f32_attr.get(1), 1.5);
+        let f64_attr = DenseF64AttributeRef::new(&context, &[2.25]);
+        assert_eq!(f64_attr.get(0), 2.25);
+    }
+
+    #[test]
+    fn empty() {
+        let context = Context::new(None, false);
+        let attr = DenseI64AttributeRef::new(&context, &[]);
+        assert_eq!(attr.len(), 0);
+        assert!(attr.is_empty());
+    }
+
+    #[test]
+    fn from_attribute() {
+        let context = Context::new(None, false);
+        let i16_attr = AttributeRef::parse(&context, "array<i16: 1, 2>").unwrap();
+        let f64_attr = AttributeRef::parse(&context, "array<f64: 1.0>").unwrap();
+        assert!(DenseI16AttributeRef::try_from_attribute(i16_attr).is_some());
+        assert!(DenseI32AttributeRef::try_from_attribute(i16_attr).is_none());
+        assert!(DenseF64AttributeRef::try_from_attribute(f64_attr).is_some());
+        assert!(DenseF32AttributeRef::try_from_attribute(f64_attr).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_out_of_bounds() {
+        let context = Context::new(None, false);
+        let attr = DenseI8AttributeRef::new(&context, &[1, 2]);
+        attr.get(2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_dense_i8_attribute_ref() {
+        let _dense_i8_attribute_ref = DenseI8AttributeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
@@ -0,0 +1,311 @@
+use super::impl_attribute_variant;
+use crate::{
+    ir::{
+        AttributeRef, ComplexTypeRef, ElementsAttributeRef, FloatTypeRef, IndexTypeRef,
+        IntegerTypeRef, RankedTensorTypeRef, ShapedTypeRef, TypeRef,
+    },
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+};
+
+use std::{ffi::c_void, marker::PhantomData};
+
+use mlir_sys::{
+    mlirAttributeIsADenseElements, mlirAttributeIsADenseFPElements,
+    mlirAttributeIsADenseIntElements, mlirDenseElementsAttrGetRawData,
+    mlirDenseElementsAttrGetSplatValue, mlirDenseElementsAttrIsSplat,
+    mlirDenseElementsAttrRawBufferGet, mlirDenseElementsAttrReshapeGet, MlirAttribute,
+};
+
+/// [DenseElementsAttributeRef] is a reference to an instance of the `mlir::DenseElementsAttr`
+/// class, which represents a constant tensor or vector whose elements are all stored densely.
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirAttributeIsADenseElements`
+/// - `mlirAttributeIsADenseFPElements`
+/// - `mlirAttributeIsADenseIntElements`
+/// - `mlirDenseElementsAttrGetRawData`
+/// - `mlirDenseElementsAttrGetSplatValue`
+/// - `mlirDenseElementsAttrIsSplat`
+/// - `mlirDenseElementsAttrRawBufferGet`
+/// - `mlirDenseElementsAttrReshapeGet`
+///
+/// The following bindings are not used/supported:
+/// - `mlirDenseElementsAttrGet`
+/// - `mlirDenseElementsAttrSplatGet`
+/// - `mlirDenseElementsAttrGetBoolSplatValue`
+/// - `mlirDenseElementsAttrGetBoolValue`
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct DenseElementsAttributeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, DenseElementsAttributeRef, MlirAttribute);
+impl_attribute_variant!(
+    DenseElementsAttributeRef,
+    mlirAttributeIsADenseElements,
+    ElementsAttributeRef
+);
+
+impl DenseElementsAttributeRef {
+    /// Constructs a new dense elements attribute of the provided shaped type by copying the
+    /// provided buffer, which holds the elements in the same (packed, row-major) layout that MLIR
+    /// uses to store them internally. A buffer holding a single element creates a splat.
+    ///
+    /// # Arguments
+    /// * `shaped_type` - The statically shaped type of the attribute.
+    /// * `buffer` - The raw bytes of the elements.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [DenseElementsAttributeRef] instance, or `None` if the
+    /// element type is not an integer, index, float or complex type, or the size of the buffer
+    /// does not match the shaped type.
+    pub fn new_from_raw_buffer<'a>(
+        shaped_type: &'a ShapedTypeRef,
+        buffer: &[u8],
+    ) -> Option<&'a Self> {
+        if !shaped_type.has_static_shape() {
+            panic!("Dense elements attributes require a static shape.");
+        }
+        element_storage_width(shaped_type.element_type())?;
+        unsafe {
+            Self::try_from_raw(mlirDenseElementsAttrRawBufferGet(
+                shaped_type.to_raw(),
+                buffer.len(),
+                buffer.as_ptr() as *const c_void,
+            ))
+        }
+    }
+
+    /// # Returns
+    /// Returns whether all elements of the attribute have the same value.
+    pub fn is_splat(&self) -> bool {
+        unsafe { mlirDenseElementsAttrIsSplat(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns the value shared by all elements of the attribute if it is a splat, otherwise
+    /// `None`.
+    pub fn splat_value(&self) -> Option<&AttributeRef> {
+        if !self.is_splat() {
+            return None;
+        }
+        unsafe {
+            Some(AttributeRef::from_raw(mlirDenseElementsAttrGetSplatValue(
+                self.to_raw(),
+            )))
+        }
+    }
+
+    /// Gets the raw bytes of the elements without copying them, in the (packed, row-major) layout
+    /// that MLIR uses to store them internally. Splats only hold a single element, and `i1`
+    /// elements are packed into bits unless the attribute is a splat.
+    ///
+    /// # Returns
+    /// Returns the raw bytes of the elements, or `None` if the attribute does not hold integer,
+    /// index, float or complex elements (e.g. if it holds strings).
+    pub fn raw_data(&self) -> Option<&[u8]> {
+        let is_int_or_fp = unsafe {
+            mlirAttributeIsADenseIntElements(self.to_raw())
+                || mlirAttributeIsADenseFPElements(self.to_raw())
+        };
+        if !is_int_or_fp {
+            return None;
+        }
+        let storage_width = element_storage_width(self.shaped_type().element_type())?;
+        let num_elements = if self.is_splat() {
+            1
+        } else {
+            self.len() as usize
+        };
+        let len = (storage_width * num_elements).div_ceil(8);
+        unsafe {
+            let data = mlirDenseElementsAttrGetRawData(self.to_raw()) as *const u8;
+            Some(std::slice::from_raw_parts(data, len))
+        }
+    }
+
+    /// Reshapes the attribute into the provided shaped type without copying its elements,
+    /// verifying that the shaped type has the same element type and number of elements.
+    ///
+    /// # Arguments
+    /// * `shaped_type` - The statically shaped type to reshape the attribute into.
+    ///
+    /// # Returns
+    /// Returns a reference to the reshaped [DenseElementsAttributeRef].
+    pub fn reshape<'a>(&self, shaped_type: &'a ShapedTypeRef) -> &'a Self {
+        if shaped_type.element_type() != self.shaped_type().element_type() {
+            panic!("Cannot reshape a dense elements attribute into a different element type.");
+        }
+        let num_elements = shaped_type
+            .shape()
+            .and_then(|shape| shape.into_iter().product::<Option<i64>>())
+            .expect("Dense elements attributes require a static shape.");
+        if num_elements != self.len() as i64 {
+            panic!(
+                "Cannot reshape {} elements into {}.",
+                self.len(),
+                shaped_type.as_type()
+            );
+        }
+        unsafe {
+            Self::from_raw(mlirDenseElementsAttrReshapeGet(
+                self.to_raw(),
+                shaped_type.to_raw(),
+            ))
+        }
+    }
+}
+
+/// Gets the number of bits used to store a single element of the provided type in a dense
+/// elements attribute, which mirrors `mlir::detail::getDenseElementStorageWidth`.
+pub(crate) fn element_storage_width(element_type: &TypeRef) -> Option<usize> {
+    let bitwidth = if let Some(ty) = ComplexTypeRef::try_from_type(element_type) {
+        return element_storage_width(ty.element_type()).map(|width| 2 * width);
+    } else if let Some(ty) = IntegerTypeRef::try_from_type(element_type) {
+        ty.bitwidth() as usize
+    } else if let Some(ty) = FloatTypeRef::try_from_type(element_type) {
+        ty.bitwidth() as usize
+    } else if IndexTypeRef::try_from_type(element_type).is_some() {
+        64
+    } else {
+        return None;
+    };
+    if bitwidth == 1 {
+        Some(1)
+    } else {
+        Some(bitwidth.div_ceil(8) * 8)
+    }
+}
+
+/// Builds the tensor type of a dense elements attribute, verifying that the shape is static and
+/// that the provided number of values either fills the shape or is a single (splat) value.
+pub(crate) fn dense_elements_type<'a>(
+    shape: &[i64],
+    element_type: &'a TypeRef,
+    num_values: usize,
+) -> &'a RankedTensorTypeRef {
+    if shape.iter().any(|&dim| dim < 0) {
+        panic!("Dense elements attributes require a static shape.");
+    }
+    let num_elements = shape.iter().product::<i64>() as usize;
+    if num_values != num_elements && num_values != 1 {
+        panic!(
+            "Expected {} values for the shape {:?}, got {}.",
+            num_elements, shape, num_values
+        );
+    }
+    RankedTensorTypeRef::new(shape, element_type, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Context;
+
+    #[test]
+    fn splat() {
+        let context = Context::new(None, false);
+        let attribute = AttributeRef::parse(&context, "dense<1> : tensor<2x2xi32>").unwrap();
+        let attribute = DenseElementsAttributeRef::try_from_attribute(attribute).unwrap();
+        assert_eq!(attribute.len(), 4);
+        assert!(attribute.is_splat());
+        assert_eq!(attribute.splat_value().unwrap().to_string(), "1 : i32");
+        assert_eq!(attribute.shaped_type().to_string(), "tensor<2x2xi32>");
+    }
+
+    #[test]
+    fn not_splat() {
+        let context = Context::new(None, false);
+        let attribute = AttributeRef::parse(&context, "dense<[1, 2]> : tensor<2xi32>").unwrap();
+        let attribute = DenseElementsAttributeRef::try_from_attribute(attribute).unwrap();
+        assert!(!attribute.is_splat());
+        assert!(attribute.splat_value().is_none());
+    }
+
+    #[test]
+    fn raw_buffer() {
+        let context = Context::new(None, false);
+        let i16_type = IntegerTypeRef::new_signless(&context, 16);
+        let tensor_type = RankedTensorTypeRef::new(&[2], i16_type, None);
+        let buffer = [1i16, -1].map(i16::to_ne_bytes).concat();
+        let attribute =
+            DenseElementsAttributeRef::new_from_raw_buffer(tensor_type, &buffer).unwrap();
+        assert_eq!(attribute.to_string(), "dense<[1, -1]> : tensor<2xi16>");
+        assert_eq!(attribute.raw_data().unwrap(), buffer.as_slice());
+        assert!(
+            DenseElementsAttributeRef::new_from_raw_buffer(tensor_type, &buffer[..3]).is_none()
+        );
+
+        let splat =
+            DenseElementsAttributeRef::new_from_raw_buffer(tensor_type, &buffer[..2]).unwrap();
+        assert!(splat.is_splat());
+        assert_eq!(splat.raw_data().unwrap(), &buffer[..2]);
+    }
+
+    #[test]
+    fn raw_data_packed_bools() {
+        let context = Context::new(None, false);
+        let attribute =
+            AttributeRef::parse(&context, "dense<[true, false, true]> : tensor<3xi1>").unwrap();
+        let attribute = DenseElementsAttributeRef::try_from_attribute(attribute).unwrap();
+        assert_eq!(attribute.raw_data().unwrap(), &[0b101]);
+    }
+
+    #[test]
+    fn raw_data_strings() {
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let attribute =
+            AttributeRef::parse(&context, r#"dense<["a"]> : tensor<1x!dialect.string>"#).unwrap();
+        let attribute = DenseElementsAttributeRef::try_from_attribute(attribute).unwrap();
+        assert!(attribute.raw_data().is_none());
+    }
+
+    #[test]
+    fn reshape() {
+        let context = Context::new(None, false);
+        let attribute =
+            AttributeRef::parse(&context, "dense<[1, 2, 3, 4]> : tensor<4xi32>").unwrap();
+        let attribute = DenseElementsAttributeRef::try_from_attribute(attribute).unwrap();
+        let i32_type = IntegerTypeRef::new_signless(&context, 32);
+        let tensor_type = RankedTensorTypeRef::new(&[2, 2], i32_type, None);
+        let reshaped = attribute.reshape(tensor_type);
+        assert_eq!(
+            reshaped.to_string(),
+            "dense<[[1, 2], [3, 4]]> : tensor<2x2xi32>"
+        );
+        assert_eq!(reshaped.raw_data(), attribute.raw_data());
+    }
+
+    #[test]
+    #[should_panic]
+    fn reshape_wrong_number_of_elements() {
+        let context = Context::new(None, false);
+        let attribute = AttributeRef::parse(&context, "dense<[1, 2, 3]> : tensor<3xi32>").unwrap();
+        let attribute = DenseElementsAttributeRef::try_from_attribute(attribute).unwrap();
+        let i32_type = IntegerTypeRef::new_signless(&context, 32);
+        attribute.reshape(RankedTensorTypeRef::new(&[2, 2], i32_type, None));
+    }
+
+    #[test]
+    fn from_attribute() {
+        let context = Context::new(None, false);
+        let dense = AttributeRef::parse(&context, "dense<1.0> : vector<2xf32>").unwrap();
+        let array = AttributeRef::parse(&context, "array<i32: 1>").unwrap();
+        assert!(DenseElementsAttributeRef::try_from_attribute(dense).is_some());
+        assert!(DenseElementsAttributeRef::try_from_attribute(array).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_dense_elements_attribute_ref() {
+        let _dense_elements_attribute_ref = DenseElementsAttributeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
@@ -0,0 +1,257 @@
+use super::{dense_elements_type, impl_attribute_variant};
+use crate::{
+    ir::{DenseElementsAttributeRef, FloatKind, FloatTypeRef},
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{
+    mlirAttributeIsADenseFPElements, mlirDenseElementsAttrDoubleGet,
+    mlirDenseElementsAttrDoubleSplatGet, mlirDenseElementsAttrFloatGet,
+    mlirDenseElementsAttrFloatSplatGet, mlirDenseElementsAttrGetDoubleValue,
+    mlirDenseElementsAttrGetFloatValue, MlirAttribute, MlirType,
+};
+
+/// [DenseFloatElement] is implemented by the Rust float types that can be stored in a
+/// [DenseFPElementsAttributeRef], mapping each of them to the matching bindings. It cannot be
+/// implemented outside of this crate.
+pub trait DenseFloatElement: Copy + private::Sealed {
+    /// The kind of the float type of the attribute elements.
+    const KIND: FloatKind;
+
+    /// Constructs a new raw dense elements attribute from the provided values.
+    ///
+    /// # Safety
+    /// The provided shaped type must be valid and have an element type matching this type.
+    unsafe fn raw_get(shaped_type: MlirType, values: &[Self]) -> MlirAttribute;
+
+    /// Constructs a new raw splat dense elements attribute from the provided value.
+    ///
+    /// # Safety
+    /// The provided shaped type must be valid and have an element type matching this type.
+    unsafe fn raw_splat_get(shaped_type: MlirType, value: Self) -> MlirAttribute;
+
+    /// Gets the element at the provided position of a raw dense elements attribute.
+    ///
+    /// # Safety
+    /// The provided attribute must be valid, have an element type matching this type, and the
+    /// position must be within bounds.
+    unsafe fn raw_value(attribute: MlirAttribute, pos: isize) -> Self;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_dense_float_element {
+    ($float_type:ty, $kind:ident, $get_fn:ident, $splat_get_fn:ident, $value_fn:ident) => {
+        impl private::Sealed for $float_type {}
+
+        impl DenseFloatElement for $float_type {
+            const KIND: FloatKind = FloatKind::$kind;
+
+            unsafe fn raw_get(shaped_type: MlirType, values: &[Self]) -> MlirAttribute {
+                $get_fn(shaped_type, values.len() as isize, values.as_ptr())
+            }
+
+            unsafe fn raw_splat_get(shaped_type: MlirType, value: Self) -> MlirAttribute {
+                $splat_get_fn(shaped_type, value)
+            }
+
+            unsafe fn raw_value(attribute: MlirAttribute, pos: isize) -> Self {
+                $value_fn(attribute, pos)
+            }
+        }
+    };
+}
+
+impl_dense_float_element!(
+    f32,
+    F32,
+    mlirDenseElementsAttrFloatGet,
+    mlirDenseElementsAttrFloatSplatGet,
+    mlirDenseElementsAttrGetFloatValue
+);
+impl_dense_float_element!(
+    f64,
+    F64,
+    mlirDenseElementsAttrDoubleGet,
+    mlirDenseElementsAttrDoubleSplatGet,
+    mlirDenseElementsAttrGetDoubleValue
+);
+
+/// [DenseFPElementsAttributeRef] is a reference to an instance of the `mlir::DenseFPElementsAttr`
+/// class, which represents a constant tensor or vector of floats.
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirAttributeIsADenseFPElements`
+/// - `mlirDenseElementsAttrDoubleGet`
+/// - `mlirDenseElementsAttrDoubleSplatGet`
+/// - `mlirDenseElementsAttrFloatGet`
+/// - `mlirDenseElementsAttrFloatSplatGet`
+/// - `mlirDenseElementsAttrGetDoubleValue`
+/// - `mlirDenseElementsAttrGetFloatValue`
+///
+/// The following bindings are not used/supported:
+/// - `mlirDenseElementsAttrBFloat16Get`
+/// - `mlirDenseElementsAttrFloat16Get`
+/// - `mlirDenseElementsAttrGetDoubleSplatValue`
+/// - `mlirDenseElementsAttrGetFloatSplatValue`
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct DenseFPElementsAttributeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, DenseFPElementsAttributeRef, MlirAttribute);
+impl_attribute_variant!(
+    DenseFPElementsAttributeRef,
+    mlirAttributeIsADenseFPElements,
+    DenseElementsAttributeRef
+);
+
+impl DenseFPElementsAttributeRef {
+    /// Constructs a new dense elements attribute holding a tensor of floats with the provided
+    /// shape, verifying that the shape is static, that the number of values matches the shape,
+    /// and that the element type matches the provided values.
+    ///
+    /// # Arguments
+    /// * `shape` - The shape of the tensor.
+    /// * `element_type` - The float type of the tensor elements.
+    /// * `values` - The elements of the tensor, in row-major order.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [DenseFPElementsAttributeRef] instance.
+    pub fn new<'a, T: DenseFloatElement>(
+        shape: &[i64],
+        element_type: &'a FloatTypeRef,
+        values: &[T],
+    ) -> &'a Self {
+        check_element_type::<T>(element_type);
+        let shaped_type = dense_elements_type(shape, element_type, values.len());
+        unsafe { Self::from_raw(T::raw_get(shaped_type.to_raw(), values)) }
+    }
+
+    /// Constructs a new dense elements attribute holding a tensor of floats with the provided
+    /// shape, all of whose elements have the same value.
+    ///
+    /// # Arguments
+    /// * `shape` - The shape of the tensor.
+    /// * `element_type` - The float type of the tensor elements.
+    /// * `value` - The value of every element of the tensor.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [DenseFPElementsAttributeRef] instance.
+    pub fn new_splat<'a, T: DenseFloatElement>(
+        shape: &[i64],
+        element_type: &'a FloatTypeRef,
+        value: T,
+    ) -> &'a Self {
+        check_element_type::<T>(element_type);
+        let shaped_type = dense_elements_type(shape, element_type, 1);
+        unsafe { Self::from_raw(T::raw_splat_get(shaped_type.to_raw(), value)) }
+    }
+
+    /// Gets the element at the provided (flattened) index, verifying that the index is within
+    /// bounds and that the element type of the attribute matches the requested type.
+    ///
+    /// # Arguments
+    /// * `idx` - The index of the element to get.
+    ///
+    /// # Returns
+    /// Returns the element at the provided index.
+    pub fn get<T: DenseFloatElement>(&self, idx: isize) -> T {
+        let element_type = FloatTypeRef::try_from_type(self.shaped_type().element_type()).unwrap();
+        check_element_type::<T>(element_type);
+        self.check_index(idx);
+        unsafe { T::raw_value(self.to_raw(), idx) }
+    }
+
+    /// # Returns
+    /// Returns all elements of the attribute, in row-major order.
+    pub fn values<T: DenseFloatElement>(&self) -> Vec<T> {
+        (0..self.len()).map(|idx| self.get(idx)).collect()
+    }
+}
+
+/// Verifies that the provided float type can hold elements of the type `T`.
+fn check_element_type<T: DenseFloatElement>(element_type: &FloatTypeRef) {
+    if element_type.kind() != T::KIND {
+        panic!(
+            "Element type {} does not match the requested float type.",
+            element_type.as_type()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::AttributeRef, Context};
+
+    #[test]
+    fn new() {
+        let context = Context::new(None, false);
+        let f32_type = FloatTypeRef::new_f32(&context);
+        let attribute = DenseFPElementsAttributeRef::new(&[2], f32_type, &[0.5f32, 1.5]);
+        assert_eq!(
+            attribute.to_string(),
+            "dense<[5.000000e-01, 1.500000e+00]> : tensor<2xf32>"
+        );
+        assert!(!attribute.is_splat());
+        assert_eq!(attribute.get::<f32>(1), 1.5);
+        assert_eq!(attribute.values::<f32>(), vec![0.5, 1.5]);
+    }
+
+    #[test]
+    fn new_splat() {
+        let context = Context::new(None, false);
+        let f64_type = FloatTypeRef::new_f64(&context);
+        let attribute = DenseFPElementsAttributeRef::new_splat(&[2, 3], f64_type, 2.0f64);
+        assert_eq!(
+            attribute.to_string(),
+            "dense<2.000000e+00> : tensor<2x3xf64>"
+        );
+        assert!(attribute.is_splat());
+        assert_eq!(attribute.len(), 6);
+        assert_eq!(attribute.get::<f64>(5), 2.0);
+    }
+
+    #[test]
+    fn from_attribute() {
+        let context = Context::new(None, false);
+        let float_attribute = AttributeRef::parse(&context, "dense<1.0> : tensor<2xf32>").unwrap();
+        let int_attribute = AttributeRef::parse(&context, "dense<1> : tensor<2xi8>").unwrap();
+        assert!(DenseFPElementsAttributeRef::try_from_attribute(float_attribute).is_some());
+        assert!(DenseFPElementsAttributeRef::try_from_attribute(int_attribute).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_mismatched_element_type() {
+        let context = Context::new(None, false);
+        let f64_type = FloatTypeRef::new_f64(&context);
+        DenseFPElementsAttributeRef::new(&[1], f64_type, &[1.0f32]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_dynamic_shape() {
+        let context = Context::new(None, false);
+        let f32_type = FloatTypeRef::new_f32(&context);
+        DenseFPElementsAttributeRef::new_splat(&[-1], f32_type, 1.0f32);
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_dense_fp_elements_attribute_ref() {
+        let _dense_fp_elements_attribute_ref = DenseFPElementsAttributeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
@@ -0,0 +1,356 @@
+use super::{dense_elements_type, impl_attribute_variant};
+use crate::{
+    ir::{DenseElementsAttributeRef, IndexTypeRef, IntegerTypeRef},
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{
+    mlirAttributeIsADenseIntElements, mlirDenseElementsAttrGetInt16Value,
+    mlirDenseElementsAttrGetInt32Value, mlirDenseElementsAttrGetInt64Value,
+    mlirDenseElementsAttrGetInt8Value, mlirDenseElementsAttrGetUInt16Value,
+    mlirDenseElementsAttrGetUInt32Value, mlirDenseElementsAttrGetUInt64Value,
+    mlirDenseElementsAttrGetUInt8Value, mlirDenseElementsAttrInt16Get,
+    mlirDenseElementsAttrInt32Get, mlirDenseElementsAttrInt32SplatGet,
+    mlirDenseElementsAttrInt64Get, mlirDenseElementsAttrInt64SplatGet,
+    mlirDenseElementsAttrInt8Get, mlirDenseElementsAttrInt8SplatGet,
+    mlirDenseElementsAttrUInt16Get, mlirDenseElementsAttrUInt32Get,
+    mlirDenseElementsAttrUInt32SplatGet, mlirDenseElementsAttrUInt64Get,
+    mlirDenseElementsAttrUInt64SplatGet, mlirDenseElementsAttrUInt8Get,
+    mlirDenseElementsAttrUInt8SplatGet, MlirAttribute, MlirType,
+};
+
+/// [DenseIntElement] is implemented by the Rust integer types that can be stored in a
+/// [DenseIntElementsAttributeRef], mapping each of them to the matching bindings. It cannot be
+/// implemented outside of this crate.
+pub trait DenseIntElement: Copy + private::Sealed {
+    /// The bitwidth of the integer type of the attribute elements.
+    const BITWIDTH: u32;
+    /// Whether the integer type of the attribute elements may be signed.
+    const IS_SIGNED: bool;
+
+    /// Constructs a new raw dense elements attribute from the provided values.
+    ///
+    /// # Safety
+    /// The provided shaped type must be valid and have an element type matching this type.
+    unsafe fn raw_get(shaped_type: MlirType, values: &[Self]) -> MlirAttribute;
+
+    /// Constructs a new raw splat dense elements attribute from the provided value.
+    ///
+    /// # Safety
+    /// The provided shaped type must be valid and have an element type matching this type.
+    unsafe fn raw_splat_get(shaped_type: MlirType, value: Self) -> MlirAttribute;
+
+    /// Gets the element at the provided position of a raw dense elements attribute.
+    ///
+    /// # Safety
+    /// The provided attribute must be valid, have an element type matching this type, and the
+    /// position must be within bounds.
+    unsafe fn raw_value(attribute: MlirAttribute, pos: isize) -> Self;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_dense_int_element {
+    (@splat splat, $splat_get_fn:ident, $shaped_type:ident, $value:ident) => {
+        $splat_get_fn($shaped_type, $value)
+    };
+    (@splat splat_as_slice, $get_fn:ident, $shaped_type:ident, $value:ident) => {
+        $get_fn($shaped_type, 1, &$value)
+    };
+    ($int_type:ty, $is_signed:literal, $get_fn:ident, $value_fn:ident) => {
+        // The C API has no splat constructor for this element type, but a single value is
+        // interpreted as a splat by `mlir::DenseElementsAttr::get`.
+        impl_dense_int_element!($int_type, $is_signed, $get_fn, $get_fn, $value_fn, splat_as_slice);
+    };
+    ($int_type:ty, $is_signed:literal, $get_fn:ident, $splat_get_fn:ident, $value_fn:ident) => {
+        impl_dense_int_element!($int_type, $is_signed, $get_fn, $splat_get_fn, $value_fn, splat);
+    };
+    (
+        $int_type:ty,
+        $is_signed:literal,
+        $get_fn:ident,
+        $splat_get_fn:ident,
+        $value_fn:ident,
+        $splat_kind:ident
+    ) => {
+        impl private::Sealed for $int_type {}
+
+        impl DenseIntElement for $int_type {
+            const BITWIDTH: u32 = <$int_type>::BITS;
+            const IS_SIGNED: bool = $is_signed;
+
+            unsafe fn raw_get(shaped_type: MlirType, values: &[Self]) -> MlirAttribute {
+                $get_fn(shaped_type, values.len() as isize, values.as_ptr())
+            }
+
+            unsafe fn raw_splat_get(shaped_type: MlirType, value: Self) -> MlirAttribute {
+                impl_dense_int_element!(@splat $splat_kind, $splat_get_fn, shaped_type, value)
+            }
+
+            unsafe fn raw_value(attribute: MlirAttribute, pos: isize) -> Self {
+                $value_fn(attribute, pos)
+            }
+        }
+    };
+}
+
+impl_dense_int_element!(
+    i8,
+    true,
+    mlirDenseElementsAttrInt8Get,
+    mlirDenseElementsAttrInt8SplatGet,
+    mlirDenseElementsAttrGetInt8Value
+);
+impl_dense_int_element!(
+    u8,
+    false,
+    mlirDenseElementsAttrUInt8Get,
+    mlirDenseElementsAttrUInt8SplatGet,
+    mlirDenseElementsAttrGetUInt8Value
+);
+impl_dense_int_element!(
+    i16,
+    true,
+    mlirDenseElementsAttrInt16Get,
+    mlirDenseElementsAttrGetInt16Value
+);
+impl_dense_int_element!(
+    u16,
+    false,
+    mlirDenseElementsAttrUInt16Get,
+    mlirDenseElementsAttrGetUInt16Value
+);
+impl_dense_int_element!(
+    i32,
+    true,
+    mlirDenseElementsAttrInt32Get,
+    mlirDenseElementsAttrInt32SplatGet,
+    mlirDenseElementsAttrGetInt32Value
+);
+impl_dense_int_element!(
+    u32,
+    false,
+    mlirDenseElementsAttrUInt32Get,
+    mlirDenseElementsAttrUInt32SplatGet,
+    mlirDenseElementsAttrGetUInt32Value
+);
+impl_dense_int_element!(
+    i64,
+    true,
+    mlirDenseElementsAttrInt64Get,
+    mlirDenseElementsAttrInt64SplatGet,
+    mlirDenseElementsAttrGetInt64Value
+);
+impl_dense_int_element!(
+    u64,
+    false,
+    mlirDenseElementsAttrUInt64Get,
+    mlirDenseElementsAttrUInt64SplatGet,
+    mlirDenseElementsAttrGetUInt64Value
+);
+
+/// [DenseIntElementsAttributeRef] is a reference to an instance of the
+/// `mlir::DenseIntElementsAttr` class, which represents a constant tensor or vector of integers.
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirAttributeIsADenseIntElements`
+/// - `mlirDenseElementsAttr{Int8,UInt8,Int16,UInt16,Int32,UInt32,Int64,UInt64}Get`
+/// - `mlirDenseElementsAttr{Int8,UInt8,Int32,UInt32,Int64,UInt64}SplatGet`
+/// - `mlirDenseElementsAttrGet{Int8,UInt8,Int16,UInt16,Int32,UInt32,Int64,UInt64}Value`
+///
+/// The following bindings are not used/supported:
+/// - `mlirDenseElementsAttrGet{Int8,UInt8,Int32,UInt32,Int64,UInt64}SplatValue`
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct DenseIntElementsAttributeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, DenseIntElementsAttributeRef, MlirAttribute);
+impl_attribute_variant!(
+    DenseIntElementsAttributeRef,
+    mlirAttributeIsADenseIntElements,
+    DenseElementsAttributeRef
+);
+
+impl DenseIntElementsAttributeRef {
+    /// Constructs a new dense elements attribute holding a tensor of integers with the provided
+    /// shape, verifying that the shape is static, that the number of values matches the shape,
+    /// and that the element type matches the provided values.
+    ///
+    /// # Arguments
+    /// * `shape` - The shape of the tensor.
+    /// * `element_type` - The integer type of the tensor elements.
+    /// * `values` - The elements of the tensor, in row-major order.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [DenseIntElementsAttributeRef] instance.
+    pub fn new<'a, T: DenseIntElement>(
+        shape: &[i64],
+        element_type: &'a IntegerTypeRef,
+        values: &[T],
+    ) -> &'a Self {
+        check_element_type::<T>(element_type);
+        let shaped_type = dense_elements_type(shape, element_type, values.len());
+        unsafe { Self::from_raw(T::raw_get(shaped_type.to_raw(), values)) }
+    }
+
+    /// Constructs a new dense elements attribute holding a tensor of integers with the provided
+    /// shape, all of whose elements have the same value.
+    ///
+    /// # Arguments
+    /// * `shape` - The shape of the tensor.
+    /// * `element_type` - The integer type of the tensor elements.
+    /// * `value` - The value of every element of the tensor.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [DenseIntElementsAttributeRef] instance.
+    pub fn new_splat<'a, T: DenseIntElement>(
+        shape: &[i64],
+        element_type: &'a IntegerTypeRef,
+        value: T,
+    ) -> &'a Self {
+        check_element_type::<T>(element_type);
+        let shaped_type = dense_elements_type(shape, element_type, 1);
+        unsafe { Self::from_raw(T::raw_splat_get(shaped_type.to_raw(), value)) }
+    }
+
+    /// Gets the element at the provided (flattened) index, verifying that the index is within
+    /// bounds and that the element type of the attribute matches the requested type.
+    ///
+    /// # Arguments
+    /// * `idx` - The index of the element to get.
+    ///
+    /// # Returns
+    /// Returns the element at the provided index.
+    pub fn get<T: DenseIntElement>(&self, idx: isize) -> T {
+        let element_type = self.shaped_type().element_type();
+        if let Some(integer_type) = IntegerTypeRef::try_from_type(element_type) {
+            check_element_type::<T>(integer_type);
+        } else if IndexTypeRef::try_from_type(element_type).is_some() && T::BITWIDTH != 64 {
+            panic!("Expected a 64-bit integer type for index elements.");
+        }
+        self.check_index(idx);
+        unsafe { T::raw_value(self.to_raw(), idx) }
+    }
+
+    /// # Returns
+    /// Returns all elements of the attribute, in row-major order.
+    pub fn values<T: DenseIntElement>(&self) -> Vec<T> {
+        (0..self.len()).map(|idx| self.get(idx)).collect()
+    }
+}
+
+/// Verifies that the provided integer type can hold elements of the type `T`.
+fn check_element_type<T: DenseIntElement>(element_type: &IntegerTypeRef) {
+    let signedness_matches = element_type.is_signless() || element_type.is_signed() == T::IS_SIGNED;
+    if element_type.bitwidth() != T::BITWIDTH || !signedness_matches {
+        panic!(
+            "Element type {} does not match the requested integer type.",
+            element_type.as_type()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::AttributeRef, Context};
+
+    #[test]
+    fn new() {
+        let context = Context::new(None, false);
+        let i32_type = IntegerTypeRef::new_signless(&context, 32);
+        let attribute = DenseIntElementsAttributeRef::new(&[2, 2], i32_type, &[1i32, 2, 3, 4]);
+        assert_eq!(
+            attribute.to_string(),
+            "dense<[[1, 2], [3, 4]]> : tensor<2x2xi32>"
+        );
+        assert_eq!(attribute.len(), 4);
+        assert!(!attribute.is_splat());
+        assert_eq!(attribute.get::<i32>(3), 4);
+        assert_eq!(attribute.values::<i32>(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn new_splat() {
+        let context = Context::new(None, false);
+        let i16_type = IntegerTypeRef::new_signless(&context, 16);
+        let attribute = DenseIntElementsAttributeRef::new_splat(&[3], i16_type, 7i16);
+        assert_eq!(attribute.to_string(), "dense<7> : tensor<3xi16>");
+        assert!(attribute.is_splat());
+        assert_eq!(attribute.len(), 3);
+        assert_eq!(attribute.get::<i16>(2), 7);
+
+        let u8_type = IntegerTypeRef::new_unsigned(&context, 8);
+        let attribute = DenseIntElementsAttributeRef::new_splat(&[2], u8_type, 255u8);
+        assert_eq!(attribute.to_string(), "dense<255> : tensor<2xui8>");
+    }
+
+    #[test]
+    fn index_elements() {
+        let context = Context::new(None, false);
+        let attribute = AttributeRef::parse(&context, "dense<[1, 2]> : tensor<2xindex>").unwrap();
+        let attribute = DenseIntElementsAttributeRef::try_from_attribute(attribute).unwrap();
+        assert_eq!(attribute.values::<i64>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn from_attribute() {
+        let context = Context::new(None, false);
+        let int_attribute = AttributeRef::parse(&context, "dense<1> : tensor<2xi8>").unwrap();
+        let float_attribute = AttributeRef::parse(&context, "dense<1.0> : tensor<2xf32>").unwrap();
+        assert!(DenseIntElementsAttributeRef::try_from_attribute(int_attribute).is_some());
+        assert!(DenseIntElementsAttributeRef::try_from_attribute(float_attribute).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_wrong_number_of_values() {
+        let context = Context::new(None, false);
+        let i32_type = IntegerTypeRef::new_signless(&context, 32);
+        DenseIntElementsAttributeRef::new(&[3], i32_type, &[1i32, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_mismatched_element_type() {
+        let context = Context::new(None, false);
+        let i32_type = IntegerTypeRef::new_signless(&context, 32);
+        DenseIntElementsAttributeRef::new(&[2], i32_type, &[1i64, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_mismatched_element_type() {
+        let context = Context::new(None, false);
+        let i32_type = IntegerTypeRef::new_signless(&context, 32);
+        let attribute = DenseIntElementsAttributeRef::new(&[2], i32_type, &[1i32, 2]);
+        attribute.get::<i8>(0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_out_of_bounds() {
+        let context = Context::new(None, false);
+        let i32_type = IntegerTypeRef::new_signless(&context, 32);
+        let attribute = DenseIntElementsAttributeRef::new(&[2], i32_type, &[1i32, 2]);
+        attribute.get::<i32>(2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_dense_int_elements_attribute_ref() {
+        let _dense_int_elements_attribute_ref = DenseIntElementsAttributeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
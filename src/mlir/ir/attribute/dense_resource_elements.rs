@@ -0,0 +1,352 @@
+use super::{element_storage_width, impl_attribute_variant};
+use crate::{
+    ir::{FloatTypeRef, IntegerTypeRef, ShapedTypeRef},
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+    StringRef,
+};
+
+use std::{ffi::c_void, marker::PhantomData, mem};
+
+use mlir_sys::{
+    mlirAttributeIsADenseResourceElements, mlirElementsAttrGetNumElements,
+    mlirUnmanagedDenseResourceElementsAttrGet, MlirAttribute,
+};
+
+/// The deleter invoked by MLIR once the last reference to a blob is released.
+type RawDeleter = unsafe extern "C" fn(*mut c_void, *const c_void, usize, usize);
+
+/// [DenseResourceElement] is implemented by the Rust integer and float types whose in-memory
+/// representation matches the storage of the elements of a [DenseResourceElementsAttributeRef],
+/// so that a blob of them can be handed to MLIR as-is. It cannot be implemented outside of this
+/// crate.
+///
+/// Booleans are not supported, as MLIR stores `i1` elements as packed bits.
+pub trait DenseResourceElement: Copy + private::Sealed {
+    /// Whether the type is a float type, which must match a float element type (and an integer
+    /// type an integer or `index` element type).
+    const IS_FLOAT: bool;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_dense_resource_element {
+    ($is_float:literal, $($element_type:ty),*) => {
+        $(
+            impl private::Sealed for $element_type {}
+
+            impl DenseResourceElement for $element_type {
+                const IS_FLOAT: bool = $is_float;
+            }
+        )*
+    };
+}
+
+impl_dense_resource_element!(false, i8, i16, i32, i64, u8, u16, u32, u64);
+impl_dense_resource_element!(true, f32, f64);
+
+/// [DenseResourceElementsAttributeRef] is a reference to an instance of the
+/// `mlir::DenseResourceElementsAttr` class, which represents a constant tensor or vector whose
+/// elements are stored in a named resource blob rather than uniqued in the context, so that
+/// large constants (such as weights) can be attached to the IR without copying them.
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirAttributeIsADenseResourceElements`
+/// - `mlirElementsAttrGetNumElements`
+/// - `mlirUnmanagedDenseResourceElementsAttrGet`
+///
+/// The following bindings are not used/supported:
+/// - `mlirUnmanaged{Bool,UInt8,Int8,UInt16,Int16,UInt32,Int32,UInt64,Int64,Float,Double}DenseResourceElementsAttrGet`
+/// - `mlirDense{Bool,UInt8,Int8,UInt16,Int16,UInt32,Int32,UInt64,Int64,Float,Double}ResourceElementsAttrGetValue`
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct DenseResourceElementsAttributeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, DenseResourceElementsAttributeRef, MlirAttribute);
+impl_attribute_variant!(
+    DenseResourceElementsAttributeRef,
+    mlirAttributeIsADenseResourceElements
+);
+
+impl DenseResourceElementsAttributeRef {
+    /// Constructs a new dense resource elements attribute of the provided shaped type whose
+    /// elements are held in a new resource blob that takes ownership of the provided data, which
+    /// is released once the blob is no longer used by the context. The data is never copied, nor
+    /// mutated by MLIR.
+    ///
+    /// # Panics
+    /// Panics if the shape is not static, if the number of values does not match it, or if the
+    /// element type does not match `T` (e.g. because it is `i1`).
+    ///
+    /// # Arguments
+    /// * `shaped_type` - The statically shaped type of the attribute.
+    /// * `name` - The name of the resource blob, which MLIR may uniquify.
+    /// * `data` - The elements of the attribute, in row-major order.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [DenseResourceElementsAttributeRef] instance.
+    pub fn new<'a, T: DenseResourceElement>(
+        shaped_type: &'a ShapedTypeRef,
+        name: &str,
+        data: Box<[T]>,
+    ) -> &'a Self {
+        check_data::<T>(shaped_type, data.len());
+        let len = mem::size_of_val(data.as_ref());
+        let data = Box::into_raw(data) as *mut T as *mut c_void;
+        unsafe {
+            Self::new_unmanaged(
+                shaped_type,
+                name,
+                data,
+                len,
+                mem::align_of::<T>(),
+                false,
+                Some(drop_boxed_slice::<T>),
+                data,
+            )
+        }
+    }
+
+    /// Constructs a new dense resource elements attribute of the provided shaped type whose
+    /// elements are held in a new resource blob that refers to the provided static data without
+    /// copying it.
+    ///
+    /// # Panics
+    /// Panics if the shape is not static, if the number of values does not match it, or if the
+    /// element type does not match `T` (e.g. because it is `i1`).
+    ///
+    /// # Arguments
+    /// * `shaped_type` - The statically shaped type of the attribute.
+    /// * `name` - The name of the resource blob, which MLIR may uniquify.
+    /// * `data` - The elements of the attribute, in row-major order.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [DenseResourceElementsAttributeRef] instance.
+    pub fn new_static<'a, T: DenseResourceElement>(
+        shaped_type: &'a ShapedTypeRef,
+        name: &str,
+        data: &'static [T],
+    ) -> &'a Self {
+        check_data::<T>(shaped_type, data.len());
+        unsafe {
+            Self::new_unmanaged(
+                shaped_type,
+                name,
+                data.as_ptr() as *mut c_void,
+                mem::size_of_val(data),
+                mem::align_of::<T>(),
+                false,
+                None,
+                std::ptr::null_mut(),
+            )
+        }
+    }
+
+    /// Constructs a new dense resource elements attribute of the provided shaped type from an
+    /// unmanaged blob of data, which MLIR refers to without copying it.
+    ///
+    /// # Safety
+    /// The caller of this function is responsible for ensuring that the data is valid for the
+    /// provided length and alignment, that it matches the shaped type, and that it remains valid
+    /// until the deleter is invoked (or for the lifetime of the context if there is no deleter).
+    ///
+    /// # Arguments
+    /// * `shaped_type` - The statically shaped type of the attribute.
+    /// * `name` - The name of the resource blob, which MLIR may uniquify.
+    /// * `data` - A pointer to the data of the blob.
+    /// * `len` - The length of the data in bytes.
+    /// * `alignment` - The alignment of the data in bytes.
+    /// * `is_mutable` - Whether MLIR may mutate the data.
+    /// * `deleter` - The callback invoked with the user data, the data, its length, and its
+    ///   alignment once the blob is released.
+    /// * `user_data` - The user data passed to the deleter.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [DenseResourceElementsAttributeRef] instance.
+    #[allow(clippy::too_many_arguments)]
+    pub unsafe fn new_unmanaged<'a>(
+        shaped_type: &'a ShapedTypeRef,
+        name: &str,
+        data: *mut c_void,
+        len: usize,
+        alignment: usize,
+        is_mutable: bool,
+        deleter: Option<RawDeleter>,
+        user_data: *mut c_void,
+    ) -> &'a Self {
+        Self::from_raw(mlirUnmanagedDenseResourceElementsAttrGet(
+            shaped_type.to_raw(),
+            StringRef::from(&name).to_raw(),
+            data,
+            len,
+            alignment,
+            is_mutable,
+            deleter,
+            user_data,
+        ))
+    }
+
+    /// # Returns
+    /// Returns the total number of elements held by the attribute.
+    pub fn len(&self) -> isize {
+        unsafe { mlirElementsAttrGetNumElements(self.to_raw()) as isize }
+    }
+
+    /// # Returns
+    /// Returns whether the attribute holds no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Verifies that the provided shaped type is static and that its elements match the provided
+/// number of values of the type `T`.
+fn check_data<T: DenseResourceElement>(shaped_type: &ShapedTypeRef, num_values: usize) {
+    let num_elements = shaped_type
+        .shape()
+        .and_then(|shape| shape.into_iter().product::<Option<i64>>())
+        .expect("Dense resource elements attributes require a static shape.");
+    if num_values as i64 != num_elements {
+        panic!(
+            "Expected {} values for {}, got {}.",
+            num_elements,
+            shaped_type.as_type(),
+            num_values
+        );
+    }
+    let element_type = shaped_type.element_type();
+    if IntegerTypeRef::try_from_type(element_type).is_some_and(|ty| ty.bitwidth() == 1) {
+        panic!(
+            "Dense resource elements attributes of i1 elements are not supported, as they are \
+            stored as packed bits."
+        );
+    }
+    let storage_width = element_storage_width(element_type);
+    let is_float = FloatTypeRef::try_from_type(element_type).is_some();
+    if is_float != T::IS_FLOAT || storage_width != Some(mem::size_of::<T>() * 8) {
+        panic!(
+            "Element type of {} does not match the provided values.",
+            shaped_type.as_type()
+        );
+    }
+}
+
+/// The deleter used by [DenseResourceElementsAttributeRef::new], which drops the boxed slice the
+/// blob took ownership of.
+///
+/// # Safety
+/// The provided user data must be the pointer returned by `Box::into_raw` for a boxed slice of
+/// `size` bytes of the type `T`.
+unsafe extern "C" fn drop_boxed_slice<T>(
+    user_data: *mut c_void,
+    _data: *const c_void,
+    size: usize,
+    _align: usize,
+) {
+    let len = size / mem::size_of::<T>().max(1);
+    drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(
+        user_data as *mut T,
+        len,
+    )));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{AttributeRef, RankedTensorTypeRef},
+        Context,
+    };
+
+    #[test]
+    fn new() {
+        let context = Context::new(None, false);
+        let f32_type = FloatTypeRef::new_f32(&context);
+        let tensor_type = RankedTensorTypeRef::new(&[2], f32_type, None);
+        let data = vec![1.0f32, 2.0].into_boxed_slice();
+        let attribute = DenseResourceElementsAttributeRef::new(tensor_type, "weights", data);
+        assert_eq!(attribute.len(), 2);
+        assert_eq!(
+            attribute.to_string(),
+            "dense_resource<weights> : tensor<2xf32>"
+        );
+    }
+
+    #[test]
+    fn new_static() {
+        static DATA: [i64; 4] = [1, 2, 3, 4];
+        let context = Context::new(None, false);
+        let i64_type = IntegerTypeRef::new_signless(&context, 64);
+        let tensor_type = RankedTensorTypeRef::new(&[2, 2], i64_type, None);
+        let attribute = DenseResourceElementsAttributeRef::new_static(tensor_type, "blob", &DATA);
+        assert_eq!(attribute.len(), 4);
+        assert!(!attribute.is_empty());
+    }
+
+    #[test]
+    fn from_attribute() {
+        let context = Context::new(None, false);
+        let i8_type = IntegerTypeRef::new_signless(&context, 8);
+        let tensor_type = RankedTensorTypeRef::new(&[1], i8_type, None);
+        let data = vec![1i8].into_boxed_slice();
+        let resource = DenseResourceElementsAttributeRef::new(tensor_type, "blob", data);
+        let dense = AttributeRef::parse(&context, "dense<1> : tensor<1xi8>").unwrap();
+        assert!(DenseResourceElementsAttributeRef::try_from_attribute(resource).is_some());
+        assert!(DenseResourceElementsAttributeRef::try_from_attribute(dense).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_mismatched_element_type() {
+        let context = Context::new(None, false);
+        let f32_type = FloatTypeRef::new_f32(&context);
+        let tensor_type = RankedTensorTypeRef::new(&[2], f32_type, None);
+        let data = vec![1.0f64, 2.0].into_boxed_slice();
+        DenseResourceElementsAttributeRef::new(tensor_type, "weights", data);
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_integer_values_for_float_type() {
+        let context = Context::new(None, false);
+        let f32_type = FloatTypeRef::new_f32(&context);
+        let tensor_type = RankedTensorTypeRef::new(&[2], f32_type, None);
+        let data = vec![1i32, 2].into_boxed_slice();
+        DenseResourceElementsAttributeRef::new(tensor_type, "weights", data);
+    }
+
+    #[test]
+    #[should_panic(expected = "i1 elements are not supported")]
+    fn new_i1() {
+        let context = Context::new(None, false);
+        let i1_type = IntegerTypeRef::new_signless(&context, 1);
+        let tensor_type = RankedTensorTypeRef::new(&[2], i1_type, None);
+        let data = vec![1u8, 0].into_boxed_slice();
+        DenseResourceElementsAttributeRef::new(tensor_type, "mask", data);
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_wrong_number_of_values() {
+        let context = Context::new(None, false);
+        let f32_type = FloatTypeRef::new_f32(&context);
+        let tensor_type = RankedTensorTypeRef::new(&[3], f32_type, None);
+        let data = vec![1.0f32, 2.0].into_boxed_slice();
+        DenseResourceElementsAttributeRef::new(tensor_type, "weights", data);
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_dense_resource_elements_attribute_ref() {
+        let _dense_resource_elements_attribute_ref = DenseResourceElementsAttributeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
@@ -1,13 +1,12 @@
 use std::{ffi::CString, marker::PhantomData};
 
 use mlir_sys::{
-    mlirAttributeGetNull, mlirAttributeIsADenseElements, mlirDenseElementsAttrGetStringValue,
-    mlirDenseElementsAttrStringGet, mlirElementsAttrGetNumElements, mlirRankedTensorTypeGet,
-    MlirAttribute, MlirStringRef,
+    mlirAttributeIsADenseElements, mlirDenseElementsAttrGetStringValue,
+    mlirDenseElementsAttrStringGet, MlirAttribute, MlirStringRef,
 };
 
 use crate::{
-    ir::{attribute::impl_attribute_variant, TypeRef},
+    ir::{attribute::impl_attribute_variant, ElementsAttributeRef, RankedTensorTypeRef, TypeRef},
     support::binding::impl_unowned_mlir_value,
     StringRef, UnownedMlirValue,
 };
@@ -17,7 +16,6 @@ use crate::{
 ///
 /// The following bindings into the MLIR C API are used/supported:
 /// - `mlirAttributeIsADenseElements`
-/// - `mlirElementsAttrGetNumElements`
 /// - `mlirDenseElementsAttrGetStringValue`
 /// - `mlirDenseElementsAttrStringGet`
 #[repr(transparent)]
@@ -27,7 +25,11 @@ pub struct DenseStringAttributeRef {
 }
 
 impl_unowned_mlir_value!(no_refs, DenseStringAttributeRef, MlirAttribute);
-impl_attribute_variant!(DenseStringAttributeRef, mlirAttributeIsADenseElements);
+impl_attribute_variant!(
+    DenseStringAttributeRef,
+    mlirAttributeIsADenseElements,
+    ElementsAttributeRef
+);
 
 impl DenseStringAttributeRef {
     /// Constructs a new dense array of strings attribute with the provided values.
@@ -42,15 +44,7 @@ impl DenseStringAttributeRef {
         values: &[impl AsRef<str>],
         string_type: &'a TypeRef,
     ) -> &'a DenseStringAttributeRef {
-        let shape: [i64; 1] = [values.len() as i64];
-        let shaped_type = unsafe {
-            mlirRankedTensorTypeGet(
-                1,
-                shape.as_ptr(),
-                string_type.to_raw(),
-                mlirAttributeGetNull(),
-            )
-        };
+        let shaped_type = RankedTensorTypeRef::new(&[values.len() as i64], string_type, None);
 
         let null_terminated_values: Vec<CString> = values
             .iter()
@@ -63,25 +57,13 @@ impl DenseStringAttributeRef {
 
         unsafe {
             Self::from_raw(mlirDenseElementsAttrStringGet(
-                shaped_type,
+                shaped_type.to_raw(),
                 string_refs.len() as isize,
                 string_refs.as_ptr() as *mut MlirStringRef,
             ))
         }
     }
 
-    /// # Returns
-    /// Returns the length of the array.
-    pub fn len(&self) -> isize {
-        unsafe { mlirElementsAttrGetNumElements(self.to_raw()) as isize }
-    }
-
-    /// # Returns
-    /// Returns whether the array is empty.
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
-    }
-
     /// Gets the element at the provided index, verifying that the index is within bounds.
     ///
     /// # Arguments
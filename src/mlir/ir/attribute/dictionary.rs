@@ -0,0 +1,273 @@
+use super::impl_attribute_variant;
+use crate::{
+    ir::{AttributeRef, NamedAttribute},
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+    ContextRef, StringRef,
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{
+    mlirAttributeIsADictionary, mlirDictionaryAttrGet, mlirDictionaryAttrGetElement,
+    mlirDictionaryAttrGetElementByName, mlirDictionaryAttrGetNumElements, MlirAttribute,
+    MlirNamedAttribute,
+};
+
+/// [DictionaryAttributeRef] is a reference to an instance of the `mlir::DictionaryAttr` class,
+/// which represents a set of named attributes, sorted by name, in the MLIR IR.
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirAttributeIsADictionary`
+/// - `mlirDictionaryAttrGet`
+/// - `mlirDictionaryAttrGetElement`
+/// - `mlirDictionaryAttrGetElementByName`
+/// - `mlirDictionaryAttrGetNumElements`
+///
+/// The following bindings are not used/supported:
+/// - `mlirDictionaryAttrGetTypeID`
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct DictionaryAttributeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, DictionaryAttributeRef, MlirAttribute);
+impl_attribute_variant!(DictionaryAttributeRef, mlirAttributeIsADictionary);
+
+impl DictionaryAttributeRef {
+    /// Constructs a new dictionary attribute with the provided elements. The elements do not need
+    /// to be sorted, but their names must be unique.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the attribute.
+    /// * `elements` - The named attributes to hold in the dictionary.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [DictionaryAttributeRef] instance.
+    pub fn new<'a>(context: &'a ContextRef, elements: &[NamedAttribute]) -> &'a Self {
+        unsafe {
+            Self::from_raw(mlirDictionaryAttrGet(
+                context.to_raw(),
+                elements.len() as isize,
+                elements.as_ptr() as *const MlirNamedAttribute,
+            ))
+        }
+    }
+
+    /// # Returns
+    /// Returns the number of elements in the dictionary.
+    pub fn len(&self) -> isize {
+        unsafe { mlirDictionaryAttrGetNumElements(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns whether the dictionary is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the element at the provided index, verifying that the index is within bounds. The
+    /// elements are sorted by name.
+    ///
+    /// # Arguments
+    /// * `idx` - The index of the element to get.
+    ///
+    /// # Returns
+    /// Returns the named attribute at the provided index.
+    pub fn get(&self, idx: isize) -> NamedAttribute {
+        if idx < 0 || idx >= self.len() {
+            panic!("Element index {} out of bounds.", idx);
+        }
+        unsafe {
+            let raw = mlirDictionaryAttrGetElement(self.to_raw(), idx);
+            NamedAttribute::from_raw(raw.name, raw.attribute)
+        }
+    }
+
+    /// Looks up the element with the provided name.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the element.
+    ///
+    /// # Returns
+    /// Returns the attribute with the provided name, if the dictionary contains one.
+    pub fn get_by_name(&self, name: &str) -> Option<&AttributeRef> {
+        unsafe {
+            AttributeRef::try_from_raw(mlirDictionaryAttrGetElementByName(
+                self.to_raw(),
+                StringRef::from(&name).to_raw(),
+            ))
+        }
+    }
+
+    /// Looks up a nested element by a dotted path, descending into nested dictionaries, e.g.
+    /// `"a.b"` looks up the element `b` of the dictionary held by the element `a`. Because
+    /// attribute names may themselves contain dots (e.g. `llvm.linkage`), an element whose name
+    /// matches a longer part of the path is preferred over descending at an earlier dot.
+    ///
+    /// # Arguments
+    /// * `path` - The dot-separated names of the elements to descend through.
+    ///
+    /// # Returns
+    /// Returns the attribute at the provided path, if there is one.
+    pub fn get_by_path(&self, path: &str) -> Option<&AttributeRef> {
+        if let Some(attribute) = self.get_by_name(path) {
+            return Some(attribute);
+        }
+        path.rmatch_indices('.').find_map(|(idx, _)| {
+            let nested = self.get_by_name(&path[..idx])?;
+            DictionaryAttributeRef::try_from_attribute(nested)?.get_by_path(&path[idx + 1..])
+        })
+    }
+
+    /// Checks whether every element of the provided dictionary is also held by this dictionary
+    /// with an equal value.
+    ///
+    /// # Arguments
+    /// * `other` - The dictionary whose elements to look for.
+    ///
+    /// # Returns
+    /// Returns whether this dictionary contains all elements of the other dictionary.
+    pub fn contains_all(&self, other: &DictionaryAttributeRef) -> bool {
+        other.iter().all(|element| {
+            self.get_by_name(element.name().value().as_str()) == Some(element.attribute())
+        })
+    }
+
+    /// Merges the provided dictionary into this one, with its elements replacing the elements of
+    /// this dictionary that have the same name.
+    ///
+    /// # Arguments
+    /// * `other` - The dictionary whose elements take precedence.
+    ///
+    /// # Returns
+    /// Returns a reference to the merged [DictionaryAttributeRef].
+    pub fn merge(&self, other: &DictionaryAttributeRef) -> &Self {
+        let mut elements = self
+            .iter()
+            .filter(|element| other.get_by_name(element.name().value().as_str()).is_none())
+            .collect::<Vec<_>>();
+        elements.extend(other.iter());
+        DictionaryAttributeRef::new(self.context(), &elements)
+    }
+
+    /// # Returns
+    /// Returns an iterator over the elements of the dictionary, sorted by name.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = NamedAttribute> {
+        (0..self.len()).map(|idx| self.get(idx))
+    }
+}
+
+impl PartialEq for DictionaryAttributeRef {
+    /// Compares the dictionaries structurally, which for uniqued dictionaries is equivalent to
+    /// comparing them by identity.
+    fn eq(&self, other: &Self) -> bool {
+        self.as_attribute() == other.as_attribute()
+    }
+}
+
+impl Eq for DictionaryAttributeRef {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Context;
+
+    #[test]
+    fn new() {
+        let context = Context::new(None, false);
+        let unit = AttributeRef::parse(&context, "unit").unwrap();
+        let string = AttributeRef::parse(&context, r#""foo""#).unwrap();
+        let dictionary =
+            DictionaryAttributeRef::new(&context, &[string.with_name("b"), unit.with_name("a")]);
+        assert_eq!(dictionary.to_string(), r#"{a, b = "foo"}"#);
+        assert_eq!(dictionary.len(), 2);
+        assert!(!dictionary.is_empty());
+        assert_eq!(dictionary.get(0).name().value(), "a");
+        assert_eq!(dictionary.get(1).attribute(), string);
+        assert_eq!(
+            dictionary
+                .iter()
+                .map(|element| element.name().value().as_str().to_owned())
+                .collect::<Vec<_>>(),
+            vec!["a", "b"]
+        );
+    }
+
+    #[test]
+    fn get_by_name() {
+        let context = Context::new(None, false);
+        let erased_dictionary = AttributeRef::parse(&context, "{x = 1 : i32, y}").unwrap();
+        let dictionary = DictionaryAttributeRef::try_from_attribute(erased_dictionary).unwrap();
+        assert_eq!(
+            dictionary.get_by_name("x"),
+            AttributeRef::parse(&context, "1 : i32")
+        );
+        assert!(dictionary.get_by_name("z").is_none());
+    }
+
+    #[test]
+    fn get_by_path() {
+        let context = Context::new(None, false);
+        let erased_dictionary = AttributeRef::parse(
+            &context,
+            r#"{a = {b = {c = 1 : i32}}, llvm.linkage = "x", llvm = {y = 2 : i32}}"#,
+        )
+        .unwrap();
+        let dictionary = DictionaryAttributeRef::try_from_attribute(erased_dictionary).unwrap();
+        assert_eq!(
+            dictionary.get_by_path("a.b.c"),
+            AttributeRef::parse(&context, "1 : i32")
+        );
+        assert_eq!(
+            dictionary.get_by_path("llvm.linkage"),
+            AttributeRef::parse(&context, r#""x""#)
+        );
+        assert_eq!(
+            dictionary.get_by_path("llvm.y"),
+            AttributeRef::parse(&context, "2 : i32")
+        );
+        assert!(dictionary.get_by_path("a.c").is_none());
+        assert!(dictionary.get_by_path("a.b.c.d").is_none());
+    }
+
+    #[test]
+    fn compare_and_merge() {
+        let context = Context::new(None, false);
+        let parse = |source| {
+            let attribute = AttributeRef::parse(&context, source).unwrap();
+            DictionaryAttributeRef::try_from_attribute(attribute).unwrap()
+        };
+        let dictionary = parse("{a = 1 : i32, b}");
+        assert!(dictionary == parse("{b, a = 1 : i32}"));
+        assert!(dictionary != parse("{a = 2 : i32, b}"));
+        assert!(dictionary.contains_all(parse("{b}")));
+        assert!(!dictionary.contains_all(parse("{a = 2 : i32}")));
+
+        let merged = dictionary.merge(parse("{a = 2 : i32, c}"));
+        assert_eq!(merged.to_string(), "{a = 2 : i32, b, c}");
+    }
+
+    #[test]
+    fn empty() {
+        let context = Context::new(None, false);
+        let dictionary = DictionaryAttributeRef::new(&context, &[]);
+        assert_eq!(dictionary.to_string(), "{}");
+        assert!(dictionary.is_empty());
+    }
+
+    #[test]
+    fn from_attribute() {
+        let context = Context::new(None, false);
+        let erased_unit = AttributeRef::parse(&context, "unit").unwrap();
+        assert!(DictionaryAttributeRef::try_from_attribute(erased_unit).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_dictionary_attribute_ref() {
+        let _dictionary_attribute_ref = DictionaryAttributeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
@@ -0,0 +1,188 @@
+use super::impl_attribute_variant;
+use crate::{
+    ir::{AttributeRef, ShapedTypeRef, TypeRef},
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{
+    mlirAttributeIsADenseResourceElements, mlirAttributeIsAElements,
+    mlirElementsAttrGetNumElements, mlirElementsAttrGetValue, mlirElementsAttrIsValidIndex,
+    MlirAttribute,
+};
+
+/// [ElementsAttributeRef] is a reference to an instance of the `mlir::ElementsAttr` interface,
+/// which is implemented by every attribute that represents a constant tensor or vector, whether
+/// its elements are stored densely or sparsely.
+///
+/// Attributes whose elements are stored in a resource blob also implement the interface, but are
+/// not elements attributes in terms of this type: the C API cannot read their elements, and
+/// asserts when asked to. They are accessible through
+/// [DenseResourceElementsAttributeRef](crate::ir::DenseResourceElementsAttributeRef) instead.
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirAttributeIsADenseResourceElements`
+/// - `mlirAttributeIsAElements`
+/// - `mlirElementsAttrGetNumElements`
+/// - `mlirElementsAttrGetValue`
+/// - `mlirElementsAttrIsValidIndex`
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct ElementsAttributeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, ElementsAttributeRef, MlirAttribute);
+impl_attribute_variant!(ElementsAttributeRef, is_elements_with_values);
+
+/// Checks whether the provided raw attribute is an elements attribute whose elements can be read
+/// through the C API, which excludes dense resource elements attributes.
+///
+/// # Safety
+/// The provided attribute must be valid.
+unsafe fn is_elements_with_values(attr: MlirAttribute) -> bool {
+    mlirAttributeIsAElements(attr) && !mlirAttributeIsADenseResourceElements(attr)
+}
+
+impl ElementsAttributeRef {
+    /// # Returns
+    /// Returns the shaped type of the attribute.
+    pub fn shaped_type(&self) -> &ShapedTypeRef {
+        ShapedTypeRef::try_from_type(self.r#type()).unwrap()
+    }
+
+    /// # Returns
+    /// Returns the type of the elements held by the attribute.
+    pub fn element_type(&self) -> &TypeRef {
+        self.shaped_type().element_type()
+    }
+
+    /// # Returns
+    /// Returns the total number of elements held by the attribute.
+    pub fn len(&self) -> isize {
+        unsafe { mlirElementsAttrGetNumElements(self.to_raw()) as isize }
+    }
+
+    /// # Returns
+    /// Returns whether the attribute holds no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Checks whether the provided multi-dimensional index refers to an element of the attribute.
+    ///
+    /// # Arguments
+    /// * `idxs` - The index of the element along each dimension.
+    ///
+    /// # Returns
+    /// Returns whether the index has one entry per dimension and is within bounds.
+    pub fn is_valid_index(&self, idxs: &[u64]) -> bool {
+        unsafe {
+            mlirElementsAttrIsValidIndex(
+                self.to_raw(),
+                idxs.len() as isize,
+                idxs.as_ptr() as *mut u64,
+            )
+        }
+    }
+
+    /// Gets the element at the provided multi-dimensional index, verifying that the index is
+    /// valid.
+    ///
+    /// # Arguments
+    /// * `idxs` - The index of the element along each dimension.
+    ///
+    /// # Returns
+    /// Returns the element at the provided index.
+    pub fn get(&self, idxs: &[u64]) -> &AttributeRef {
+        if !self.is_valid_index(idxs) {
+            panic!("Element index {:?} out of bounds.", idxs);
+        }
+        unsafe {
+            AttributeRef::from_raw(mlirElementsAttrGetValue(
+                self.to_raw(),
+                idxs.len() as isize,
+                idxs.as_ptr() as *mut u64,
+            ))
+        }
+    }
+
+    /// Verifies that the provided (flattened) index is within bounds.
+    pub(crate) fn check_index(&self, idx: isize) {
+        if idx < 0 || idx >= self.len() {
+            panic!("Element index {} out of bounds.", idx);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{DenseResourceElementsAttributeRef, IntegerTypeRef, RankedTensorTypeRef},
+        Context,
+    };
+
+    #[test]
+    fn dense() {
+        let context = Context::new(None, false);
+        let attribute =
+            AttributeRef::parse(&context, "dense<[[1, 2], [3, 4]]> : tensor<2x2xi32>").unwrap();
+        let attribute = ElementsAttributeRef::try_from_attribute(attribute).unwrap();
+        assert_eq!(attribute.len(), 4);
+        assert!(!attribute.is_empty());
+        assert_eq!(attribute.shaped_type().to_string(), "tensor<2x2xi32>");
+        assert_eq!(attribute.element_type().to_string(), "i32");
+        assert!(attribute.is_valid_index(&[1, 0]));
+        assert!(!attribute.is_valid_index(&[2, 0]));
+        assert!(!attribute.is_valid_index(&[1]));
+        assert_eq!(attribute.get(&[1, 0]).to_string(), "3 : i32");
+    }
+
+    #[test]
+    fn sparse() {
+        let context = Context::new(None, false);
+        let attribute =
+            AttributeRef::parse(&context, "sparse<[[0, 1]], [5]> : tensor<2x2xi64>").unwrap();
+        let attribute = ElementsAttributeRef::try_from_attribute(attribute).unwrap();
+        assert_eq!(attribute.len(), 4);
+        assert_eq!(attribute.get(&[0, 1]).to_string(), "5 : i64");
+        assert_eq!(attribute.get(&[1, 1]).to_string(), "0 : i64");
+    }
+
+    #[test]
+    fn from_attribute() {
+        let context = Context::new(None, false);
+        let elements = AttributeRef::parse(&context, "dense<1.0> : tensor<2xf32>").unwrap();
+        let array = AttributeRef::parse(&context, "[1 : i32]").unwrap();
+        let i8_type = IntegerTypeRef::new_signless(&context, 8);
+        let tensor_type = RankedTensorTypeRef::new(&[1], i8_type, None);
+        let data = vec![1i8].into_boxed_slice();
+        let resource = DenseResourceElementsAttributeRef::new(tensor_type, "blob", data);
+        assert!(ElementsAttributeRef::try_from_attribute(elements).is_some());
+        assert!(ElementsAttributeRef::try_from_attribute(array).is_none());
+        assert!(ElementsAttributeRef::try_from_attribute(resource).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_out_of_bounds() {
+        let context = Context::new(None, false);
+        let attribute = AttributeRef::parse(&context, "dense<1> : tensor<2xi8>").unwrap();
+        let attribute = ElementsAttributeRef::try_from_attribute(attribute).unwrap();
+        attribute.get(&[2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_elements_attribute_ref() {
+        let _elements_attribute_ref = ElementsAttributeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
@@ -1,14 +1,15 @@
 use super::impl_attribute_variant;
 use crate::{
-    ir::FloatTypeRef,
+    ir::{FloatKind, FloatTypeRef, LocationRef, TypeRef},
     support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
-    ContextRef,
+    ContextRef, Diagnostic,
 };
 
 use std::marker::PhantomData;
 
 use mlir_sys::{
-    mlirAttributeIsAFloat, mlirFloatAttrDoubleGet, mlirFloatAttrGetValueDouble, MlirAttribute,
+    mlirAttributeIsAFloat, mlirFloatAttrDoubleGet, mlirFloatAttrDoubleGetChecked,
+    mlirFloatAttrGetValueDouble, MlirAttribute,
 };
 
 /// [FloatAttributeRef] is a reference to an instance of the `mlir::FloatAttr` class, which
@@ -17,10 +18,10 @@ use mlir_sys::{
 /// The following bindings into the MLIR C API are used/supported:
 /// - `mlirAttributeIsAFloat`
 /// - `mlirFloatAttrDoubleGet`
+/// - `mlirFloatAttrDoubleGetChecked`
 /// - `mlirFloatAttrGetValueDouble`
 ///
 /// The following bindings are not used/supported:
-/// - `mlirFloatAttrDoubleGetChecked`
 /// - `mlirFloatAttrGetTypeID`
 ///
 /// # Safety
@@ -48,11 +49,63 @@ impl FloatAttributeRef {
         unsafe { Self::from_raw(mlirFloatAttrDoubleGet(context.to_raw(), ty.to_raw(), value)) }
     }
 
+    /// Constructs a new float attribute of the specified type with the provided value, verifying
+    /// that the type is a float type and capturing the diagnostics emitted if it is not.
+    ///
+    /// # Arguments
+    /// * `location` - The location to emit diagnostics at.
+    /// * `ty` - The type of the float attribute.
+    /// * `value` - The float value to hold in the attribute.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [FloatAttributeRef] instance, or the diagnostics describing
+    /// why the attribute could not be constructed.
+    pub fn new_checked<'a>(
+        location: &'a LocationRef,
+        ty: &'a TypeRef,
+        value: f64,
+    ) -> Result<&'a Self, Vec<Diagnostic>> {
+        let capture = location.context().capture_diagnostics();
+        let attribute = unsafe {
+            Self::try_from_raw(mlirFloatAttrDoubleGetChecked(
+                location.to_raw(),
+                ty.to_raw(),
+                value,
+            ))
+        };
+        attribute.ok_or_else(|| capture.take())
+    }
+
+    /// Constructs a new `f32` attribute with the provided value. Every `f32` value is exactly
+    /// representable as the `f64` passed through the MLIR C API, so the value is never rounded.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the attribute.
+    /// * `value` - The float value to hold in the attribute.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [FloatAttributeRef] instance.
+    pub fn new_f32(context: &ContextRef, value: f32) -> &Self {
+        Self::new(context, FloatTypeRef::new_f32(context), value as f64)
+    }
+
     /// # Returns
     /// Returns the value held by the attribute as a 64-bit float.
     pub fn value(&self) -> f64 {
         unsafe { mlirFloatAttrGetValueDouble(self.to_raw()) }
     }
+
+    /// Gets the value held by an `f32` attribute, verifying the type of the attribute.
+    ///
+    /// # Returns
+    /// Returns the value held by the attribute as a 32-bit float.
+    pub fn value_f32(&self) -> f32 {
+        let ty = FloatTypeRef::try_from_type(self.r#type()).unwrap();
+        if ty.kind() != FloatKind::F32 {
+            panic!("Expected an f32 attribute, got {}.", ty.as_type());
+        }
+        self.value() as f32
+    }
 }
 
 #[cfg(test)]
@@ -68,6 +121,37 @@ mod tests {
         assert_eq!(float_attribute.value(), 6.29);
     }
 
+    #[test]
+    fn new_checked() {
+        let context = Context::new(None, false);
+        let location = LocationRef::new_unknown(&context);
+        let f16_type = FloatTypeRef::new_f16(&context);
+        let attribute = FloatAttributeRef::new_checked(location, f16_type, 0.5).unwrap();
+        assert_eq!(attribute.to_string(), "5.000000e-01 : f16");
+
+        let i32_type = TypeRef::parse(&context, "i32").unwrap();
+        let diagnostics = FloatAttributeRef::new_checked(location, i32_type, 0.5).unwrap_err();
+        assert_eq!(diagnostics.len(), 1);
+        assert!(!diagnostics[0].message().is_empty());
+    }
+
+    #[test]
+    fn f32_value() {
+        let context = Context::new(None, false);
+        let value = 0.1f32;
+        let attribute = FloatAttributeRef::new_f32(&context, value);
+        assert_eq!(attribute.r#type().to_string(), "f32");
+        assert_eq!(attribute.value_f32(), value);
+    }
+
+    #[test]
+    #[should_panic]
+    fn f32_value_of_f64_attribute() {
+        let context = Context::new(None, false);
+        let float_type = FloatTypeRef::new_f64(&context);
+        FloatAttributeRef::new(&context, float_type, 0.1).value_f32();
+    }
+
     #[test]
     fn from_attribute() {
         let context = Context::new(None, false);
@@ -0,0 +1,209 @@
+use super::impl_attribute_variant;
+use crate::{
+    ir::{AttributeRef, DictionaryAttributeRef},
+    support::binding::{impl_unowned_mlir_value, OptionalMlirValue, UnownedMlirValue},
+    ContextRef, StringRef,
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{
+    mlirAttributeIsAGPUObjectAttr, mlirGPUObjectAttrGet, mlirGPUObjectAttrGetFormat,
+    mlirGPUObjectAttrGetObject, mlirGPUObjectAttrGetProperties, mlirGPUObjectAttrGetTarget,
+    mlirGPUObjectAttrHasProperties, MlirAttribute,
+};
+
+/// [CompilationTarget] represents the format of a GPU object, i.e. how far the kernels of a
+/// `gpu.module` operation were compiled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompilationTarget {
+    /// The object holds an intermediate representation (e.g. LLVM bitcode) for offloading.
+    Offload = 1,
+    /// The object holds assembly code (e.g. PTX).
+    Assembly = 2,
+    /// The object holds a binary for a single device.
+    Binary = 3,
+    /// The object holds a binary for multiple devices.
+    Fatbin = 4,
+}
+
+impl CompilationTarget {
+    const ALL: [Self; 4] = [Self::Offload, Self::Assembly, Self::Binary, Self::Fatbin];
+
+    /// Converts the provided raw format into a [CompilationTarget].
+    ///
+    /// # Arguments
+    /// * `raw` - The raw format.
+    ///
+    /// # Returns
+    /// Returns the matching [CompilationTarget], or `None` if the raw value is unknown.
+    pub fn from_raw(raw: u32) -> Option<Self> {
+        Self::ALL.into_iter().find(|format| *format as u32 == raw)
+    }
+}
+
+/// [GPUObjectAttributeRef] is a reference to an instance of the `mlir::gpu::ObjectAttr` class,
+/// which holds the result of compiling a `gpu.module` operation for a single target, as stored in
+/// the `objects` of a `gpu.binary` operation.
+///
+/// All relevant bindings into the MLIR C API are used/supported:
+/// - `mlirAttributeIsAGPUObjectAttr`
+/// - `mlirGPUObjectAttrGet`
+/// - `mlirGPUObjectAttrGetFormat`
+/// - `mlirGPUObjectAttrGetObject`
+/// - `mlirGPUObjectAttrGetProperties`
+/// - `mlirGPUObjectAttrGetTarget`
+/// - `mlirGPUObjectAttrHasProperties`
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct GPUObjectAttributeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, GPUObjectAttributeRef, MlirAttribute);
+impl_attribute_variant!(GPUObjectAttributeRef, mlirAttributeIsAGPUObjectAttr);
+
+impl GPUObjectAttributeRef {
+    /// Constructs a new GPU object attribute. The `gpu` dialect must be loaded in the context.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the attribute.
+    /// * `target` - The target attribute the object was compiled for (e.g. `#nvvm.target`), which
+    ///   must implement (or promise) the `gpu::TargetAttrInterface`.
+    /// * `format` - The format of the object.
+    /// * `object` - The contents of the object, which may be a binary blob.
+    /// * `properties` - Additional properties of the object, if any.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [GPUObjectAttributeRef] instance.
+    pub fn new<'a>(
+        context: &'a ContextRef,
+        target: &AttributeRef,
+        format: CompilationTarget,
+        object: &[u8],
+        properties: Option<&DictionaryAttributeRef>,
+    ) -> &'a Self {
+        unsafe {
+            Self::from_raw(mlirGPUObjectAttrGet(
+                context.to_raw(),
+                target.to_raw(),
+                format as u32,
+                StringRef::from_bytes(object).to_raw(),
+                properties.to_raw_or_null(),
+            ))
+        }
+    }
+
+    /// # Returns
+    /// Returns the target attribute the object was compiled for.
+    pub fn target(&self) -> &AttributeRef {
+        unsafe { AttributeRef::from_raw(mlirGPUObjectAttrGetTarget(self.to_raw())) }
+    }
+
+    /// # Returns
+    /// Returns the format of the object, or `None` if it is not a known format.
+    pub fn format(&self) -> Option<CompilationTarget> {
+        CompilationTarget::from_raw(unsafe { mlirGPUObjectAttrGetFormat(self.to_raw()) })
+    }
+
+    /// # Returns
+    /// Returns the contents of the object, which are not required to be valid UTF-8.
+    pub fn object(&self) -> &[u8] {
+        unsafe { StringRef::from_raw(mlirGPUObjectAttrGetObject(self.to_raw())) }.as_bytes()
+    }
+
+    /// # Returns
+    /// Returns the additional properties of the object, if there are any.
+    pub fn properties(&self) -> Option<&DictionaryAttributeRef> {
+        if !unsafe { mlirGPUObjectAttrHasProperties(self.to_raw()) } {
+            return None;
+        }
+        let properties =
+            unsafe { AttributeRef::from_raw(mlirGPUObjectAttrGetProperties(self.to_raw())) };
+        DictionaryAttributeRef::try_from_attribute(properties)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::NamedAttribute, Context, DialectRegistry};
+
+    fn context() -> Context {
+        // mlir-sys binds no handle of the `nvvm` dialect, which is only registered with all others.
+        let dialect_registry = DialectRegistry::default();
+        dialect_registry.register_all_dialects();
+        let context = Context::new(Some(&dialect_registry), false);
+        context.load_all_available_dialects();
+        context
+    }
+
+    #[test]
+    fn new() {
+        let context = context();
+        let target = AttributeRef::parse(&context, "#nvvm.target").unwrap();
+        let object =
+            GPUObjectAttributeRef::new(&context, target, CompilationTarget::Assembly, b"ptx", None);
+        let parsed_object =
+            AttributeRef::parse(&context, "#gpu.object<#nvvm.target, assembly = \"ptx\">").unwrap();
+        assert_eq!(object.as_attribute(), parsed_object);
+        assert_eq!(object.target(), target);
+        assert_eq!(object.format(), Some(CompilationTarget::Assembly));
+        assert_eq!(object.object(), b"ptx");
+        assert!(object.properties().is_none());
+    }
+
+    #[test]
+    fn binary_object() {
+        let context = context();
+        let target = AttributeRef::parse(&context, "#nvvm.target").unwrap();
+        let blob = [0x7f, b'E', b'L', b'F', 0x00, 0xff];
+        let object =
+            GPUObjectAttributeRef::new(&context, target, CompilationTarget::Binary, &blob, None);
+        assert_eq!(object.format(), Some(CompilationTarget::Binary));
+        assert_eq!(object.object(), &blob);
+    }
+
+    #[test]
+    fn properties() {
+        let context = context();
+        let target = AttributeRef::parse(&context, "#nvvm.target").unwrap();
+        let properties = DictionaryAttributeRef::new(
+            &context,
+            &[NamedAttribute::new(
+                &context,
+                "O",
+                AttributeRef::parse(&context, "3 : i32").unwrap(),
+            )],
+        );
+        let object = GPUObjectAttributeRef::new(
+            &context,
+            target,
+            CompilationTarget::Offload,
+            b"",
+            Some(properties),
+        );
+        assert_eq!(
+            object
+                .properties()
+                .map(|properties| properties.as_attribute()),
+            Some(properties.as_attribute())
+        );
+    }
+
+    #[test]
+    fn compilation_target_from_raw() {
+        for format in CompilationTarget::ALL {
+            assert_eq!(CompilationTarget::from_raw(format as u32), Some(format));
+        }
+        assert_eq!(CompilationTarget::from_raw(0), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_gpu_object_attribute_ref() {
+        let _gpu_object_attribute_ref = GPUObjectAttributeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
@@ -1,14 +1,14 @@
 use super::impl_attribute_variant;
 use crate::{
-    ir::IntegerTypeRef,
+    ir::{AttributeRef, IndexTypeRef, IntegerTypeRef},
     support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
 };
 
 use std::marker::PhantomData;
 
 use mlir_sys::{
-    mlirAttributeIsAInteger, mlirIntegerAttrGet, mlirIntegerAttrGetValueSInt,
-    mlirIntegerAttrGetValueUInt, MlirAttribute,
+    mlirAttributeIsAInteger, mlirIntegerAttrGet, mlirIntegerAttrGetValueInt,
+    mlirIntegerAttrGetValueSInt, mlirIntegerAttrGetValueUInt, MlirAttribute,
 };
 
 /// [IntegerAttributeRef] is a reference to an instance of the `mlir::IntegerAttr` class, which
@@ -17,13 +17,16 @@ use mlir_sys::{
 /// The following bindings into the MLIR C API are used/supported:
 /// - `mlirAttributeIsAInteger`
 /// - `mlirIntegerAttrGet`
+/// - `mlirIntegerAttrGetValueInt`
 /// - `mlirIntegerAttrGetValueSInt`
 /// - `mlirIntegerAttrGetValueUInt`
 ///
 /// The following bindings are not used/supported:
-/// - `mlirIntegerAttrGetValueInt`
 /// - `mlirIntegerAttrGetTypeID`
 ///
+/// The MLIR C API only supports integer values of up to 64 bits, so wider values are constructed
+/// and retrieved through the textual form of the attribute.
+///
 /// # Safety
 /// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
 /// undefined behaviour.
@@ -50,6 +53,77 @@ impl IntegerAttributeRef {
         unsafe { Self::from_raw(mlirIntegerAttrGet(ty.to_raw(), value)) }
     }
 
+    /// Constructs a new integer attribute of the specified type with the provided value, which
+    /// supports types wider than 64 bits.
+    ///
+    /// # Arguments
+    /// * `ty` - The type of the integer attribute.
+    /// * `value` - The integer value to hold in the attribute.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [IntegerAttributeRef] instance, or `None` if the value does
+    /// not fit into the type.
+    pub fn new_i128(ty: &IntegerTypeRef, value: i128) -> Option<&Self> {
+        Self::new_from_str(ty, &value.to_string())
+    }
+
+    /// Constructs a new integer attribute of the specified type with the value given in decimal
+    /// form, which supports types of any width.
+    ///
+    /// # Arguments
+    /// * `ty` - The type of the integer attribute.
+    /// * `value` - The decimal form of the integer value to hold in the attribute.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [IntegerAttributeRef] instance, or `None` if the value is not
+    /// a decimal integer or does not fit into the type.
+    pub fn new_from_str<'a>(ty: &'a IntegerTypeRef, value: &str) -> Option<&'a Self> {
+        let digits = value.strip_prefix('-').unwrap_or(value);
+        if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+            return None;
+        }
+        let source = format!("{} : {}", value, ty.as_type());
+        let attribute = AttributeRef::parse(ty.context(), &source)?;
+        Self::try_from_attribute(attribute)
+    }
+
+    /// Gets the integer value held by an attribute of a signless integer or index type, verifying
+    /// the type of the attribute.
+    ///
+    /// # Returns
+    /// Returns the integer value held by the attribute, sign-extended to 64 bits.
+    pub fn value(&self) -> i64 {
+        let ty = self.r#type();
+        let is_signless = IntegerTypeRef::try_from_type(ty).is_some_and(|ty| ty.is_signless());
+        if !is_signless && IndexTypeRef::try_from_type(ty).is_none() {
+            panic!(
+                "Expected a signless integer or index attribute, got {}.",
+                ty
+            );
+        }
+        unsafe { mlirIntegerAttrGetValueInt(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns the integer value held by the attribute in decimal form, which supports types of
+    /// any width. Values of signless types are interpreted as signed.
+    pub fn value_string(&self) -> String {
+        let source = self.to_string();
+        let value = source.split(" : ").next().unwrap();
+        match value {
+            "true" => "-1".to_string(),
+            "false" => "0".to_string(),
+            value => value.to_string(),
+        }
+    }
+
+    /// # Returns
+    /// Returns the integer value held by the attribute as a 128-bit integer (see
+    /// [IntegerAttributeRef::value_string]), or `None` if it does not fit.
+    pub fn value_i128(&self) -> Option<i128> {
+        self.value_string().parse().ok()
+    }
+
     /// # Returns
     /// Returns the integer value held by the attribute as a signed 64-bit integer.
     pub fn value_signed(&self) -> i64 {
@@ -86,6 +160,59 @@ mod tests {
         assert_eq!(integer_attribute.value_signed(), 42);
     }
 
+    #[test]
+    fn signless_value() {
+        let context = Context::new(None, false);
+        let i8_type = IntegerTypeRef::new_signless(&context, 8);
+        assert_eq!(IntegerAttributeRef::new(i8_type, -3).value(), -3);
+        let index_attribute = AttributeRef::parse(&context, "7 : index").unwrap();
+        let index_attribute = IntegerAttributeRef::try_from_attribute(index_attribute).unwrap();
+        assert_eq!(index_attribute.value(), 7);
+    }
+
+    #[test]
+    #[should_panic]
+    fn signed_value() {
+        let context = Context::new(None, false);
+        let si8_type = IntegerTypeRef::new_signed(&context, 8);
+        IntegerAttributeRef::new(si8_type, -3).value();
+    }
+
+    #[test]
+    fn wide_values() {
+        let context = Context::new(None, false);
+        let i128_type = IntegerTypeRef::new_signless(&context, 128);
+        let attribute = IntegerAttributeRef::new_i128(i128_type, i128::MIN).unwrap();
+        assert_eq!(attribute.value_i128(), Some(i128::MIN));
+        assert_eq!(attribute.value_string(), i128::MIN.to_string());
+
+        let i256_type = IntegerTypeRef::new_unsigned(&context, 256);
+        let value =
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+        let attribute = IntegerAttributeRef::new_from_str(i256_type, value).unwrap();
+        assert_eq!(attribute.value_string(), value);
+        assert_eq!(attribute.value_i128(), None);
+    }
+
+    #[test]
+    fn narrow_values() {
+        let context = Context::new(None, false);
+        let i1_type = IntegerTypeRef::new_signless(&context, 1);
+        let attribute = IntegerAttributeRef::new(i1_type, 1);
+        assert_eq!(attribute.value_string(), "-1");
+        let ui8_type = IntegerTypeRef::new_unsigned(&context, 8);
+        let attribute = IntegerAttributeRef::new_i128(ui8_type, 255).unwrap();
+        assert_eq!(attribute.value_i128(), Some(255));
+    }
+
+    #[test]
+    fn invalid_values() {
+        let context = Context::new(None, false);
+        let i8_type = IntegerTypeRef::new_signless(&context, 8);
+        assert!(IntegerAttributeRef::new_from_str(i8_type, "1 : i32").is_none());
+        assert!(IntegerAttributeRef::new_from_str(i8_type, "").is_none());
+    }
+
     #[test]
     #[should_panic]
     fn no_owned_integer_attribute_ref() {
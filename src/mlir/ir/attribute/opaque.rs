@@ -0,0 +1,114 @@
+use super::impl_attribute_variant;
+use crate::{
+    ir::TypeRef,
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+    ContextRef, StringRef,
+};
+
+use std::{marker::PhantomData, os::raw::c_char};
+
+use mlir_sys::{
+    mlirAttributeIsAOpaque, mlirOpaqueAttrGet, mlirOpaqueAttrGetData,
+    mlirOpaqueAttrGetDialectNamespace, MlirAttribute,
+};
+
+/// [OpaqueAttributeRef] is a reference to an instance of the `mlir::OpaqueAttr` class, which
+/// represents an attribute of an unregistered dialect, holding its textual payload unparsed.
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirAttributeIsAOpaque`
+/// - `mlirOpaqueAttrGet`
+/// - `mlirOpaqueAttrGetData`
+/// - `mlirOpaqueAttrGetDialectNamespace`
+///
+/// The following bindings are not used/supported:
+/// - `mlirOpaqueAttrGetTypeID`
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct OpaqueAttributeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, OpaqueAttributeRef, MlirAttribute);
+impl_attribute_variant!(OpaqueAttributeRef, mlirAttributeIsAOpaque);
+
+impl OpaqueAttributeRef {
+    /// Constructs a new opaque attribute. The context must allow unregistered dialects, or the
+    /// dialect namespace must not belong to a loaded dialect.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the attribute.
+    /// * `dialect_namespace` - The namespace of the dialect the attribute belongs to.
+    /// * `data` - The payload of the attribute.
+    /// * `ty` - The type of the attribute.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [OpaqueAttributeRef] instance.
+    pub fn new<'a>(
+        context: &'a ContextRef,
+        dialect_namespace: &str,
+        data: &[u8],
+        ty: &TypeRef,
+    ) -> &'a Self {
+        unsafe {
+            Self::from_raw(mlirOpaqueAttrGet(
+                context.to_raw(),
+                StringRef::from(&dialect_namespace).to_raw(),
+                data.len() as isize,
+                data.as_ptr() as *const c_char,
+                ty.to_raw(),
+            ))
+        }
+    }
+
+    /// # Returns
+    /// Returns the namespace of the dialect the attribute belongs to.
+    pub fn dialect_namespace(&self) -> StringRef {
+        unsafe { StringRef::from_raw(mlirOpaqueAttrGetDialectNamespace(self.to_raw())) }
+    }
+
+    /// # Returns
+    /// Returns the raw payload of the attribute.
+    pub fn data(&self) -> &[u8] {
+        unsafe { StringRef::from_raw(mlirOpaqueAttrGetData(self.to_raw())) }.as_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{AttributeRef, NoneTypeRef},
+        Context,
+    };
+
+    #[test]
+    fn new() {
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let none_type = NoneTypeRef::new(&context);
+        let attribute = OpaqueAttributeRef::new(&context, "mydialect", b"payload<1>", none_type);
+        assert_eq!(attribute.to_string(), "#mydialect.payload<1>");
+        assert_eq!(attribute.dialect_namespace(), "mydialect");
+        assert_eq!(attribute.data(), b"payload<1>");
+    }
+
+    #[test]
+    fn from_attribute() {
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let erased_opaque = AttributeRef::parse(&context, "#mydialect<\"data\">").unwrap();
+        let erased_unit = AttributeRef::parse(&context, "unit").unwrap();
+        let opaque = OpaqueAttributeRef::try_from_attribute(erased_opaque).unwrap();
+        assert_eq!(opaque.dialect_namespace(), "mydialect");
+        assert!(OpaqueAttributeRef::try_from_attribute(erased_unit).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_opaque_attribute_ref() {
+        let _opaque_attribute_ref = OpaqueAttributeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
@@ -0,0 +1,354 @@
+use super::impl_attribute_variant;
+use crate::{
+    ir::AffineMapRef,
+    support::binding::{impl_unowned_mlir_value, OptionalMlirValue, UnownedMlirValue},
+    ContextRef,
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{
+    mlirAttributeIsASparseTensorEncodingAttr, mlirSparseTensorEncodingAttrGet,
+    mlirSparseTensorEncodingAttrGetCrdWidth, mlirSparseTensorEncodingAttrGetDimToLvl,
+    mlirSparseTensorEncodingAttrGetLvlToDim, mlirSparseTensorEncodingAttrGetLvlType,
+    mlirSparseTensorEncodingAttrGetPosWidth, mlirSparseTensorEncodingGetLvlRank, MlirAttribute,
+    MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_COMPRESSED,
+    MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_COMPRESSED_NO,
+    MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_COMPRESSED_NU,
+    MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_COMPRESSED_NU_NO,
+    MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_DENSE,
+    MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_LOOSE_COMPRESSED,
+    MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_LOOSE_COMPRESSED_NO,
+    MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_LOOSE_COMPRESSED_NU,
+    MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_LOOSE_COMPRESSED_NU_NO,
+    MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_SINGLETON,
+    MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_SINGLETON_NO,
+    MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_SINGLETON_NU,
+    MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_SINGLETON_NU_NO,
+    MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_TWO_OUT_OF_FOUR,
+    MlirSparseTensorLevelType,
+};
+
+/// [SparseLevelType] represents the storage format of a single level of a sparse tensor, along
+/// with whether its coordinates are non-unique (`Nu`) and/or non-ordered (`No`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SparseLevelType {
+    Dense,
+    TwoOutOfFour,
+    Compressed,
+    CompressedNu,
+    CompressedNo,
+    CompressedNuNo,
+    Singleton,
+    SingletonNu,
+    SingletonNo,
+    SingletonNuNo,
+    LooseCompressed,
+    LooseCompressedNu,
+    LooseCompressedNo,
+    LooseCompressedNuNo,
+}
+
+impl SparseLevelType {
+    const ALL: [Self; 14] = [
+        Self::Dense,
+        Self::TwoOutOfFour,
+        Self::Compressed,
+        Self::CompressedNu,
+        Self::CompressedNo,
+        Self::CompressedNuNo,
+        Self::Singleton,
+        Self::SingletonNu,
+        Self::SingletonNo,
+        Self::SingletonNuNo,
+        Self::LooseCompressed,
+        Self::LooseCompressedNu,
+        Self::LooseCompressedNo,
+        Self::LooseCompressedNuNo,
+    ];
+
+    /// Converts the provided raw level type into a [SparseLevelType].
+    ///
+    /// # Arguments
+    /// * `raw` - The raw level type.
+    ///
+    /// # Returns
+    /// Returns the matching [SparseLevelType], or `None` if the raw value is unknown.
+    pub fn from_raw(raw: MlirSparseTensorLevelType) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|level_type| level_type.to_raw() == raw)
+    }
+
+    /// # Returns
+    /// Returns the raw [MlirSparseTensorLevelType] value.
+    pub fn to_raw(&self) -> MlirSparseTensorLevelType {
+        let raw = match self {
+            Self::Dense => MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_DENSE,
+            Self::TwoOutOfFour => {
+                MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_TWO_OUT_OF_FOUR
+            }
+            Self::Compressed => MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_COMPRESSED,
+            Self::CompressedNu => {
+                MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_COMPRESSED_NU
+            }
+            Self::CompressedNo => {
+                MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_COMPRESSED_NO
+            }
+            Self::CompressedNuNo => {
+                MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_COMPRESSED_NU_NO
+            }
+            Self::Singleton => MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_SINGLETON,
+            Self::SingletonNu => {
+                MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_SINGLETON_NU
+            }
+            Self::SingletonNo => {
+                MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_SINGLETON_NO
+            }
+            Self::SingletonNuNo => {
+                MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_SINGLETON_NU_NO
+            }
+            Self::LooseCompressed => {
+                MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_LOOSE_COMPRESSED
+            }
+            Self::LooseCompressedNu => {
+                MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_LOOSE_COMPRESSED_NU
+            }
+            Self::LooseCompressedNo => {
+                MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_LOOSE_COMPRESSED_NO
+            }
+            Self::LooseCompressedNuNo => {
+                MlirBaseSparseTensorLevelType_MLIR_SPARSE_TENSOR_LEVEL_LOOSE_COMPRESSED_NU_NO
+            }
+        };
+        raw as MlirSparseTensorLevelType
+    }
+}
+
+/// [SparseTensorEncodingAttributeRef] is a reference to an instance of the
+/// `mlir::sparse_tensor::SparseTensorEncodingAttr` class, which describes how the elements of a
+/// ranked tensor are stored when it is used as the encoding of the tensor type.
+///
+/// All relevant bindings into the MLIR C API are used/supported:
+/// - `mlirAttributeIsASparseTensorEncodingAttr`
+/// - `mlirSparseTensorEncodingAttrGet`
+/// - `mlirSparseTensorEncodingAttrGetCrdWidth`
+/// - `mlirSparseTensorEncodingAttrGetDimToLvl`
+/// - `mlirSparseTensorEncodingAttrGetLvlToDim`
+/// - `mlirSparseTensorEncodingAttrGetLvlType`
+/// - `mlirSparseTensorEncodingAttrGetPosWidth`
+/// - `mlirSparseTensorEncodingGetLvlRank`
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct SparseTensorEncodingAttributeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, SparseTensorEncodingAttributeRef, MlirAttribute);
+impl_attribute_variant!(
+    SparseTensorEncodingAttributeRef,
+    mlirAttributeIsASparseTensorEncodingAttr
+);
+
+impl SparseTensorEncodingAttributeRef {
+    /// Constructs a new sparse tensor encoding attribute. The `sparse_tensor` dialect must be
+    /// loaded in the context.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the attribute.
+    /// * `level_types` - The storage format of each level.
+    /// * `dim_to_lvl` - The map from dimensions to levels, or `None` for the identity map.
+    /// * `lvl_to_dim` - The map from levels to dimensions, or `None` to infer it from
+    ///   `dim_to_lvl`.
+    /// * `pos_width` - The bitwidth of the positions, or 0 for the native index width.
+    /// * `crd_width` - The bitwidth of the coordinates, or 0 for the native index width.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [SparseTensorEncodingAttributeRef] instance.
+    pub fn new<'a>(
+        context: &'a ContextRef,
+        level_types: &[SparseLevelType],
+        dim_to_lvl: Option<&AffineMapRef>,
+        lvl_to_dim: Option<&AffineMapRef>,
+        pos_width: i32,
+        crd_width: i32,
+    ) -> &'a Self {
+        let raw_level_types = level_types
+            .iter()
+            .map(SparseLevelType::to_raw)
+            .collect::<Vec<_>>();
+        unsafe {
+            Self::from_raw(mlirSparseTensorEncodingAttrGet(
+                context.to_raw(),
+                raw_level_types.len() as isize,
+                raw_level_types.as_ptr(),
+                dim_to_lvl.to_raw_or_null(),
+                lvl_to_dim.to_raw_or_null(),
+                pos_width,
+                crd_width,
+            ))
+        }
+    }
+
+    /// # Returns
+    /// Returns the number of levels of the encoding.
+    pub fn level_rank(&self) -> isize {
+        unsafe { mlirSparseTensorEncodingGetLvlRank(self.to_raw()) }
+    }
+
+    /// Gets the storage format of the provided level, verifying that the level is within bounds.
+    ///
+    /// # Arguments
+    /// * `lvl` - The index of the level.
+    ///
+    /// # Returns
+    /// Returns the storage format of the level, or `None` if it is not a known level type.
+    pub fn level_type(&self, lvl: isize) -> Option<SparseLevelType> {
+        if lvl < 0 || lvl >= self.level_rank() {
+            panic!("Level index {} out of bounds.", lvl);
+        }
+        SparseLevelType::from_raw(unsafe {
+            mlirSparseTensorEncodingAttrGetLvlType(self.to_raw(), lvl)
+        })
+    }
+
+    /// # Returns
+    /// Returns an iterator over the storage formats of all levels.
+    pub fn level_types(&self) -> impl ExactSizeIterator<Item = Option<SparseLevelType>> + '_ {
+        (0..self.level_rank()).map(|lvl| self.level_type(lvl))
+    }
+
+    /// # Returns
+    /// Returns the map from dimensions to levels, or `None` if it is the identity map.
+    pub fn dim_to_lvl(&self) -> Option<&AffineMapRef> {
+        unsafe {
+            AffineMapRef::try_from_raw(mlirSparseTensorEncodingAttrGetDimToLvl(self.to_raw()))
+        }
+    }
+
+    /// # Returns
+    /// Returns the map from levels to dimensions, or `None` if it is the identity map.
+    pub fn lvl_to_dim(&self) -> Option<&AffineMapRef> {
+        unsafe {
+            AffineMapRef::try_from_raw(mlirSparseTensorEncodingAttrGetLvlToDim(self.to_raw()))
+        }
+    }
+
+    /// # Returns
+    /// Returns the bitwidth of the positions, where 0 means the native index width.
+    pub fn pos_width(&self) -> i32 {
+        unsafe { mlirSparseTensorEncodingAttrGetPosWidth(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns the bitwidth of the coordinates, where 0 means the native index width.
+    pub fn crd_width(&self) -> i32 {
+        unsafe { mlirSparseTensorEncodingAttrGetCrdWidth(self.to_raw()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{AttributeRef, IntegerTypeRef, RankedTensorTypeRef},
+        Context, DialectHandle, OwnedMlirValue,
+    };
+
+    use mlir_sys::mlirGetDialectHandle__sparse_tensor__;
+
+    fn context() -> Context {
+        let context = Context::new(None, false);
+        let dialect_handle =
+            unsafe { DialectHandle::from_raw(mlirGetDialectHandle__sparse_tensor__()) };
+        dialect_handle.load_into_context(&context);
+        context
+    }
+
+    #[test]
+    fn new() {
+        let context = context();
+        let encoding = SparseTensorEncodingAttributeRef::new(
+            &context,
+            &[SparseLevelType::Dense, SparseLevelType::Compressed],
+            None,
+            None,
+            32,
+            16,
+        );
+        let parsed_encoding = AttributeRef::parse(
+            &context,
+            "#sparse_tensor.encoding<{ map = (d0, d1) -> (d0 : dense, d1 : compressed), \
+             posWidth = 32, crdWidth = 16 }>",
+        )
+        .unwrap();
+        assert_eq!(encoding.as_attribute(), parsed_encoding);
+        assert_eq!(encoding.level_rank(), 2);
+        assert_eq!(
+            encoding.level_types().collect::<Vec<_>>(),
+            vec![
+                Some(SparseLevelType::Dense),
+                Some(SparseLevelType::Compressed)
+            ]
+        );
+        assert_eq!(encoding.pos_width(), 32);
+        assert_eq!(encoding.crd_width(), 16);
+    }
+
+    #[test]
+    fn dim_to_lvl() {
+        let context = context();
+        let encoding = AttributeRef::parse(
+            &context,
+            "#sparse_tensor.encoding<{ map = (d0, d1) -> (d1 : dense, d0 : compressed) }>",
+        )
+        .unwrap();
+        let encoding = SparseTensorEncodingAttributeRef::try_from_attribute(encoding).unwrap();
+        assert_eq!(
+            encoding.dim_to_lvl(),
+            Some(AffineMapRef::new_permutation(&context, &[1, 0]))
+        );
+    }
+
+    #[test]
+    fn tensor_encoding() {
+        let context = context();
+        let encoding = SparseTensorEncodingAttributeRef::new(
+            &context,
+            &[SparseLevelType::Compressed],
+            None,
+            None,
+            0,
+            0,
+        );
+        let i32_type = IntegerTypeRef::new_signless(&context, 32);
+        let tensor_type = RankedTensorTypeRef::new(&[8], i32_type, Some(encoding));
+        let tensor_encoding = tensor_type.encoding().unwrap();
+        assert!(SparseTensorEncodingAttributeRef::try_from_attribute(tensor_encoding).is_some());
+    }
+
+    #[test]
+    fn from_attribute() {
+        let context = context();
+        let erased_attribute = AttributeRef::parse(&context, "unit").unwrap();
+        assert!(SparseTensorEncodingAttributeRef::try_from_attribute(erased_attribute).is_none());
+    }
+
+    #[test]
+    fn level_type_round_trip() {
+        for level_type in SparseLevelType::ALL {
+            assert_eq!(
+                SparseLevelType::from_raw(level_type.to_raw()),
+                Some(level_type)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_sparse_tensor_encoding_attribute_ref() {
+        let _sparse_tensor_encoding_attribute_ref = SparseTensorEncodingAttributeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
@@ -0,0 +1,149 @@
+use super::impl_attribute_variant;
+use crate::{
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+    ContextRef,
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{
+    mlirAttributeIsAStridedLayout, mlirStridedLayoutAttrGet, mlirStridedLayoutAttrGetNumStrides,
+    mlirStridedLayoutAttrGetOffset, mlirStridedLayoutAttrGetStride, MlirAttribute,
+};
+
+/// [StridedLayoutAttributeRef] is a reference to an instance of the `mlir::StridedLayoutAttr`
+/// class, which represents the layout of a memref as an offset and a stride per dimension.
+/// Dynamic offsets and strides are represented by
+/// [ShapedTypeRef::DYNAMIC_STRIDE_OR_OFFSET](crate::ir::ShapedTypeRef::DYNAMIC_STRIDE_OR_OFFSET).
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirAttributeIsAStridedLayout`
+/// - `mlirStridedLayoutAttrGet`
+/// - `mlirStridedLayoutAttrGetNumStrides`
+/// - `mlirStridedLayoutAttrGetOffset`
+/// - `mlirStridedLayoutAttrGetStride`
+///
+/// The following bindings are not used/supported:
+/// - `mlirStridedLayoutAttrGetTypeID`
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct StridedLayoutAttributeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, StridedLayoutAttributeRef, MlirAttribute);
+impl_attribute_variant!(StridedLayoutAttributeRef, mlirAttributeIsAStridedLayout);
+
+impl StridedLayoutAttributeRef {
+    /// Constructs a new strided layout attribute with the provided offset and strides.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the attribute.
+    /// * `offset` - The offset of the layout.
+    /// * `strides` - The strides of the layout, one per dimension.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [StridedLayoutAttributeRef] instance.
+    pub fn new<'a>(context: &'a ContextRef, offset: i64, strides: &[i64]) -> &'a Self {
+        unsafe {
+            Self::from_raw(mlirStridedLayoutAttrGet(
+                context.to_raw(),
+                offset,
+                strides.len() as isize,
+                strides.as_ptr(),
+            ))
+        }
+    }
+
+    /// # Returns
+    /// Returns the offset of the layout.
+    pub fn offset(&self) -> i64 {
+        unsafe { mlirStridedLayoutAttrGetOffset(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns the number of strides of the layout.
+    pub fn num_strides(&self) -> isize {
+        unsafe { mlirStridedLayoutAttrGetNumStrides(self.to_raw()) }
+    }
+
+    /// Gets the stride at the provided index, verifying that the index is within bounds.
+    ///
+    /// # Arguments
+    /// * `idx` - The index of the stride to get.
+    ///
+    /// # Returns
+    /// Returns the stride at the provided index.
+    pub fn stride(&self, idx: isize) -> i64 {
+        if idx < 0 || idx >= self.num_strides() {
+            panic!("Stride index {} out of bounds.", idx);
+        }
+        unsafe { mlirStridedLayoutAttrGetStride(self.to_raw(), idx) }
+    }
+
+    /// # Returns
+    /// Returns the strides of the layout.
+    pub fn strides(&self) -> Vec<i64> {
+        (0..self.num_strides())
+            .map(|idx| self.stride(idx))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{AttributeRef, ShapedTypeRef},
+        Context,
+    };
+
+    #[test]
+    fn new() {
+        let context = Context::new(None, false);
+        let layout = StridedLayoutAttributeRef::new(&context, 4, &[8, 1]);
+        assert_eq!(layout.to_string(), "strided<[8, 1], offset: 4>");
+        assert_eq!(layout.offset(), 4);
+        assert_eq!(layout.num_strides(), 2);
+        assert_eq!(layout.stride(0), 8);
+        assert_eq!(layout.strides(), vec![8, 1]);
+    }
+
+    #[test]
+    fn dynamic() {
+        let context = Context::new(None, false);
+        let dynamic = ShapedTypeRef::DYNAMIC_STRIDE_OR_OFFSET;
+        let layout = StridedLayoutAttributeRef::new(&context, dynamic, &[dynamic, 1]);
+        assert_eq!(layout.to_string(), "strided<[?, 1], offset: ?>");
+        assert!(ShapedTypeRef::is_dynamic_stride_or_offset(layout.offset()));
+    }
+
+    #[test]
+    fn from_attribute() {
+        let context = Context::new(None, false);
+        let layout = AttributeRef::parse(&context, "strided<[1]>").unwrap();
+        let unit = AttributeRef::parse(&context, "unit").unwrap();
+        assert!(StridedLayoutAttributeRef::try_from_attribute(layout).is_some());
+        assert!(StridedLayoutAttributeRef::try_from_attribute(unit).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn stride_out_of_bounds() {
+        let context = Context::new(None, false);
+        let layout = StridedLayoutAttributeRef::new(&context, 0, &[1]);
+        layout.stride(1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_strided_layout_attribute_ref() {
+        let _strided_layout_attribute_ref = StridedLayoutAttributeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
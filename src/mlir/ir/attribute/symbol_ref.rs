@@ -0,0 +1,257 @@
+use super::impl_attribute_variant;
+use crate::{
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+    ContextRef, StringRef,
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{
+    mlirAttributeIsAFlatSymbolRef, mlirAttributeIsASymbolRef, mlirFlatSymbolRefAttrGet,
+    mlirFlatSymbolRefAttrGetValue, mlirSymbolRefAttrGet, mlirSymbolRefAttrGetLeafReference,
+    mlirSymbolRefAttrGetNestedReference, mlirSymbolRefAttrGetNumNestedReferences,
+    mlirSymbolRefAttrGetRootReference, MlirAttribute,
+};
+
+/// [SymbolRefAttributeRef] is a reference to an instance of the `mlir::SymbolRefAttr` class,
+/// which represents a (possibly nested) reference to a symbol, e.g. `@module::@function`.
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirAttributeIsASymbolRef`
+/// - `mlirSymbolRefAttrGet`
+/// - `mlirSymbolRefAttrGetLeafReference`
+/// - `mlirSymbolRefAttrGetNestedReference`
+/// - `mlirSymbolRefAttrGetNumNestedReferences`
+/// - `mlirSymbolRefAttrGetRootReference`
+///
+/// The following bindings are not used/supported:
+/// - `mlirSymbolRefAttrGetTypeID`
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct SymbolRefAttributeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, SymbolRefAttributeRef, MlirAttribute);
+impl_attribute_variant!(SymbolRefAttributeRef, mlirAttributeIsASymbolRef);
+
+impl SymbolRefAttributeRef {
+    /// Constructs a new symbol reference attribute with the provided root symbol and nested
+    /// references.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the attribute.
+    /// * `root` - The name of the root symbol.
+    /// * `nested` - The nested references, from the outermost to the innermost (leaf) symbol.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [SymbolRefAttributeRef] instance.
+    pub fn new<'a>(
+        context: &'a ContextRef,
+        root: &str,
+        nested: &[&FlatSymbolRefAttributeRef],
+    ) -> &'a Self {
+        unsafe {
+            Self::from_raw(mlirSymbolRefAttrGet(
+                context.to_raw(),
+                StringRef::from(&root).to_raw(),
+                nested.len() as isize,
+                nested.as_ptr() as *const MlirAttribute,
+            ))
+        }
+    }
+
+    /// Constructs a new symbol reference attribute from a path of symbol names.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the attribute.
+    /// * `path` - The names of the referenced symbols, from the root to the leaf symbol. Must not
+    ///   be empty.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [SymbolRefAttributeRef] instance.
+    pub fn from_path<'a>(context: &'a ContextRef, path: &[&str]) -> &'a Self {
+        let Some((root, nested)) = path.split_first() else {
+            panic!("Symbol reference path must not be empty.");
+        };
+        let nested = nested
+            .iter()
+            .map(|name| FlatSymbolRefAttributeRef::new(context, name))
+            .collect::<Vec<_>>();
+        Self::new(context, root, &nested)
+    }
+
+    /// # Returns
+    /// Returns the name of the root symbol.
+    pub fn root_reference(&self) -> StringRef {
+        unsafe { StringRef::from_raw(mlirSymbolRefAttrGetRootReference(self.to_raw())) }
+    }
+
+    /// # Returns
+    /// Returns the name of the leaf symbol, which is the root symbol if there are no nested
+    /// references.
+    pub fn leaf_reference(&self) -> StringRef {
+        unsafe { StringRef::from_raw(mlirSymbolRefAttrGetLeafReference(self.to_raw())) }
+    }
+
+    /// # Returns
+    /// Returns the number of nested references.
+    pub fn num_nested_references(&self) -> isize {
+        unsafe { mlirSymbolRefAttrGetNumNestedReferences(self.to_raw()) }
+    }
+
+    /// Gets the nested reference at the provided index, verifying that the index is within
+    /// bounds.
+    ///
+    /// # Arguments
+    /// * `idx` - The index of the nested reference.
+    ///
+    /// # Returns
+    /// Returns a reference to the nested reference.
+    pub fn nested_reference(&self, idx: isize) -> &FlatSymbolRefAttributeRef {
+        if idx < 0 || idx >= self.num_nested_references() {
+            panic!("Nested reference index {} out of bounds.", idx);
+        }
+        unsafe {
+            FlatSymbolRefAttributeRef::from_raw(mlirSymbolRefAttrGetNestedReference(
+                self.to_raw(),
+                idx,
+            ))
+        }
+    }
+
+    /// # Returns
+    /// Returns an iterator over the nested references.
+    pub fn nested_references(&self) -> impl ExactSizeIterator<Item = &FlatSymbolRefAttributeRef> {
+        (0..self.num_nested_references()).map(|idx| self.nested_reference(idx))
+    }
+}
+
+/// [FlatSymbolRefAttributeRef] is a reference to an instance of the `mlir::FlatSymbolRefAttr`
+/// class, which represents a reference to a symbol without any nested references, e.g.
+/// `@function`.
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirAttributeIsAFlatSymbolRef`
+/// - `mlirFlatSymbolRefAttrGet`
+/// - `mlirFlatSymbolRefAttrGetValue`
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct FlatSymbolRefAttributeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, FlatSymbolRefAttributeRef, MlirAttribute);
+impl_attribute_variant!(FlatSymbolRefAttributeRef, mlirAttributeIsAFlatSymbolRef);
+
+impl FlatSymbolRefAttributeRef {
+    /// Constructs a new flat symbol reference attribute.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the attribute.
+    /// * `symbol` - The name of the referenced symbol.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [FlatSymbolRefAttributeRef] instance.
+    pub fn new<'a>(context: &'a ContextRef, symbol: &str) -> &'a Self {
+        unsafe {
+            Self::from_raw(mlirFlatSymbolRefAttrGet(
+                context.to_raw(),
+                StringRef::from(&symbol).to_raw(),
+            ))
+        }
+    }
+
+    /// # Returns
+    /// Returns the name of the referenced symbol.
+    pub fn value(&self) -> StringRef {
+        unsafe { StringRef::from_raw(mlirFlatSymbolRefAttrGetValue(self.to_raw())) }
+    }
+
+    /// # Returns
+    /// Returns the attribute as a [SymbolRefAttributeRef], since every flat symbol reference is
+    /// also a symbol reference.
+    pub fn as_symbol_ref(&self) -> &SymbolRefAttributeRef {
+        unsafe { SymbolRefAttributeRef::from_raw(self.to_raw()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::AttributeRef, Context};
+
+    #[test]
+    fn new_symbol_ref() {
+        let context = Context::new(None, false);
+        let inner = FlatSymbolRefAttributeRef::new(&context, "inner");
+        let leaf = FlatSymbolRefAttributeRef::new(&context, "leaf");
+        let symbol_ref = SymbolRefAttributeRef::new(&context, "root", &[inner, leaf]);
+        assert_eq!(symbol_ref.to_string(), "@root::@inner::@leaf");
+        assert_eq!(symbol_ref.root_reference(), "root");
+        assert_eq!(symbol_ref.leaf_reference(), "leaf");
+        assert_eq!(symbol_ref.num_nested_references(), 2);
+        assert_eq!(
+            symbol_ref
+                .nested_references()
+                .map(|reference| reference.value().as_str().to_owned())
+                .collect::<Vec<_>>(),
+            vec!["inner", "leaf"]
+        );
+    }
+
+    #[test]
+    fn from_path() {
+        let context = Context::new(None, false);
+        let symbol_ref = SymbolRefAttributeRef::from_path(&context, &["a", "b"]);
+        assert_eq!(symbol_ref.to_string(), "@a::@b");
+        let root_only = SymbolRefAttributeRef::from_path(&context, &["a"]);
+        assert_eq!(root_only.leaf_reference(), "a");
+        assert!(FlatSymbolRefAttributeRef::try_from_attribute(root_only).is_some());
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_empty_path() {
+        let context = Context::new(None, false);
+        SymbolRefAttributeRef::from_path(&context, &[]);
+    }
+
+    #[test]
+    fn new_flat_symbol_ref() {
+        let context = Context::new(None, false);
+        let flat_symbol_ref = FlatSymbolRefAttributeRef::new(&context, "function");
+        assert_eq!(flat_symbol_ref.to_string(), "@function");
+        assert_eq!(flat_symbol_ref.value(), "function");
+        assert_eq!(flat_symbol_ref.as_symbol_ref().root_reference(), "function");
+    }
+
+    #[test]
+    fn from_attribute() {
+        let context = Context::new(None, false);
+        let nested = AttributeRef::parse(&context, "@a::@b").unwrap();
+        let flat = AttributeRef::parse(&context, "@a").unwrap();
+        let unit = AttributeRef::parse(&context, "unit").unwrap();
+        assert!(SymbolRefAttributeRef::try_from_attribute(nested).is_some());
+        assert!(FlatSymbolRefAttributeRef::try_from_attribute(nested).is_none());
+        assert!(SymbolRefAttributeRef::try_from_attribute(flat).is_some());
+        assert!(FlatSymbolRefAttributeRef::try_from_attribute(flat).is_some());
+        assert!(SymbolRefAttributeRef::try_from_attribute(unit).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_symbol_ref_attribute_ref() {
+        let _symbol_ref_attribute_ref = SymbolRefAttributeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_flat_symbol_ref_attribute_ref() {
+        let _flat_symbol_ref_attribute_ref = FlatSymbolRefAttributeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
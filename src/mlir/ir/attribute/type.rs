@@ -0,0 +1,84 @@
+use super::impl_attribute_variant;
+use crate::{
+    ir::TypeRef,
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{mlirAttributeIsAType, mlirTypeAttrGet, mlirTypeAttrGetValue, MlirAttribute};
+
+/// [TypeAttributeRef] is a reference to an instance of the `mlir::TypeAttr` class, which
+/// represents a type used as a constant value in the MLIR IR.
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirAttributeIsAType`
+/// - `mlirTypeAttrGet`
+/// - `mlirTypeAttrGetValue`
+///
+/// The following bindings are not used/supported:
+/// - `mlirTypeAttrGetTypeID`
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct TypeAttributeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, TypeAttributeRef, MlirAttribute);
+impl_attribute_variant!(TypeAttributeRef, mlirAttributeIsAType);
+
+impl TypeAttributeRef {
+    /// Constructs a new type attribute holding the provided type. The attribute is owned by the
+    /// same context that owns the type.
+    ///
+    /// # Arguments
+    /// * `ty` - The type to hold in the attribute.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [TypeAttributeRef] instance.
+    pub fn new(ty: &TypeRef) -> &Self {
+        unsafe { Self::from_raw(mlirTypeAttrGet(ty.to_raw())) }
+    }
+
+    /// # Returns
+    /// Returns the type held by the attribute.
+    pub fn value(&self) -> &TypeRef {
+        unsafe { TypeRef::from_raw(mlirTypeAttrGetValue(self.to_raw())) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{AttributeRef, IntegerTypeRef},
+        Context,
+    };
+
+    #[test]
+    fn new() {
+        let context = Context::new(None, false);
+        let i32_type = IntegerTypeRef::new_signless(&context, 32);
+        let attribute = TypeAttributeRef::new(i32_type);
+        assert_eq!(attribute.to_string(), "i32");
+        assert_eq!(attribute.value(), i32_type.as_type());
+    }
+
+    #[test]
+    fn from_attribute() {
+        let context = Context::new(None, false);
+        let erased_type_attribute = AttributeRef::parse(&context, "(i32) -> i1").unwrap();
+        let erased_integer_attribute = AttributeRef::parse(&context, "1 : i32").unwrap();
+        let type_attribute = TypeAttributeRef::try_from_attribute(erased_type_attribute).unwrap();
+        assert_eq!(type_attribute.value().to_string(), "(i32) -> i1");
+        assert!(TypeAttributeRef::try_from_attribute(erased_integer_attribute).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_type_attribute_ref() {
+        let _type_attribute_ref = TypeAttributeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
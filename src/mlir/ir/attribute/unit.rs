@@ -0,0 +1,76 @@
+use super::impl_attribute_variant;
+use crate::{
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+    ContextRef,
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{mlirAttributeIsAUnit, mlirUnitAttrGet, MlirAttribute};
+
+/// [UnitAttributeRef] is a reference to an instance of the `mlir::UnitAttr` class, which
+/// represents an attribute whose only meaning is its presence, such as a flag on an operation.
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirAttributeIsAUnit`
+/// - `mlirUnitAttrGet`
+///
+/// The following bindings are not used/supported:
+/// - `mlirUnitAttrGetTypeID`
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct UnitAttributeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, UnitAttributeRef, MlirAttribute);
+impl_attribute_variant!(UnitAttributeRef, mlirAttributeIsAUnit);
+
+impl UnitAttributeRef {
+    /// # Arguments
+    /// * `context` - The context that owns the attribute.
+    ///
+    /// # Returns
+    /// Returns a reference to the unit attribute of the provided context.
+    pub fn new(context: &ContextRef) -> &Self {
+        unsafe { Self::from_raw(mlirUnitAttrGet(context.to_raw())) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::AttributeRef, Context};
+
+    #[test]
+    fn new() {
+        let context = Context::new(None, false);
+        let attr = UnitAttributeRef::new(&context);
+        assert_eq!(attr.to_string(), "unit");
+        assert_eq!(
+            attr.as_attribute(),
+            UnitAttributeRef::new(&context).as_attribute()
+        );
+    }
+
+    #[test]
+    fn from_attribute() {
+        let context = Context::new(None, false);
+        let erased_unit_attribute = AttributeRef::parse(&context, "unit").unwrap();
+        let erased_bool_attribute = AttributeRef::parse(&context, "true").unwrap();
+        assert!(UnitAttributeRef::try_from_attribute(erased_unit_attribute).is_some());
+        assert!(UnitAttributeRef::try_from_attribute(erased_bool_attribute).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_unit_attribute_ref() {
+        let _unit_attribute_ref = UnitAttributeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
@@ -1,5 +1,8 @@
 use crate::{
-    ir::{LocationRef, Operation, OperationRef, RegionRef, TypeRef, ValueRef},
+    ir::{
+        BlockArgumentRef, FunctionTypeRef, LocationRef, Operation, OperationRef, RegionRef,
+        TypeRef, ValueRef,
+    },
     support::{
         binding::{
             impl_owned_mlir_value, impl_unowned_mlir_value, OwnedMlirValue, UnownedMlirValue,
@@ -15,18 +18,22 @@ use std::{
 };
 
 use mlir_sys::{
-    mlirBlockAppendOwnedOperation, mlirBlockCreate, mlirBlockDestroy, mlirBlockEqual,
-    mlirBlockGetArgument, mlirBlockGetFirstOperation, mlirBlockGetNextInRegion,
-    mlirBlockGetNumArguments, mlirBlockGetParentOperation, mlirBlockGetParentRegion,
-    mlirBlockGetTerminator, mlirBlockPrint, MlirBlock, MlirLocation, MlirType,
+    mlirBlockAddArgument, mlirBlockAppendOwnedOperation, mlirBlockCreate, mlirBlockDestroy,
+    mlirBlockDetach, mlirBlockEqual, mlirBlockGetArgument, mlirBlockGetFirstOperation,
+    mlirBlockGetNextInRegion, mlirBlockGetNumArguments, mlirBlockGetParentOperation,
+    mlirBlockGetParentRegion, mlirBlockGetTerminator, mlirBlockInsertArgument,
+    mlirBlockInsertOwnedOperation, mlirBlockInsertOwnedOperationAfter,
+    mlirBlockInsertOwnedOperationBefore, mlirBlockPrint, MlirBlock, MlirLocation, MlirType,
 };
 
 /// [Block] wraps the `mlir::Block` class, which represents a block of operations in the MLIR IR.
 ///
 /// The following bindings into the MLIR C API are used/supported:
+/// - `mlirBlockAddArgument`
 /// - `mlirBlockAppendOwnedOperation`
 /// - `mlirBlockCreate`
 /// - `mlirBlockDestroy`
+/// - `mlirBlockDetach`
 /// - `mlirBlockEqual`
 /// - `mlirBlockGetArgument`
 /// - `mlirBlockGetFirstOperation`
@@ -35,18 +42,13 @@ use mlir_sys::{
 /// - `mlirBlockGetParentOperation`
 /// - `mlirBlockGetParentRegion`
 /// - `mlirBlockGetTerminator`
-/// - `mlirBlockPrint`
-///
-/// The following bindings are not used/supported:
-/// - `mlirBlockAddArgument`
-/// - `mlirBlockArgumentGetArgNumber`
-/// - `mlirBlockArgumentGetOwner`
-/// - `mlirBlockArgumentSetType`
-/// - `mlirBlockDetach`
 /// - `mlirBlockInsertArgument`
+/// - `mlirBlockInsertOwnedOperation`
 /// - `mlirBlockInsertOwnedOperationAfter`
 /// - `mlirBlockInsertOwnedOperationBefore`
-/// - `mlirBlockInsertOwnedOperation`
+/// - `mlirBlockPrint`
+///
+/// The following bindings are not used/supported:
 #[repr(transparent)]
 #[derive(Debug)]
 pub struct Block<'c> {
@@ -65,16 +67,45 @@ impl<'c> Block<'c> {
     /// # Returns
     /// Returns a new [Block] instance.
     pub fn new(args: &[(&'c TypeRef, &'c LocationRef)]) -> Block<'c> {
-        let types = args.iter().map(|&(t, _)| t).collect::<Vec<_>>();
-        let locs = args.iter().map(|&(_, l)| l).collect::<Vec<_>>();
+        Self::from_arguments(args.iter().copied())
+    }
+
+    /// Creates a new block from an iterator over the types and locations of its arguments.
+    ///
+    /// # Arguments
+    /// * `args` - The types and locations of the input arguments to the block.
+    ///
+    /// # Returns
+    /// Returns a new [Block] instance.
+    pub fn from_arguments<I>(args: I) -> Block<'c>
+    where
+        I: IntoIterator<Item = (&'c TypeRef, &'c LocationRef)>,
+    {
+        let (types, locs): (Vec<_>, Vec<_>) = args.into_iter().unzip();
         unsafe {
             Self::from_raw(mlirBlockCreate(
-                args.len() as isize,
+                types.len() as isize,
                 types.as_ptr() as *const MlirType,
                 locs.as_ptr() as *const MlirLocation,
             ))
         }
     }
+
+    /// Creates a new block whose arguments match the inputs of the provided function type, e.g.
+    /// for the entry block of a `func.func` operation.
+    ///
+    /// # Arguments
+    /// * `function_type` - The function type to take the argument types from.
+    /// * `loc` - The location to use for every argument.
+    ///
+    /// # Returns
+    /// Returns a new [Block] instance.
+    pub fn for_function_type(
+        function_type: &'c FunctionTypeRef,
+        loc: &'c LocationRef,
+    ) -> Block<'c> {
+        Self::from_arguments(function_type.inputs().map(|ty| (ty, loc)))
+    }
 }
 
 impl<'c> Drop for Block<'c> {
@@ -120,6 +151,115 @@ impl<'c> BlockRef<'c> {
         operation_ref
     }
 
+    /// Inserts the given operation into the block at the provided index, verifying that the index
+    /// is within bounds (inserting at the end is allowed).
+    ///
+    /// # Arguments
+    /// * `idx` - The index to insert the operation at.
+    /// * `operation` - The operation to insert into the block.
+    ///
+    /// # Returns
+    /// Returns a reference to the inserted operation owned by the block.
+    pub fn insert_operation_at<'a>(
+        &'a self,
+        idx: isize,
+        operation: Operation<'c>,
+    ) -> &'a OperationRef<'c> {
+        let num_operations = self.operations().count() as isize;
+        if idx < 0 || idx > num_operations {
+            panic!("Operation index {} out of bounds", idx);
+        }
+        let operation_ref = unsafe { OperationRef::from_raw(operation.to_raw()) };
+        unsafe { mlirBlockInsertOwnedOperation(self.to_raw(), idx, operation.to_raw()) };
+        forget(operation);
+        operation_ref
+    }
+
+    /// Inserts the given operation at the front of the block.
+    ///
+    /// # Arguments
+    /// * `operation` - The operation to prepend to the block.
+    ///
+    /// # Returns
+    /// Returns a reference to the prepended operation owned by the block.
+    pub fn prepend_operation<'a>(&'a self, operation: Operation<'c>) -> &'a OperationRef<'c> {
+        self.insert_operation_at(0, operation)
+    }
+
+    /// Inserts the given operation into the block after the reference operation.
+    ///
+    /// # Arguments
+    /// * `reference` - The operation to insert after, which must be in this block.
+    /// * `operation` - The operation to insert into the block.
+    ///
+    /// # Returns
+    /// Returns a reference to the inserted operation owned by the block.
+    pub fn insert_operation_after<'a>(
+        &'a self,
+        reference: &OperationRef<'c>,
+        operation: Operation<'c>,
+    ) -> &'a OperationRef<'c> {
+        if reference.parent_block() != Some(self) {
+            panic!("Reference operation is not in this block");
+        }
+        let operation_ref = unsafe { OperationRef::from_raw(operation.to_raw()) };
+        unsafe {
+            mlirBlockInsertOwnedOperationAfter(
+                self.to_raw(),
+                reference.to_raw(),
+                operation.to_raw(),
+            )
+        };
+        forget(operation);
+        operation_ref
+    }
+
+    /// Inserts the given operation into the block before the reference operation, e.g. to place
+    /// an operation before the terminator.
+    ///
+    /// # Arguments
+    /// * `reference` - The operation to insert before, which must be in this block.
+    /// * `operation` - The operation to insert into the block.
+    ///
+    /// # Returns
+    /// Returns a reference to the inserted operation owned by the block.
+    pub fn insert_operation_before<'a>(
+        &'a self,
+        reference: &OperationRef<'c>,
+        operation: Operation<'c>,
+    ) -> &'a OperationRef<'c> {
+        if reference.parent_block() != Some(self) {
+            panic!("Reference operation is not in this block");
+        }
+        let operation_ref = unsafe { OperationRef::from_raw(operation.to_raw()) };
+        unsafe {
+            mlirBlockInsertOwnedOperationBefore(
+                self.to_raw(),
+                reference.to_raw(),
+                operation.to_raw(),
+            )
+        };
+        forget(operation);
+        operation_ref
+    }
+
+    /// Detaches the block from its parent region, transferring ownership of it from the region to
+    /// the caller. The returned block can then be inserted into another region (or back into the
+    /// same one), and is destroyed if it is dropped instead, which invalidates every reference to
+    /// the block and to its contents.
+    ///
+    /// A block without a parent region is not detached, as it is already owned, e.g. by a [Block].
+    ///
+    /// # Returns
+    /// Returns the detached [Block], or `None` if the block has no parent region.
+    pub fn detach(&self) -> Option<Block<'c>> {
+        self.parent_region()?;
+        unsafe {
+            mlirBlockDetach(self.to_raw());
+            Some(Block::from_raw(self.to_raw()))
+        }
+    }
+
     /// # Returns
     /// Returns the number of arguments the block has.
     pub fn num_arguments(&self) -> isize {
@@ -140,6 +280,61 @@ impl<'c> BlockRef<'c> {
         unsafe { ValueRef::from_raw(mlirBlockGetArgument(self.to_raw(), idx)) }
     }
 
+    /// # Returns
+    /// Returns an iterator over the arguments of the block, in order.
+    pub fn arguments(&self) -> impl ExactSizeIterator<Item = &BlockArgumentRef<'c>> {
+        (0..self.num_arguments()).map(|idx| unsafe {
+            BlockArgumentRef::from_raw(mlirBlockGetArgument(self.to_raw(), idx))
+        })
+    }
+
+    /// Appends a new argument to the block.
+    ///
+    /// # Arguments
+    /// * `ty` - The type of the new argument.
+    /// * `loc` - The location of the new argument.
+    ///
+    /// # Returns
+    /// Returns the new argument.
+    pub fn add_argument(&self, ty: &'c TypeRef, loc: &'c LocationRef) -> &ValueRef<'c> {
+        unsafe {
+            ValueRef::from_raw(mlirBlockAddArgument(
+                self.to_raw(),
+                ty.to_raw(),
+                loc.to_raw(),
+            ))
+        }
+    }
+
+    /// Inserts a new argument into the block at the provided index, verifying that the index is
+    /// within bounds (inserting at the end is allowed).
+    ///
+    /// # Arguments
+    /// * `idx` - The index to insert the argument at.
+    /// * `ty` - The type of the new argument.
+    /// * `loc` - The location of the new argument.
+    ///
+    /// # Returns
+    /// Returns the new argument.
+    pub fn insert_argument(
+        &self,
+        idx: isize,
+        ty: &'c TypeRef,
+        loc: &'c LocationRef,
+    ) -> &ValueRef<'c> {
+        if idx < 0 || idx > self.num_arguments() {
+            panic!("Argument index {} out of bounds", idx);
+        }
+        unsafe {
+            ValueRef::from_raw(mlirBlockInsertArgument(
+                self.to_raw(),
+                idx,
+                ty.to_raw(),
+                loc.to_raw(),
+            ))
+        }
+    }
+
     /// # Returns
     /// If the block is nested within a region, returns the parent region.
     pub fn parent_region(&self) -> Option<&RegionRef<'c>> {
@@ -165,11 +360,69 @@ impl<'c> BlockRef<'c> {
         unsafe { OperationRef::try_from_raw(mlirBlockGetFirstOperation(self.to_raw())) }
     }
 
+    /// # Returns
+    /// Returns an iterator over the operations of the block, in order.
+    pub fn operations(&self) -> impl Iterator<Item = &OperationRef<'c>> {
+        std::iter::successors(self.first_operation(), |operation| {
+            operation.next_in_parent_block()
+        })
+    }
+
     /// # Returns
     /// Returns the terminating operation of the region, if it has one.
     pub fn terminator(&self) -> Option<&OperationRef<'c>> {
         unsafe { OperationRef::try_from_raw(mlirBlockGetTerminator(self.to_raw())) }
     }
+
+    /// Moves all operations of the block, including its terminator, before the provided operation,
+    /// replacing the uses of the arguments of the block with the provided values. The block is
+    /// left empty, but is not removed from its region.
+    ///
+    /// # Arguments
+    /// * `target` - The operation to move the operations before, which must be in another block.
+    /// * `arguments` - The values to replace the arguments of the block with, one per argument.
+    pub fn inline_before(&self, target: &OperationRef<'c>, arguments: &[&ValueRef<'c>]) {
+        if target.parent_block() == Some(self) {
+            panic!("Cannot inline a block before one of its own operations");
+        }
+        self.replace_arguments(arguments);
+        let operations = self.operations().collect::<Vec<_>>();
+        for operation in operations {
+            operation.move_before(target);
+        }
+    }
+
+    /// Moves all operations of the source block to the end of this block, replacing the uses of
+    /// the arguments of the source block with the provided values, and then destroys the source
+    /// block. A block of a region must be detached first (see [BlockRef::detach]), and must not be
+    /// the successor of any operation.
+    ///
+    /// # Arguments
+    /// * `source` - The block to merge into this block.
+    /// * `arguments` - The values to replace the arguments of the source block with, one per
+    ///   argument.
+    pub fn merge_block(&self, source: Block<'c>, arguments: &[&ValueRef<'c>]) {
+        source.replace_arguments(arguments);
+        let operations = source.operations().collect::<Vec<_>>();
+        for operation in operations {
+            operation.remove_from_parent();
+            self.append_operation(unsafe { Operation::from_raw(operation.to_raw()) });
+        }
+    }
+
+    /// Replaces the uses of the arguments of the block with the provided values.
+    fn replace_arguments(&self, arguments: &[&ValueRef<'c>]) {
+        if arguments.len() as isize != self.num_arguments() {
+            panic!(
+                "Expected {} block arguments, got {}",
+                self.num_arguments(),
+                arguments.len()
+            );
+        }
+        for (idx, value) in arguments.iter().enumerate() {
+            self.argument(idx as isize).replace_all_uses_with(value);
+        }
+    }
 }
 
 impl<'c> PartialEq for BlockRef<'c> {
@@ -197,6 +450,233 @@ impl<'c> Display for BlockRef<'c> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        ir::{IntegerTypeRef, OperationBuilder, Region},
+        Context,
+    };
+
+    fn operation_names(block: &BlockRef) -> Vec<String> {
+        block
+            .operations()
+            .map(|operation| operation.name().value().as_str().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn insert_operations() {
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let loc = LocationRef::new_unknown(&context);
+        let new_op = |name| OperationBuilder::new(name, loc).build().unwrap();
+        let block = Block::new(&[]);
+        let terminator = block.append_operation(new_op("dialect.terminator"));
+        block.insert_operation_before(terminator, new_op("dialect.op3"));
+        let first = block.prepend_operation(new_op("dialect.op1"));
+        block.insert_operation_after(first, new_op("dialect.op2"));
+        block.insert_operation_at(4, new_op("dialect.last"));
+        assert_eq!(
+            operation_names(&block),
+            vec![
+                "dialect.op1",
+                "dialect.op2",
+                "dialect.op3",
+                "dialect.terminator",
+                "dialect.last"
+            ]
+        );
+    }
+
+    #[test]
+    fn operations() {
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let operation_source = r#"
+module {
+    "dialect.op1"() : () -> ()
+    "dialect.op2"() : () -> ()
+    "dialect.op1"() : () -> ()
+}
+"#;
+        let module = Operation::parse(&context, operation_source, "").unwrap();
+        let block = module.region(0).first_block().unwrap();
+        assert_eq!(block.operations().count(), 3);
+        assert_eq!(
+            block
+                .operations()
+                .filter(|operation| operation.name().value() == "dialect.op1")
+                .count(),
+            2
+        );
+        assert_eq!(Block::new(&[]).operations().count(), 0);
+    }
+
+    #[test]
+    fn inline_before() {
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let operation_source = r#"
+module {
+    %0 = "dialect.value"() : () -> i8
+    "dialect.wrapper"() ({
+    ^bb0(%arg0: i8):
+        "dialect.use"(%arg0) : (i8) -> ()
+        "dialect.yield"() : () -> ()
+    }) : () -> ()
+}
+"#;
+        let module = Operation::parse(&context, operation_source, "").unwrap();
+        let body = module.region(0).first_block().unwrap();
+        let value = body.first_operation().unwrap();
+        let wrapper = value.next_in_parent_block().unwrap();
+        let inner = wrapper.region(0).first_block().unwrap();
+        inner.inline_before(wrapper, &[value.result(0)]);
+
+        assert_eq!(inner.operations().count(), 0);
+        assert_eq!(
+            operation_names(body),
+            vec![
+                "dialect.value",
+                "dialect.use",
+                "dialect.yield",
+                "dialect.wrapper"
+            ]
+        );
+        assert_eq!(value.result(0).num_uses(), 1);
+    }
+
+    #[test]
+    fn merge_block() {
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let loc = LocationRef::new_unknown(&context);
+        let i8_type = IntegerTypeRef::new_signless(&context, 8);
+        let region = Region::new(&context);
+        let dest = region.append_block(Block::new(&[(i8_type, loc)]));
+        let source = region.append_block(Block::new(&[(i8_type, loc)]));
+        dest.append_operation(OperationBuilder::new("dialect.op1", loc).build().unwrap());
+        source.append_operation(
+            OperationBuilder::new("dialect.op2", loc)
+                .add_operands(&[source.argument(0)])
+                .build()
+                .unwrap(),
+        );
+
+        dest.merge_block(source.detach().unwrap(), &[dest.argument(0)]);
+        assert!(dest.next_in_parent_region().is_none());
+        assert_eq!(operation_names(dest), vec!["dialect.op1", "dialect.op2"]);
+        assert_eq!(dest.argument(0).num_uses(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn inline_with_missing_arguments() {
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let loc = LocationRef::new_unknown(&context);
+        let i8_type = IntegerTypeRef::new_signless(&context, 8);
+        let target = Block::new(&[]);
+        let op = target.append_operation(OperationBuilder::new("dialect.op", loc).build().unwrap());
+        Block::new(&[(i8_type, loc)]).inline_before(op, &[]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_operation_out_of_bounds() {
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let loc = LocationRef::new_unknown(&context);
+        let block = Block::new(&[]);
+        let operation = OperationBuilder::new("dialect.op1", loc).build().unwrap();
+        block.insert_operation_at(1, operation);
+    }
+
+    #[test]
+    fn add_and_insert_arguments() {
+        let context = Context::new(None, false);
+        let loc = LocationRef::new_unknown(&context);
+        let i1_type = IntegerTypeRef::new_signless(&context, 1);
+        let i8_type = IntegerTypeRef::new_signless(&context, 8);
+        let i16_type = IntegerTypeRef::new_signless(&context, 16);
+        let block = Block::new(&[(i8_type, loc)]);
+        assert_eq!(block.num_arguments(), 1);
+
+        let argument = block.add_argument(i16_type, loc);
+        assert_eq!(argument.r#type(), i16_type.as_type());
+        let argument = block.insert_argument(0, i1_type, loc);
+        assert_eq!(argument.r#type(), i1_type.as_type());
+
+        let types = block
+            .arguments()
+            .map(|argument| argument.r#type())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            types,
+            vec![i1_type.as_type(), i8_type.as_type(), i16_type.as_type()]
+        );
+    }
+
+    #[test]
+    fn for_function_type() {
+        let context = Context::new(None, false);
+        let loc = LocationRef::new_unknown(&context);
+        let function_type = TypeRef::parse(&context, "(i8, i32) -> i1").unwrap();
+        let function_type = FunctionTypeRef::try_from_type(function_type).unwrap();
+        let block = Block::for_function_type(function_type, loc);
+        assert_eq!(
+            block
+                .arguments()
+                .map(|argument| argument.r#type())
+                .collect::<Vec<_>>(),
+            function_type.inputs().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn from_arguments() {
+        let context = Context::new(None, false);
+        let loc = LocationRef::new_unknown(&context);
+        let types =
+            [8, 16, 32].map(|width| IntegerTypeRef::new_signless(&context, width).as_type());
+        let block = Block::from_arguments(types.iter().map(|&ty| (ty, loc)));
+        assert_eq!(block.num_arguments(), 3);
+        assert_eq!(block.argument(2).r#type(), types[2]);
+    }
+
+    #[test]
+    fn arguments() {
+        let context = Context::new(None, false);
+        let loc = LocationRef::new_unknown(&context);
+        let i8_type = IntegerTypeRef::new_signless(&context, 8);
+        let block = Block::new(&[(i8_type, loc), (i8_type, loc)]);
+        let arguments = block.arguments();
+        assert_eq!(arguments.len(), 2);
+        for (idx, argument) in arguments.enumerate() {
+            assert_eq!(argument.arg_number(), idx as isize);
+            assert_eq!(argument.owner(), &block);
+        }
+        assert_eq!(Block::new(&[]).arguments().len(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_argument_out_of_bounds() {
+        let context = Context::new(None, false);
+        let loc = LocationRef::new_unknown(&context);
+        let i8_type = IntegerTypeRef::new_signless(&context, 8);
+        let block = Block::new(&[]);
+        block.insert_argument(1, i8_type, loc);
+    }
+
+    #[test]
+    fn detach() {
+        let context = Context::new(None, false);
+        let region = Region::new(&context);
+        let block = region.append_block(Block::new(&[]));
+        let detached = block.detach().unwrap();
+        assert!(region.first_block().is_none());
+        assert!(detached.parent_region().is_none());
+        assert!(detached.detach().is_none());
+    }
 
     #[test]
     #[should_panic]
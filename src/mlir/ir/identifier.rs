@@ -55,6 +55,15 @@ impl IdentifierRef {
     pub fn value(&self) -> StringRef {
         unsafe { StringRef::from_raw(mlirIdentifierStr(self.to_raw())) }
     }
+
+    /// Splits the identifier at its first '.', e.g. splitting an operation name such as
+    /// "func.func" into its dialect namespace and mnemonic.
+    ///
+    /// # Returns
+    /// Returns the parts of the identifier before and after the first '.', if it contains one.
+    pub fn split_dialect(&self) -> Option<(&str, &str)> {
+        self.value().as_str().split_once('.')
+    }
 }
 
 impl PartialEq for IdentifierRef {
@@ -84,6 +93,17 @@ mod tests {
         assert_eq!(identifier.value(), "test");
     }
 
+    #[test]
+    fn split_dialect() {
+        let context = Context::new(None, false);
+        let identifier = IdentifierRef::new(&context, "func.func");
+        assert_eq!(identifier.split_dialect(), Some(("func", "func")));
+        let identifier = IdentifierRef::new(&context, "llvm.intr.memcpy");
+        assert_eq!(identifier.split_dialect(), Some(("llvm", "intr.memcpy")));
+        let identifier = IdentifierRef::new(&context, "test");
+        assert_eq!(identifier.split_dialect(), None);
+    }
+
     #[test]
     fn compare_identifiers() {
         let context = Context::new(None, false);
@@ -1,37 +1,48 @@
 use crate::{
     ir::AttributeRef,
     support::{
-        binding::{impl_unowned_mlir_value, UnownedMlirValue},
+        binding::{impl_unowned_mlir_value, OptionalMlirValue, OwnedMlirValue, UnownedMlirValue},
         string_reader::StringReader,
     },
-    ContextRef, StringRef,
+    ContextRef, StringRef, TypeId,
 };
 
 use std::{
+    cell::RefCell,
     fmt::{Display, Formatter},
     marker::PhantomData,
-    ptr::null,
 };
 
 use mlir_sys::{
-    mlirLocationCallSiteGet, mlirLocationEqual, mlirLocationFileLineColGet, mlirLocationFusedGet,
-    mlirLocationGetContext, mlirLocationNameGet, mlirLocationPrint, mlirLocationUnknownGet,
-    MlirLocation,
+    mlirAttributeGetTypeID, mlirLocationCallSiteGet, mlirLocationEqual, mlirLocationFileLineColGet,
+    mlirLocationFusedGet, mlirLocationGetAttribute, mlirLocationGetContext, mlirLocationNameGet,
+    mlirLocationPrint, mlirLocationUnknownGet, MlirContext, MlirLocation,
 };
 
 /// [LocationRef] is a reference to an instance of the `mlir::Location` class, which represents a
 /// source location in the MLIR IR.
 ///
-/// All relevant bindings into the MLIR C API are used/supported:
+/// The MLIR 18 C API has no accessors for the kind or the components of a location. The kind is
+/// identified by comparing the [TypeId] of the attribute of the location with those of locations
+/// of each kind, while the components are recovered from the printed form of the location, with
+/// any nested locations reconstructed in the same context (locations are uniqued, so these are the
+/// original nested locations).
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirAttributeGetTypeID`
 /// - `mlirLocationFileLineColGet`
 /// - `mlirLocationCallSiteGet`
 /// - `mlirLocationFusedGet`
 /// - `mlirLocationNameGet`
 /// - `mlirLocationUnknownGet`
+/// - `mlirLocationGetAttribute`
 /// - `mlirLocationGetContext`
 /// - `mlirLocationEqual`
 /// - `mlirLocationPrint`
 ///
+/// The following bindings are not used/supported:
+/// - `mlirLocationFromAttribute`
+///
 /// # Safety
 /// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
 /// undefined behaviour.
@@ -82,26 +93,27 @@ impl LocationRef {
         unsafe { Self::from_raw(mlirLocationCallSiteGet(callee.to_raw(), caller.to_raw())) }
     }
 
-    /// Constructs a new [LocationRef] representing a fused location with associated metadata.
+    /// Constructs a new [LocationRef] representing a fused location with optional associated
+    /// metadata.
     ///
     /// # Arguments
     /// * `context` - The context that should own the location.
     /// * `locations` - The locations to fuse.
-    /// * `metadata` - The metadata to associate with the fused location.
+    /// * `metadata` - The metadata to associate with the fused location, if any.
     ///
     /// # Returns
     /// Returns a new [LocationRef] reference.
     pub fn new_fused<'a>(
         context: &'a ContextRef,
         locations: &[&'a LocationRef],
-        metadata: &'a AttributeRef,
+        metadata: Option<&AttributeRef>,
     ) -> &'a Self {
         unsafe {
             Self::from_raw(mlirLocationFusedGet(
                 context.to_raw(),
                 locations.len() as isize,
                 locations.as_ptr() as *const MlirLocation,
-                metadata.to_raw(),
+                metadata.to_raw_or_null(),
             ))
         }
     }
@@ -124,7 +136,7 @@ impl LocationRef {
             Self::from_raw(mlirLocationNameGet(
                 context.to_raw(),
                 StringRef::from(&name).to_raw(),
-                child_location.map_or(MlirLocation { ptr: null() }, |l| l.to_raw()),
+                child_location.to_raw_or_null(),
             ))
         }
     }
@@ -145,6 +157,562 @@ impl LocationRef {
     pub fn context(&self) -> &ContextRef {
         unsafe { ContextRef::from_raw(mlirLocationGetContext(self.to_raw())) }
     }
+
+    /// Classifies the location into one of the builtin location kinds, so that its components can
+    /// be matched on directly.
+    ///
+    /// # Returns
+    /// Returns the [Location] variant that the location belongs to.
+    pub fn classify(&self) -> Location {
+        let Some(kind) = self.kind() else {
+            return Location::Other;
+        };
+        let printed = self.to_string();
+        printed
+            .strip_prefix("loc(")
+            .and_then(|inner| inner.strip_suffix(')'))
+            .and_then(|inner| {
+                let mut parser = LocationParser::new(self.context(), inner);
+                let location = parser.parse_kind(kind)?;
+                parser.at_end().then_some(location)
+            })
+            .unwrap_or(Location::Other)
+    }
+
+    /// # Returns
+    /// Returns the builtin kind of the location, or `None` if it is not a builtin location.
+    fn kind(&self) -> Option<LocationKind> {
+        let context = self.context();
+        let file_line_col = LocationRef::new_file_line_col(context, "", 0, 0);
+        let other_file_line_col = LocationRef::new_file_line_col(context, "", 1, 0);
+        let references = [
+            (LocationKind::Unknown, LocationRef::new_unknown(context)),
+            (LocationKind::FileLineCol, file_line_col),
+            (
+                LocationKind::CallSite,
+                LocationRef::new_call_site(file_line_col, file_line_col),
+            ),
+            (
+                LocationKind::Fused,
+                LocationRef::new_fused(context, &[file_line_col, other_file_line_col], None),
+            ),
+            (LocationKind::Name, LocationRef::new_name(context, "", None)),
+        ];
+        let type_id = self.attribute_type_id();
+        references
+            .into_iter()
+            .find(|(_, reference)| reference.attribute_type_id() == type_id)
+            .map(|(kind, _)| kind)
+    }
+
+    /// # Returns
+    /// Returns the [TypeId] of the class of the attribute of the location.
+    fn attribute_type_id(&self) -> TypeId {
+        unsafe {
+            TypeId::from_raw(mlirAttributeGetTypeID(mlirLocationGetAttribute(
+                self.to_raw(),
+            )))
+        }
+    }
+
+    /// # Returns
+    /// Returns whether the location is an unknown location.
+    pub fn is_unknown(&self) -> bool {
+        self.kind() == Some(LocationKind::Unknown)
+    }
+
+    /// # Returns
+    /// Returns whether the location is a file, line number, and column number location.
+    pub fn is_file_line_col(&self) -> bool {
+        self.kind() == Some(LocationKind::FileLineCol)
+    }
+
+    /// # Returns
+    /// Returns whether the location is a call site location.
+    pub fn is_call_site(&self) -> bool {
+        self.kind() == Some(LocationKind::CallSite)
+    }
+
+    /// # Returns
+    /// Returns whether the location is a fused location.
+    pub fn is_fused(&self) -> bool {
+        self.kind() == Some(LocationKind::Fused)
+    }
+
+    /// # Returns
+    /// Returns whether the location is a name location.
+    pub fn is_name(&self) -> bool {
+        self.kind() == Some(LocationKind::Name)
+    }
+
+    /// # Returns
+    /// Returns the name of the file of a file, line number, and column number location, otherwise
+    /// `None`.
+    pub fn filename(&self) -> Option<String> {
+        match self.classify() {
+            Location::FileLineCol { filename, .. } => Some(filename),
+            _ => None,
+        }
+    }
+
+    /// # Returns
+    /// Returns the line number of a file, line number, and column number location, otherwise
+    /// `None`.
+    pub fn line(&self) -> Option<u32> {
+        match self.classify() {
+            Location::FileLineCol { line, .. } => Some(line),
+            _ => None,
+        }
+    }
+
+    /// # Returns
+    /// Returns the column number of a file, line number, and column number location, otherwise
+    /// `None`.
+    pub fn col(&self) -> Option<u32> {
+        match self.classify() {
+            Location::FileLineCol { col, .. } => Some(col),
+            _ => None,
+        }
+    }
+
+    /// # Returns
+    /// Returns the location of the callee of a call site location, otherwise `None`.
+    pub fn callee(&self) -> Option<&LocationRef> {
+        match self.classify() {
+            Location::CallSite { callee, .. } => Some(callee),
+            _ => None,
+        }
+    }
+
+    /// # Returns
+    /// Returns the location of the caller of a call site location, otherwise `None`.
+    pub fn caller(&self) -> Option<&LocationRef> {
+        match self.classify() {
+            Location::CallSite { caller, .. } => Some(caller),
+            _ => None,
+        }
+    }
+
+    /// # Returns
+    /// Returns the locations fused by a fused location, otherwise `None`.
+    pub fn fused_locations(&self) -> Option<Vec<&LocationRef>> {
+        match self.classify() {
+            Location::Fused { locations, .. } => Some(locations),
+            _ => None,
+        }
+    }
+
+    /// # Returns
+    /// Returns the metadata associated with a fused location, if it is a fused location with
+    /// metadata, otherwise `None`.
+    pub fn fused_metadata(&self) -> Option<&AttributeRef> {
+        match self.classify() {
+            Location::Fused { metadata, .. } => metadata,
+            _ => None,
+        }
+    }
+
+    /// # Returns
+    /// Returns the name of a name location, otherwise `None`.
+    pub fn name(&self) -> Option<String> {
+        match self.classify() {
+            Location::Name { name, .. } => Some(name),
+            _ => None,
+        }
+    }
+
+    /// # Returns
+    /// Returns the child location of a name location, which is an unknown location if the name
+    /// location was constructed without a child, otherwise `None`.
+    pub fn child_location(&self) -> Option<&LocationRef> {
+        match self.classify() {
+            Location::Name { child_location, .. } => Some(child_location),
+            _ => None,
+        }
+    }
+
+    /// Resolves the location to a file, line number, and column number location by flattening
+    /// it: name locations resolve through their child, call site locations through their callee,
+    /// and fused locations through the first of their locations that can be resolved.
+    ///
+    /// # Returns
+    /// Returns the resolved file, line number, and column number location, or `None` if the
+    /// location does not contain one.
+    pub fn resolve_file_line_col(&self) -> Option<&LocationRef> {
+        match self.classify() {
+            Location::FileLineCol { .. } => Some(self),
+            Location::Name { child_location, .. } => child_location.resolve_file_line_col(),
+            Location::CallSite { callee, .. } => callee.resolve_file_line_col(),
+            Location::Fused { locations, .. } => locations
+                .into_iter()
+                .find_map(|location| location.resolve_file_line_col()),
+            Location::Unknown | Location::Other => None,
+        }
+    }
+}
+
+/// [Location] is the result of classifying a [LocationRef] into one of the builtin location kinds,
+/// exposing the components of the location.
+#[derive(Debug, Clone)]
+pub enum Location<'a> {
+    Unknown,
+    FileLineCol {
+        filename: String,
+        line: u32,
+        col: u32,
+    },
+    CallSite {
+        callee: &'a LocationRef,
+        caller: &'a LocationRef,
+    },
+    Fused {
+        locations: Vec<&'a LocationRef>,
+        metadata: Option<&'a AttributeRef>,
+    },
+    Name {
+        name: String,
+        /// The child location, which is an unknown location if there is no child.
+        child_location: &'a LocationRef,
+    },
+    /// A location that does not belong to any of the builtin location kinds.
+    Other,
+}
+
+/// [LocationKind] identifies the builtin location class of a location.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LocationKind {
+    Unknown,
+    FileLineCol,
+    CallSite,
+    Fused,
+    Name,
+}
+
+thread_local! {
+    /// The contexts and locations set by the [LocationScope] instances alive on the current thread,
+    /// innermost last. The entries of a context are cleared when the context is destroyed, in case
+    /// a scope of the context was leaked.
+    static CURRENT_LOCATIONS: RefCell<Vec<Option<(MlirContext, MlirLocation)>>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+/// [LocationScope] is an RAII guard that sets the current location of the current thread until it
+/// is dropped, which is used by [OperationBuilder::new_here] so that code generating operations
+/// does not need to pass a location through every function. Scopes can be nested, with the
+/// innermost scope determining the current location.
+///
+/// The current location is only ever returned for a borrowed context (see
+/// [LocationScope::current]), so it cannot outlive its context, even if a scope is leaked (e.g.
+/// via [std::mem::forget]). Scopes should still be dropped in the reverse order of their
+/// creation, as dropping a scope also ends the scopes created after it.
+///
+/// [OperationBuilder::new_here]: crate::ir::OperationBuilder::new_here
+#[derive(Debug)]
+pub struct LocationScope<'a> {
+    depth: usize,
+    location: &'a LocationRef,
+    /// Scopes are bound to the thread they were created on.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl<'a> LocationScope<'a> {
+    /// Sets the provided location as the current location until the returned scope is dropped.
+    ///
+    /// # Arguments
+    /// * `location` - The location to use as the current location.
+    ///
+    /// # Returns
+    /// Returns a new [LocationScope] instance.
+    pub fn new(location: &'a LocationRef) -> Self {
+        let entry = (location.context().to_raw(), location.to_raw());
+        let depth = CURRENT_LOCATIONS.with(|locations| {
+            let mut locations = locations.borrow_mut();
+            locations.push(Some(entry));
+            locations.len() - 1
+        });
+        LocationScope {
+            depth,
+            location,
+            _not_send: PhantomData,
+        }
+    }
+
+    /// # Returns
+    /// Returns the location set by this scope.
+    pub fn location(&self) -> &'a LocationRef {
+        self.location
+    }
+
+    /// Gets the current location of the provided context, as set by the innermost [LocationScope]
+    /// of the context alive on the current thread.
+    ///
+    /// # Arguments
+    /// * `context` - The context of the location, which bounds the lifetime of the result.
+    ///
+    /// # Returns
+    /// Returns the current location, if there is one.
+    pub fn current(context: &ContextRef) -> Option<&LocationRef> {
+        let context = context.to_raw();
+        CURRENT_LOCATIONS
+            .with(|locations| {
+                locations
+                    .borrow()
+                    .iter()
+                    .rev()
+                    .flatten()
+                    .find(|(location_context, _)| location_context.ptr == context.ptr)
+                    .map(|(_, location)| *location)
+            })
+            // The context is alive and owns the location, as its entries are cleared when it is
+            // destroyed.
+            .map(|location| unsafe { LocationRef::from_raw(location) })
+    }
+}
+
+impl Drop for LocationScope<'_> {
+    fn drop(&mut self) {
+        CURRENT_LOCATIONS.with(|locations| locations.borrow_mut().truncate(self.depth));
+    }
+}
+
+/// Clears the entries of the provided context set by (leaked) [LocationScope] instances on the
+/// current thread, before the context is destroyed.
+///
+/// # Arguments
+/// * `context` - The context being destroyed.
+pub(crate) fn clear_location_scopes(context: MlirContext) {
+    // The thread-local storage may already be destroyed if the context is dropped during thread
+    // teardown, in which case there is nothing to clear.
+    let _ = CURRENT_LOCATIONS.try_with(|locations| {
+        for entry in locations.borrow_mut().iter_mut() {
+            if matches!(entry, Some((location_context, _)) if location_context.ptr == context.ptr) {
+                *entry = None;
+            }
+        }
+    });
+}
+
+/// [LocationParser] parses the printed form of a location (without the surrounding `loc(...)`),
+/// reconstructing the nested locations in the provided context.
+struct LocationParser<'a, 's> {
+    context: &'a ContextRef,
+    input: &'s str,
+}
+
+impl<'a, 's> LocationParser<'a, 's> {
+    fn new(context: &'a ContextRef, input: &'s str) -> Self {
+        LocationParser { context, input }
+    }
+
+    fn at_end(&self) -> bool {
+        self.input.is_empty()
+    }
+
+    fn eat(&mut self, prefix: &str) -> bool {
+        match self.input.strip_prefix(prefix) {
+            Some(rest) => {
+                self.input = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn parse_location(&mut self) -> Option<&'a LocationRef> {
+        let context = self.context;
+        let location = match self.parse_components()? {
+            Location::Unknown => LocationRef::new_unknown(context),
+            Location::FileLineCol {
+                filename,
+                line,
+                col,
+            } => LocationRef::new_file_line_col(context, &filename, line, col),
+            Location::CallSite { callee, caller } => LocationRef::new_call_site(callee, caller),
+            Location::Fused {
+                locations,
+                metadata,
+            } => LocationRef::new_fused(context, &locations, metadata),
+            Location::Name {
+                name,
+                child_location,
+            } => LocationRef::new_name(context, &name, Some(child_location)),
+            Location::Other => return None,
+        };
+        Some(location)
+    }
+
+    fn parse_components(&mut self) -> Option<Location<'a>> {
+        let kind = if self.input.starts_with("unknown") {
+            LocationKind::Unknown
+        } else if self.input.starts_with("callsite(") {
+            LocationKind::CallSite
+        } else if self.input.starts_with("fused") {
+            LocationKind::Fused
+        } else if self.input.starts_with('"') {
+            return self.parse_file_line_col_or_name();
+        } else {
+            return None;
+        };
+        self.parse_kind(kind)
+    }
+
+    /// Parses the components of a location of the provided kind.
+    fn parse_kind(&mut self, kind: LocationKind) -> Option<Location<'a>> {
+        match kind {
+            LocationKind::Unknown => self.eat("unknown").then_some(Location::Unknown),
+            LocationKind::CallSite => self.parse_call_site(),
+            LocationKind::Fused => self.parse_fused(),
+            LocationKind::FileLineCol | LocationKind::Name => {
+                let location = self.parse_file_line_col_or_name()?;
+                let matches_kind = matches!(
+                    (kind, &location),
+                    (LocationKind::FileLineCol, Location::FileLineCol { .. })
+                        | (LocationKind::Name, Location::Name { .. })
+                );
+                matches_kind.then_some(location)
+            }
+        }
+    }
+
+    fn parse_call_site(&mut self) -> Option<Location<'a>> {
+        if !self.eat("callsite(") {
+            return None;
+        }
+        let callee = self.parse_location()?;
+        if !self.eat(" at ") {
+            return None;
+        }
+        let caller = self.parse_location()?;
+        self.eat(")")
+            .then_some(Location::CallSite { callee, caller })
+    }
+
+    fn parse_fused(&mut self) -> Option<Location<'a>> {
+        if !self.eat("fused") {
+            return None;
+        }
+        let metadata = if self.input.starts_with('<') {
+            let end = balanced_end(self.input)?;
+            let metadata = AttributeRef::parse(self.context, &self.input[1..end - 1])?;
+            self.input = &self.input[end..];
+            Some(metadata)
+        } else {
+            None
+        };
+        if !self.eat("[") {
+            return None;
+        }
+        let mut locations = vec![self.parse_location()?];
+        while self.eat(", ") {
+            locations.push(self.parse_location()?);
+        }
+        self.eat("]").then_some(Location::Fused {
+            locations,
+            metadata,
+        })
+    }
+
+    /// Parses a file, line number, and column number location or a name location, which both
+    /// start with a string literal.
+    fn parse_file_line_col_or_name(&mut self) -> Option<Location<'a>> {
+        let string = self.parse_string()?;
+        if self.eat(":") {
+            let line = self.parse_integer()?;
+            if !self.eat(":") {
+                return None;
+            }
+            let col = self.parse_integer()?;
+            Some(Location::FileLineCol {
+                filename: string,
+                line,
+                col,
+            })
+        } else if self.eat("(") {
+            let child_location = self.parse_location()?;
+            self.eat(")").then_some(Location::Name {
+                name: string,
+                child_location,
+            })
+        } else {
+            Some(Location::Name {
+                name: string,
+                child_location: LocationRef::new_unknown(self.context),
+            })
+        }
+    }
+
+    fn parse_integer(&mut self) -> Option<u32> {
+        let end = self
+            .input
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(self.input.len());
+        let value = self.input[..end].parse().ok()?;
+        self.input = &self.input[end..];
+        Some(value)
+    }
+
+    /// Parses a string literal, undoing the escaping applied by `llvm::printEscapedString`.
+    fn parse_string(&mut self) -> Option<String> {
+        let mut bytes = Vec::new();
+        let mut iter = self.input.strip_prefix('"')?.bytes().enumerate();
+        while let Some((idx, byte)) = iter.next() {
+            match byte {
+                b'"' => {
+                    self.input = &self.input[idx + 2..];
+                    return String::from_utf8(bytes).ok();
+                }
+                b'\\' => match iter.next()? {
+                    (_, escaped @ (b'\\' | b'"')) => bytes.push(escaped),
+                    (_, high) => {
+                        let (_, low) = iter.next()?;
+                        let hex = [high, low];
+                        let hex = std::str::from_utf8(&hex).ok()?;
+                        bytes.push(u8::from_str_radix(hex, 16).ok()?);
+                    }
+                },
+                _ => bytes.push(byte),
+            }
+        }
+        None
+    }
+}
+
+/// Finds the end (exclusive) of the bracketed group at the start of the provided input, skipping
+/// over nested brackets, string literals, the arrows of function types, and the `>=` comparisons
+/// of integer sets.
+fn balanced_end(input: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut previous = None;
+    let mut chars = input.char_indices().peekable();
+    while let Some((idx, c)) = chars.next() {
+        let is_arrow = previous == Some('-') && c == '>';
+        let is_comparison = c == '>' && matches!(chars.peek(), Some((_, '=')));
+        previous = Some(c);
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '<' | '(' | '[' | '{' => depth += 1,
+            '>' if is_arrow || is_comparison => {}
+            '>' | ')' | ']' | '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(idx + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
 }
 
 impl PartialEq for LocationRef {
@@ -187,7 +755,30 @@ mod tests {
         );
     }
 
-    // TODO: new_fused test
+    #[test]
+    fn new_fused() {
+        let context = Context::new(None, false);
+        let location1 = LocationRef::new_file_line_col(&context, "file", 1, 2);
+        let location2 = LocationRef::new_file_line_col(&context, "file", 3, 4);
+        let location = LocationRef::new_fused(&context, &[location1, location2], None);
+        assert_eq!(
+            location.to_string(),
+            r#"loc(fused["file":1:2, "file":3:4])"#
+        );
+        let metadata = AttributeRef::parse(&context, r#""meta""#).unwrap();
+        let location = LocationRef::new_fused(&context, &[location1, location2], Some(metadata));
+        assert_eq!(
+            location.to_string(),
+            r#"loc(fused<"meta">["file":1:2, "file":3:4])"#
+        );
+    }
+
+    #[test]
+    fn new_name_without_child() {
+        let context = Context::new(None, false);
+        let location = LocationRef::new_name(&context, "name", None);
+        assert_eq!(location.to_string(), r#"loc("name")"#);
+    }
 
     #[test]
     fn new_name() {
@@ -204,6 +795,147 @@ mod tests {
         assert_eq!(location.to_string(), r#"loc(unknown)"#);
     }
 
+    #[test]
+    fn classify_file_line_col() {
+        let context = Context::new(None, false);
+        let location = LocationRef::new_file_line_col(&context, "dir/fi\"le\n", 3, 14);
+        assert!(location.is_file_line_col());
+        assert_eq!(location.filename().as_deref(), Some("dir/fi\"le\n"));
+        assert_eq!(location.line(), Some(3));
+        assert_eq!(location.col(), Some(14));
+        assert!(location.name().is_none());
+    }
+
+    #[test]
+    fn classify_call_site() {
+        let context = Context::new(None, false);
+        let callee = LocationRef::new_file_line_col(&context, "callee", 1, 2);
+        let caller = LocationRef::new_name(&context, "caller", Some(callee));
+        let location = LocationRef::new_call_site(callee, caller);
+        assert!(location.is_call_site());
+        assert_eq!(location.callee(), Some(callee));
+        assert_eq!(location.caller(), Some(caller));
+        assert!(location.filename().is_none());
+    }
+
+    #[test]
+    fn classify_fused() {
+        let context = Context::new(None, false);
+        let location1 = LocationRef::new_file_line_col(&context, "file", 1, 2);
+        let location2 = LocationRef::new_name(&context, "name", None);
+        let metadata = AttributeRef::parse(
+            &context,
+            r#"{a = "x>", b = () -> (), c = affine_set<(d0) : (d0 >= 0)>}"#,
+        )
+        .unwrap();
+        let location = LocationRef::new_fused(&context, &[location1, location2], Some(metadata));
+        assert!(location.is_fused());
+        assert_eq!(location.fused_locations(), Some(vec![location1, location2]));
+        assert_eq!(location.fused_metadata(), Some(metadata));
+
+        let location = LocationRef::new_fused(&context, &[location1, location2], None);
+        assert!(location.is_fused());
+        assert_eq!(location.fused_locations(), Some(vec![location1, location2]));
+        assert!(location.fused_metadata().is_none());
+
+        // Unknown locations are dropped when fusing.
+        let unknown = LocationRef::new_unknown(&context);
+        let location = LocationRef::new_fused(&context, &[location1, unknown], Some(metadata));
+        assert!(location.is_fused());
+        assert_eq!(location.fused_locations(), Some(vec![location1]));
+    }
+
+    #[test]
+    fn balanced_end_comparison() {
+        let input = "<affine_set<(d0) : (d0 >= 0)>>[";
+        assert_eq!(balanced_end(input), Some(input.len() - 1));
+        let input = "<(i32) -> i32>[";
+        assert_eq!(balanced_end(input), Some(input.len() - 1));
+    }
+
+    #[test]
+    fn classify_name() {
+        let context = Context::new(None, false);
+        let child = LocationRef::new_file_line_col(&context, "child", 1, 2);
+        let location = LocationRef::new_name(&context, "name", Some(child));
+        assert!(location.is_name());
+        assert_eq!(location.name().as_deref(), Some("name"));
+        assert_eq!(location.child_location(), Some(child));
+
+        let location = LocationRef::new_name(&context, "name", None);
+        assert!(location.child_location().unwrap().is_unknown());
+    }
+
+    #[test]
+    fn classify_unknown() {
+        let context = Context::new(None, false);
+        let location = LocationRef::new_unknown(&context);
+        assert!(location.is_unknown());
+        assert!(matches!(location.classify(), Location::Unknown));
+        assert!(!location.is_name());
+    }
+
+    #[test]
+    fn resolve_file_line_col() {
+        let context = Context::new(None, false);
+        let file = LocationRef::new_file_line_col(&context, "file", 1, 2);
+        let caller = LocationRef::new_file_line_col(&context, "caller", 3, 4);
+        let unknown = LocationRef::new_unknown(&context);
+        let call_site = LocationRef::new_call_site(file, caller);
+        let name = LocationRef::new_name(&context, "name", Some(call_site));
+        let fused = LocationRef::new_fused(&context, &[unknown, name], None);
+        assert_eq!(fused.resolve_file_line_col(), Some(file));
+        assert!(unknown.resolve_file_line_col().is_none());
+        assert!(LocationRef::new_name(&context, "name", None)
+            .resolve_file_line_col()
+            .is_none());
+    }
+
+    #[test]
+    fn location_scope() {
+        let context = Context::new(None, false);
+        let outer = LocationRef::new_file_line_col(&context, "outer", 1, 2);
+        let inner = LocationRef::new_file_line_col(&context, "inner", 3, 4);
+        assert!(LocationScope::current(&context).is_none());
+        {
+            let outer_scope = LocationScope::new(outer);
+            assert_eq!(outer_scope.location(), outer);
+            assert_eq!(LocationScope::current(&context), Some(outer));
+            {
+                let _inner_scope = LocationScope::new(inner);
+                assert_eq!(LocationScope::current(&context), Some(inner));
+            }
+            assert_eq!(LocationScope::current(&context), Some(outer));
+        }
+        assert!(LocationScope::current(&context).is_none());
+    }
+
+    #[test]
+    fn location_scope_other_context() {
+        let context = Context::new(None, false);
+        let other_context = Context::new(None, false);
+        let location = LocationRef::new_unknown(&context);
+        let _scope = LocationScope::new(location);
+        assert_eq!(LocationScope::current(&context), Some(location));
+        assert!(LocationScope::current(&other_context).is_none());
+    }
+
+    #[test]
+    fn leaked_location_scope() {
+        let context = Context::new(None, false);
+        let location = LocationRef::new_unknown(&context);
+        std::mem::forget(LocationScope::new(location));
+        let raw_context = context.to_raw();
+        drop(context);
+        CURRENT_LOCATIONS.with(|locations| {
+            assert!(locations
+                .borrow()
+                .iter()
+                .flatten()
+                .all(|(location_context, _)| location_context.ptr != raw_context.ptr));
+        });
+    }
+
     #[test]
     fn context() {
         let context = Context::new(None, false);
@@ -0,0 +1,323 @@
+use crate::{
+    ir::{BlockRef, LocationRef, Operation, OperationRef, ParseFileError},
+    support::binding::{
+        impl_owned_mlir_value, impl_unowned_mlir_value, OwnedMlirValue, UnownedMlirValue,
+    },
+    ContextRef, StringRef,
+};
+
+use std::{
+    ffi::CString,
+    fmt::{Display, Formatter},
+    fs,
+    marker::PhantomData,
+    mem::forget,
+    path::Path,
+};
+
+use mlir_sys::{
+    mlirModuleCreateEmpty, mlirModuleCreateParse, mlirModuleDestroy, mlirModuleFromOperation,
+    mlirModuleGetBody, mlirModuleGetContext, mlirModuleGetOperation, MlirModule, MlirStringRef,
+};
+
+/// [Module] wraps the `mlir::ModuleOp` class, the top-level `builtin.module` operation holding a
+/// single block of operations.
+///
+/// All relevant bindings into the MLIR C API are used/supported:
+/// - `mlirModuleCreateEmpty`
+/// - `mlirModuleCreateParse`
+/// - `mlirModuleDestroy`
+/// - `mlirModuleFromOperation`
+/// - `mlirModuleGetBody`
+/// - `mlirModuleGetContext`
+/// - `mlirModuleGetOperation`
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct Module<'c> {
+    raw: MlirModule,
+    _context: PhantomData<&'c ()>,
+}
+
+impl_owned_mlir_value!(context_ref, Module, MlirModule);
+
+impl<'c> Module<'c> {
+    /// Creates a new, empty module.
+    ///
+    /// # Arguments
+    /// * `location` - The location of the module.
+    ///
+    /// # Returns
+    /// Returns a new [Module] instance.
+    pub fn new(location: &'c LocationRef) -> Self {
+        unsafe { Self::from_raw(mlirModuleCreateEmpty(location.to_raw())) }
+    }
+
+    /// Attempts to parse a module from the provided source string.
+    ///
+    /// # Arguments
+    /// * `context` - The context to associate with the module.
+    /// * `source` - The source string to parse the module from.
+    ///
+    /// # Returns
+    /// Returns a new [Module] if the module could be parsed, otherwise `None`.
+    pub fn parse(context: &'c ContextRef, source: &str) -> Option<Self> {
+        // The source string needs to be null-terminated, see `Operation::parse`.
+        let source = CString::new(source).expect("Failed to convert source string to CString");
+        unsafe {
+            Self::try_from_raw(mlirModuleCreateParse(
+                context.to_raw(),
+                StringRef::from_cstring(&source).to_raw(),
+            ))
+        }
+    }
+
+    /// Attempts to parse a module from the file at the provided path. Both the textual and the
+    /// bytecode format are supported and detected automatically. If the file contains a single
+    /// operation other than a `builtin.module`, or several top-level operations, they are wrapped
+    /// in a new module, mirroring the implicit top-level module of the textual format.
+    ///
+    /// # Arguments
+    /// * `context` - The context to associate with the module.
+    /// * `path` - The path of the file to parse the module from. It is also used as the filename
+    ///   of the locations in the parsed module, unless the file contains several top-level
+    ///   operations (MLIR 18 does not support naming the source when parsing a module).
+    ///
+    /// # Returns
+    /// Returns a new [Module] if the file could be read and parsed, otherwise a [ParseFileError]
+    /// describing why it could not.
+    pub fn parse_file(
+        context: &'c ContextRef,
+        path: impl AsRef<Path>,
+    ) -> Result<Self, ParseFileError> {
+        let path = path.as_ref();
+        let error = match Operation::parse_file(context, path) {
+            Ok(operation) => {
+                return Ok(
+                    Module::from_operation(operation).unwrap_or_else(|operation| {
+                        // Locations are owned by the context, so the location outlives the operation.
+                        let location =
+                            unsafe { LocationRef::from_raw(operation.location().to_raw()) };
+                        let module = Module::new(location);
+                        module.body().append_operation(operation);
+                        module
+                    }),
+                );
+            }
+            Err(error @ ParseFileError::Io { .. }) => return Err(error),
+            Err(error) => error,
+        };
+        // A single operation can only be parsed from a file with exactly one top-level operation,
+        // so the file is parsed again as the body of an implicit module. If this fails as well,
+        // the diagnostics of the first attempt are reported, as they refer to the parsed file.
+        let mut source = fs::read(path).map_err(|error| ParseFileError::Io {
+            path: path.to_path_buf(),
+            error,
+        })?;
+        // Bytecode may contain null bytes, see `Operation::parse_file`.
+        source.push(0);
+        let source_ref = MlirStringRef {
+            data: source.as_ptr() as *const _,
+            length: source.len() - 1,
+        };
+        let _capture = context.capture_diagnostics();
+        unsafe { Self::try_from_raw(mlirModuleCreateParse(context.to_raw(), source_ref)) }
+            .ok_or(error)
+    }
+
+    /// Takes ownership of the provided operation as a module.
+    ///
+    /// # Arguments
+    /// * `operation` - The operation to take ownership of.
+    ///
+    /// # Returns
+    /// Returns a new [Module] if the operation is a `builtin.module`, otherwise the operation.
+    pub fn from_operation(operation: Operation<'c>) -> Result<Self, Operation<'c>> {
+        match unsafe { Self::try_from_raw(mlirModuleFromOperation(operation.to_raw())) } {
+            Some(module) => {
+                forget(operation);
+                Ok(module)
+            }
+            None => Err(operation),
+        }
+    }
+
+    /// # Returns
+    /// Returns the module as an owned [Operation], transferring ownership of it.
+    pub fn into_operation(self) -> Operation<'c> {
+        let operation = unsafe { Operation::from_raw(mlirModuleGetOperation(self.raw)) };
+        forget(self);
+        operation
+    }
+}
+
+impl<'c> Drop for Module<'c> {
+    fn drop(&mut self) {
+        unsafe { mlirModuleDestroy(self.raw) }
+    }
+}
+
+/// [ModuleRef] is a reference to an instance of the `mlir::ModuleOp` class.
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct ModuleRef<'c> {
+    _context: PhantomData<&'c ()>,
+}
+
+impl_unowned_mlir_value!(context_ref, Module, ModuleRef, MlirModule);
+
+impl<'c> ModuleRef<'c> {
+    /// Gets the provided operation as a module.
+    ///
+    /// # Arguments
+    /// * `operation` - The operation to get as a module.
+    ///
+    /// # Returns
+    /// Returns the operation as a [ModuleRef] if it is a `builtin.module`, otherwise `None`.
+    pub fn from_operation<'a>(operation: &'a OperationRef<'c>) -> Option<&'a Self> {
+        unsafe { Self::try_from_raw(mlirModuleFromOperation(operation.to_raw())) }
+    }
+
+    /// # Returns
+    /// Returns the context associated with the module.
+    pub fn context(&self) -> &'c ContextRef {
+        unsafe { ContextRef::from_raw(mlirModuleGetContext(self.to_raw())) }
+    }
+
+    /// # Returns
+    /// Returns the block holding the operations of the module.
+    pub fn body(&self) -> &BlockRef<'c> {
+        unsafe { BlockRef::from_raw(mlirModuleGetBody(self.to_raw())) }
+    }
+
+    /// # Returns
+    /// Returns the `builtin.module` operation of the module.
+    pub fn as_operation(&self) -> &OperationRef<'c> {
+        unsafe { OperationRef::from_raw(mlirModuleGetOperation(self.to_raw())) }
+    }
+}
+
+impl<'c> PartialEq for ModuleRef<'c> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_operation() == other.as_operation()
+    }
+}
+
+impl<'c> Eq for ModuleRef<'c> {}
+
+impl<'c> Display for ModuleRef<'c> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self.as_operation(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::OperationBuilder, Context, DialectRegistry};
+
+    #[test]
+    fn new() {
+        let context = Context::new(None, false);
+        let location = LocationRef::new_unknown(&context);
+        let module = Module::new(location);
+        assert_eq!(module.context(), &context);
+        assert!(module.body().first_operation().is_none());
+        assert_eq!(module.to_string(), "module {\n}\n");
+    }
+
+    #[test]
+    fn parse() {
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let module = Module::parse(&context, r#""dialect.op"() : () -> ()"#).unwrap();
+        let operation = module.body().first_operation().unwrap();
+        assert_eq!(operation.name().value(), "dialect.op");
+        assert_eq!(
+            module.as_operation().to_string(),
+            "module {\n  \"dialect.op\"() : () -> ()\n}\n"
+        );
+        assert!(Module::parse(&context, "}").is_none());
+    }
+
+    #[test]
+    fn parse_file() {
+        let path = std::env::temp_dir().join("mlir-rs-module-parse-file.mlir");
+        let dialect_registry = DialectRegistry::default();
+        dialect_registry.register_all_dialects();
+        let context = Context::new(Some(&dialect_registry), false);
+        context.load_all_available_dialects();
+        context.set_allow_unregistered_dialects(true);
+        std::fs::write(&path, r#""dialect.op"() : () -> ()"#).unwrap();
+        let module = Module::parse_file(&context, &path).unwrap();
+        let operation = module.body().first_operation().unwrap();
+        assert_eq!(operation.name().value(), "dialect.op");
+        assert_eq!(
+            operation.location().filename().unwrap(),
+            path.to_string_lossy()
+        );
+
+        std::fs::write(&path, "module {\n}\n").unwrap();
+        let module = Module::parse_file(&context, &path).unwrap();
+        assert!(module.body().first_operation().is_none());
+
+        std::fs::write(
+            &path,
+            "func.func @f() {\n  return\n}\nfunc.func @g() {\n  return\n}\n",
+        )
+        .unwrap();
+        let module = Module::parse_file(&context, &path).unwrap();
+        let f = module.body().first_operation().unwrap();
+        assert_eq!(f.name().value(), "func.func");
+        let g = f.next_in_parent_block().unwrap();
+        assert_eq!(g.name().value(), "func.func");
+        assert!(g.next_in_parent_block().is_none());
+
+        std::fs::write(&path, "}").unwrap();
+        assert!(matches!(
+            Module::parse_file(&context, &path),
+            Err(ParseFileError::Invalid { .. })
+        ));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn operation_round_trip() {
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let location = LocationRef::new_unknown(&context);
+        let module = Module::new(location);
+        let operation = module.into_operation();
+        assert!(ModuleRef::from_operation(&operation).is_some());
+        let module = Module::from_operation(operation).unwrap();
+        assert_eq!(module.as_operation().name().value(), "builtin.module");
+
+        let operation = OperationBuilder::new("dialect.op", location)
+            .build()
+            .unwrap();
+        assert!(ModuleRef::from_operation(&operation).is_none());
+        let operation = Module::from_operation(operation).unwrap_err();
+        assert_eq!(operation.name().value(), "dialect.op");
+    }
+
+    #[test]
+    fn compare_modules() {
+        let context = Context::new(None, false);
+        let location = LocationRef::new_unknown(&context);
+        let module1 = Module::new(location);
+        let module2 = Module::new(location);
+        assert_eq!(*module1, *module1);
+        assert_ne!(*module1, *module2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_module_ref() {
+        let _module_ref = ModuleRef {
+            _context: PhantomData,
+        };
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{
+    ir::OperationRef,
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{
+    mlirOpOperandGetNextUse, mlirOpOperandGetOperandNumber, mlirOpOperandGetOwner, MlirOpOperand,
+};
+
+/// [OpOperandRef] is a reference to an instance of the `mlir::OpOperand` class, which represents a
+/// single use of a value as an operand of an operation.
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirOpOperandGetNextUse`
+/// - `mlirOpOperandGetOperandNumber`
+/// - `mlirOpOperandGetOwner`
+///
+/// The following bindings are not used/supported:
+/// - `mlirOpOperandIsNull`
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct OpOperandRef<'c> {
+    _context: PhantomData<&'c ()>,
+}
+
+impl_unowned_mlir_value!(context_ref, OpOperandRef, MlirOpOperand);
+
+impl<'c> OpOperandRef<'c> {
+    /// # Returns
+    /// Returns the operation that owns the operand (i.e. the user of the value).
+    pub fn owner(&self) -> &OperationRef<'c> {
+        unsafe { OperationRef::from_raw(mlirOpOperandGetOwner(self.to_raw())) }
+    }
+
+    /// # Returns
+    /// Returns the index of the operand in the operand list of its owner, such that the use can
+    /// be rewritten with [OperationRef::set_operand].
+    pub fn operand_number(&self) -> isize {
+        unsafe { mlirOpOperandGetOperandNumber(self.to_raw()) as isize }
+    }
+
+    /// # Returns
+    /// Returns the next use of the same value, if there is one.
+    pub fn next_use(&self) -> Option<&OpOperandRef<'c>> {
+        unsafe { Self::try_from_raw(mlirOpOperandGetNextUse(self.to_raw())) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::Operation, Context};
+
+    #[test]
+    fn operand_number_and_next_use() {
+        #[rustfmt::skip]
+        let operation_source = r#"
+module {
+    %0 = "dialect.op1"() : () -> i32
+    %1 = "dialect.op2"() : () -> i32
+    "dialect.op3"(%1, %0) : (i32, i32) -> ()
+}
+"#;
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let operation = Operation::parse(&context, operation_source, "").unwrap();
+        let op1 = operation.ops_named("dialect.op1").next().unwrap();
+        let op2 = operation.ops_named("dialect.op2").next().unwrap();
+        let op3 = operation.ops_named("dialect.op3").next().unwrap();
+
+        let first_use = op1.result(0).first_use().unwrap();
+        assert_eq!(first_use.owner(), op3);
+        assert_eq!(first_use.operand_number(), 1);
+        assert!(first_use.next_use().is_none());
+
+        op3.set_operand(first_use.operand_number(), op2.result(0));
+        assert!(op1.result(0).first_use().is_none());
+        assert_eq!(
+            op2.result(0)
+                .first_use()
+                .unwrap()
+                .next_use()
+                .unwrap()
+                .owner(),
+            op3
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_op_operand_ref() {
+        let _op_operand_ref = OpOperandRef {
+            _context: PhantomData,
+        };
+    }
+}
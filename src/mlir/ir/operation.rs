@@ -1,39 +1,59 @@
 mod builder;
+mod function;
+mod source_map;
+mod symbol;
 
-pub use self::builder::OperationBuilder;
+pub(crate) use self::symbol::symbol_attribute_name;
+pub use self::{
+    builder::{BuildError, OperationBuilder},
+    source_map::{SourceMap, SourceMapEntry},
+    symbol::SymbolVisibility,
+};
 use crate::{
-    ir::{AttributeRef, BlockRef, IdentifierRef, LocationRef, NamedAttribute, RegionRef, ValueRef},
+    ir::{
+        AttributeRef, BlockRef, IdentifierRef, LocationRef, NamedAttribute, RegionRef, TypeRef,
+        ValueRef,
+    },
     support::{
         binding::{
             impl_owned_mlir_value, impl_unowned_mlir_value, OwnedMlirValue, UnownedMlirValue,
         },
         string_reader::StringReader,
     },
-    ContextRef, StringRef,
+    ContextRef, Diagnostic, DialectRef, StringRef,
 };
 
 use std::{
+    any::Any,
+    collections::HashMap,
+    error::Error,
     ffi::CString,
     fmt::{Display, Formatter},
+    fs, io,
     marker::PhantomData,
+    os::raw::c_void,
+    panic::{catch_unwind, resume_unwind, AssertUnwindSafe},
+    path::{Path, PathBuf},
 };
 
 use mlir_sys::{
-    mlirOperationClone, mlirOperationCreateParse, mlirOperationDestroy, mlirOperationEqual,
-    mlirOperationGetAttribute, mlirOperationGetAttributeByName, mlirOperationGetBlock,
-    mlirOperationGetContext, mlirOperationGetDiscardableAttribute,
-    mlirOperationGetDiscardableAttributeByName, mlirOperationGetFirstRegion,
-    mlirOperationGetInherentAttributeByName, mlirOperationGetLocation, mlirOperationGetName,
-    mlirOperationGetNextInBlock, mlirOperationGetNumAttributes,
+    mlirOpPrintingFlagsCreate, mlirOpPrintingFlagsDestroy, mlirOpPrintingFlagsEnableDebugInfo,
+    mlirOpPrintingFlagsPrintGenericOpForm, mlirOperationClone, mlirOperationCreateParse,
+    mlirOperationDestroy, mlirOperationEqual, mlirOperationGetAttribute,
+    mlirOperationGetAttributeByName, mlirOperationGetBlock, mlirOperationGetContext,
+    mlirOperationGetDiscardableAttribute, mlirOperationGetDiscardableAttributeByName,
+    mlirOperationGetFirstRegion, mlirOperationGetInherentAttributeByName, mlirOperationGetLocation,
+    mlirOperationGetName, mlirOperationGetNextInBlock, mlirOperationGetNumAttributes,
     mlirOperationGetNumDiscardableAttributes, mlirOperationGetNumOperands,
     mlirOperationGetNumRegions, mlirOperationGetNumResults, mlirOperationGetOperand,
     mlirOperationGetParentOperation, mlirOperationGetRegion, mlirOperationGetResult,
     mlirOperationHasInherentAttributeByName, mlirOperationMoveAfter, mlirOperationMoveBefore,
-    mlirOperationPrint, mlirOperationRemoveAttributeByName,
+    mlirOperationPrint, mlirOperationPrintWithFlags, mlirOperationRemoveAttributeByName,
     mlirOperationRemoveDiscardableAttributeByName, mlirOperationRemoveFromParent,
     mlirOperationSetAttributeByName, mlirOperationSetDiscardableAttributeByName,
     mlirOperationSetInherentAttributeByName, mlirOperationSetOperand, mlirOperationVerify,
-    MlirOperation,
+    mlirOperationWalk, MlirOperation, MlirStringRef, MlirWalkOrder,
+    MlirWalkOrder_MlirWalkPostOrder, MlirWalkOrder_MlirWalkPreOrder,
 };
 
 /// [Operation] wraps the `mlir::Operation` class, which represents a single operation in the MLIR
@@ -68,6 +88,7 @@ use mlir_sys::{
 /// - `mlirOperationMoveAfter`
 /// - `mlirOperationMoveBefore`
 /// - `mlirOperationPrint`
+/// - `mlirOperationPrintWithFlags`
 /// - `mlirOperationRemoveAttributeByName`
 /// - `mlirOperationRemoveDiscardableAttributeByName`
 /// - `mlirOperationRemoveFromParent`
@@ -76,6 +97,7 @@ use mlir_sys::{
 /// - `mlirOperationSetInherentAttributeByName`
 /// - `mlirOperationSetOperand`
 /// - `mlirOperationVerify`
+/// - `mlirOperationWalk`
 ///
 /// The following bindings are not used/supported:
 /// - `mlirOperationDump`
@@ -84,11 +106,9 @@ use mlir_sys::{
 /// - `mlirOperationGetTypeID`
 /// - `mlirOperationImplementsInterfaceStatic`
 /// - `mlirOperationImplementsInterface`
-/// - `mlirOperationPrintWithFlags`
 /// - `mlirOperationPrintWithState`
 /// - `mlirOperationSetOperands`
 /// - `mlirOperationSetSuccessor`
-/// - `mlirOperationWalk`
 /// - `mlirOperationWriteBytecodeWithConfig`
 /// - `mlirOperationWriteBytecode`
 #[repr(transparent)]
@@ -129,6 +149,88 @@ impl<'c> Operation<'c> {
             ))
         }
     }
+
+    /// Attempts to parse an operation from the file at the provided path. Both the textual and
+    /// the bytecode format are supported and detected automatically.
+    ///
+    /// # Arguments
+    /// * `context` - The context to associate with the operation.
+    /// * `path` - The path of the file to parse the operation from. It is also used as the
+    ///   filename of the locations in the parsed operation.
+    ///
+    /// # Returns
+    /// Returns a new [Operation] if the file could be read and parsed, otherwise a
+    /// [ParseFileError] describing why it could not.
+    pub fn parse_file(
+        context: &'c ContextRef,
+        path: impl AsRef<Path>,
+    ) -> Result<Operation<'c>, ParseFileError> {
+        let path = path.as_ref();
+        // Bytecode may contain null bytes, so the source cannot be passed as a `CString`. It is
+        // still null-terminated manually, see `Operation::parse`.
+        let mut source = fs::read(path).map_err(|error| ParseFileError::Io {
+            path: path.to_path_buf(),
+            error,
+        })?;
+        source.push(0);
+        let source_ref = MlirStringRef {
+            data: source.as_ptr() as *const _,
+            length: source.len() - 1,
+        };
+        let source_filename = path.to_string_lossy();
+        let source_filename_ref = StringRef::from(&source_filename.as_ref()).to_raw();
+        let capture = context.capture_diagnostics();
+        unsafe {
+            Self::try_from_raw(mlirOperationCreateParse(
+                context.to_raw(),
+                source_ref,
+                source_filename_ref,
+            ))
+        }
+        .ok_or_else(|| ParseFileError::Invalid {
+            path: path.to_path_buf(),
+            diagnostics: capture.take(),
+        })
+    }
+}
+
+/// [ParseFileError] is returned by [Operation::parse_file] and [crate::ir::Module::parse_file]
+/// when a file could not be read or its contents could not be parsed.
+#[derive(Debug)]
+pub enum ParseFileError {
+    /// The file could not be read.
+    Io { path: PathBuf, error: io::Error },
+    /// The contents of the file could not be parsed.
+    Invalid {
+        path: PathBuf,
+        diagnostics: Vec<Diagnostic>,
+    },
+}
+
+impl Display for ParseFileError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io { path, error } => {
+                write!(f, "failed to read '{}': {}", path.display(), error)
+            }
+            Self::Invalid { path, diagnostics } => {
+                write!(f, "failed to parse '{}'", path.display())?;
+                for diagnostic in diagnostics {
+                    write!(f, "\n{}", diagnostic)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Error for ParseFileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io { error, .. } => Some(error),
+            Self::Invalid { .. } => None,
+        }
+    }
 }
 
 impl<'c> Drop for Operation<'c> {
@@ -186,12 +288,96 @@ impl<'c> OperationRef<'c> {
         unsafe { mlirOperationVerify(self.to_raw()) }
     }
 
+    /// Verifies the operation, capturing any diagnostics emitted instead of printing them.
+    ///
+    /// # Returns
+    /// Returns `Ok` if the operation is valid, otherwise the diagnostics describing why it is not,
+    /// located at the offending (possibly nested) operations.
+    pub fn verify_with_diagnostics(&self) -> Result<(), Vec<Diagnostic>> {
+        let capture = self.context().capture_diagnostics();
+        if self.verify() {
+            Ok(())
+        } else {
+            Err(capture.take())
+        }
+    }
+
+    /// Prints the operation in the generic operation form, which can always be parsed back (e.g.
+    /// into another context), even when the printed operations have custom assembly formats.
+    ///
+    /// # Arguments
+    /// * `with_locations` - Whether to print the locations of the operations.
+    ///
+    /// # Returns
+    /// Returns the generic textual form of the operation.
+    pub fn to_string_generic(&self, with_locations: bool) -> String {
+        let mut string = String::new();
+        let mut reader = StringReader::new(&mut string);
+        unsafe {
+            let flags = mlirOpPrintingFlagsCreate();
+            mlirOpPrintingFlagsPrintGenericOpForm(flags);
+            mlirOpPrintingFlagsEnableDebugInfo(flags, with_locations, false);
+            mlirOperationPrintWithFlags(
+                self.to_raw(),
+                flags,
+                reader.callback(),
+                reader.as_raw_mut(),
+            );
+            mlirOpPrintingFlagsDestroy(flags);
+        }
+        string
+    }
+
+    /// Parses a copy of the operation into the provided context by round-tripping it through its
+    /// generic form, preserving the locations of the operations.
+    ///
+    /// The provided context must have the dialects of the operations loaded, or allow unregistered
+    /// dialects.
+    ///
+    /// # Arguments
+    /// * `context` - The context to parse the operation into.
+    ///
+    /// # Returns
+    /// Returns a new [Operation] associated with the provided context if the operation could be
+    /// parsed, otherwise `None`.
+    pub fn reparse_in<'a>(&self, context: &'a ContextRef) -> Option<Operation<'a>> {
+        Operation::parse(context, &self.to_string_generic(true), "")
+    }
+
     /// # Returns
     /// Returns the name of the operation.
     pub fn name(&self) -> &IdentifierRef {
         unsafe { IdentifierRef::from_raw(mlirOperationGetName(self.to_raw())) }
     }
 
+    /// # Returns
+    /// Returns the dialect namespace of the operation's name, e.g. "func" for "func.func".
+    pub fn dialect_namespace(&self) -> &str {
+        self.name()
+            .split_dialect()
+            .map_or("", |(namespace, _)| namespace)
+    }
+
+    /// # Returns
+    /// Returns the name of the operation without its dialect namespace, e.g. "func" for
+    /// "func.func".
+    pub fn mnemonic(&self) -> &str {
+        let name = self.name();
+        name.split_dialect()
+            .map_or(name.value().as_str(), |(_, mnemonic)| mnemonic)
+    }
+
+    /// # Returns
+    /// Returns the dialect that defines the operation, if the operation is registered.
+    pub fn dialect(&self) -> Option<&DialectRef> {
+        if self.context().is_operation_registered(self.name().value()) {
+            self.context()
+                .get_or_load_dialect(&self.dialect_namespace())
+        } else {
+            None
+        }
+    }
+
     /// # Returns
     /// Returns the location associated with the operation.
     pub fn location(&self) -> &LocationRef {
@@ -287,6 +473,12 @@ impl<'c> OperationRef<'c> {
         unsafe { mlirOperationSetOperand(self.to_raw(), idx, new_value.to_raw()) }
     }
 
+    /// # Returns
+    /// Returns an iterator over the types of the operation's operands.
+    pub fn operand_types(&self) -> impl Iterator<Item = &'c TypeRef> + '_ {
+        (0..self.num_operands()).map(|idx| self.operand(idx).r#type())
+    }
+
     /// # Returns
     /// Returns the number of regions the operation has.
     pub fn num_regions(&self) -> isize {
@@ -333,6 +525,12 @@ impl<'c> OperationRef<'c> {
         unsafe { ValueRef::from_raw(mlirOperationGetResult(self.to_raw(), idx)) }
     }
 
+    /// # Returns
+    /// Returns an iterator over the types of the operation's results.
+    pub fn result_types(&self) -> impl Iterator<Item = &'c TypeRef> + '_ {
+        (0..self.num_results()).map(|idx| self.result(idx).r#type())
+    }
+
     /// Checks if the operation has an inherent attribute with the specified name.
     ///
     /// # Arguments
@@ -513,6 +711,161 @@ impl<'c> OperationRef<'c> {
             mlirOperationRemoveAttributeByName(self.to_raw(), StringRef::from(&name).to_raw())
         }
     }
+
+    /// # Returns
+    /// Returns a map from the name of each attribute the operation has to its value.
+    pub fn attributes_map(&self) -> HashMap<String, &AttributeRef> {
+        (0..self.num_attributes())
+            .map(|idx| {
+                let attribute = self.attribute_at(idx);
+                (
+                    attribute.name().value().as_str().to_owned(),
+                    attribute.attribute(),
+                )
+            })
+            .collect()
+    }
+
+    /// # Returns
+    /// Returns a map from the name of each discardable attribute the operation has to its value.
+    pub fn discardable_attributes_map(&self) -> HashMap<String, &AttributeRef> {
+        (0..self.num_discardable_attributes())
+            .map(|idx| {
+                let attribute = self.discardable_attribute_at(idx);
+                (
+                    attribute.name().value().as_str().to_owned(),
+                    attribute.attribute(),
+                )
+            })
+            .collect()
+    }
+
+    /// # Returns
+    /// Returns a map from the name of each inherent attribute the operation has to its value.
+    pub fn inherent_attributes_map(&self) -> HashMap<String, &AttributeRef> {
+        self.attributes_map()
+            .into_iter()
+            .filter(|(name, _)| self.has_inherent_attribute(name))
+            .collect()
+    }
+
+    /// Walks the operation and all operations nested within it, invoking the provided callback on
+    /// each operation in the specified order.
+    ///
+    /// If the callback panics, it is not invoked on any of the remaining operations, and the panic
+    /// is resumed once the walk has returned (panics must not unwind through MLIR).
+    ///
+    /// # Arguments
+    /// * `order` - The order in which to visit the operations.
+    /// * `callback` - The callback to invoke on each operation.
+    pub fn walk<'a, F>(&'a self, order: WalkOrder, callback: F)
+    where
+        F: FnMut(&'a OperationRef<'c>),
+    {
+        let mut walk = Walk {
+            callback,
+            panic: None,
+        };
+        unsafe {
+            mlirOperationWalk(
+                self.to_raw(),
+                Some(walk_callback::<F>),
+                &mut walk as *mut Walk<F> as *mut c_void,
+                order.to_raw(),
+            )
+        }
+        if let Some(panic) = walk.panic {
+            resume_unwind(panic);
+        }
+    }
+
+    /// Finds all operations with the provided fully-qualified name (e.g. 'dialect.operation'),
+    /// including this operation and all operations nested within it.
+    ///
+    /// # Arguments
+    /// * `name` - The fully-qualified name of the operations to find.
+    ///
+    /// # Returns
+    /// Returns an iterator over the matching operations, in pre-order.
+    pub fn ops_named<'a>(&'a self, name: &str) -> impl Iterator<Item = &'a OperationRef<'c>> {
+        let mut operations = Vec::new();
+        self.walk(WalkOrder::PreOrder, |operation| {
+            if operation.name().value() == name {
+                operations.push(operation);
+            }
+        });
+        operations.into_iter()
+    }
+
+    /// Finds the first operation satisfying the provided predicate, considering this operation and
+    /// all operations nested within it in pre-order.
+    ///
+    /// # Arguments
+    /// * `predicate` - The predicate an operation must satisfy to be returned.
+    ///
+    /// # Returns
+    /// Returns the first matching operation, if there is one.
+    pub fn find_first<'a, P>(&'a self, mut predicate: P) -> Option<&'a OperationRef<'c>>
+    where
+        P: FnMut(&OperationRef<'c>) -> bool,
+    {
+        let mut found = None;
+        self.walk(WalkOrder::PreOrder, |operation| {
+            if found.is_none() && predicate(operation) {
+                found = Some(operation);
+            }
+        });
+        found
+    }
+}
+
+/// The state of a walk started by [OperationRef::walk].
+struct Walk<F> {
+    callback: F,
+    /// The payload of the panic raised by the callback, if it panicked.
+    panic: Option<Box<dyn Any + Send>>,
+}
+
+/// Invokes the closure passed to [OperationRef::walk] through the raw walk callback. The MLIR C
+/// API does not allow interrupting a walk, so once the closure panics, it is skipped for all
+/// remaining operations.
+///
+/// # Safety
+/// `user_data` must point to a valid [Walk] with a closure of type `F`.
+unsafe extern "C" fn walk_callback<'a, 'c: 'a, F>(
+    raw_operation: MlirOperation,
+    user_data: *mut c_void,
+) where
+    F: FnMut(&'a OperationRef<'c>),
+{
+    let walk = &mut *(user_data as *mut Walk<F>);
+    if walk.panic.is_some() {
+        return;
+    }
+    let operation = OperationRef::from_raw(raw_operation);
+    if let Err(panic) = catch_unwind(AssertUnwindSafe(|| (walk.callback)(operation))) {
+        walk.panic = Some(panic);
+    }
+}
+
+/// [WalkOrder] specifies the order in which operations are visited when walking an operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WalkOrder {
+    /// Each operation is visited before the operations nested within it.
+    PreOrder,
+    /// Each operation is visited after the operations nested within it.
+    PostOrder,
+}
+
+impl WalkOrder {
+    /// # Returns
+    /// Returns the raw [MlirWalkOrder] value.
+    pub fn to_raw(&self) -> MlirWalkOrder {
+        match self {
+            WalkOrder::PreOrder => MlirWalkOrder_MlirWalkPreOrder,
+            WalkOrder::PostOrder => MlirWalkOrder_MlirWalkPostOrder,
+        }
+    }
 }
 
 impl<'c> PartialEq for OperationRef<'c> {
@@ -540,7 +893,7 @@ impl<'c> Display for OperationRef<'c> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Context;
+    use crate::{Context, DialectRegistry};
 
     #[test]
     fn parse() {
@@ -554,6 +907,123 @@ module {
         assert_eq!(operation.to_string(), operation_source);
     }
 
+    #[test]
+    fn parse_file() {
+        let path = std::env::temp_dir().join("mlir-rs-operation-parse-file.mlir");
+        fs::write(&path, "module {\n}\n").unwrap();
+        let context = Context::new(None, false);
+        let operation = Operation::parse_file(&context, &path).unwrap();
+        assert_eq!(operation.to_string(), "module {\n}\n");
+        assert_eq!(
+            operation.location().filename().unwrap(),
+            path.to_string_lossy()
+        );
+
+        fs::write(&path, "module {\n").unwrap();
+        let error = Operation::parse_file(&context, &path).unwrap_err();
+        let ParseFileError::Invalid { diagnostics, .. } = &error else {
+            panic!("Expected an invalid source error.");
+        };
+        assert!(!diagnostics.is_empty());
+        assert!(error.source().is_none());
+        fs::remove_file(&path).unwrap();
+
+        let error = Operation::parse_file(&context, &path).unwrap_err();
+        assert!(matches!(error, ParseFileError::Io { .. }));
+        assert!(error.source().is_some());
+    }
+
+    #[test]
+    fn verify_with_diagnostics() {
+        let dialect_registry = DialectRegistry::default();
+        dialect_registry.register_all_dialects();
+        let context = Context::new(Some(&dialect_registry), false);
+        context.load_all_available_dialects();
+        let operation_source = r#"
+module {
+    func.func @f() {
+        return
+    }
+}
+"#;
+        let operation = Operation::parse(&context, operation_source, "").unwrap();
+        assert_eq!(operation.verify_with_diagnostics(), Ok(()));
+
+        let location = LocationRef::new_file_line_col(&context, "test.mlir", 3, 4);
+        let r#return = OperationBuilder::new("func.return", location)
+            .build()
+            .unwrap();
+        let body = operation.first_region().unwrap().first_block().unwrap();
+        body.append_operation(r#return);
+        let diagnostics = operation.verify_with_diagnostics().unwrap_err();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].location(), r#"loc("test.mlir":3:4)"#);
+    }
+
+    #[test]
+    fn to_string_generic() {
+        #[rustfmt::skip]
+        let operation_source = r#"
+module {
+    "dialect.op1"() : () -> ()
+}
+"#;
+        #[rustfmt::skip]
+        let generic_source = r#"
+"builtin.module"() ({
+  "dialect.op1"() : () -> ()
+}) : () -> ()
+"#.trim_start();
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let operation = Operation::parse(&context, operation_source, "test.mlir").unwrap();
+        assert_eq!(operation.to_string_generic(false), generic_source);
+        assert!(operation.to_string_generic(true).contains("test.mlir"));
+    }
+
+    #[test]
+    fn reparse_in() {
+        let operation_source = r#"module { "dialect.op1"() : () -> () }"#;
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let operation = Operation::parse(&context, operation_source, "test.mlir").unwrap();
+
+        let other_context = Context::new(None, false);
+        other_context.set_allow_unregistered_dialects(true);
+        let reparsed = operation.reparse_in(&other_context).unwrap();
+        assert_eq!(reparsed.context(), &other_context);
+        assert_eq!(reparsed.to_string(), operation.to_string());
+        assert_eq!(
+            reparsed.location(),
+            LocationRef::new_file_line_col(&other_context, "test.mlir", 1, 1),
+        );
+    }
+
+    #[test]
+    fn dialect_and_mnemonic() {
+        let dialect_registry = DialectRegistry::default();
+        dialect_registry.register_all_dialects();
+        let context = Context::new(Some(&dialect_registry), false);
+        context.load_all_available_dialects();
+        context.set_allow_unregistered_dialects(true);
+        let operation_source = r#"
+func.func @f() {
+    "dialect.op1"() : () -> ()
+    return
+}
+"#;
+        let operation = Operation::parse(&context, operation_source, "").unwrap();
+        let function = operation.ops_named("func.func").next().unwrap();
+        assert_eq!(function.dialect_namespace(), "func");
+        assert_eq!(function.mnemonic(), "func");
+        assert_eq!(function.dialect().unwrap().namespace(), "func");
+
+        let unregistered = operation.ops_named("dialect.op1").next().unwrap();
+        assert_eq!(unregistered.dialect_namespace(), "dialect");
+        assert_eq!(unregistered.mnemonic(), "op1");
+        assert!(unregistered.dialect().is_none());
+    }
+
     #[test]
     fn context() {
         let operation_source = "module {}";
@@ -746,6 +1216,184 @@ module {
         let _third_operand = second_op.operand(2);
     }
 
+    #[test]
+    fn operand_and_result_types() {
+        #[rustfmt::skip]
+        let operation_source = r#"
+module {
+    %0:2 = "dialect.op1"() : () -> (i1, i16)
+    "dialect.op2"(%0#0, %0#1) : (i1, i16) -> ()
+}
+"#;
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let operation = Operation::parse(&context, operation_source, "test.mlir").unwrap();
+        let first_op = operation
+            .region(0)
+            .first_block()
+            .unwrap()
+            .first_operation()
+            .unwrap();
+        let second_op = first_op.next_in_parent_block().unwrap();
+        let i1 = TypeRef::parse(&context, "i1").unwrap();
+        let i16 = TypeRef::parse(&context, "i16").unwrap();
+        assert_eq!(first_op.operand_types().count(), 0);
+        assert_eq!(first_op.result_types().collect::<Vec<_>>(), [i1, i16]);
+        assert_eq!(second_op.operand_types().collect::<Vec<_>>(), [i1, i16]);
+        assert_eq!(second_op.result_types().count(), 0);
+    }
+
+    #[test]
+    fn attributes_map() {
+        #[rustfmt::skip]
+        let operation_source = r#"
+module {
+    %0 = "arith.constant"() {value = 42 : i32, extra = unit} : () -> i32
+}
+"#;
+        let dialect_registry = DialectRegistry::default();
+        dialect_registry.register_all_dialects();
+        let context = Context::new(Some(&dialect_registry), false);
+        context.load_all_available_dialects();
+        let operation = Operation::parse(&context, operation_source, "test.mlir").unwrap();
+        let constant_op = operation.ops_named("arith.constant").next().unwrap();
+        let value = AttributeRef::parse(&context, "42 : i32").unwrap();
+        let extra = AttributeRef::parse(&context, "unit").unwrap();
+
+        let attributes = constant_op.attributes_map();
+        assert_eq!(attributes.len(), 2);
+        assert_eq!(attributes["value"], value);
+        assert_eq!(attributes["extra"], extra);
+
+        let discardable_attributes = constant_op.discardable_attributes_map();
+        assert_eq!(discardable_attributes.len(), 1);
+        assert_eq!(discardable_attributes["extra"], extra);
+
+        let inherent_attributes = constant_op.inherent_attributes_map();
+        assert_eq!(inherent_attributes.len(), 1);
+        assert_eq!(inherent_attributes["value"], value);
+    }
+
+    #[test]
+    fn walk() {
+        #[rustfmt::skip]
+        let operation_source = r#"
+module {
+    "dialect.op1"() ({
+        "dialect.op2"() : () -> ()
+    }) : () -> ()
+    "dialect.op3"() : () -> ()
+}
+"#;
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let operation = Operation::parse(&context, operation_source, "test.mlir").unwrap();
+
+        let mut pre_order = Vec::new();
+        operation.walk(WalkOrder::PreOrder, |op| {
+            pre_order.push(op.name().value().as_str().to_owned())
+        });
+        assert_eq!(
+            pre_order,
+            [
+                "builtin.module",
+                "dialect.op1",
+                "dialect.op2",
+                "dialect.op3"
+            ]
+        );
+
+        let mut post_order = Vec::new();
+        operation.walk(WalkOrder::PostOrder, |op| {
+            post_order.push(op.name().value().as_str().to_owned())
+        });
+        assert_eq!(
+            post_order,
+            [
+                "dialect.op2",
+                "dialect.op1",
+                "dialect.op3",
+                "builtin.module"
+            ]
+        );
+    }
+
+    #[test]
+    fn walk_panic() {
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let operation_source = r#"
+module {
+    "dialect.op1"() : () -> ()
+    "dialect.op2"() : () -> ()
+}
+"#;
+        let operation = Operation::parse(&context, operation_source, "").unwrap();
+        let mut visited = 0;
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            operation.walk(WalkOrder::PreOrder, |_| {
+                visited += 1;
+                panic!("failed to visit operation");
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(visited, 1);
+    }
+
+    #[test]
+    fn ops_named() {
+        #[rustfmt::skip]
+        let operation_source = r#"
+module {
+    "dialect.op1"() ({
+        "dialect.op2"() {index = 0 : i32} : () -> ()
+    }) : () -> ()
+    "dialect.op2"() {index = 1 : i32} : () -> ()
+}
+"#;
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let operation = Operation::parse(&context, operation_source, "test.mlir").unwrap();
+        let ops = operation.ops_named("dialect.op2").collect::<Vec<_>>();
+        assert_eq!(ops.len(), 2);
+        assert_eq!(
+            ops[0].attribute("index").unwrap(),
+            AttributeRef::parse(&context, "0 : i32").unwrap()
+        );
+        assert_eq!(
+            ops[1].attribute("index").unwrap(),
+            AttributeRef::parse(&context, "1 : i32").unwrap()
+        );
+        assert_eq!(operation.ops_named("builtin.module").count(), 1);
+        assert_eq!(operation.ops_named("dialect.op3").count(), 0);
+    }
+
+    #[test]
+    fn find_first() {
+        #[rustfmt::skip]
+        let operation_source = r#"
+module {
+    "dialect.op1"() : () -> ()
+    "dialect.op2"() {index = 0 : i32} : () -> ()
+    "dialect.op2"() {index = 1 : i32} : () -> ()
+}
+"#;
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let operation = Operation::parse(&context, operation_source, "test.mlir").unwrap();
+        let found = operation
+            .find_first(|op| op.attribute("index").is_some())
+            .unwrap();
+        assert_eq!(found.name().value().as_str(), "dialect.op2");
+        assert_eq!(
+            found.attribute("index").unwrap(),
+            AttributeRef::parse(&context, "0 : i32").unwrap()
+        );
+        assert!(operation
+            .find_first(|op| op.name().value() == "dialect.op3")
+            .is_none());
+    }
+
     #[test]
     #[should_panic]
     fn no_owned_operation_ref() {
@@ -1,13 +1,22 @@
 use crate::{
-    ir::{LocationRef, NamedAttribute, Operation, Region, TypeRef, ValueRef},
-    support::binding::OwnedMlirValue,
-    StringRef, UnownedMlirValue,
+    ir::{
+        AttributeRef, IdentifierRef, LocationRef, LocationScope, NamedAttribute, Operation,
+        OperationRef, Region, TypeRef, ValueRef,
+    },
+    support::binding::{OptionalMlirValue, OwnedMlirValue},
+    ContextRef, Diagnostic, StringRef, UnownedMlirValue,
 };
 
-use std::{marker::PhantomData, mem::forget};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    marker::PhantomData,
+    mem::forget,
+};
 
 use mlir_sys::{
-    mlirOperationCreate, mlirOperationStateAddAttributes, mlirOperationStateAddOperands,
+    mlirLocationFusedGet, mlirLocationGetContext, mlirOperationCreate,
+    mlirOperationStateAddAttributes, mlirOperationStateAddOperands,
     mlirOperationStateAddOwnedRegions, mlirOperationStateAddResults,
     mlirOperationStateEnableResultTypeInference, mlirOperationStateGet, MlirNamedAttribute,
     MlirOperationState, MlirRegion, MlirType, MlirValue,
@@ -28,6 +37,69 @@ impl<'a> OperationBuilder<'a> {
         }
     }
 
+    /// Creates a builder for an operation with the provided name at the current location of the
+    /// provided context, as set by the innermost [LocationScope] of the context alive on the
+    /// current thread.
+    ///
+    /// # Arguments
+    /// * `context` - The context of the current location.
+    /// * `name` - The fully-qualified name of the operation.
+    ///
+    /// # Returns
+    /// Returns a new [OperationBuilder] instance.
+    pub fn new_here(context: &'a ContextRef, name: &str) -> OperationBuilder<'a> {
+        let location = LocationScope::current(context).expect("No location scope is active.");
+        Self::new(name, location)
+    }
+
+    /// Creates a builder seeded with the name, location, operands, attributes, and result types of
+    /// the provided operation, so that a modified copy of it can be built. Regions and successors
+    /// are not copied.
+    ///
+    /// # Arguments
+    /// * `operation` - The operation to seed the builder from.
+    ///
+    /// # Returns
+    /// Returns a new [OperationBuilder] instance.
+    pub fn from_operation(operation: &'a OperationRef) -> OperationBuilder<'a> {
+        let operands = (0..operation.num_operands())
+            .map(|idx| operation.operand(idx))
+            .collect::<Vec<_>>();
+        let attributes = (0..operation.num_attributes())
+            .map(|idx| operation.attribute_at(idx))
+            .collect::<Vec<_>>();
+        let result_types = operation.result_types().collect::<Vec<_>>();
+        Self::new(operation.name().value().as_str(), operation.location())
+            .add_operands(&operands)
+            .add_attributes(&attributes)
+            .add_results(&result_types)
+    }
+
+    /// Sets the location of the operation to the fusion of the locations of the provided
+    /// operations, e.g. when it replaces or is derived from several source operations. The
+    /// location is left unchanged if no operations are provided.
+    ///
+    /// # Arguments
+    /// * `operations` - The operations whose locations should be fused.
+    pub fn with_location_from(mut self, operations: &[&OperationRef]) -> Self {
+        if operations.is_empty() {
+            return self;
+        }
+        let locations = operations
+            .iter()
+            .map(|operation| operation.location().to_raw())
+            .collect::<Vec<_>>();
+        self.state.location = unsafe {
+            mlirLocationFusedGet(
+                mlirLocationGetContext(self.state.location),
+                locations.len() as isize,
+                locations.as_ptr(),
+                None::<&AttributeRef>.to_raw_or_null(),
+            )
+        };
+        self
+    }
+
     pub fn add_results(mut self, types: &[&TypeRef]) -> Self {
         unsafe {
             mlirOperationStateAddResults(
@@ -50,6 +122,19 @@ impl<'a> OperationBuilder<'a> {
         self
     }
 
+    /// Replaces the operand at the provided index, verifying that the index is within bounds.
+    ///
+    /// # Arguments
+    /// * `idx` - The index of the operand to replace.
+    /// * `operand` - The new operand.
+    pub fn set_operand(self, idx: isize, operand: &ValueRef) -> Self {
+        if idx < 0 || idx >= self.state.nOperands {
+            panic!("Operand index {} out of bounds.", idx);
+        }
+        unsafe { *self.state.operands.offset(idx) = operand.to_raw() }
+        self
+    }
+
     pub fn add_regions(mut self, regions: Vec<Region>) -> Self {
         unsafe {
             mlirOperationStateAddOwnedRegions(
@@ -75,6 +160,45 @@ impl<'a> OperationBuilder<'a> {
         self
     }
 
+    /// Adds an attribute with the provided name to the operation, constructing its identifier in
+    /// the context of the attribute. Typed attribute variants (e.g. [IntegerAttributeRef]) can be
+    /// passed directly.
+    ///
+    /// [IntegerAttributeRef]: crate::ir::IntegerAttributeRef
+    pub fn add_attribute(self, name: &str, attribute: &AttributeRef) -> Self {
+        self.add_attributes(&[attribute.with_name(name)])
+    }
+
+    /// Sets the attribute with the provided name, replacing the attribute already added under that
+    /// name if there is one.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the attribute.
+    /// * `attribute` - The attribute value.
+    pub fn set_attribute(self, name: &str, attribute: &AttributeRef) -> Self {
+        for idx in 0..self.state.nAttributes {
+            let named_attribute = unsafe { &mut *self.state.attributes.offset(idx) };
+            if unsafe { IdentifierRef::from_raw(named_attribute.name) }.value() == name {
+                named_attribute.attribute = attribute.to_raw();
+                return self;
+            }
+        }
+        self.add_attribute(name, attribute)
+    }
+
+    /// Adds the provided name-attribute pairs to the operation, constructing their identifiers in
+    /// the context of each attribute.
+    pub fn add_attribute_pairs<A>(self, attributes: &[(&str, &A)]) -> Self
+    where
+        A: AsRef<AttributeRef> + ?Sized,
+    {
+        let attributes = attributes
+            .iter()
+            .map(|&(name, attribute)| NamedAttribute::from((name, attribute)))
+            .collect::<Vec<_>>();
+        self.add_attributes(&attributes)
+    }
+
     pub fn enable_result_type_inference(mut self) -> Self {
         unsafe {
             mlirOperationStateEnableResultTypeInference(&mut self.state as *mut MlirOperationState)
@@ -82,20 +206,91 @@ impl<'a> OperationBuilder<'a> {
         self
     }
 
-    pub fn build(mut self) -> Option<Operation<'a>> {
-        unsafe {
+    /// Builds the operation, capturing the diagnostics emitted while doing so (e.g. when result
+    /// type inference fails). The captured diagnostics are only surfaced if the operation could not
+    /// be created: any diagnostics emitted for an operation that is created successfully (such as
+    /// warnings) are dropped, and do not reach the diagnostic handlers of the context either.
+    ///
+    /// # Returns
+    /// Returns the new [Operation], or a [BuildError] holding the captured diagnostics if the
+    /// operation could not be created.
+    pub fn build(mut self) -> Result<Operation<'a>, BuildError> {
+        let context = unsafe { ContextRef::from_raw(mlirLocationGetContext(self.state.location)) };
+        let capture = context.capture_diagnostics();
+        let operation = unsafe {
             Operation::try_from_raw(mlirOperationCreate(
                 &mut self.state as *mut MlirOperationState,
             ))
+        };
+        operation.ok_or_else(|| BuildError {
+            operation_name: unsafe { StringRef::from_raw(self.state.name) }
+                .as_str()
+                .to_string(),
+            diagnostics: capture.take(),
+        })
+    }
+}
+
+/// [BuildError] is returned by [OperationBuilder::build] when an operation could not be created,
+/// and holds the diagnostics that were emitted while attempting to create it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildError {
+    operation_name: String,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl BuildError {
+    /// # Returns
+    /// Returns the name of the operation that could not be created.
+    pub fn operation_name(&self) -> &str {
+        &self.operation_name
+    }
+
+    /// # Returns
+    /// Returns the diagnostics emitted while attempting to create the operation.
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+}
+
+impl Display for BuildError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "failed to build operation '{}'", self.operation_name)?;
+        for diagnostic in &self.diagnostics {
+            write!(f, "\n{}", diagnostic)?;
         }
+        Ok(())
     }
 }
 
+impl Error for BuildError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ir::AttributeRef;
-    use crate::{ir::LocationRef, Context};
+    use crate::{
+        ir::{IntegerAttributeRef, IntegerTypeRef, LocationRef},
+        Context, DialectRegistry,
+    };
+
+    #[test]
+    fn new_here() {
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let loc = LocationRef::new_file_line_col(&context, "file", 1, 2);
+        let _scope = LocationScope::new(loc);
+        let op = OperationBuilder::new_here(&context, "dialect.op1")
+            .build()
+            .unwrap();
+        assert_eq!(op.location(), loc);
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_here_without_scope() {
+        let context = Context::new(None, false);
+        OperationBuilder::new_here(&context, "dialect.op1");
+    }
 
     #[test]
     fn build() {
@@ -118,4 +313,109 @@ mod tests {
 "#
         );
     }
+
+    #[test]
+    fn add_attribute_pairs() {
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let loc = LocationRef::new_unknown(&context);
+        let i32_type = IntegerTypeRef::new_signless(&context, 32);
+        let answer = IntegerAttributeRef::new(i32_type, 42);
+        let seven = IntegerAttributeRef::new(i32_type, 7);
+        let op = OperationBuilder::new("dialect.op1", loc)
+            .add_attribute_pairs(&[("answer", answer), ("seven", seven)])
+            .add_attribute("flag", AttributeRef::parse(&context, "unit").unwrap())
+            .add_attribute("typed", answer)
+            .build()
+            .unwrap();
+        assert_eq!(
+            op.to_string(),
+            r#""dialect.op1"() {answer = 42 : i32, flag, seven = 7 : i32, typed = 42 : i32} : () -> ()
+"#
+        );
+    }
+
+    #[test]
+    fn build_inference_failure() {
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let loc = LocationRef::new_unknown(&context);
+        let error = OperationBuilder::new("dialect.op1", loc)
+            .enable_result_type_inference()
+            .build()
+            .unwrap_err();
+        assert_eq!(error.operation_name(), "dialect.op1");
+        assert_eq!(error.diagnostics().len(), 1);
+        assert!(error.diagnostics()[0]
+            .message()
+            .contains("the operation was not registered"));
+        assert!(error
+            .to_string()
+            .starts_with("failed to build operation 'dialect.op1'"));
+    }
+
+    #[test]
+    fn from_operation() {
+        let dialect_registry = DialectRegistry::default();
+        dialect_registry.register_all_dialects();
+        let context = Context::new(Some(&dialect_registry), false);
+        context.load_all_available_dialects();
+        let operation_source = r#"
+func.func @f(%arg0: i32, %arg1: i32) -> i32 {
+    %0 = arith.addi %arg0, %arg1 {tag = 1 : i32} : i32
+    return %0 : i32
+}
+"#;
+        let function = Operation::parse(&context, operation_source, "").unwrap();
+        let add = function.ops_named("arith.addi").next().unwrap();
+        let i32_type = IntegerTypeRef::new_signless(&context, 32);
+        let copy = OperationBuilder::from_operation(add)
+            .set_operand(1, add.operand(0))
+            .set_attribute("tag", IntegerAttributeRef::new(i32_type, 2))
+            .build()
+            .unwrap();
+        assert_eq!(copy.name().value(), "arith.addi");
+        assert_eq!(copy.location(), add.location());
+        assert_eq!(copy.operand(0), add.operand(0));
+        assert_eq!(copy.operand(1), add.operand(0));
+        assert_eq!(copy.num_attributes(), add.num_attributes());
+        assert_eq!(
+            copy.attribute("tag").unwrap().to_string(),
+            "2 : i32".to_string()
+        );
+        assert_eq!(
+            copy.result_types().collect::<Vec<_>>(),
+            add.result_types().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn with_location_from() {
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let operation_source = r#"
+module {
+    "dialect.op1"() : () -> () loc("a.mlir":1:2)
+    "dialect.op2"() : () -> () loc("b.mlir":3:4)
+}
+"#;
+        let module = Operation::parse(&context, operation_source, "").unwrap();
+        let op1 = module.ops_named("dialect.op1").next().unwrap();
+        let op2 = module.ops_named("dialect.op2").next().unwrap();
+        let loc = LocationRef::new_unknown(&context);
+        let op = OperationBuilder::new("dialect.op3", loc)
+            .with_location_from(&[op1, op2])
+            .build()
+            .unwrap();
+        assert_eq!(
+            op.location().to_string(),
+            r#"loc(fused["a.mlir":1:2, "b.mlir":3:4])"#
+        );
+
+        let op = OperationBuilder::new("dialect.op3", loc)
+            .with_location_from(&[op1])
+            .build()
+            .unwrap();
+        assert_eq!(op.location(), op1.location());
+    }
 }
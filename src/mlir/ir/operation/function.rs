@@ -0,0 +1,144 @@
+use crate::{
+    dialects::func::FuncOpRef,
+    ir::{BlockArgumentRef, LocationRef, OperationRef, TypeRef},
+};
+
+/// Helpers for `func.func` operations, which keep the `function_type` attribute, the optional
+/// `arg_attrs` attribute, and the arguments of the entry block in sync. They delegate to
+/// [FuncOpRef], panicking if the operation is not a `func.func`.
+impl<'c> OperationRef<'c> {
+    /// Inserts a new argument into the signature of the `func.func` operation at the provided
+    /// index (see [FuncOpRef::insert_argument]).
+    ///
+    /// # Arguments
+    /// * `idx` - The index to insert the argument at.
+    /// * `ty` - The type of the new argument.
+    /// * `loc` - The location of the new entry block argument.
+    ///
+    /// # Returns
+    /// Returns the new entry block argument, if the function has a body.
+    pub fn insert_function_argument(
+        &self,
+        idx: isize,
+        ty: &'c TypeRef,
+        loc: &'c LocationRef,
+    ) -> Option<&BlockArgumentRef<'c>> {
+        self.as_func_op().insert_argument(idx, ty, loc)
+    }
+
+    /// Removes the argument at the provided index from the signature of the `func.func`
+    /// operation (see [FuncOpRef::remove_argument]). The entry block is replaced by a new block,
+    /// so references to the previous entry block and to its arguments must not be used
+    /// afterwards.
+    ///
+    /// # Arguments
+    /// * `idx` - The index of the argument to remove.
+    pub fn remove_function_argument(&self, idx: isize) {
+        self.as_func_op().remove_argument(idx)
+    }
+
+    fn as_func_op(&self) -> &FuncOpRef<'c> {
+        FuncOpRef::try_from_operation(self).unwrap_or_else(|| {
+            panic!(
+                "Expected a func.func operation, got {}.",
+                self.name().value().as_str()
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ir::{IntegerTypeRef, LocationRef, Operation},
+        Context, DialectRegistry,
+    };
+
+    fn context() -> Context {
+        let dialect_registry = DialectRegistry::default();
+        dialect_registry.register_all_dialects();
+        let context = Context::new(Some(&dialect_registry), false);
+        context.load_all_available_dialects();
+        context
+    }
+
+    #[test]
+    fn insert_function_argument() {
+        let context = context();
+        let operation_source = r#"
+func.func @f(%arg0: i32 {test.attr}) -> i32 {
+    return %arg0 : i32
+}
+"#;
+        let function = Operation::parse(&context, operation_source, "").unwrap();
+        let i8_type = IntegerTypeRef::new_signless(&context, 8);
+        let loc = LocationRef::new_unknown(&context);
+        let argument = function.insert_function_argument(0, i8_type, loc).unwrap();
+        assert_eq!(argument.arg_number(), 0);
+        assert!(function.verify());
+        assert_eq!(
+            function.to_string(),
+            r#"func.func @f(%arg0: i8, %arg1: i32 {test.attr}) -> i32 {
+  return %arg1 : i32
+}
+"#
+        );
+    }
+
+    #[test]
+    fn remove_function_argument() {
+        let context = context();
+        let operation_source = r#"
+func.func @f(%arg0: i8, %arg1: i32) -> i32 {
+    return %arg1 : i32
+}
+"#;
+        let function = Operation::parse(&context, operation_source, "").unwrap();
+        function.remove_function_argument(0);
+        assert!(function.verify());
+        assert_eq!(
+            function.to_string(),
+            r#"func.func @f(%arg0: i32) -> i32 {
+  return %arg0 : i32
+}
+"#
+        );
+    }
+
+    #[test]
+    fn external_function() {
+        let context = context();
+        let operation_source = "func.func private @f(i8, i32)";
+        let function = Operation::parse(&context, operation_source, "").unwrap();
+        function.remove_function_argument(1);
+        let i16_type = IntegerTypeRef::new_signless(&context, 16);
+        let loc = LocationRef::new_unknown(&context);
+        assert!(function
+            .insert_function_argument(1, i16_type, loc)
+            .is_none());
+        assert_eq!(function.to_string(), "func.func private @f(i8, i16)\n");
+    }
+
+    #[test]
+    #[should_panic]
+    fn remove_used_function_argument() {
+        let context = context();
+        let operation_source = r#"
+func.func @f(%arg0: i32) -> i32 {
+    return %arg0 : i32
+}
+"#;
+        let function = Operation::parse(&context, operation_source, "").unwrap();
+        function.remove_function_argument(0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_function_argument_out_of_bounds() {
+        let context = context();
+        let function = Operation::parse(&context, "func.func private @f(i8)", "").unwrap();
+        let i16_type = IntegerTypeRef::new_signless(&context, 16);
+        let loc = LocationRef::new_unknown(&context);
+        function.insert_function_argument(2, i16_type, loc);
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{
+    ir::{LocationRef, OpResultRef, OperationRef, ValueRef, WalkOrder},
+    UnownedMlirValue,
+};
+
+use std::collections::HashMap;
+
+/// [SourceMapEntry] associates an operation with the file, line number, and column number location
+/// its own location resolves to.
+#[derive(Debug, Clone, Copy)]
+pub struct SourceMapEntry<'a, 'c> {
+    operation: &'a OperationRef<'c>,
+    location: &'a LocationRef,
+}
+
+impl<'a, 'c> SourceMapEntry<'a, 'c> {
+    /// # Returns
+    /// Returns the operation of the entry.
+    pub fn operation(&self) -> &'a OperationRef<'c> {
+        self.operation
+    }
+
+    /// # Returns
+    /// Returns the resolved file, line number, and column number location of the operation.
+    pub fn location(&self) -> &'a LocationRef {
+        self.location
+    }
+}
+
+/// [SourceMap] maps the operations of an operation tree (and the values they produce) to the file,
+/// line number, and column number locations their locations resolve to, as produced by
+/// [OperationRef::source_map]. It can be used to emit debug maps alongside generated code.
+#[derive(Debug, Clone)]
+pub struct SourceMap<'a, 'c> {
+    entries: Vec<SourceMapEntry<'a, 'c>>,
+    /// The index of the entry of each operation, keyed by the address of the operation.
+    indices: HashMap<usize, usize>,
+}
+
+impl<'a, 'c> SourceMap<'a, 'c> {
+    /// # Returns
+    /// Returns the entries of the map, in pre-order.
+    pub fn entries(&self) -> &[SourceMapEntry<'a, 'c>] {
+        &self.entries
+    }
+
+    /// Looks up the resolved location of the provided operation.
+    ///
+    /// # Arguments
+    /// * `operation` - The operation to look up.
+    ///
+    /// # Returns
+    /// Returns the resolved location of the operation, if it is part of the map.
+    pub fn location_of(&self, operation: &OperationRef<'c>) -> Option<&'a LocationRef> {
+        let idx = self.indices.get(&(operation.to_raw().ptr as usize))?;
+        Some(self.entries[*idx].location)
+    }
+
+    /// Looks up the resolved location of the provided value, which is the location of the
+    /// operation producing it.
+    ///
+    /// # Arguments
+    /// * `value` - The value to look up.
+    ///
+    /// # Returns
+    /// Returns the resolved location of the value, if it is the result of an operation that is
+    /// part of the map.
+    pub fn location_of_value(&self, value: &ValueRef<'c>) -> Option<&'a LocationRef> {
+        let result = OpResultRef::try_from_value(value)?;
+        self.location_of(result.owner())
+    }
+}
+
+impl<'c> OperationRef<'c> {
+    /// Walks the operation and all operations nested within it, resolving the location of each of
+    /// them to a file, line number, and column number location (see
+    /// [LocationRef::resolve_file_line_col]). Operations whose locations cannot be resolved are
+    /// left out of the map.
+    ///
+    /// # Returns
+    /// Returns the [SourceMap] of the operation tree.
+    pub fn source_map<'a>(&'a self) -> SourceMap<'a, 'c> {
+        let mut entries = Vec::new();
+        let mut indices = HashMap::new();
+        self.walk(WalkOrder::PreOrder, |operation| {
+            if let Some(location) = operation.location().resolve_file_line_col() {
+                indices.insert(operation.to_raw().ptr as usize, entries.len());
+                entries.push(SourceMapEntry {
+                    operation,
+                    location,
+                });
+            }
+        });
+        SourceMap { entries, indices }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ir::Operation, Context};
+
+    #[test]
+    fn source_map() {
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let source = r#"
+            "test.parent"() ({
+              %0 = "test.a"() : () -> i32 loc("a.mlir":1:2)
+              "test.b"(%0) : (i32) -> () loc("name"("b.mlir":3:4))
+              "test.c"() : () -> () loc(unknown)
+              %1 = "test.d"() : () -> i32 loc(fused[unknown, callsite("d.mlir":5:6 at "e.mlir":7:8)])
+            }) : () -> () loc(unknown)
+        "#;
+        let operation = Operation::parse(&context, source, "source").unwrap();
+        let source_map = operation.source_map();
+        let entries = source_map.entries();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].operation().name().value(), "test.a");
+        assert_eq!(entries[0].location().to_string(), r#"loc("a.mlir":1:2)"#);
+        assert_eq!(entries[1].location().filename().as_deref(), Some("b.mlir"));
+        assert_eq!(entries[2].location().line(), Some(5));
+
+        let a = entries[0].operation();
+        let b = entries[1].operation();
+        assert_eq!(
+            source_map.location_of_value(b.operand(0)),
+            Some(entries[0].location())
+        );
+        assert_eq!(source_map.location_of(a), Some(entries[0].location()));
+        let d = entries[2].operation();
+        assert_eq!(
+            source_map.location_of_value(d.result(0)),
+            Some(entries[2].location())
+        );
+        assert!(source_map.location_of(&operation).is_none());
+    }
+}
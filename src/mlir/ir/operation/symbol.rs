@@ -0,0 +1,219 @@
+use crate::{
+    ir::{OperationRef, StringAttributeRef},
+    support::binding::UnownedMlirValue,
+    StringRef,
+};
+
+use mlir_sys::{
+    mlirSymbolTableGetSymbolAttributeName, mlirSymbolTableGetVisibilityAttributeName,
+    mlirSymbolTableReplaceAllSymbolUses,
+};
+
+/// [SymbolVisibility] represents the visibility of a symbol, as held by the
+/// `sym_visibility` attribute of an operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolVisibility {
+    /// The symbol may be referenced from anywhere. This is the default if the operation has no
+    /// visibility attribute.
+    Public,
+    /// The symbol may only be referenced from within the symbol table that defines it.
+    Private,
+    /// The symbol may be referenced from within the symbol table that defines it and from the
+    /// symbol tables nested within it.
+    Nested,
+}
+
+impl SymbolVisibility {
+    /// # Returns
+    /// Returns the name of the visibility, as held by the visibility attribute.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SymbolVisibility::Public => "public",
+            SymbolVisibility::Private => "private",
+            SymbolVisibility::Nested => "nested",
+        }
+    }
+}
+
+/// Helpers for operations that define symbols, which use the canonical attribute names of
+/// `mlir::SymbolTable`.
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirSymbolTableGetSymbolAttributeName`
+/// - `mlirSymbolTableGetVisibilityAttributeName`
+/// - `mlirSymbolTableReplaceAllSymbolUses`
+impl<'c> OperationRef<'c> {
+    /// # Returns
+    /// Returns the name of the symbol defined by the operation, if it has one.
+    pub fn symbol_name(&self) -> Option<StringRef<'c>> {
+        self.attribute(symbol_attribute_name())
+            .and_then(StringAttributeRef::try_from_attribute)
+            .map(|name| name.value())
+    }
+
+    /// Sets the name of the symbol defined by the operation.
+    ///
+    /// # Arguments
+    /// * `name` - The new name of the symbol.
+    pub fn set_symbol_name(&self, name: &str) {
+        let name = StringAttributeRef::new(self.context(), name);
+        self.set_attribute(symbol_attribute_name(), name);
+    }
+
+    /// Gets the visibility of the symbol defined by the operation, panicking if its visibility
+    /// attribute is not a valid visibility.
+    ///
+    /// # Returns
+    /// Returns the visibility of the symbol, which is public if the operation has no visibility
+    /// attribute.
+    pub fn visibility(&self) -> SymbolVisibility {
+        let Some(attribute) = self.attribute(visibility_attribute_name()) else {
+            return SymbolVisibility::Public;
+        };
+        let visibility = StringAttributeRef::try_from_attribute(attribute)
+            .map(|visibility| visibility.value().as_str());
+        match visibility {
+            Some("public") => SymbolVisibility::Public,
+            Some("private") => SymbolVisibility::Private,
+            Some("nested") => SymbolVisibility::Nested,
+            _ => panic!("Invalid symbol visibility {}.", attribute),
+        }
+    }
+
+    /// Sets the visibility of the symbol defined by the operation. Like
+    /// `mlir::SymbolTable::setSymbolVisibility`, public visibility is represented by removing
+    /// the visibility attribute.
+    ///
+    /// # Arguments
+    /// * `visibility` - The new visibility of the symbol.
+    pub fn set_visibility(&self, visibility: SymbolVisibility) {
+        if visibility == SymbolVisibility::Public {
+            self.remove_attribute(visibility_attribute_name());
+        } else {
+            let visibility = StringAttributeRef::new(self.context(), visibility.as_str());
+            self.set_attribute(visibility_attribute_name(), visibility);
+        }
+    }
+
+    /// Renames a symbol defined directly within the body of the operation (e.g. a function in a
+    /// module), replacing every reference to it (e.g. by `func.call` operations or symbol
+    /// reference attributes) nested within the operation as well.
+    ///
+    /// # Arguments
+    /// * `old` - The current name of the symbol.
+    /// * `new` - The new name of the symbol.
+    ///
+    /// # Returns
+    /// Returns whether all references to the symbol could be replaced. If they could not, the
+    /// definition of the symbol is not renamed.
+    pub fn rename_symbol(&self, old: &str, new: &str) -> bool {
+        let result = unsafe {
+            mlirSymbolTableReplaceAllSymbolUses(
+                StringRef::from(&old).to_raw(),
+                StringRef::from(&new).to_raw(),
+                self.to_raw(),
+            )
+        };
+        if result.value == 0 {
+            return false;
+        }
+
+        let body = (self.num_regions() > 0)
+            .then(|| self.region(0).first_block())
+            .flatten();
+        for operation in body.into_iter().flat_map(|block| block.operations()) {
+            if operation
+                .symbol_name()
+                .is_some_and(|name| name.as_str() == old)
+            {
+                operation.set_symbol_name(new);
+            }
+        }
+        true
+    }
+}
+
+/// # Returns
+/// Returns the name of the attribute holding the name of a symbol.
+pub(crate) fn symbol_attribute_name() -> &'static str {
+    unsafe { StringRef::from_raw(mlirSymbolTableGetSymbolAttributeName()).as_str() }
+}
+
+/// # Returns
+/// Returns the name of the attribute holding the visibility of a symbol.
+fn visibility_attribute_name() -> &'static str {
+    unsafe { StringRef::from_raw(mlirSymbolTableGetVisibilityAttributeName()).as_str() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::Operation, Context, DialectRegistry};
+
+    fn context() -> Context {
+        let dialect_registry = DialectRegistry::default();
+        dialect_registry.register_all_dialects();
+        let context = Context::new(Some(&dialect_registry), false);
+        context.load_all_available_dialects();
+        context
+    }
+
+    #[test]
+    fn symbol_name() {
+        let context = context();
+        let function = Operation::parse(&context, "func.func private @f()", "").unwrap();
+        assert_eq!(function.symbol_name().unwrap().as_str(), "f");
+        function.set_symbol_name("g");
+        assert_eq!(function.symbol_name().unwrap().as_str(), "g");
+        assert!(function.verify());
+        assert_eq!(function.to_string(), "func.func private @g()\n");
+
+        let module = Operation::parse(&context, "module {\n}", "").unwrap();
+        assert!(module.symbol_name().is_none());
+    }
+
+    #[test]
+    fn visibility() {
+        let context = context();
+        let function = Operation::parse(&context, "func.func private @f()", "").unwrap();
+        assert_eq!(function.visibility(), SymbolVisibility::Private);
+        function.set_visibility(SymbolVisibility::Nested);
+        assert_eq!(function.visibility(), SymbolVisibility::Nested);
+        function.set_visibility(SymbolVisibility::Public);
+        assert_eq!(function.visibility(), SymbolVisibility::Public);
+        assert!(function.attribute("sym_visibility").is_none());
+    }
+
+    #[test]
+    fn rename_symbol() {
+        let context = context();
+        let module_source = r#"
+module {
+  func.func private @f()
+  func.func @g() {
+    func.call @f() : () -> ()
+    return
+  }
+}
+"#
+        .trim_start();
+        let module = Operation::parse(&context, module_source, "").unwrap();
+        assert!(module.rename_symbol("f", "h"));
+        assert!(module.verify());
+        assert_eq!(module.to_string(), module_source.replace("@f", "@h"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_visibility() {
+        let context = context();
+        context.set_allow_unregistered_dialects(true);
+        let operation = Operation::parse(
+            &context,
+            r#""dialect.op"() {sym_visibility = 1 : i32} : () -> ()"#,
+            "",
+        )
+        .unwrap();
+        operation.visibility();
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    ir::{Block, BlockRef},
+    ir::{Block, BlockRef, OperationRef, ValueRef},
     support::binding::{
         impl_owned_mlir_value, impl_unowned_mlir_value, OwnedMlirValue, UnownedMlirValue,
     },
@@ -10,7 +10,9 @@ use std::{marker::PhantomData, mem::forget};
 
 use mlir_sys::{
     mlirRegionAppendOwnedBlock, mlirRegionCreate, mlirRegionDestroy, mlirRegionEqual,
-    mlirRegionGetFirstBlock, mlirRegionGetNextInOperation, MlirRegion,
+    mlirRegionGetFirstBlock, mlirRegionGetNextInOperation, mlirRegionInsertOwnedBlock,
+    mlirRegionInsertOwnedBlockAfter, mlirRegionInsertOwnedBlockBefore, mlirRegionTakeBody,
+    MlirRegion,
 };
 
 /// [Region] wraps the `mlir::Region` class, which represents a region of blocks in the MLIR IR.
@@ -22,8 +24,6 @@ use mlir_sys::{
 /// - `mlirRegionEqual`
 /// - `mlirRegionGetFirstBlock`
 /// - `mlirRegionGetNextInOperation`
-///
-/// The following bindings are not used/supported:
 /// - `mlirRegionInsertOwnedBlock`
 /// - `mlirRegionInsertOwnedBlockAfter`
 /// - `mlirRegionInsertOwnedBlockBefore`
@@ -99,6 +99,86 @@ impl<'c> RegionRef<'c> {
         block_ref
     }
 
+    /// Inserts the given block into the region at the provided position, verifying that the
+    /// position is within bounds (inserting at the end is allowed).
+    ///
+    /// # Arguments
+    /// * `pos` - The position to insert the block at.
+    /// * `block` - The block to insert into the region.
+    ///
+    /// # Returns
+    /// Returns a reference to the inserted block owned by the region.
+    pub fn insert_block<'a>(&'a self, pos: isize, block: Block<'c>) -> &'a BlockRef<'c> {
+        let num_blocks = std::iter::successors(self.first_block(), |block| {
+            block.next_in_parent_region()
+        })
+        .count() as isize;
+        if pos < 0 || pos > num_blocks {
+            panic!("Block position {} out of bounds.", pos);
+        }
+        let block_ref = unsafe { BlockRef::from_raw(block.to_raw()) };
+        unsafe { mlirRegionInsertOwnedBlock(self.to_raw(), pos, block_ref.to_raw()) };
+        forget(block);
+        block_ref
+    }
+
+    /// Inserts the given block into the region after the reference block.
+    ///
+    /// # Arguments
+    /// * `reference` - The block to insert the new block after, which must be in this region.
+    /// * `block` - The block to insert into the region.
+    ///
+    /// # Returns
+    /// Returns a reference to the inserted block owned by the region.
+    pub fn insert_block_after<'a>(
+        &'a self,
+        reference: &BlockRef<'c>,
+        block: Block<'c>,
+    ) -> &'a BlockRef<'c> {
+        if reference.parent_region() != Some(self) {
+            panic!("Reference block is not in this region.");
+        }
+        let block_ref = unsafe { BlockRef::from_raw(block.to_raw()) };
+        unsafe {
+            mlirRegionInsertOwnedBlockAfter(self.to_raw(), reference.to_raw(), block_ref.to_raw())
+        };
+        forget(block);
+        block_ref
+    }
+
+    /// Inserts the given block into the region before the reference block.
+    ///
+    /// # Arguments
+    /// * `reference` - The block to insert the new block before, which must be in this region.
+    /// * `block` - The block to insert into the region.
+    ///
+    /// # Returns
+    /// Returns a reference to the inserted block owned by the region.
+    pub fn insert_block_before<'a>(
+        &'a self,
+        reference: &BlockRef<'c>,
+        block: Block<'c>,
+    ) -> &'a BlockRef<'c> {
+        if reference.parent_region() != Some(self) {
+            panic!("Reference block is not in this region.");
+        }
+        let block_ref = unsafe { BlockRef::from_raw(block.to_raw()) };
+        unsafe {
+            mlirRegionInsertOwnedBlockBefore(self.to_raw(), reference.to_raw(), block_ref.to_raw())
+        };
+        forget(block);
+        block_ref
+    }
+
+    /// Moves all blocks of the source region into this region, leaving the source region empty.
+    /// Any blocks previously in this region are destroyed.
+    ///
+    /// # Arguments
+    /// * `source` - The region to take the blocks from.
+    pub fn take_body(&self, source: &RegionRef<'c>) {
+        unsafe { mlirRegionTakeBody(self.to_raw(), source.to_raw()) }
+    }
+
     /// # Returns
     /// Returns the first block of the region, if it has one.
     pub fn first_block(&self) -> Option<&BlockRef<'c>> {
@@ -111,6 +191,23 @@ impl<'c> RegionRef<'c> {
     pub fn next_in_parent_operation(&self) -> Option<&RegionRef<'c>> {
         unsafe { Self::try_from_raw(mlirRegionGetNextInOperation(self.to_raw())) }
     }
+
+    /// Moves all operations of the single block of the region before the provided operation (see
+    /// [BlockRef::inline_before]), e.g. to inline the body of a call or of an `scf.if`. The block
+    /// is left empty in the region.
+    ///
+    /// # Arguments
+    /// * `target` - The operation to move the operations before, which must not be in the region.
+    /// * `arguments` - The values to replace the arguments of the block with, one per argument.
+    pub fn inline_before(&self, target: &OperationRef<'c>, arguments: &[&ValueRef<'c>]) {
+        let block = self
+            .first_block()
+            .expect("Cannot inline a region without blocks");
+        if block.next_in_parent_region().is_some() {
+            panic!("Cannot inline a region with more than one block");
+        }
+        block.inline_before(target, arguments);
+    }
 }
 
 impl<'c> PartialEq for RegionRef<'c> {
@@ -130,6 +227,83 @@ impl<'c> Eq for RegionRef<'c> {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        ir::{IntegerTypeRef, LocationRef, OperationBuilder},
+        Context,
+    };
+
+    fn block_arg_widths(region: &RegionRef) -> Vec<isize> {
+        std::iter::successors(region.first_block(), |block| block.next_in_parent_region())
+            .map(|block| block.num_arguments())
+            .collect()
+    }
+
+    #[test]
+    fn insert_blocks() {
+        let context = Context::new(None, false);
+        let loc = LocationRef::new_unknown(&context);
+        let i8_type = IntegerTypeRef::new_signless(&context, 8);
+        let region = Region::new(&context);
+        let last = region.append_block(Block::new(&[(i8_type, loc), (i8_type, loc)]));
+        region.insert_block(0, Block::new(&[]));
+        region.insert_block_before(last, Block::new(&[(i8_type, loc)]));
+        region.insert_block_after(last, Block::new(&[(i8_type.as_type(), loc); 3]));
+        assert_eq!(block_arg_widths(&region), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn move_block_between_regions() {
+        let context = Context::new(None, false);
+        let loc = LocationRef::new_unknown(&context);
+        let i8_type = IntegerTypeRef::new_signless(&context, 8);
+        let source = Region::new(&context);
+        let target = Region::new(&context);
+        let block = source.append_block(Block::new(&[(i8_type, loc)]));
+        target.append_block(Block::new(&[]));
+
+        let block = target.insert_block(0, block.detach().unwrap());
+        assert!(source.first_block().is_none());
+        assert_eq!(block.parent_region().unwrap(), &target);
+        assert_eq!(block_arg_widths(&target), vec![1, 0]);
+    }
+
+    #[test]
+    fn take_body() {
+        let context = Context::new(None, false);
+        let loc = LocationRef::new_unknown(&context);
+        let i8_type = IntegerTypeRef::new_signless(&context, 8);
+        let source = Region::new(&context);
+        let target = Region::new(&context);
+        source.append_block(Block::new(&[(i8_type, loc)]));
+        source.append_block(Block::new(&[(i8_type, loc), (i8_type, loc)]));
+        target.append_block(Block::new(&[]));
+
+        target.take_body(&source);
+        assert!(source.first_block().is_none());
+        assert_eq!(block_arg_widths(&target), vec![1, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn inline_multiple_blocks() {
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let loc = LocationRef::new_unknown(&context);
+        let region = Region::new(&context);
+        region.append_block(Block::new(&[]));
+        region.append_block(Block::new(&[]));
+        let target = Block::new(&[]);
+        let op = target.append_operation(OperationBuilder::new("dialect.op", loc).build().unwrap());
+        region.inline_before(op, &[]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_block_out_of_bounds() {
+        let context = Context::new(None, false);
+        let region = Region::new(&context);
+        region.insert_block(1, Block::new(&[]));
+    }
 
     #[test]
     #[should_panic]
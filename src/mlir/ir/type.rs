@@ -1,14 +1,28 @@
+mod complex;
+mod dispatch;
 mod float;
+mod function;
+mod index;
 mod integer;
+mod memref;
 mod none;
+mod quant;
+mod ranked_tensor;
+mod shaped;
+mod unranked_memref;
+mod unranked_tensor;
+mod vector;
 
-pub use self::{float::*, integer::*, none::*};
+pub use self::{
+    complex::*, dispatch::*, float::*, function::*, index::*, integer::*, memref::*, none::*,
+    quant::*, ranked_tensor::*, shaped::*, unranked_memref::*, unranked_tensor::*, vector::*,
+};
 use crate::{
     support::{
-        binding::{impl_unowned_mlir_value, UnownedMlirValue},
+        binding::{impl_unowned_mlir_value, OwnedMlirValue, UnownedMlirValue},
         string_reader::StringReader,
     },
-    ContextRef, DialectRef, StringRef,
+    ContextRef, DialectRef, StringRef, TypeId,
 };
 
 use std::{
@@ -17,8 +31,8 @@ use std::{
 };
 
 use mlir_sys::{
-    mlirTypeEqual, mlirTypeGetContext, mlirTypeGetDialect, mlirTypeParseGet, mlirTypePrint,
-    MlirType,
+    mlirTypeEqual, mlirTypeGetContext, mlirTypeGetDialect, mlirTypeGetTypeID, mlirTypeParseGet,
+    mlirTypePrint, MlirType,
 };
 
 /// [TypeRef] is a reference to an instance of the `mlir::Type` class, which represents a type in
@@ -28,12 +42,12 @@ use mlir_sys::{
 /// - `mlirTypeEqual`
 /// - `mlirTypeGetContext`
 /// - `mlirTypeGetDialect`
+/// - `mlirTypeGetTypeID`
 /// - `mlirTypeParseGet`
 /// - `mlirTypePrint`
 ///
 /// The following bindings are not used/supported:
 /// - `mlirTypeDump`
-/// - `mlirTypeGetTypeID`
 #[repr(transparent)]
 #[derive(Debug)]
 pub struct TypeRef {
@@ -71,6 +85,106 @@ impl TypeRef {
     pub fn dialect(&self) -> &DialectRef {
         unsafe { DialectRef::from_raw(mlirTypeGetDialect(self.to_raw())) }
     }
+
+    /// # Returns
+    /// Returns the [TypeId] of the class of the type.
+    pub fn type_id(&self) -> TypeId {
+        unsafe { TypeId::from_raw(mlirTypeGetTypeID(self.to_raw())) }
+    }
+
+    /// Invokes the handler registered for the class of the type in the provided registry.
+    ///
+    /// # Arguments
+    /// * `registry` - The registry to look the handler up in.
+    ///
+    /// # Returns
+    /// Returns the result of the handler, or `None` if no handler is registered for the class of
+    /// the type.
+    pub fn dispatch<R>(&self, registry: &TypeDispatchRegistry<R>) -> Option<R> {
+        registry.dispatch(self)
+    }
+
+    /// Classifies the type by checking it against each of the supported type variants, so that
+    /// it can be matched on directly.
+    ///
+    /// # Returns
+    /// Returns the [Type] variant that the type belongs to.
+    pub fn classify(&self) -> Type {
+        if let Some(ty) = IntegerTypeRef::try_from_type(self) {
+            Type::Integer(ty)
+        } else if let Some(ty) = FloatTypeRef::try_from_type(self) {
+            Type::Float(ty)
+        } else if let Some(ty) = IndexTypeRef::try_from_type(self) {
+            Type::Index(ty)
+        } else if let Some(ty) = NoneTypeRef::try_from_type(self) {
+            Type::None(ty)
+        } else if let Some(ty) = ComplexTypeRef::try_from_type(self) {
+            Type::Complex(ty)
+        } else if let Some(ty) = FunctionTypeRef::try_from_type(self) {
+            Type::Function(ty)
+        } else if let Some(ty) = VectorTypeRef::try_from_type(self) {
+            Type::Vector(ty)
+        } else if let Some(ty) = RankedTensorTypeRef::try_from_type(self) {
+            Type::RankedTensor(ty)
+        } else if let Some(ty) = UnrankedTensorTypeRef::try_from_type(self) {
+            Type::UnrankedTensor(ty)
+        } else if let Some(ty) = MemRefTypeRef::try_from_type(self) {
+            Type::MemRef(ty)
+        } else if let Some(ty) = UnrankedMemRefTypeRef::try_from_type(self) {
+            Type::UnrankedMemRef(ty)
+        } else if let Some(ty) = ShapedTypeRef::try_from_type(self) {
+            Type::Shaped(ty)
+        } else if let Some(ty) = QuantizedTypeRef::try_from_type(self) {
+            Type::Quantized(ty)
+        } else {
+            Type::Other(self)
+        }
+    }
+}
+
+/// [Type] is the result of classifying a [TypeRef] into one of the supported type variants, which
+/// allows matching on a type instead of attempting each variant in turn.
+#[derive(Debug, Clone, Copy)]
+pub enum Type<'a> {
+    Integer(&'a IntegerTypeRef),
+    Float(&'a FloatTypeRef),
+    Index(&'a IndexTypeRef),
+    None(&'a NoneTypeRef),
+    Complex(&'a ComplexTypeRef),
+    Function(&'a FunctionTypeRef),
+    Vector(&'a VectorTypeRef),
+    RankedTensor(&'a RankedTensorTypeRef),
+    UnrankedTensor(&'a UnrankedTensorTypeRef),
+    MemRef(&'a MemRefTypeRef),
+    UnrankedMemRef(&'a UnrankedMemRefTypeRef),
+    /// Any other shaped type that is not one of the variants above.
+    Shaped(&'a ShapedTypeRef),
+    Quantized(&'a QuantizedTypeRef),
+    /// A type that does not belong to any of the supported variants.
+    Other(&'a TypeRef),
+}
+
+impl<'a> Type<'a> {
+    /// # Returns
+    /// Returns the classified type as a [TypeRef].
+    pub fn as_type(&self) -> &'a TypeRef {
+        match *self {
+            Self::Integer(ty) => ty.as_type(),
+            Self::Float(ty) => ty.as_type(),
+            Self::Index(ty) => ty.as_type(),
+            Self::None(ty) => ty.as_type(),
+            Self::Complex(ty) => ty.as_type(),
+            Self::Function(ty) => ty.as_type(),
+            Self::Vector(ty) => ty.as_type(),
+            Self::RankedTensor(ty) => ty.as_type(),
+            Self::UnrankedTensor(ty) => ty.as_type(),
+            Self::MemRef(ty) => ty.as_type(),
+            Self::UnrankedMemRef(ty) => ty.as_type(),
+            Self::Shaped(ty) => ty.as_type(),
+            Self::Quantized(ty) => ty.as_type(),
+            Self::Other(ty) => ty,
+        }
+    }
 }
 
 impl PartialEq for TypeRef {
@@ -91,6 +205,9 @@ impl Display for TypeRef {
 
 macro_rules! impl_type_variant {
     ($variant_type:ident, $verify_fn:ident) => {
+        impl_type_variant!($variant_type, $verify_fn, $crate::ir::TypeRef);
+    };
+    ($variant_type:ident, $verify_fn:ident, $target_type:ty) => {
         impl $variant_type {
             pub fn try_from_type(ty: &$crate::ir::TypeRef) -> Option<&Self> {
                 let types_match = unsafe { $verify_fn(ty.to_raw()) };
@@ -107,10 +224,10 @@ macro_rules! impl_type_variant {
         }
 
         impl std::ops::Deref for $variant_type {
-            type Target = $crate::ir::TypeRef;
+            type Target = $target_type;
 
             fn deref(&self) -> &Self::Target {
-                self.as_type()
+                unsafe { <$target_type>::from_raw(self.to_raw()) }
             }
         }
     };
@@ -161,6 +278,28 @@ mod tests {
         assert_ne!(type3, type2);
     }
 
+    #[test]
+    fn classify() {
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let classify = |source| TypeRef::parse(&context, source).unwrap().classify();
+        assert!(matches!(classify("i32"), Type::Integer(ty) if ty.bitwidth() == 32));
+        assert!(matches!(classify("f64"), Type::Float(_)));
+        assert!(matches!(classify("index"), Type::Index(_)));
+        assert!(matches!(classify("none"), Type::None(_)));
+        assert!(matches!(classify("complex<f32>"), Type::Complex(_)));
+        assert!(matches!(classify("(i32) -> ()"), Type::Function(_)));
+        assert!(matches!(classify("vector<4xi8>"), Type::Vector(_)));
+        assert!(matches!(classify("tensor<4xi8>"), Type::RankedTensor(_)));
+        assert!(matches!(classify("tensor<*xi8>"), Type::UnrankedTensor(_)));
+        assert!(matches!(classify("memref<4xi8>"), Type::MemRef(_)));
+        assert!(matches!(classify("memref<*xi8>"), Type::UnrankedMemRef(_)));
+        assert!(matches!(classify("!dialect.type"), Type::Other(_)));
+
+        let ty = TypeRef::parse(&context, "vector<4xi8>").unwrap();
+        assert_eq!(ty.classify().as_type(), ty);
+    }
+
     #[test]
     #[should_panic]
     fn no_owned_type_ref() {
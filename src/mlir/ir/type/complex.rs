@@ -0,0 +1,84 @@
+use super::impl_type_variant;
+use crate::{
+    ir::TypeRef,
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{mlirComplexTypeGet, mlirComplexTypeGetElementType, mlirTypeIsAComplex, MlirType};
+
+/// [ComplexTypeRef] is a reference to an instance of the `mlir::ComplexType` class, which
+/// represents a complex number with real and imaginary parts of the same element type.
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirComplexTypeGet`
+/// - `mlirComplexTypeGetElementType`
+/// - `mlirTypeIsAComplex`
+///
+/// The following bindings are not used/supported:
+/// - `mlirComplexTypeGetTypeID`
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct ComplexTypeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, ComplexTypeRef, MlirType);
+impl_type_variant!(ComplexTypeRef, mlirTypeIsAComplex);
+
+impl ComplexTypeRef {
+    /// Constructs a new complex type with the provided element type. The type is owned by the
+    /// same context that owns its element type.
+    ///
+    /// # Arguments
+    /// * `element_type` - The type of the real and imaginary parts (an integer or float type).
+    ///
+    /// # Returns
+    /// Returns a reference to a new [ComplexTypeRef] instance.
+    pub fn new(element_type: &TypeRef) -> &Self {
+        unsafe { Self::from_raw(mlirComplexTypeGet(element_type.to_raw())) }
+    }
+
+    /// # Returns
+    /// Returns the element type of the complex type.
+    pub fn element_type(&self) -> &TypeRef {
+        unsafe { TypeRef::from_raw(mlirComplexTypeGetElementType(self.to_raw())) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::FloatTypeRef, Context};
+
+    #[test]
+    fn new() {
+        let context = Context::new(None, false);
+        let f32_type = FloatTypeRef::new_f32(&context);
+        let complex_type = ComplexTypeRef::new(f32_type);
+        assert_eq!(complex_type.to_string(), "complex<f32>");
+        assert_eq!(complex_type.element_type(), f32_type.as_type());
+    }
+
+    #[test]
+    fn from_type() {
+        let context = Context::new(None, false);
+        let erased_complex_type = TypeRef::parse(&context, "complex<f64>").unwrap();
+        let erased_float_type = TypeRef::parse(&context, "f64").unwrap();
+        assert!(ComplexTypeRef::try_from_type(erased_complex_type).is_some());
+        assert!(ComplexTypeRef::try_from_type(erased_float_type).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_complex_type_ref() {
+        let _complex_type_ref = ComplexTypeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
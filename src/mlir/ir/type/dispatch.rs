@@ -0,0 +1,132 @@
+use crate::{ir::TypeRef, TypeId};
+
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter},
+};
+
+/// A handler registered in a [TypeDispatchRegistry].
+type TypeHandler<'h, R> = Box<dyn Fn(&TypeRef) -> R + 'h>;
+
+/// [TypeDispatchRegistry] maps the [TypeId] of a type class to a handler, so that the handler for
+/// a type can be found with a single lookup instead of checking the type against each variant in
+/// turn. This is mainly useful for types from custom dialects, which have no variant in this
+/// crate.
+///
+/// # Type Parameters
+/// * `'h` - The lifetime of the registered handlers.
+/// * `R` - The result type of the handlers.
+pub struct TypeDispatchRegistry<'h, R> {
+    handlers: HashMap<TypeId, TypeHandler<'h, R>>,
+}
+
+impl<'h, R> TypeDispatchRegistry<'h, R> {
+    /// # Returns
+    /// Returns a new, empty [TypeDispatchRegistry].
+    pub fn new() -> Self {
+        Self {
+            handlers: HashMap::new(),
+        }
+    }
+
+    /// Registers a handler for the type class with the provided [TypeId], replacing any handler
+    /// previously registered for it.
+    ///
+    /// # Arguments
+    /// * `type_id` - The [TypeId] of the type class.
+    /// * `handler` - The handler to invoke for types of the class.
+    pub fn register<F>(&mut self, type_id: TypeId, handler: F)
+    where
+        F: Fn(&TypeRef) -> R + 'h,
+    {
+        self.handlers.insert(type_id, Box::new(handler));
+    }
+
+    /// Registers a handler for the class of the provided type, replacing any handler previously
+    /// registered for it.
+    ///
+    /// # Arguments
+    /// * `ty` - A type of the class to register the handler for.
+    /// * `handler` - The handler to invoke for types of the class.
+    pub fn register_for<F>(&mut self, ty: &TypeRef, handler: F)
+    where
+        F: Fn(&TypeRef) -> R + 'h,
+    {
+        self.register(ty.type_id(), handler);
+    }
+
+    /// # Arguments
+    /// * `type_id` - The [TypeId] of the type class.
+    ///
+    /// # Returns
+    /// Returns whether a handler is registered for the type class.
+    pub fn contains(&self, type_id: TypeId) -> bool {
+        self.handlers.contains_key(&type_id)
+    }
+
+    /// Invokes the handler registered for the class of the provided type.
+    ///
+    /// # Arguments
+    /// * `ty` - The type to dispatch.
+    ///
+    /// # Returns
+    /// Returns the result of the handler, or `None` if no handler is registered for the class of
+    /// the type.
+    pub fn dispatch(&self, ty: &TypeRef) -> Option<R> {
+        self.handlers.get(&ty.type_id()).map(|handler| handler(ty))
+    }
+}
+
+impl<'h, R> Default for TypeDispatchRegistry<'h, R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'h, R> Debug for TypeDispatchRegistry<'h, R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TypeDispatchRegistry")
+            .field("handlers", &self.handlers.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::IntegerTypeRef, Context};
+
+    #[test]
+    fn dispatch() {
+        let context = Context::new(None, false);
+        let i32_type = TypeRef::parse(&context, "i32").unwrap();
+        let f32_type = TypeRef::parse(&context, "f32").unwrap();
+        let index_type = TypeRef::parse(&context, "index").unwrap();
+
+        let mut registry = TypeDispatchRegistry::new();
+        registry.register_for(i32_type, |ty| {
+            format!(
+                "int{}",
+                IntegerTypeRef::try_from_type(ty).unwrap().bitwidth()
+            )
+        });
+        registry.register(f32_type.type_id(), |ty| format!("float:{}", ty));
+        assert!(registry.contains(i32_type.type_id()));
+        assert!(!registry.contains(index_type.type_id()));
+
+        let i8_type = TypeRef::parse(&context, "i8").unwrap();
+        assert_eq!(i8_type.dispatch(&registry), Some("int8".to_string()));
+        assert_eq!(f32_type.dispatch(&registry), Some("float:f32".to_string()));
+        assert_eq!(index_type.dispatch(&registry), None);
+    }
+
+    #[test]
+    fn register_replaces_handler() {
+        let context = Context::new(None, false);
+        let i32_type = TypeRef::parse(&context, "i32").unwrap();
+        let mut registry = TypeDispatchRegistry::default();
+        registry.register_for(i32_type, |_| 1);
+        registry.register_for(i32_type, |_| 2);
+        assert_eq!(registry.dispatch(i32_type), Some(2));
+    }
+}
@@ -6,24 +6,46 @@ use crate::{
 
 use std::marker::PhantomData;
 
-use mlir_sys::{mlirF32TypeGet, mlirF64TypeGet, mlirTypeIsAF32, mlirTypeIsAF64, MlirType};
+use mlir_sys::{
+    mlirBF16TypeGet, mlirF16TypeGet, mlirF32TypeGet, mlirF64TypeGet, mlirFloat8E4M3B11FNUZTypeGet,
+    mlirFloat8E4M3FNTypeGet, mlirFloat8E4M3FNUZTypeGet, mlirFloat8E5M2FNUZTypeGet,
+    mlirFloat8E5M2TypeGet, mlirTF32TypeGet, mlirTypeIsABF16, mlirTypeIsAF16, mlirTypeIsAF32,
+    mlirTypeIsAF64, mlirTypeIsAFloat8E4M3B11FNUZ, mlirTypeIsAFloat8E4M3FN,
+    mlirTypeIsAFloat8E4M3FNUZ, mlirTypeIsAFloat8E5M2, mlirTypeIsAFloat8E5M2FNUZ, mlirTypeIsATF32,
+    MlirContext, MlirType,
+};
 
 /// [FloatTypeRef] is a reference to an instance of the `mlir::FloatType` class, which represents a
 /// floating-point type in the MLIR IR.
 ///
 /// The following bindings into the MLIR C API are used/supported:
+/// - `mlirBF16TypeGet`
+/// - `mlirF16TypeGet`
 /// - `mlirF32TypeGet`
 /// - `mlirF64TypeGet`
+/// - `mlirFloat8E4M3B11FNUZTypeGet`
+/// - `mlirFloat8E4M3FNTypeGet`
+/// - `mlirFloat8E4M3FNUZTypeGet`
+/// - `mlirFloat8E5M2FNUZTypeGet`
+/// - `mlirFloat8E5M2TypeGet`
+/// - `mlirTF32TypeGet`
+/// - `mlirTypeIsABF16`
+/// - `mlirTypeIsAF16`
 /// - `mlirTypeIsAF32`
 /// - `mlirTypeIsAF64`
+/// - `mlirTypeIsAFloat8E4M3B11FNUZ`
+/// - `mlirTypeIsAFloat8E4M3FN`
+/// - `mlirTypeIsAFloat8E4M3FNUZ`
+/// - `mlirTypeIsAFloat8E5M2`
+/// - `mlirTypeIsAFloat8E5M2FNUZ`
+/// - `mlirTypeIsATF32`
 ///
 /// The following bindings are not used/supported:
-/// - `mlirF16TypeGet`
 /// - `mlirFloat16GetTypeID`
 /// - `mlirFloat32GetTypeID`
 /// - `mlirFloat64GetTypeID`
-/// - `mlirTypeIsAF16`
-/// - (functions for other variants such as BF16, TF32, ...)
+/// - `mlirFloat8*TypeGetTypeID`
+/// - (functions for other variants such as F80, F128, ...)
 ///
 /// # Safety
 /// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
@@ -34,7 +56,102 @@ pub struct FloatTypeRef {
     _prevent_external_instantiation: PhantomData<()>,
 }
 
-/// Checks if the given raw MLIR type is a `f32` or `f64` type.
+/// [FloatKind] identifies one of the floating point types supported by [FloatTypeRef].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FloatKind {
+    F8E5M2,
+    F8E4M3FN,
+    F8E5M2FNUZ,
+    F8E4M3FNUZ,
+    F8E4M3B11FNUZ,
+    BF16,
+    F16,
+    TF32,
+    F32,
+    F64,
+}
+
+impl FloatKind {
+    const ALL: [Self; 10] = [
+        Self::F8E5M2,
+        Self::F8E4M3FN,
+        Self::F8E5M2FNUZ,
+        Self::F8E4M3FNUZ,
+        Self::F8E4M3B11FNUZ,
+        Self::BF16,
+        Self::F16,
+        Self::TF32,
+        Self::F32,
+        Self::F64,
+    ];
+
+    /// # Returns
+    /// Returns the bitwidth of floating point types of this kind.
+    pub fn bitwidth(&self) -> u32 {
+        match self {
+            Self::F8E5M2
+            | Self::F8E4M3FN
+            | Self::F8E5M2FNUZ
+            | Self::F8E4M3FNUZ
+            | Self::F8E4M3B11FNUZ => 8,
+            Self::BF16 | Self::F16 => 16,
+            Self::TF32 => 19,
+            Self::F32 => 32,
+            Self::F64 => 64,
+        }
+    }
+
+    /// Checks if the given raw MLIR type is of this kind.
+    ///
+    /// # Safety
+    /// The given raw MLIR type must point to a valid MLIR type instance.
+    unsafe fn matches(&self, ty: MlirType) -> bool {
+        match self {
+            Self::F8E5M2 => mlirTypeIsAFloat8E5M2(ty),
+            Self::F8E4M3FN => mlirTypeIsAFloat8E4M3FN(ty),
+            Self::F8E5M2FNUZ => mlirTypeIsAFloat8E5M2FNUZ(ty),
+            Self::F8E4M3FNUZ => mlirTypeIsAFloat8E4M3FNUZ(ty),
+            Self::F8E4M3B11FNUZ => mlirTypeIsAFloat8E4M3B11FNUZ(ty),
+            Self::BF16 => mlirTypeIsABF16(ty),
+            Self::F16 => mlirTypeIsAF16(ty),
+            Self::TF32 => mlirTypeIsATF32(ty),
+            Self::F32 => mlirTypeIsAF32(ty),
+            Self::F64 => mlirTypeIsAF64(ty),
+        }
+    }
+
+    /// Gets the raw MLIR type of this kind.
+    ///
+    /// # Safety
+    /// The given raw MLIR context must point to a valid MLIR context instance.
+    unsafe fn get(&self, context: MlirContext) -> MlirType {
+        match self {
+            Self::F8E5M2 => mlirFloat8E5M2TypeGet(context),
+            Self::F8E4M3FN => mlirFloat8E4M3FNTypeGet(context),
+            Self::F8E5M2FNUZ => mlirFloat8E5M2FNUZTypeGet(context),
+            Self::F8E4M3FNUZ => mlirFloat8E4M3FNUZTypeGet(context),
+            Self::F8E4M3B11FNUZ => mlirFloat8E4M3B11FNUZTypeGet(context),
+            Self::BF16 => mlirBF16TypeGet(context),
+            Self::F16 => mlirF16TypeGet(context),
+            Self::TF32 => mlirTF32TypeGet(context),
+            Self::F32 => mlirF32TypeGet(context),
+            Self::F64 => mlirF64TypeGet(context),
+        }
+    }
+
+    /// Finds the kind of the given raw MLIR type.
+    ///
+    /// # Safety
+    /// The given raw MLIR type must point to a valid MLIR type instance.
+    unsafe fn of(ty: MlirType) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.matches(ty))
+    }
+}
+
+/// Checks if the given raw MLIR type is one of the floating point types listed in [FloatKind].
+///
+/// The MLIR C API only gained a generic `mlirTypeIsAFloat` check (and `mlirFloatTypeGetWidth`)
+/// after MLIR 18, so every supported kind is checked individually instead.
 ///
 /// # Safety
 /// The given raw MLIR type must point to a valid MLIR type instance.
@@ -45,13 +162,58 @@ pub struct FloatTypeRef {
 /// # Returns
 /// Returns whether the type is a floating-point type.
 unsafe fn mlir_type_is_a_float(ty: MlirType) -> bool {
-    mlirTypeIsAF32(ty) || mlirTypeIsAF64(ty)
+    FloatKind::of(ty).is_some()
 }
 
 impl_unowned_mlir_value!(no_refs, FloatTypeRef, MlirType);
 impl_type_variant!(FloatTypeRef, mlir_type_is_a_float);
 
 impl FloatTypeRef {
+    /// Constructs a new floating point type of the provided kind.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the type.
+    /// * `kind` - The kind of floating point type to construct.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [FloatTypeRef] instance.
+    pub fn new(context: &ContextRef, kind: FloatKind) -> &Self {
+        unsafe { Self::from_raw(kind.get(context.to_raw())) }
+    }
+
+    /// Constructs a new 16-bit (IEEE half precision) floating point type.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the type.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [FloatTypeRef] instance.
+    pub fn new_f16(context: &ContextRef) -> &Self {
+        unsafe { Self::from_raw(mlirF16TypeGet(context.to_raw())) }
+    }
+
+    /// Constructs a new 16-bit bfloat16 (brain floating point) type.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the type.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [FloatTypeRef] instance.
+    pub fn new_bf16(context: &ContextRef) -> &Self {
+        unsafe { Self::from_raw(mlirBF16TypeGet(context.to_raw())) }
+    }
+
+    /// Constructs a new 19-bit TensorFloat-32 floating point type.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the type.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [FloatTypeRef] instance.
+    pub fn new_tf32(context: &ContextRef) -> &Self {
+        unsafe { Self::from_raw(mlirTF32TypeGet(context.to_raw())) }
+    }
+
     /// Constructs a new 32-bit floating point type.
     ///
     /// # Arguments
@@ -74,16 +236,129 @@ impl FloatTypeRef {
         unsafe { Self::from_raw(mlirF64TypeGet(context.to_raw())) }
     }
 
+    /// Constructs a new 8-bit floating point type with 5 exponent and 2 mantissa bits (`f8E5M2`).
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the type.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [FloatTypeRef] instance.
+    pub fn new_f8e5m2(context: &ContextRef) -> &Self {
+        unsafe { Self::from_raw(mlirFloat8E5M2TypeGet(context.to_raw())) }
+    }
+
+    /// Constructs a new 8-bit floating point type with 4 exponent and 3 mantissa bits, finite values only and
+    /// no negative zero (`f8E4M3FN`).
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the type.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [FloatTypeRef] instance.
+    pub fn new_f8e4m3fn(context: &ContextRef) -> &Self {
+        unsafe { Self::from_raw(mlirFloat8E4M3FNTypeGet(context.to_raw())) }
+    }
+
+    /// Constructs a new 8-bit floating point type with 5 exponent and 2 mantissa bits, finite values only and
+    /// an unsigned zero (`f8E5M2FNUZ`).
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the type.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [FloatTypeRef] instance.
+    pub fn new_f8e5m2fnuz(context: &ContextRef) -> &Self {
+        unsafe { Self::from_raw(mlirFloat8E5M2FNUZTypeGet(context.to_raw())) }
+    }
+
+    /// Constructs a new 8-bit floating point type with 4 exponent and 3 mantissa bits, finite values only and
+    /// an unsigned zero (`f8E4M3FNUZ`).
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the type.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [FloatTypeRef] instance.
+    pub fn new_f8e4m3fnuz(context: &ContextRef) -> &Self {
+        unsafe { Self::from_raw(mlirFloat8E4M3FNUZTypeGet(context.to_raw())) }
+    }
+
+    /// Constructs a new 8-bit floating point type with 4 exponent and 3 mantissa bits, an exponent bias of
+    /// 11, finite values only and an unsigned zero (`f8E4M3B11FNUZ`).
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the type.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [FloatTypeRef] instance.
+    pub fn new_f8e4m3b11fnuz(context: &ContextRef) -> &Self {
+        unsafe { Self::from_raw(mlirFloat8E4M3B11FNUZTypeGet(context.to_raw())) }
+    }
+
     /// # Returns
     /// Returns the bitwidth of the floating point type.
     pub fn bitwidth(&self) -> u32 {
-        if unsafe { mlirTypeIsAF32(self.to_raw()) } {
-            32
-        } else if unsafe { mlirTypeIsAF64(self.to_raw()) } {
-            64
-        } else {
-            unreachable!("Invalid floating point type.")
-        }
+        self.kind().bitwidth()
+    }
+
+    /// # Returns
+    /// Returns the kind of the floating point type.
+    pub fn kind(&self) -> FloatKind {
+        unsafe { FloatKind::of(self.to_raw()) }.expect("Invalid floating point type.")
+    }
+
+    /// # Returns
+    /// Returns whether the type is the `f16` type.
+    pub fn is_f16(&self) -> bool {
+        unsafe { mlirTypeIsAF16(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns whether the type is the `bf16` type.
+    pub fn is_bf16(&self) -> bool {
+        unsafe { mlirTypeIsABF16(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns whether the type is the `tf32` type.
+    pub fn is_tf32(&self) -> bool {
+        unsafe { mlirTypeIsATF32(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns whether the type is the `f8E5M2` type.
+    pub fn is_f8e5m2(&self) -> bool {
+        unsafe { mlirTypeIsAFloat8E5M2(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns whether the type is the `f8E4M3FN` type.
+    pub fn is_f8e4m3fn(&self) -> bool {
+        unsafe { mlirTypeIsAFloat8E4M3FN(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns whether the type is the `f8E5M2FNUZ` type.
+    pub fn is_f8e5m2fnuz(&self) -> bool {
+        unsafe { mlirTypeIsAFloat8E5M2FNUZ(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns whether the type is the `f8E4M3FNUZ` type.
+    pub fn is_f8e4m3fnuz(&self) -> bool {
+        unsafe { mlirTypeIsAFloat8E4M3FNUZ(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns whether the type is the `f8E4M3B11FNUZ` type.
+    pub fn is_f8e4m3b11fnuz(&self) -> bool {
+        unsafe { mlirTypeIsAFloat8E4M3B11FNUZ(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns whether the type is one of the 8-bit floating point types.
+    pub fn is_f8(&self) -> bool {
+        self.bitwidth() == 8
     }
 }
 
@@ -99,6 +374,64 @@ mod tests {
         let f64_type = FloatTypeRef::new_f64(&context);
         assert_eq!(f32_type.bitwidth(), 32);
         assert_eq!(f64_type.bitwidth(), 64);
+        assert_eq!(FloatTypeRef::new_f16(&context).bitwidth(), 16);
+        assert_eq!(FloatTypeRef::new_bf16(&context).bitwidth(), 16);
+        assert_eq!(FloatTypeRef::new_tf32(&context).bitwidth(), 19);
+    }
+
+    #[test]
+    fn half_precision_types() {
+        let context = Context::new(None, false);
+        let f16_type = FloatTypeRef::new_f16(&context);
+        let bf16_type = FloatTypeRef::new_bf16(&context);
+        let tf32_type = FloatTypeRef::new_tf32(&context);
+        assert_eq!(f16_type.to_string(), "f16");
+        assert_eq!(bf16_type.to_string(), "bf16");
+        assert_eq!(tf32_type.to_string(), "tf32");
+        assert!(f16_type.is_f16() && !f16_type.is_bf16() && !f16_type.is_tf32());
+        assert!(bf16_type.is_bf16() && !bf16_type.is_f16());
+        assert!(tf32_type.is_tf32());
+        for name in ["f16", "bf16", "tf32"] {
+            let erased_type = TypeRef::parse(&context, name).unwrap();
+            assert!(FloatTypeRef::try_from_type(erased_type).is_some());
+        }
+    }
+
+    #[test]
+    fn new() {
+        let context = Context::new(None, false);
+        for kind in FloatKind::ALL {
+            let float_type = FloatTypeRef::new(&context, kind);
+            assert_eq!(float_type.kind(), kind);
+            assert_eq!(float_type.bitwidth(), kind.bitwidth());
+        }
+        assert_eq!(
+            FloatTypeRef::new(&context, FloatKind::F64).as_type(),
+            FloatTypeRef::new_f64(&context).as_type()
+        );
+    }
+
+    #[test]
+    fn float8_types() {
+        let context = Context::new(None, false);
+        let float8_types = [
+            (FloatTypeRef::new_f8e5m2(&context), "f8E5M2"),
+            (FloatTypeRef::new_f8e4m3fn(&context), "f8E4M3FN"),
+            (FloatTypeRef::new_f8e5m2fnuz(&context), "f8E5M2FNUZ"),
+            (FloatTypeRef::new_f8e4m3fnuz(&context), "f8E4M3FNUZ"),
+            (FloatTypeRef::new_f8e4m3b11fnuz(&context), "f8E4M3B11FNUZ"),
+        ];
+        for (float8_type, name) in float8_types {
+            assert_eq!(float8_type.to_string(), name);
+            assert_eq!(float8_type.bitwidth(), 8);
+            assert!(float8_type.is_f8());
+            let erased_type = TypeRef::parse(&context, name).unwrap();
+            assert!(FloatTypeRef::try_from_type(erased_type).is_some());
+        }
+        assert!(FloatTypeRef::new_f8e5m2(&context).is_f8e5m2());
+        assert!(!FloatTypeRef::new_f8e5m2(&context).is_f8e4m3fn());
+        assert!(FloatTypeRef::new_f8e4m3b11fnuz(&context).is_f8e4m3b11fnuz());
+        assert!(!FloatTypeRef::new_f16(&context).is_f8());
     }
 
     #[test]
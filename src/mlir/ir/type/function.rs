@@ -0,0 +1,161 @@
+use super::impl_type_variant;
+use crate::{
+    ir::TypeRef,
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+    ContextRef,
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{
+    mlirFunctionTypeGet, mlirFunctionTypeGetInput, mlirFunctionTypeGetNumInputs,
+    mlirFunctionTypeGetNumResults, mlirFunctionTypeGetResult, mlirTypeIsAFunction, MlirType,
+};
+
+/// [FunctionTypeRef] is a reference to an instance of the `mlir::FunctionType` class, which
+/// represents the type of a function, mapping a list of input types to a list of result types.
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirFunctionTypeGet`
+/// - `mlirFunctionTypeGetInput`
+/// - `mlirFunctionTypeGetNumInputs`
+/// - `mlirFunctionTypeGetNumResults`
+/// - `mlirFunctionTypeGetResult`
+/// - `mlirTypeIsAFunction`
+///
+/// The following bindings are not used/supported:
+/// - `mlirFunctionTypeGetTypeID`
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct FunctionTypeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, FunctionTypeRef, MlirType);
+impl_type_variant!(FunctionTypeRef, mlirTypeIsAFunction);
+
+impl FunctionTypeRef {
+    /// Constructs a new function type with the provided input and result types.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the function type.
+    /// * `inputs` - The input types of the function.
+    /// * `results` - The result types of the function.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [FunctionTypeRef] instance.
+    pub fn new<'a>(context: &'a ContextRef, inputs: &[&TypeRef], results: &[&TypeRef]) -> &'a Self {
+        unsafe {
+            Self::from_raw(mlirFunctionTypeGet(
+                context.to_raw(),
+                inputs.len() as isize,
+                inputs.as_ptr() as *const MlirType,
+                results.len() as isize,
+                results.as_ptr() as *const MlirType,
+            ))
+        }
+    }
+
+    /// # Returns
+    /// Returns the number of inputs of the function type.
+    pub fn num_inputs(&self) -> isize {
+        unsafe { mlirFunctionTypeGetNumInputs(self.to_raw()) }
+    }
+
+    /// Gets the input type at the provided index, verifying that the index is within bounds.
+    ///
+    /// # Arguments
+    /// * `idx` - The index of the input type to get.
+    ///
+    /// # Returns
+    /// Returns a reference to the input type.
+    pub fn input(&self, idx: isize) -> &TypeRef {
+        if idx < 0 || idx >= self.num_inputs() {
+            panic!("Input index {} out of bounds.", idx);
+        }
+        unsafe { TypeRef::from_raw(mlirFunctionTypeGetInput(self.to_raw(), idx)) }
+    }
+
+    /// # Returns
+    /// Returns an iterator over the input types of the function type.
+    pub fn inputs(&self) -> impl ExactSizeIterator<Item = &TypeRef> {
+        (0..self.num_inputs()).map(|idx| self.input(idx))
+    }
+
+    /// # Returns
+    /// Returns the number of results of the function type.
+    pub fn num_results(&self) -> isize {
+        unsafe { mlirFunctionTypeGetNumResults(self.to_raw()) }
+    }
+
+    /// Gets the result type at the provided index, verifying that the index is within bounds.
+    ///
+    /// # Arguments
+    /// * `idx` - The index of the result type to get.
+    ///
+    /// # Returns
+    /// Returns a reference to the result type.
+    pub fn result(&self, idx: isize) -> &TypeRef {
+        if idx < 0 || idx >= self.num_results() {
+            panic!("Result index {} out of bounds.", idx);
+        }
+        unsafe { TypeRef::from_raw(mlirFunctionTypeGetResult(self.to_raw(), idx)) }
+    }
+
+    /// # Returns
+    /// Returns an iterator over the result types of the function type.
+    pub fn results(&self) -> impl ExactSizeIterator<Item = &TypeRef> {
+        (0..self.num_results()).map(|idx| self.result(idx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::IntegerTypeRef, Context};
+
+    #[test]
+    fn new() {
+        let context = Context::new(None, false);
+        let i1_type = IntegerTypeRef::new_signless(&context, 1).as_type();
+        let i32_type = IntegerTypeRef::new_signless(&context, 32).as_type();
+        let function_type = FunctionTypeRef::new(&context, &[i32_type, i32_type], &[i1_type]);
+        assert_eq!(function_type.to_string(), "(i32, i32) -> i1");
+        assert_eq!(function_type.num_inputs(), 2);
+        assert_eq!(function_type.num_results(), 1);
+        assert_eq!(
+            function_type.inputs().collect::<Vec<_>>(),
+            vec![i32_type, i32_type]
+        );
+        assert_eq!(function_type.results().collect::<Vec<_>>(), vec![i1_type]);
+    }
+
+    #[test]
+    fn from_type() {
+        let context = Context::new(None, false);
+        let erased_function_type = TypeRef::parse(&context, "(i32) -> ()").unwrap();
+        let erased_integer_type = TypeRef::parse(&context, "i32").unwrap();
+        assert!(FunctionTypeRef::try_from_type(erased_function_type).is_some());
+        assert!(FunctionTypeRef::try_from_type(erased_integer_type).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn input_out_of_bounds() {
+        let context = Context::new(None, false);
+        let function_type = FunctionTypeRef::new(&context, &[], &[]);
+        function_type.input(0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_function_type_ref() {
+        let _function_type_ref = FunctionTypeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
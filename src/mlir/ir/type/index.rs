@@ -0,0 +1,78 @@
+use super::impl_type_variant;
+use crate::{
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+    ContextRef,
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{mlirIndexTypeGet, mlirTypeIsAIndex, MlirType};
+
+/// [IndexTypeRef] is a reference to an instance of the `mlir::IndexType` class, which represents
+/// the target-dependent integer type used for sizes, loop bounds and indexing in the MLIR IR.
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirIndexTypeGet`
+/// - `mlirTypeIsAIndex`
+///
+/// The following bindings are not used/supported:
+/// - `mlirIndexTypeGetTypeID`
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct IndexTypeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, IndexTypeRef, MlirType);
+impl_type_variant!(IndexTypeRef, mlirTypeIsAIndex);
+
+impl IndexTypeRef {
+    /// Constructs a new index type.
+    ///
+    /// # Arguments
+    /// * `context` - The context that should own the index type.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [IndexTypeRef] instance.
+    pub fn new(context: &ContextRef) -> &Self {
+        unsafe { Self::from_raw(mlirIndexTypeGet(context.to_raw())) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::TypeRef, Context};
+
+    #[test]
+    fn new() {
+        let context = Context::new(None, false);
+        let index_type = IndexTypeRef::new(&context);
+        assert_eq!(index_type.to_string(), "index");
+        assert_eq!(
+            index_type.as_type(),
+            TypeRef::parse(&context, "index").unwrap()
+        );
+    }
+
+    #[test]
+    fn from_type() {
+        let context = Context::new(None, false);
+        let erased_index_type = TypeRef::parse(&context, "index").unwrap();
+        let erased_integer_type = TypeRef::parse(&context, "i64").unwrap();
+        assert!(IndexTypeRef::try_from_type(erased_index_type).is_some());
+        assert!(IndexTypeRef::try_from_type(erased_integer_type).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_index_type_ref() {
+        let _index_type_ref = IndexTypeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
@@ -0,0 +1,150 @@
+use super::impl_type_variant;
+use crate::{
+    ir::{AttributeRef, ShapedTypeRef, TypeRef},
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{
+    mlirAttributeGetNull, mlirMemRefTypeContiguousGet, mlirMemRefTypeGet, mlirMemRefTypeGetLayout,
+    mlirMemRefTypeGetMemorySpace, mlirTypeIsAMemRef, MlirType,
+};
+
+/// [MemRefTypeRef] is a reference to an instance of the `mlir::MemRefType` class, which represents
+/// a ranked reference to a region of memory, with a layout mapping its indices to memory and an
+/// optional memory space. Its shape is accessible through [ShapedTypeRef].
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirMemRefTypeContiguousGet`
+/// - `mlirMemRefTypeGet`
+/// - `mlirMemRefTypeGetLayout`
+/// - `mlirMemRefTypeGetMemorySpace`
+/// - `mlirTypeIsAMemRef`
+///
+/// The following bindings are not used/supported:
+/// - `mlirMemRefTypeContiguousGetChecked`
+/// - `mlirMemRefTypeGetAffineMap`
+/// - `mlirMemRefTypeGetChecked`
+/// - `mlirMemRefTypeGetTypeID`
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct MemRefTypeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, MemRefTypeRef, MlirType);
+impl_type_variant!(MemRefTypeRef, mlirTypeIsAMemRef, ShapedTypeRef);
+
+impl MemRefTypeRef {
+    /// Constructs a new memref type with the provided shape, element type, layout and memory
+    /// space. The type is owned by the same context that owns its element type.
+    ///
+    /// # Arguments
+    /// * `shape` - The size of each dimension of the memref.
+    /// * `element_type` - The type of the elements of the memref.
+    /// * `layout` - The layout of the memref (e.g. a strided layout), or `None` for the identity
+    ///   (contiguous row-major) layout.
+    /// * `memory_space` - The memory space of the memref, if any.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [MemRefTypeRef] instance.
+    pub fn new<'a>(
+        shape: &[i64],
+        element_type: &'a TypeRef,
+        layout: Option<&AttributeRef>,
+        memory_space: Option<&AttributeRef>,
+    ) -> &'a Self {
+        unsafe {
+            let raw_memory_space =
+                memory_space.map_or_else(|| mlirAttributeGetNull(), |m| m.to_raw());
+            Self::from_raw(match layout {
+                Some(layout) => mlirMemRefTypeGet(
+                    element_type.to_raw(),
+                    shape.len() as isize,
+                    shape.as_ptr(),
+                    layout.to_raw(),
+                    raw_memory_space,
+                ),
+                None => mlirMemRefTypeContiguousGet(
+                    element_type.to_raw(),
+                    shape.len() as isize,
+                    shape.as_ptr(),
+                    raw_memory_space,
+                ),
+            })
+        }
+    }
+
+    /// # Returns
+    /// Returns the layout of the memref.
+    pub fn layout(&self) -> &AttributeRef {
+        unsafe { AttributeRef::from_raw(mlirMemRefTypeGetLayout(self.to_raw())) }
+    }
+
+    /// # Returns
+    /// Returns the memory space of the memref, if it has one.
+    pub fn memory_space(&self) -> Option<&AttributeRef> {
+        unsafe { AttributeRef::try_from_raw(mlirMemRefTypeGetMemorySpace(self.to_raw())) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::IntegerTypeRef, Context};
+
+    #[test]
+    fn new() {
+        let context = Context::new(None, false);
+        let i32_type = IntegerTypeRef::new_signless(&context, 32);
+        let memref_type =
+            MemRefTypeRef::new(&[3, ShapedTypeRef::DYNAMIC_SIZE], i32_type, None, None);
+        assert_eq!(memref_type.to_string(), "memref<3x?xi32>");
+        assert_eq!(memref_type.element_type(), i32_type.as_type());
+        assert_eq!(memref_type.shape(), Some(vec![Some(3), None]));
+        assert_eq!(
+            memref_type.layout().to_string(),
+            "affine_map<(d0, d1) -> (d0, d1)>"
+        );
+        assert!(memref_type.memory_space().is_none());
+    }
+
+    #[test]
+    fn new_with_layout_and_memory_space() {
+        let context = Context::new(None, false);
+        let i32_type = IntegerTypeRef::new_signless(&context, 32);
+        let layout = AttributeRef::parse(&context, "strided<[1, 4], offset: ?>").unwrap();
+        let memory_space = AttributeRef::parse(&context, "1 : i64").unwrap();
+        let memref_type = MemRefTypeRef::new(&[4, 4], i32_type, Some(layout), Some(memory_space));
+        assert_eq!(
+            memref_type.to_string(),
+            "memref<4x4xi32, strided<[1, 4], offset: ?>, 1>"
+        );
+        assert_eq!(memref_type.layout(), layout);
+        assert_eq!(memref_type.memory_space(), Some(memory_space));
+    }
+
+    #[test]
+    fn from_type() {
+        let context = Context::new(None, false);
+        let erased_memref_type = TypeRef::parse(&context, "memref<4xi8>").unwrap();
+        let erased_unranked_type = TypeRef::parse(&context, "memref<*xi8>").unwrap();
+        let erased_tensor_type = TypeRef::parse(&context, "tensor<4xi8>").unwrap();
+        assert!(MemRefTypeRef::try_from_type(erased_memref_type).is_some());
+        assert!(MemRefTypeRef::try_from_type(erased_unranked_type).is_none());
+        assert!(MemRefTypeRef::try_from_type(erased_tensor_type).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_memref_type_ref() {
+        let _memref_type_ref = MemRefTypeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
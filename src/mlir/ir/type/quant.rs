@@ -0,0 +1,336 @@
+mod any;
+mod calibrated;
+mod uniform;
+mod uniform_per_axis;
+
+pub use self::{any::*, calibrated::*, uniform::*, uniform_per_axis::*};
+use super::impl_type_variant;
+use crate::{
+    ir::TypeRef,
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{
+    mlirQuantizedTypeCastExpressedToStorageType, mlirQuantizedTypeCastFromExpressedType,
+    mlirQuantizedTypeCastFromStorageType, mlirQuantizedTypeCastToExpressedType,
+    mlirQuantizedTypeCastToStorageType, mlirQuantizedTypeGetDefaultMaximumForInteger,
+    mlirQuantizedTypeGetDefaultMinimumForInteger, mlirQuantizedTypeGetExpressedType,
+    mlirQuantizedTypeGetFlags, mlirQuantizedTypeGetQuantizedElementType,
+    mlirQuantizedTypeGetSignedFlag, mlirQuantizedTypeGetStorageType,
+    mlirQuantizedTypeGetStorageTypeIntegralWidth, mlirQuantizedTypeGetStorageTypeMax,
+    mlirQuantizedTypeGetStorageTypeMin, mlirQuantizedTypeIsCompatibleExpressedType,
+    mlirQuantizedTypeIsSigned, mlirTypeIsAQuantizedType, MlirType,
+};
+
+/// [QuantizedTypeRef] is a reference to an instance of the `mlir::quant::QuantizedType` class,
+/// which is the base class of the types of the `quant` dialect. A quantized type maps values of an
+/// expressed (usually floating point) type to values of an integral storage type.
+///
+/// All relevant bindings into the MLIR C API are used/supported:
+/// - `mlirQuantizedTypeCastExpressedToStorageType`
+/// - `mlirQuantizedTypeCastFromExpressedType`
+/// - `mlirQuantizedTypeCastFromStorageType`
+/// - `mlirQuantizedTypeCastToExpressedType`
+/// - `mlirQuantizedTypeCastToStorageType`
+/// - `mlirQuantizedTypeGetDefaultMaximumForInteger`
+/// - `mlirQuantizedTypeGetDefaultMinimumForInteger`
+/// - `mlirQuantizedTypeGetExpressedType`
+/// - `mlirQuantizedTypeGetFlags`
+/// - `mlirQuantizedTypeGetQuantizedElementType`
+/// - `mlirQuantizedTypeGetSignedFlag`
+/// - `mlirQuantizedTypeGetStorageType`
+/// - `mlirQuantizedTypeGetStorageTypeIntegralWidth`
+/// - `mlirQuantizedTypeGetStorageTypeMax`
+/// - `mlirQuantizedTypeGetStorageTypeMin`
+/// - `mlirQuantizedTypeIsCompatibleExpressedType`
+/// - `mlirQuantizedTypeIsSigned`
+/// - `mlirTypeIsAQuantizedType`
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct QuantizedTypeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, QuantizedTypeRef, MlirType);
+impl_type_variant!(QuantizedTypeRef, mlirTypeIsAQuantizedType);
+
+impl QuantizedTypeRef {
+    /// Gets the default minimum value of a storage type with the provided signedness and width.
+    ///
+    /// # Arguments
+    /// * `is_signed` - Whether the storage type is signed.
+    /// * `integral_width` - The width of the storage type in bits.
+    ///
+    /// # Returns
+    /// Returns the default minimum storage value.
+    pub fn default_storage_min(is_signed: bool, integral_width: u32) -> i64 {
+        unsafe { mlirQuantizedTypeGetDefaultMinimumForInteger(is_signed, integral_width) }
+    }
+
+    /// Gets the default maximum value of a storage type with the provided signedness and width.
+    ///
+    /// # Arguments
+    /// * `is_signed` - Whether the storage type is signed.
+    /// * `integral_width` - The width of the storage type in bits.
+    ///
+    /// # Returns
+    /// Returns the default maximum storage value.
+    pub fn default_storage_max(is_signed: bool, integral_width: u32) -> i64 {
+        unsafe { mlirQuantizedTypeGetDefaultMaximumForInteger(is_signed, integral_width) }
+    }
+
+    /// Converts the provided signedness into the flags expected when constructing quantized
+    /// types.
+    fn flags_for(is_signed: bool) -> u32 {
+        if is_signed {
+            unsafe { mlirQuantizedTypeGetSignedFlag() }
+        } else {
+            0
+        }
+    }
+
+    /// # Returns
+    /// Returns the raw flags of the quantized type.
+    pub fn flags(&self) -> u32 {
+        unsafe { mlirQuantizedTypeGetFlags(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns whether the storage type of the quantized type is signed.
+    pub fn is_signed(&self) -> bool {
+        unsafe { mlirQuantizedTypeIsSigned(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns the expressed type of the quantized type.
+    pub fn expressed_type(&self) -> &TypeRef {
+        unsafe { TypeRef::from_raw(mlirQuantizedTypeGetExpressedType(self.to_raw())) }
+    }
+
+    /// # Returns
+    /// Returns the storage type of the quantized type.
+    pub fn storage_type(&self) -> &TypeRef {
+        unsafe { TypeRef::from_raw(mlirQuantizedTypeGetStorageType(self.to_raw())) }
+    }
+
+    /// # Returns
+    /// Returns the minimum value that the storage type can hold.
+    pub fn storage_type_min(&self) -> i64 {
+        unsafe { mlirQuantizedTypeGetStorageTypeMin(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns the maximum value that the storage type can hold.
+    pub fn storage_type_max(&self) -> i64 {
+        unsafe { mlirQuantizedTypeGetStorageTypeMax(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns the width of the storage type in bits.
+    pub fn storage_type_integral_width(&self) -> u32 {
+        unsafe { mlirQuantizedTypeGetStorageTypeIntegralWidth(self.to_raw()) }
+    }
+
+    /// Checks if the provided candidate type can be quantized to this quantized type.
+    ///
+    /// # Arguments
+    /// * `candidate` - The candidate expressed type.
+    ///
+    /// # Returns
+    /// Returns whether the candidate type is compatible.
+    pub fn is_compatible_expressed_type(&self, candidate: &TypeRef) -> bool {
+        unsafe { mlirQuantizedTypeIsCompatibleExpressedType(self.to_raw(), candidate.to_raw()) }
+    }
+
+    /// Gets the quantized element type of the provided type, which may be a quantized type or a
+    /// shaped type with a quantized element type.
+    ///
+    /// # Arguments
+    /// * `ty` - The type to get the quantized element type of.
+    ///
+    /// # Returns
+    /// Returns the quantized element type, or `None` if the type is not quantized.
+    pub fn element_type_of(ty: &TypeRef) -> Option<&QuantizedTypeRef> {
+        unsafe { Self::try_from_raw(mlirQuantizedTypeGetQuantizedElementType(ty.to_raw())) }
+    }
+
+    /// Casts the provided storage type (or a shaped type of it) to the corresponding type using
+    /// this quantized type.
+    ///
+    /// # Arguments
+    /// * `candidate` - The storage type to cast from.
+    ///
+    /// # Returns
+    /// Returns the cast type, or `None` if the candidate type is not compatible.
+    pub fn cast_from_storage_type<'a>(&'a self, candidate: &TypeRef) -> Option<&'a TypeRef> {
+        unsafe {
+            TypeRef::try_from_raw(mlirQuantizedTypeCastFromStorageType(
+                self.to_raw(),
+                candidate.to_raw(),
+            ))
+        }
+    }
+
+    /// Casts the provided quantized type (or a shaped type of it) to the corresponding storage
+    /// type.
+    ///
+    /// # Arguments
+    /// * `ty` - The type to cast.
+    ///
+    /// # Returns
+    /// Returns the cast type, or `None` if the type is not quantized.
+    pub fn cast_to_storage_type(ty: &TypeRef) -> Option<&TypeRef> {
+        unsafe { TypeRef::try_from_raw(mlirQuantizedTypeCastToStorageType(ty.to_raw())) }
+    }
+
+    /// Casts the provided expressed type (or a shaped type of it) to the corresponding type using
+    /// this quantized type.
+    ///
+    /// # Arguments
+    /// * `candidate` - The expressed type to cast from.
+    ///
+    /// # Returns
+    /// Returns the cast type, or `None` if the candidate type is not compatible.
+    pub fn cast_from_expressed_type<'a>(&'a self, candidate: &TypeRef) -> Option<&'a TypeRef> {
+        unsafe {
+            TypeRef::try_from_raw(mlirQuantizedTypeCastFromExpressedType(
+                self.to_raw(),
+                candidate.to_raw(),
+            ))
+        }
+    }
+
+    /// Casts the provided quantized type (or a shaped type of it) to the corresponding expressed
+    /// type.
+    ///
+    /// # Arguments
+    /// * `ty` - The type to cast.
+    ///
+    /// # Returns
+    /// Returns the cast type, or `None` if the type is not quantized.
+    pub fn cast_to_expressed_type(ty: &TypeRef) -> Option<&TypeRef> {
+        unsafe { TypeRef::try_from_raw(mlirQuantizedTypeCastToExpressedType(ty.to_raw())) }
+    }
+
+    /// Casts the provided expressed type (or a shaped type of it) to the corresponding storage
+    /// type of this quantized type.
+    ///
+    /// # Arguments
+    /// * `candidate` - The expressed type to cast from.
+    ///
+    /// # Returns
+    /// Returns the cast type, or `None` if the candidate type is not compatible.
+    pub fn cast_expressed_to_storage_type<'a>(
+        &'a self,
+        candidate: &TypeRef,
+    ) -> Option<&'a TypeRef> {
+        unsafe {
+            TypeRef::try_from_raw(mlirQuantizedTypeCastExpressedToStorageType(
+                self.to_raw(),
+                candidate.to_raw(),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::{Context, DialectHandle, OwnedMlirValue};
+
+    use mlir_sys::mlirGetDialectHandle__quant__;
+
+    pub(crate) fn context() -> Context {
+        let context = Context::new(None, false);
+        let dialect_handle = unsafe { DialectHandle::from_raw(mlirGetDialectHandle__quant__()) };
+        dialect_handle.load_into_context(&context);
+        context
+    }
+
+    #[test]
+    fn default_storage_range() {
+        assert_eq!(QuantizedTypeRef::default_storage_min(true, 8), -128);
+        assert_eq!(QuantizedTypeRef::default_storage_max(true, 8), 127);
+        assert_eq!(QuantizedTypeRef::default_storage_min(false, 8), 0);
+        assert_eq!(QuantizedTypeRef::default_storage_max(false, 8), 255);
+    }
+
+    #[test]
+    fn accessors() {
+        let context = context();
+        let ty = TypeRef::parse(&context, "!quant.uniform<i8:f32, 0.5:3>").unwrap();
+        let quantized_type = QuantizedTypeRef::try_from_type(ty).unwrap();
+        assert!(quantized_type.is_signed());
+        assert_eq!(quantized_type.storage_type().to_string(), "i8");
+        assert_eq!(quantized_type.expressed_type().to_string(), "f32");
+        assert_eq!(quantized_type.storage_type_min(), -128);
+        assert_eq!(quantized_type.storage_type_max(), 127);
+        assert_eq!(quantized_type.storage_type_integral_width(), 8);
+        let f32_type = TypeRef::parse(&context, "f32").unwrap();
+        assert!(quantized_type.is_compatible_expressed_type(f32_type));
+    }
+
+    #[test]
+    fn casts() {
+        let context = context();
+        let ty = TypeRef::parse(&context, "!quant.uniform<i8:f32, 0.5:3>").unwrap();
+        let quantized_type = QuantizedTypeRef::try_from_type(ty).unwrap();
+        let tensor_type = TypeRef::parse(&context, "tensor<4xf32>").unwrap();
+        let quantized_tensor_type = quantized_type
+            .cast_from_expressed_type(tensor_type)
+            .unwrap();
+        assert_eq!(
+            quantized_tensor_type.to_string(),
+            "tensor<4x!quant.uniform<i8:f32, 5.000000e-01:3>>"
+        );
+        assert_eq!(
+            QuantizedTypeRef::element_type_of(quantized_tensor_type).map(|ty| ty.as_type()),
+            Some(ty)
+        );
+        assert_eq!(
+            QuantizedTypeRef::cast_to_storage_type(quantized_tensor_type)
+                .unwrap()
+                .to_string(),
+            "tensor<4xi8>"
+        );
+        assert_eq!(
+            QuantizedTypeRef::cast_to_expressed_type(quantized_tensor_type),
+            Some(tensor_type)
+        );
+        assert_eq!(
+            quantized_type
+                .cast_expressed_to_storage_type(tensor_type)
+                .unwrap()
+                .to_string(),
+            "tensor<4xi8>"
+        );
+        let i8_tensor_type = TypeRef::parse(&context, "tensor<4xi8>").unwrap();
+        assert_eq!(
+            quantized_type.cast_from_storage_type(i8_tensor_type),
+            Some(quantized_tensor_type)
+        );
+    }
+
+    #[test]
+    fn from_type() {
+        let context = context();
+        let erased_quantized_type = TypeRef::parse(&context, "!quant.any<i8:f32>").unwrap();
+        let erased_integer_type = TypeRef::parse(&context, "i8").unwrap();
+        assert!(QuantizedTypeRef::try_from_type(erased_quantized_type).is_some());
+        assert!(QuantizedTypeRef::try_from_type(erased_integer_type).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_quantized_type_ref() {
+        let _quantized_type_ref = QuantizedTypeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
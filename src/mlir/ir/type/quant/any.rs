@@ -0,0 +1,99 @@
+use super::{impl_type_variant, QuantizedTypeRef};
+use crate::{
+    ir::TypeRef,
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{mlirAnyQuantizedTypeGet, mlirTypeIsAAnyQuantizedType, MlirType};
+
+/// [AnyQuantizedTypeRef] is a reference to an instance of the `mlir::quant::AnyQuantizedType`
+/// class, which represents a quantized type whose quantization parameters have not been decided
+/// yet.
+///
+/// All relevant bindings into the MLIR C API are used/supported:
+/// - `mlirAnyQuantizedTypeGet`
+/// - `mlirTypeIsAAnyQuantizedType`
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct AnyQuantizedTypeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, AnyQuantizedTypeRef, MlirType);
+impl_type_variant!(
+    AnyQuantizedTypeRef,
+    mlirTypeIsAAnyQuantizedType,
+    QuantizedTypeRef
+);
+
+impl AnyQuantizedTypeRef {
+    /// Constructs a new quantized type without quantization parameters. The type is owned by the
+    /// same context that owns its storage type.
+    ///
+    /// # Arguments
+    /// * `is_signed` - Whether the storage type is signed.
+    /// * `storage_type` - The integral type that quantized values are stored in.
+    /// * `expressed_type` - The type that the quantized values express.
+    /// * `storage_type_min` - The minimum value that the storage type can hold.
+    /// * `storage_type_max` - The maximum value that the storage type can hold.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [AnyQuantizedTypeRef] instance.
+    pub fn new<'a>(
+        is_signed: bool,
+        storage_type: &'a TypeRef,
+        expressed_type: &TypeRef,
+        storage_type_min: i64,
+        storage_type_max: i64,
+    ) -> &'a Self {
+        unsafe {
+            Self::from_raw(mlirAnyQuantizedTypeGet(
+                QuantizedTypeRef::flags_for(is_signed),
+                storage_type.to_raw(),
+                expressed_type.to_raw(),
+                storage_type_min,
+                storage_type_max,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::r#type::quant::tests::context;
+
+    #[test]
+    fn new() {
+        let context = context();
+        let i8_type = TypeRef::parse(&context, "i8").unwrap();
+        let f32_type = TypeRef::parse(&context, "f32").unwrap();
+        let any_type = AnyQuantizedTypeRef::new(true, i8_type, f32_type, -128, 127);
+        assert_eq!(any_type.to_string(), "!quant.any<i8:f32>");
+        assert!(any_type.is_signed());
+        assert_eq!(any_type.storage_type(), i8_type);
+    }
+
+    #[test]
+    fn from_type() {
+        let context = context();
+        let erased_any_type = TypeRef::parse(&context, "!quant.any<i8:f32>").unwrap();
+        let erased_uniform_type = TypeRef::parse(&context, "!quant.uniform<i8:f32, 0.5>").unwrap();
+        assert!(AnyQuantizedTypeRef::try_from_type(erased_any_type).is_some());
+        assert!(AnyQuantizedTypeRef::try_from_type(erased_uniform_type).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_any_quantized_type_ref() {
+        let _any_quantized_type_ref = AnyQuantizedTypeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
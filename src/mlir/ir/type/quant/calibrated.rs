@@ -0,0 +1,110 @@
+use super::{impl_type_variant, QuantizedTypeRef};
+use crate::{
+    ir::TypeRef,
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{
+    mlirCalibratedQuantizedTypeGet, mlirCalibratedQuantizedTypeGetMax,
+    mlirCalibratedQuantizedTypeGetMin, mlirTypeIsACalibratedQuantizedType, MlirType,
+};
+
+/// [CalibratedQuantizedTypeRef] is a reference to an instance of the
+/// `mlir::quant::CalibratedQuantizedType` class, which records the calibrated range of an
+/// expressed type before quantization parameters are derived from it.
+///
+/// All relevant bindings into the MLIR C API are used/supported:
+/// - `mlirCalibratedQuantizedTypeGet`
+/// - `mlirCalibratedQuantizedTypeGetMax`
+/// - `mlirCalibratedQuantizedTypeGetMin`
+/// - `mlirTypeIsACalibratedQuantizedType`
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct CalibratedQuantizedTypeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, CalibratedQuantizedTypeRef, MlirType);
+impl_type_variant!(
+    CalibratedQuantizedTypeRef,
+    mlirTypeIsACalibratedQuantizedType,
+    QuantizedTypeRef
+);
+
+impl CalibratedQuantizedTypeRef {
+    /// Constructs a new calibrated quantized type. The type is owned by the same context that owns
+    /// its expressed type.
+    ///
+    /// # Arguments
+    /// * `expressed_type` - The type that the calibrated values express.
+    /// * `min` - The minimum calibrated value.
+    /// * `max` - The maximum calibrated value.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [CalibratedQuantizedTypeRef] instance.
+    pub fn new(expressed_type: &TypeRef, min: f64, max: f64) -> &Self {
+        unsafe {
+            Self::from_raw(mlirCalibratedQuantizedTypeGet(
+                expressed_type.to_raw(),
+                min,
+                max,
+            ))
+        }
+    }
+
+    /// # Returns
+    /// Returns the minimum calibrated value.
+    pub fn min(&self) -> f64 {
+        unsafe { mlirCalibratedQuantizedTypeGetMin(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns the maximum calibrated value.
+    pub fn max(&self) -> f64 {
+        unsafe { mlirCalibratedQuantizedTypeGetMax(self.to_raw()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::r#type::quant::tests::context;
+
+    #[test]
+    fn new() {
+        let context = context();
+        let f32_type = TypeRef::parse(&context, "f32").unwrap();
+        let calibrated_type = CalibratedQuantizedTypeRef::new(f32_type, -1.0, 1.0);
+        assert_eq!(calibrated_type.min(), -1.0);
+        assert_eq!(calibrated_type.max(), 1.0);
+        assert_eq!(calibrated_type.expressed_type(), f32_type);
+        assert_eq!(
+            calibrated_type.to_string(),
+            "!quant.calibrated<f32<-1.000000e+00:1.000000e+00>>"
+        );
+    }
+
+    #[test]
+    fn from_type() {
+        let context = context();
+        let erased_calibrated_type =
+            TypeRef::parse(&context, "!quant.calibrated<f32<-1.0:1.0>>").unwrap();
+        let erased_any_type = TypeRef::parse(&context, "!quant.any<i8:f32>").unwrap();
+        assert!(CalibratedQuantizedTypeRef::try_from_type(erased_calibrated_type).is_some());
+        assert!(CalibratedQuantizedTypeRef::try_from_type(erased_any_type).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_calibrated_quantized_type_ref() {
+        let _calibrated_quantized_type_ref = CalibratedQuantizedTypeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
@@ -0,0 +1,135 @@
+use super::{impl_type_variant, QuantizedTypeRef};
+use crate::{
+    ir::TypeRef,
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{
+    mlirTypeIsAUniformQuantizedType, mlirUniformQuantizedTypeGet, mlirUniformQuantizedTypeGetScale,
+    mlirUniformQuantizedTypeGetZeroPoint, mlirUniformQuantizedTypeIsFixedPoint, MlirType,
+};
+
+/// [UniformQuantizedTypeRef] is a reference to an instance of the
+/// `mlir::quant::UniformQuantizedType` class, which represents a quantized type with a single
+/// scale and zero point shared by all values.
+///
+/// All relevant bindings into the MLIR C API are used/supported:
+/// - `mlirTypeIsAUniformQuantizedType`
+/// - `mlirUniformQuantizedTypeGet`
+/// - `mlirUniformQuantizedTypeGetScale`
+/// - `mlirUniformQuantizedTypeGetZeroPoint`
+/// - `mlirUniformQuantizedTypeIsFixedPoint`
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct UniformQuantizedTypeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, UniformQuantizedTypeRef, MlirType);
+impl_type_variant!(
+    UniformQuantizedTypeRef,
+    mlirTypeIsAUniformQuantizedType,
+    QuantizedTypeRef
+);
+
+impl UniformQuantizedTypeRef {
+    /// Constructs a new uniform quantized type. The type is owned by the same context that owns
+    /// its storage type.
+    ///
+    /// # Arguments
+    /// * `is_signed` - Whether the storage type is signed.
+    /// * `storage_type` - The integral type that quantized values are stored in.
+    /// * `expressed_type` - The type that the quantized values express.
+    /// * `scale` - The scale of the quantization.
+    /// * `zero_point` - The storage value that the expressed value zero maps to.
+    /// * `storage_type_min` - The minimum value that the storage type can hold.
+    /// * `storage_type_max` - The maximum value that the storage type can hold.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [UniformQuantizedTypeRef] instance.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<'a>(
+        is_signed: bool,
+        storage_type: &'a TypeRef,
+        expressed_type: &TypeRef,
+        scale: f64,
+        zero_point: i64,
+        storage_type_min: i64,
+        storage_type_max: i64,
+    ) -> &'a Self {
+        unsafe {
+            Self::from_raw(mlirUniformQuantizedTypeGet(
+                QuantizedTypeRef::flags_for(is_signed),
+                storage_type.to_raw(),
+                expressed_type.to_raw(),
+                scale,
+                zero_point,
+                storage_type_min,
+                storage_type_max,
+            ))
+        }
+    }
+
+    /// # Returns
+    /// Returns the scale of the quantization.
+    pub fn scale(&self) -> f64 {
+        unsafe { mlirUniformQuantizedTypeGetScale(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns the storage value that the expressed value zero maps to.
+    pub fn zero_point(&self) -> i64 {
+        unsafe { mlirUniformQuantizedTypeGetZeroPoint(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns whether the quantization is fixed point, i.e. whether the zero point is zero.
+    pub fn is_fixed_point(&self) -> bool {
+        unsafe { mlirUniformQuantizedTypeIsFixedPoint(self.to_raw()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::r#type::quant::tests::context;
+
+    #[test]
+    fn new() {
+        let context = context();
+        let i8_type = TypeRef::parse(&context, "i8").unwrap();
+        let f32_type = TypeRef::parse(&context, "f32").unwrap();
+        let uniform_type = UniformQuantizedTypeRef::new(true, i8_type, f32_type, 0.5, 3, -128, 127);
+        assert_eq!(
+            uniform_type.to_string(),
+            "!quant.uniform<i8:f32, 5.000000e-01:3>"
+        );
+        assert_eq!(uniform_type.scale(), 0.5);
+        assert_eq!(uniform_type.zero_point(), 3);
+        assert!(!uniform_type.is_fixed_point());
+        assert_eq!(uniform_type.expressed_type(), f32_type);
+    }
+
+    #[test]
+    fn from_type() {
+        let context = context();
+        let erased_uniform_type = TypeRef::parse(&context, "!quant.uniform<i8:f32, 0.5>").unwrap();
+        let erased_any_type = TypeRef::parse(&context, "!quant.any<i8:f32>").unwrap();
+        assert!(UniformQuantizedTypeRef::try_from_type(erased_uniform_type).is_some());
+        assert!(UniformQuantizedTypeRef::try_from_type(erased_any_type).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_uniform_quantized_type_ref() {
+        let _uniform_quantized_type_ref = UniformQuantizedTypeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
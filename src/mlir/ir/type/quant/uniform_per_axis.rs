@@ -0,0 +1,212 @@
+use super::{impl_type_variant, QuantizedTypeRef};
+use crate::{
+    ir::TypeRef,
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{
+    mlirTypeIsAUniformQuantizedPerAxisType, mlirUniformQuantizedPerAxisTypeGet,
+    mlirUniformQuantizedPerAxisTypeGetNumDims,
+    mlirUniformQuantizedPerAxisTypeGetQuantizedDimension, mlirUniformQuantizedPerAxisTypeGetScale,
+    mlirUniformQuantizedPerAxisTypeGetZeroPoint, mlirUniformQuantizedPerAxisTypeIsFixedPoint,
+    MlirType,
+};
+
+/// [UniformQuantizedPerAxisTypeRef] is a reference to an instance of the
+/// `mlir::quant::UniformQuantizedPerAxisType` class, which represents a quantized type with a
+/// separate scale and zero point for each slice along a quantized dimension.
+///
+/// All relevant bindings into the MLIR C API are used/supported:
+/// - `mlirTypeIsAUniformQuantizedPerAxisType`
+/// - `mlirUniformQuantizedPerAxisTypeGet`
+/// - `mlirUniformQuantizedPerAxisTypeGetNumDims`
+/// - `mlirUniformQuantizedPerAxisTypeGetQuantizedDimension`
+/// - `mlirUniformQuantizedPerAxisTypeGetScale`
+/// - `mlirUniformQuantizedPerAxisTypeGetZeroPoint`
+/// - `mlirUniformQuantizedPerAxisTypeIsFixedPoint`
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct UniformQuantizedPerAxisTypeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, UniformQuantizedPerAxisTypeRef, MlirType);
+impl_type_variant!(
+    UniformQuantizedPerAxisTypeRef,
+    mlirTypeIsAUniformQuantizedPerAxisType,
+    QuantizedTypeRef
+);
+
+impl UniformQuantizedPerAxisTypeRef {
+    /// Constructs a new per-axis uniform quantized type. The type is owned by the same context
+    /// that owns its storage type.
+    ///
+    /// # Arguments
+    /// * `is_signed` - Whether the storage type is signed.
+    /// * `storage_type` - The integral type that quantized values are stored in.
+    /// * `expressed_type` - The type that the quantized values express.
+    /// * `scales` - The scale of each slice along the quantized dimension.
+    /// * `zero_points` - The zero point of each slice along the quantized dimension. Must have the
+    ///   same length as `scales`.
+    /// * `quantized_dimension` - The dimension that the quantization parameters apply along.
+    /// * `storage_type_min` - The minimum value that the storage type can hold.
+    /// * `storage_type_max` - The maximum value that the storage type can hold.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [UniformQuantizedPerAxisTypeRef] instance.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<'a>(
+        is_signed: bool,
+        storage_type: &'a TypeRef,
+        expressed_type: &TypeRef,
+        scales: &[f64],
+        zero_points: &[i64],
+        quantized_dimension: i32,
+        storage_type_min: i64,
+        storage_type_max: i64,
+    ) -> &'a Self {
+        if scales.len() != zero_points.len() {
+            panic!(
+                "Got {} scales but {} zero points.",
+                scales.len(),
+                zero_points.len()
+            );
+        }
+        unsafe {
+            Self::from_raw(mlirUniformQuantizedPerAxisTypeGet(
+                QuantizedTypeRef::flags_for(is_signed),
+                storage_type.to_raw(),
+                expressed_type.to_raw(),
+                scales.len() as isize,
+                scales.as_ptr() as *mut f64,
+                zero_points.as_ptr() as *mut i64,
+                quantized_dimension,
+                storage_type_min,
+                storage_type_max,
+            ))
+        }
+    }
+
+    /// # Returns
+    /// Returns the number of slices along the quantized dimension.
+    pub fn num_dims(&self) -> isize {
+        unsafe { mlirUniformQuantizedPerAxisTypeGetNumDims(self.to_raw()) }
+    }
+
+    /// Gets the scale of the provided slice, verifying that the index is within bounds.
+    ///
+    /// # Arguments
+    /// * `idx` - The index of the slice.
+    ///
+    /// # Returns
+    /// Returns the scale of the slice.
+    pub fn scale(&self, idx: isize) -> f64 {
+        if idx < 0 || idx >= self.num_dims() {
+            panic!("Scale index {} out of bounds.", idx);
+        }
+        unsafe { mlirUniformQuantizedPerAxisTypeGetScale(self.to_raw(), idx) }
+    }
+
+    /// # Returns
+    /// Returns an iterator over the scales of all slices.
+    pub fn scales(&self) -> impl ExactSizeIterator<Item = f64> + '_ {
+        (0..self.num_dims()).map(|idx| self.scale(idx))
+    }
+
+    /// Gets the zero point of the provided slice, verifying that the index is within bounds.
+    ///
+    /// # Arguments
+    /// * `idx` - The index of the slice.
+    ///
+    /// # Returns
+    /// Returns the zero point of the slice.
+    pub fn zero_point(&self, idx: isize) -> i64 {
+        if idx < 0 || idx >= self.num_dims() {
+            panic!("Zero point index {} out of bounds.", idx);
+        }
+        unsafe { mlirUniformQuantizedPerAxisTypeGetZeroPoint(self.to_raw(), idx) }
+    }
+
+    /// # Returns
+    /// Returns an iterator over the zero points of all slices.
+    pub fn zero_points(&self) -> impl ExactSizeIterator<Item = i64> + '_ {
+        (0..self.num_dims()).map(|idx| self.zero_point(idx))
+    }
+
+    /// # Returns
+    /// Returns the dimension that the quantization parameters apply along.
+    pub fn quantized_dimension(&self) -> i32 {
+        unsafe { mlirUniformQuantizedPerAxisTypeGetQuantizedDimension(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns whether the quantization is fixed point, i.e. whether all zero points are zero.
+    pub fn is_fixed_point(&self) -> bool {
+        unsafe { mlirUniformQuantizedPerAxisTypeIsFixedPoint(self.to_raw()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::r#type::quant::tests::context;
+
+    #[test]
+    fn new() {
+        let context = context();
+        let i8_type = TypeRef::parse(&context, "i8").unwrap();
+        let f32_type = TypeRef::parse(&context, "f32").unwrap();
+        let per_axis_type = UniformQuantizedPerAxisTypeRef::new(
+            true,
+            i8_type,
+            f32_type,
+            &[0.5, 2.0],
+            &[0, 0],
+            1,
+            -128,
+            127,
+        );
+        assert_eq!(per_axis_type.num_dims(), 2);
+        assert_eq!(per_axis_type.scales().collect::<Vec<_>>(), vec![0.5, 2.0]);
+        assert_eq!(per_axis_type.zero_points().collect::<Vec<_>>(), vec![0, 0]);
+        assert_eq!(per_axis_type.quantized_dimension(), 1);
+        assert!(per_axis_type.is_fixed_point());
+        assert_eq!(
+            TypeRef::parse(&context, &per_axis_type.to_string()),
+            Some(per_axis_type.as_type())
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_mismatched_lengths() {
+        let context = context();
+        let i8_type = TypeRef::parse(&context, "i8").unwrap();
+        let f32_type = TypeRef::parse(&context, "f32").unwrap();
+        UniformQuantizedPerAxisTypeRef::new(true, i8_type, f32_type, &[0.5], &[], 0, -128, 127);
+    }
+
+    #[test]
+    fn from_type() {
+        let context = context();
+        let erased_per_axis_type =
+            TypeRef::parse(&context, "!quant.uniform<i8:f32:0, {0.5, 2.0}>").unwrap();
+        let erased_uniform_type = TypeRef::parse(&context, "!quant.uniform<i8:f32, 0.5>").unwrap();
+        assert!(UniformQuantizedPerAxisTypeRef::try_from_type(erased_per_axis_type).is_some());
+        assert!(UniformQuantizedPerAxisTypeRef::try_from_type(erased_uniform_type).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_uniform_quantized_per_axis_type_ref() {
+        let _uniform_quantized_per_axis_type_ref = UniformQuantizedPerAxisTypeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
@@ -0,0 +1,122 @@
+use super::impl_type_variant;
+use crate::{
+    ir::{AttributeRef, ShapedTypeRef, TypeRef},
+    support::binding::{impl_unowned_mlir_value, OptionalMlirValue, UnownedMlirValue},
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{
+    mlirRankedTensorTypeGet, mlirRankedTensorTypeGetEncoding, mlirTypeIsARankedTensor, MlirType,
+};
+
+/// [RankedTensorTypeRef] is a reference to an instance of the `mlir::RankedTensorType` class,
+/// which represents a multi-dimensional array with a known rank and an optional encoding
+/// attribute. Its shape is accessible through [ShapedTypeRef].
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirRankedTensorTypeGet`
+/// - `mlirRankedTensorTypeGetEncoding`
+/// - `mlirTypeIsARankedTensor`
+///
+/// The following bindings are not used/supported:
+/// - `mlirRankedTensorTypeGetChecked`
+/// - `mlirRankedTensorTypeGetTypeID`
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct RankedTensorTypeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, RankedTensorTypeRef, MlirType);
+impl_type_variant!(RankedTensorTypeRef, mlirTypeIsARankedTensor, ShapedTypeRef);
+
+impl RankedTensorTypeRef {
+    /// Constructs a new ranked tensor type with the provided shape, element type and encoding. The
+    /// type is owned by the same context that owns its element type.
+    ///
+    /// # Arguments
+    /// * `shape` - The size of each dimension of the tensor.
+    /// * `element_type` - The type of the elements of the tensor.
+    /// * `encoding` - The encoding attribute of the tensor, if any.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [RankedTensorTypeRef] instance.
+    pub fn new<'a>(
+        shape: &[i64],
+        element_type: &'a TypeRef,
+        encoding: Option<&AttributeRef>,
+    ) -> &'a Self {
+        unsafe {
+            Self::from_raw(mlirRankedTensorTypeGet(
+                shape.len() as isize,
+                shape.as_ptr(),
+                element_type.to_raw(),
+                encoding.to_raw_or_null(),
+            ))
+        }
+    }
+
+    /// # Returns
+    /// Returns the encoding attribute of the tensor, if it has one.
+    pub fn encoding(&self) -> Option<&AttributeRef> {
+        unsafe { AttributeRef::try_from_raw(mlirRankedTensorTypeGetEncoding(self.to_raw())) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::IntegerTypeRef, Context};
+
+    #[test]
+    fn new() {
+        let context = Context::new(None, false);
+        let i32_type = IntegerTypeRef::new_signless(&context, 32);
+        let tensor_type = RankedTensorTypeRef::new(&[3, 5], i32_type, None);
+        assert_eq!(tensor_type.to_string(), "tensor<3x5xi32>");
+        assert_eq!(tensor_type.element_type(), i32_type.as_type());
+        assert_eq!(tensor_type.rank(), Some(2));
+        assert_eq!(tensor_type.shape(), Some(vec![Some(3), Some(5)]));
+        assert!(tensor_type.encoding().is_none());
+    }
+
+    #[test]
+    fn new_with_encoding() {
+        let context = Context::new(None, false);
+        let i32_type = IntegerTypeRef::new_signless(&context, 32);
+        let encoding = AttributeRef::parse(&context, "\"enc\"").unwrap();
+        let tensor_type = RankedTensorTypeRef::new(&[4], i32_type, Some(encoding));
+        assert_eq!(tensor_type.to_string(), "tensor<4xi32, \"enc\">");
+        assert_eq!(tensor_type.encoding(), Some(encoding));
+    }
+
+    #[test]
+    fn from_type() {
+        let context = Context::new(None, false);
+        let erased_tensor_type = TypeRef::parse(&context, "tensor<4xi8>").unwrap();
+        let erased_unranked_type = TypeRef::parse(&context, "tensor<*xi8>").unwrap();
+        assert!(RankedTensorTypeRef::try_from_type(erased_tensor_type).is_some());
+        assert!(RankedTensorTypeRef::try_from_type(erased_unranked_type).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn dim_size_out_of_bounds() {
+        let context = Context::new(None, false);
+        let i32_type = IntegerTypeRef::new_signless(&context, 32);
+        RankedTensorTypeRef::new(&[4], i32_type, None).dim_size(1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_ranked_tensor_type_ref() {
+        let _ranked_tensor_type_ref = RankedTensorTypeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
@@ -0,0 +1,224 @@
+use super::impl_type_variant;
+use crate::{
+    ir::TypeRef,
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{
+    mlirShapedTypeGetDimSize, mlirShapedTypeGetDynamicSize, mlirShapedTypeGetDynamicStrideOrOffset,
+    mlirShapedTypeGetElementType, mlirShapedTypeGetRank, mlirShapedTypeHasRank,
+    mlirShapedTypeHasStaticShape, mlirShapedTypeIsDynamicDim, mlirShapedTypeIsDynamicSize,
+    mlirShapedTypeIsDynamicStrideOrOffset, mlirTypeIsAShaped, MlirType,
+};
+
+/// [ShapedTypeRef] is a reference to an instance of the `mlir::ShapedType` interface, which is
+/// implemented by the types that describe a (possibly multi-dimensional) container of elements,
+/// such as tensors, vectors and memrefs.
+///
+/// All relevant bindings into the MLIR C API are used/supported:
+/// - `mlirShapedTypeGetDimSize`
+/// - `mlirShapedTypeGetDynamicSize`
+/// - `mlirShapedTypeGetDynamicStrideOrOffset`
+/// - `mlirShapedTypeGetElementType`
+/// - `mlirShapedTypeGetRank`
+/// - `mlirShapedTypeHasRank`
+/// - `mlirShapedTypeHasStaticShape`
+/// - `mlirShapedTypeIsDynamicDim`
+/// - `mlirShapedTypeIsDynamicSize`
+/// - `mlirShapedTypeIsDynamicStrideOrOffset`
+/// - `mlirTypeIsAShaped`
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct ShapedTypeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, ShapedTypeRef, MlirType);
+impl_type_variant!(ShapedTypeRef, mlirTypeIsAShaped);
+
+impl ShapedTypeRef {
+    /// The sentinel value used in place of the size of a dynamic dimension.
+    pub const DYNAMIC_SIZE: i64 = i64::MIN;
+
+    /// The sentinel value used in place of a dynamic stride or offset.
+    pub const DYNAMIC_STRIDE_OR_OFFSET: i64 = i64::MIN;
+
+    /// # Returns
+    /// Returns the sentinel value used in place of the size of a dynamic dimension, as reported
+    /// by MLIR.
+    pub fn dynamic_size() -> i64 {
+        unsafe { mlirShapedTypeGetDynamicSize() }
+    }
+
+    /// # Returns
+    /// Returns the sentinel value used in place of a dynamic stride or offset, as reported by MLIR.
+    pub fn dynamic_stride_or_offset() -> i64 {
+        unsafe { mlirShapedTypeGetDynamicStrideOrOffset() }
+    }
+
+    /// # Arguments
+    /// * `size` - The dimension size to check.
+    ///
+    /// # Returns
+    /// Returns whether the provided dimension size is the dynamic size sentinel.
+    pub fn is_dynamic_size(size: i64) -> bool {
+        unsafe { mlirShapedTypeIsDynamicSize(size) }
+    }
+
+    /// # Arguments
+    /// * `value` - The stride or offset to check.
+    ///
+    /// # Returns
+    /// Returns whether the provided stride or offset is the dynamic stride/offset sentinel.
+    pub fn is_dynamic_stride_or_offset(value: i64) -> bool {
+        unsafe { mlirShapedTypeIsDynamicStrideOrOffset(value) }
+    }
+
+    /// # Returns
+    /// Returns the element type of the shaped type.
+    pub fn element_type(&self) -> &TypeRef {
+        unsafe { TypeRef::from_raw(mlirShapedTypeGetElementType(self.to_raw())) }
+    }
+
+    /// # Returns
+    /// Returns whether the shaped type is ranked.
+    pub fn has_rank(&self) -> bool {
+        unsafe { mlirShapedTypeHasRank(self.to_raw()) }
+    }
+
+    /// # Returns
+    /// Returns the number of dimensions of the shaped type, or `None` if it is unranked.
+    pub fn rank(&self) -> Option<isize> {
+        if self.has_rank() {
+            Some(unsafe { mlirShapedTypeGetRank(self.to_raw()) as isize })
+        } else {
+            None
+        }
+    }
+
+    /// # Returns
+    /// Returns whether the shaped type is ranked and all of its dimensions are static.
+    pub fn has_static_shape(&self) -> bool {
+        unsafe { mlirShapedTypeHasStaticShape(self.to_raw()) }
+    }
+
+    /// Checks if the provided dimension is dynamic, verifying that the dimension is within bounds.
+    ///
+    /// # Arguments
+    /// * `dim` - The index of the dimension.
+    ///
+    /// # Returns
+    /// Returns whether the dimension is dynamic.
+    pub fn is_dynamic_dim(&self, dim: isize) -> bool {
+        self.check_dim(dim);
+        unsafe { mlirShapedTypeIsDynamicDim(self.to_raw(), dim) }
+    }
+
+    /// Gets the size of the provided dimension, verifying that the dimension is within bounds.
+    ///
+    /// # Arguments
+    /// * `dim` - The index of the dimension.
+    ///
+    /// # Returns
+    /// Returns the size of the dimension, or `None` if it is dynamic.
+    pub fn dim_size(&self, dim: isize) -> Option<i64> {
+        self.check_dim(dim);
+        let size = unsafe { mlirShapedTypeGetDimSize(self.to_raw(), dim) };
+        if Self::is_dynamic_size(size) {
+            None
+        } else {
+            Some(size)
+        }
+    }
+
+    /// # Returns
+    /// Returns the sizes of all dimensions of the shaped type, with `None` for dynamic
+    /// dimensions, or `None` if it is unranked.
+    pub fn shape(&self) -> Option<Vec<Option<i64>>> {
+        self.rank()
+            .map(|rank| (0..rank).map(|dim| self.dim_size(dim)).collect())
+    }
+
+    fn check_dim(&self, dim: isize) {
+        let rank = self.rank().expect("Shaped type is unranked.");
+        if dim < 0 || dim >= rank {
+            panic!("Dimension index {} out of bounds.", dim);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Context;
+
+    #[test]
+    fn sentinels() {
+        assert_eq!(ShapedTypeRef::dynamic_size(), ShapedTypeRef::DYNAMIC_SIZE);
+        assert_eq!(
+            ShapedTypeRef::dynamic_stride_or_offset(),
+            ShapedTypeRef::DYNAMIC_STRIDE_OR_OFFSET
+        );
+        assert!(ShapedTypeRef::is_dynamic_size(ShapedTypeRef::DYNAMIC_SIZE));
+        assert!(!ShapedTypeRef::is_dynamic_size(4));
+        assert!(ShapedTypeRef::is_dynamic_stride_or_offset(
+            ShapedTypeRef::DYNAMIC_STRIDE_OR_OFFSET
+        ));
+    }
+
+    #[test]
+    fn dynamic_shape() {
+        let context = Context::new(None, false);
+        let ty = TypeRef::parse(&context, "memref<4x?xf32>").unwrap();
+        let shaped_type = ShapedTypeRef::try_from_type(ty).unwrap();
+        assert_eq!(shaped_type.element_type().to_string(), "f32");
+        assert_eq!(shaped_type.rank(), Some(2));
+        assert!(!shaped_type.has_static_shape());
+        assert!(!shaped_type.is_dynamic_dim(0));
+        assert!(shaped_type.is_dynamic_dim(1));
+        assert_eq!(shaped_type.shape(), Some(vec![Some(4), None]));
+    }
+
+    #[test]
+    fn unranked() {
+        let context = Context::new(None, false);
+        let ty = TypeRef::parse(&context, "tensor<*xi8>").unwrap();
+        let shaped_type = ShapedTypeRef::try_from_type(ty).unwrap();
+        assert!(!shaped_type.has_rank());
+        assert_eq!(shaped_type.rank(), None);
+        assert_eq!(shaped_type.shape(), None);
+    }
+
+    #[test]
+    fn from_type() {
+        let context = Context::new(None, false);
+        for source in ["tensor<4xi8>", "vector<4xi8>", "memref<4xi8>"] {
+            let ty = TypeRef::parse(&context, source).unwrap();
+            assert!(ShapedTypeRef::try_from_type(ty).is_some());
+        }
+        let erased_integer_type = TypeRef::parse(&context, "i8").unwrap();
+        assert!(ShapedTypeRef::try_from_type(erased_integer_type).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn dim_size_out_of_bounds() {
+        let context = Context::new(None, false);
+        let ty = TypeRef::parse(&context, "tensor<4xi8>").unwrap();
+        ShapedTypeRef::try_from_type(ty).unwrap().dim_size(1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_shaped_type_ref() {
+        let _shaped_type_ref = ShapedTypeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
@@ -0,0 +1,107 @@
+use super::impl_type_variant;
+use crate::{
+    ir::{AttributeRef, ShapedTypeRef, TypeRef},
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{
+    mlirAttributeGetNull, mlirTypeIsAUnrankedMemRef, mlirUnrankedMemRefTypeGet,
+    mlirUnrankedMemrefGetMemorySpace, MlirType,
+};
+
+/// [UnrankedMemRefTypeRef] is a reference to an instance of the `mlir::UnrankedMemRefType` class,
+/// which represents a reference to a region of memory whose rank is not known statically.
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirTypeIsAUnrankedMemRef`
+/// - `mlirUnrankedMemRefTypeGet`
+/// - `mlirUnrankedMemrefGetMemorySpace`
+///
+/// The following bindings are not used/supported:
+/// - `mlirUnrankedMemRefTypeGetChecked`
+/// - `mlirUnrankedMemRefTypeGetTypeID`
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct UnrankedMemRefTypeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, UnrankedMemRefTypeRef, MlirType);
+impl_type_variant!(
+    UnrankedMemRefTypeRef,
+    mlirTypeIsAUnrankedMemRef,
+    ShapedTypeRef
+);
+
+impl UnrankedMemRefTypeRef {
+    /// Constructs a new unranked memref type with the provided element type and memory space. The
+    /// type is owned by the same context that owns its element type.
+    ///
+    /// # Arguments
+    /// * `element_type` - The type of the elements of the memref.
+    /// * `memory_space` - The memory space of the memref, if any.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [UnrankedMemRefTypeRef] instance.
+    pub fn new<'a>(element_type: &'a TypeRef, memory_space: Option<&AttributeRef>) -> &'a Self {
+        unsafe {
+            let raw_memory_space =
+                memory_space.map_or_else(|| mlirAttributeGetNull(), |m| m.to_raw());
+            Self::from_raw(mlirUnrankedMemRefTypeGet(
+                element_type.to_raw(),
+                raw_memory_space,
+            ))
+        }
+    }
+
+    /// # Returns
+    /// Returns the memory space of the memref, if it has one.
+    pub fn memory_space(&self) -> Option<&AttributeRef> {
+        unsafe { AttributeRef::try_from_raw(mlirUnrankedMemrefGetMemorySpace(self.to_raw())) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::IntegerTypeRef, Context};
+
+    #[test]
+    fn new() {
+        let context = Context::new(None, false);
+        let i32_type = IntegerTypeRef::new_signless(&context, 32);
+        let memref_type = UnrankedMemRefTypeRef::new(i32_type, None);
+        assert_eq!(memref_type.to_string(), "memref<*xi32>");
+        assert_eq!(memref_type.element_type(), i32_type.as_type());
+        assert_eq!(memref_type.rank(), None);
+        assert!(memref_type.memory_space().is_none());
+
+        let memory_space = AttributeRef::parse(&context, "1 : i64").unwrap();
+        let memref_type = UnrankedMemRefTypeRef::new(i32_type, Some(memory_space));
+        assert_eq!(memref_type.to_string(), "memref<*xi32, 1>");
+        assert_eq!(memref_type.memory_space(), Some(memory_space));
+    }
+
+    #[test]
+    fn from_type() {
+        let context = Context::new(None, false);
+        let erased_unranked_type = TypeRef::parse(&context, "memref<*xi8>").unwrap();
+        let erased_memref_type = TypeRef::parse(&context, "memref<4xi8>").unwrap();
+        assert!(UnrankedMemRefTypeRef::try_from_type(erased_unranked_type).is_some());
+        assert!(UnrankedMemRefTypeRef::try_from_type(erased_memref_type).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_unranked_memref_type_ref() {
+        let _unranked_memref_type_ref = UnrankedMemRefTypeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
@@ -0,0 +1,83 @@
+use super::impl_type_variant;
+use crate::{
+    ir::{ShapedTypeRef, TypeRef},
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{mlirTypeIsAUnrankedTensor, mlirUnrankedTensorTypeGet, MlirType};
+
+/// [UnrankedTensorTypeRef] is a reference to an instance of the `mlir::UnrankedTensorType` class,
+/// which represents a multi-dimensional array whose rank is not known statically.
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirTypeIsAUnrankedTensor`
+/// - `mlirUnrankedTensorTypeGet`
+///
+/// The following bindings are not used/supported:
+/// - `mlirUnrankedTensorTypeGetChecked`
+/// - `mlirUnrankedTensorTypeGetTypeID`
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct UnrankedTensorTypeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, UnrankedTensorTypeRef, MlirType);
+impl_type_variant!(
+    UnrankedTensorTypeRef,
+    mlirTypeIsAUnrankedTensor,
+    ShapedTypeRef
+);
+
+impl UnrankedTensorTypeRef {
+    /// Constructs a new unranked tensor type with the provided element type. The type is owned by
+    /// the same context that owns its element type.
+    ///
+    /// # Arguments
+    /// * `element_type` - The type of the elements of the tensor.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [UnrankedTensorTypeRef] instance.
+    pub fn new(element_type: &TypeRef) -> &Self {
+        unsafe { Self::from_raw(mlirUnrankedTensorTypeGet(element_type.to_raw())) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::IntegerTypeRef, Context};
+
+    #[test]
+    fn new() {
+        let context = Context::new(None, false);
+        let i32_type = IntegerTypeRef::new_signless(&context, 32);
+        let tensor_type = UnrankedTensorTypeRef::new(i32_type);
+        assert_eq!(tensor_type.to_string(), "tensor<*xi32>");
+        assert_eq!(tensor_type.element_type(), i32_type.as_type());
+        assert_eq!(tensor_type.rank(), None);
+    }
+
+    #[test]
+    fn from_type() {
+        let context = Context::new(None, false);
+        let erased_unranked_type = TypeRef::parse(&context, "tensor<*xi8>").unwrap();
+        let erased_tensor_type = TypeRef::parse(&context, "tensor<4xi8>").unwrap();
+        assert!(UnrankedTensorTypeRef::try_from_type(erased_unranked_type).is_some());
+        assert!(UnrankedTensorTypeRef::try_from_type(erased_tensor_type).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_unranked_tensor_type_ref() {
+        let _unranked_tensor_type_ref = UnrankedTensorTypeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
@@ -0,0 +1,170 @@
+use super::impl_type_variant;
+use crate::{
+    ir::{ShapedTypeRef, TypeRef},
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{
+    mlirTypeIsAVector, mlirVectorTypeGet, mlirVectorTypeGetScalable, mlirVectorTypeIsDimScalable,
+    mlirVectorTypeIsScalable, MlirType,
+};
+
+/// [VectorTypeRef] is a reference to an instance of the `mlir::VectorType` class, which represents
+/// a multi-dimensional SIMD vector with a fixed or scalable (e.g. SVE/RVV) size along each
+/// dimension. Its shape is accessible through [ShapedTypeRef].
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirTypeIsAVector`
+/// - `mlirVectorTypeGet`
+/// - `mlirVectorTypeGetScalable`
+/// - `mlirVectorTypeIsDimScalable`
+/// - `mlirVectorTypeIsScalable`
+///
+/// The following bindings are not used/supported:
+/// - `mlirVectorTypeGetChecked`
+/// - `mlirVectorTypeGetScalableChecked`
+/// - `mlirVectorTypeGetTypeID`
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct VectorTypeRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, VectorTypeRef, MlirType);
+impl_type_variant!(VectorTypeRef, mlirTypeIsAVector, ShapedTypeRef);
+
+impl VectorTypeRef {
+    /// Constructs a new fixed-size vector type with the provided shape and element type. The type
+    /// is owned by the same context that owns its element type.
+    ///
+    /// # Arguments
+    /// * `shape` - The size of each dimension of the vector.
+    /// * `element_type` - The type of the elements of the vector.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [VectorTypeRef] instance.
+    pub fn new<'a>(shape: &[i64], element_type: &'a TypeRef) -> &'a Self {
+        unsafe {
+            Self::from_raw(mlirVectorTypeGet(
+                shape.len() as isize,
+                shape.as_ptr(),
+                element_type.to_raw(),
+            ))
+        }
+    }
+
+    /// Constructs a new vector type with the provided shape and element type, where each
+    /// dimension may be scalable (i.e. a runtime multiple of its size).
+    ///
+    /// # Arguments
+    /// * `shape` - The size of each dimension of the vector.
+    /// * `scalable` - Whether each dimension of the vector is scalable. Must have the same length
+    ///   as `shape`.
+    /// * `element_type` - The type of the elements of the vector.
+    ///
+    /// # Returns
+    /// Returns a reference to a new [VectorTypeRef] instance.
+    pub fn new_scalable<'a>(
+        shape: &[i64],
+        scalable: &[bool],
+        element_type: &'a TypeRef,
+    ) -> &'a Self {
+        if shape.len() != scalable.len() {
+            panic!(
+                "Shape has {} dimensions but scalability was specified for {}.",
+                shape.len(),
+                scalable.len()
+            );
+        }
+        unsafe {
+            Self::from_raw(mlirVectorTypeGetScalable(
+                shape.len() as isize,
+                shape.as_ptr(),
+                scalable.as_ptr(),
+                element_type.to_raw(),
+            ))
+        }
+    }
+
+    /// # Returns
+    /// Returns whether any dimension of the vector is scalable.
+    pub fn is_scalable(&self) -> bool {
+        unsafe { mlirVectorTypeIsScalable(self.to_raw()) }
+    }
+
+    /// Checks if the provided dimension is scalable, verifying that the dimension is within
+    /// bounds.
+    ///
+    /// # Arguments
+    /// * `dim` - The index of the dimension.
+    ///
+    /// # Returns
+    /// Returns whether the dimension is scalable.
+    pub fn is_dim_scalable(&self, dim: isize) -> bool {
+        if dim < 0 || dim >= self.rank().unwrap() {
+            panic!("Dimension index {} out of bounds.", dim);
+        }
+        unsafe { mlirVectorTypeIsDimScalable(self.to_raw(), dim) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::FloatTypeRef, Context};
+
+    #[test]
+    fn new() {
+        let context = Context::new(None, false);
+        let f32_type = FloatTypeRef::new_f32(&context);
+        let vector_type = VectorTypeRef::new(&[2, 4], f32_type);
+        assert_eq!(vector_type.to_string(), "vector<2x4xf32>");
+        assert_eq!(vector_type.element_type(), f32_type.as_type());
+        assert_eq!(vector_type.rank(), Some(2));
+        assert_eq!(vector_type.shape(), Some(vec![Some(2), Some(4)]));
+        assert!(!vector_type.is_scalable());
+    }
+
+    #[test]
+    fn new_scalable() {
+        let context = Context::new(None, false);
+        let f32_type = FloatTypeRef::new_f32(&context);
+        let vector_type = VectorTypeRef::new_scalable(&[2, 4], &[false, true], f32_type);
+        assert_eq!(vector_type.to_string(), "vector<2x[4]xf32>");
+        assert!(vector_type.is_scalable());
+        assert!(!vector_type.is_dim_scalable(0));
+        assert!(vector_type.is_dim_scalable(1));
+        assert_eq!(vector_type.dim_size(1), Some(4));
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_scalable_mismatched_lengths() {
+        let context = Context::new(None, false);
+        let f32_type = FloatTypeRef::new_f32(&context);
+        VectorTypeRef::new_scalable(&[2, 4], &[true], f32_type);
+    }
+
+    #[test]
+    fn from_type() {
+        let context = Context::new(None, false);
+        let erased_vector_type = TypeRef::parse(&context, "vector<4xi8>").unwrap();
+        let erased_tensor_type = TypeRef::parse(&context, "tensor<4xi8>").unwrap();
+        assert!(VectorTypeRef::try_from_type(erased_vector_type).is_some());
+        assert!(VectorTypeRef::try_from_type(erased_tensor_type).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_vector_type_ref() {
+        let _vector_type_ref = VectorTypeRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
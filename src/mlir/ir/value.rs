@@ -1,6 +1,10 @@
-use super::TypeRef;
+mod block_argument;
+mod op_result;
+
+pub use self::{block_argument::*, op_result::*};
+use super::{AsmState, OpOperandRef, OperationRef, TypeRef};
 use crate::support::{
-    binding::{impl_unowned_mlir_value, UnownedMlirValue},
+    binding::{impl_unowned_mlir_value, OwnedMlirValue, UnownedMlirValue},
     string_reader::StringReader,
 };
 
@@ -10,8 +14,9 @@ use std::{
 };
 
 use mlir_sys::{
-    mlirValueEqual, mlirValueGetType, mlirValueIsABlockArgument, mlirValueIsAOpResult,
-    mlirValuePrint, mlirValueSetType, MlirValue,
+    mlirValueEqual, mlirValueGetFirstUse, mlirValueGetType, mlirValueIsABlockArgument,
+    mlirValueIsAOpResult, mlirValuePrint, mlirValuePrintAsOperand, mlirValueReplaceAllUsesOfWith,
+    mlirValueSetType, MlirValue,
 };
 
 /// [ValueRef] is a reference to an instance of the `mlir::Value` class, which represents a value in
@@ -19,17 +24,17 @@ use mlir_sys::{
 ///
 /// The following bindings into the MLIR C API are used/supported:
 /// - `mlirValueEqual`
+/// - `mlirValueGetFirstUse`
 /// - `mlirValueGetType`
 /// - `mlirValueIsABlockArgument`
 /// - `mlirValueIsAOpResult`
 /// - `mlirValuePrint`
+/// - `mlirValuePrintAsOperand`
+/// - `mlirValueReplaceAllUsesOfWith`
 /// - `mlirValueSetType`
 ///
 /// The following bindings are not used/supported:
 /// - `mlirValueDump`
-/// - `mlirValueGetFirstUse`
-/// - `mlirValuePrintAsOperand`
-/// - `mlirValueReplaceAllUsesOfWith`
 ///
 /// # Safety
 /// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
@@ -70,6 +75,102 @@ impl<'c> ValueRef<'c> {
     pub fn is_op_result(&self) -> bool {
         unsafe { mlirValueIsAOpResult(self.to_raw()) }
     }
+
+    /// # Returns
+    /// Returns the first use of the value, if it has any.
+    pub fn first_use(&self) -> Option<&OpOperandRef<'c>> {
+        unsafe { OpOperandRef::try_from_raw(mlirValueGetFirstUse(self.to_raw())) }
+    }
+
+    /// # Returns
+    /// Returns an iterator over all uses of the value.
+    pub fn uses(&self) -> impl Iterator<Item = &OpOperandRef<'c>> {
+        std::iter::successors(self.first_use(), |operand| operand.next_use())
+    }
+
+    /// # Returns
+    /// Returns an iterator over the operations using the value, yielding an operation once for
+    /// each of its operands that uses the value (as `mlir::Value::getUsers` does).
+    pub fn users(&self) -> impl Iterator<Item = &OperationRef<'c>> {
+        self.uses().map(|operand| operand.owner())
+    }
+
+    /// # Returns
+    /// Returns the number of uses of the value.
+    pub fn num_uses(&self) -> usize {
+        self.uses().count()
+    }
+
+    /// # Returns
+    /// Returns whether the value has exactly one use.
+    pub fn has_one_use(&self) -> bool {
+        let mut uses = self.uses();
+        uses.next().is_some() && uses.next().is_none()
+    }
+
+    /// Replaces the uses of the value for which the predicate returns `true` with the provided
+    /// value, e.g. to replace all uses except those in specific operations.
+    ///
+    /// # Arguments
+    /// * `new_value` - The value to replace the selected uses with.
+    /// * `predicate` - A function receiving the operation owning a use and the index of the
+    ///   operand, which returns whether the use should be replaced.
+    pub fn replace_uses_where<P>(&self, new_value: &ValueRef<'c>, mut predicate: P)
+    where
+        P: FnMut(&OperationRef<'c>, isize) -> bool,
+    {
+        // The use list is modified by each replacement, so collect the uses to replace first.
+        let replacements = self
+            .uses()
+            .map(|operand| (operand.owner(), operand.operand_number()))
+            .filter(|&(owner, idx)| predicate(owner, idx))
+            .collect::<Vec<_>>();
+        for (owner, idx) in replacements {
+            owner.set_operand(idx, new_value);
+        }
+    }
+
+    /// Prints the value as it is referred to when used as an operand (e.g. `%3` or `%arg0`),
+    /// rather than printing its defining operation.
+    ///
+    /// This creates a new [AsmState] for the IR surrounding the value on every call; use
+    /// [ValueRef::to_operand_string_with_state] when printing many values.
+    ///
+    /// # Returns
+    /// Returns the operand name of the value.
+    pub fn to_operand_string(&self) -> String {
+        self.to_operand_string_with_state(&AsmState::for_value(self))
+    }
+
+    /// Prints the value as it is referred to when used as an operand (e.g. `%3` or `%arg0`), using
+    /// the names assigned by the provided printing state.
+    ///
+    /// # Arguments
+    /// * `state` - The printing state to take value names from.
+    ///
+    /// # Returns
+    /// Returns the operand name of the value.
+    pub fn to_operand_string_with_state(&self, state: &AsmState<'c>) -> String {
+        let mut string = String::new();
+        let mut reader = StringReader::new(&mut string);
+        unsafe {
+            mlirValuePrintAsOperand(
+                self.to_raw(),
+                state.to_raw(),
+                reader.callback(),
+                reader.as_raw_mut(),
+            )
+        }
+        string
+    }
+
+    /// Replaces all uses of the value with the provided value.
+    ///
+    /// # Arguments
+    /// * `new_value` - The value to replace all uses of this value with.
+    pub fn replace_all_uses_with(&self, new_value: &ValueRef<'c>) {
+        unsafe { mlirValueReplaceAllUsesOfWith(self.to_raw(), new_value.to_raw()) }
+    }
 }
 
 impl<'c> PartialEq for ValueRef<'c> {
@@ -88,9 +189,168 @@ impl<'c> Display for ValueRef<'c> {
     }
 }
 
+macro_rules! impl_value_variant {
+    ($variant_type:ident, $verify_fn:ident) => {
+        impl<'c> $variant_type<'c> {
+            pub fn try_from_value<'a>(value: &'a $crate::ir::ValueRef<'c>) -> Option<&'a Self> {
+                let types_match = unsafe { $verify_fn(value.to_raw()) };
+                if types_match {
+                    Some(unsafe { $variant_type::from_raw(value.to_raw()) })
+                } else {
+                    None
+                }
+            }
+
+            pub fn as_value(&self) -> &$crate::ir::ValueRef<'c> {
+                unsafe { $crate::ir::ValueRef::from_raw(self.to_raw()) }
+            }
+        }
+
+        impl<'c> std::ops::Deref for $variant_type<'c> {
+            type Target = $crate::ir::ValueRef<'c>;
+
+            fn deref(&self) -> &Self::Target {
+                self.as_value()
+            }
+        }
+    };
+}
+
+use impl_value_variant;
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{ir::Operation, Context};
+
+    #[test]
+    fn uses() {
+        #[rustfmt::skip]
+        let operation_source = r#"
+module {
+    %0:2 = "dialect.op1"() : () -> (i32, i32)
+    "dialect.op2"(%0#0) : (i32) -> ()
+    "dialect.op3"(%0#0, %0#0) : (i32, i32) -> ()
+}
+"#;
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let operation = Operation::parse(&context, operation_source, "test.mlir").unwrap();
+        let op1 = operation.ops_named("dialect.op1").next().unwrap();
+        let value = op1.result(0);
+        assert!(value.first_use().is_some());
+        let mut owners = value
+            .uses()
+            .map(|operand| operand.owner().name().value().as_str().to_string())
+            .collect::<Vec<_>>();
+        owners.sort();
+        assert_eq!(owners, vec!["dialect.op2", "dialect.op3", "dialect.op3"]);
+
+        let unused = op1.result(1);
+        assert!(unused.first_use().is_none());
+        assert_eq!(unused.uses().count(), 0);
+    }
+
+    #[test]
+    fn users() {
+        #[rustfmt::skip]
+        let operation_source = r#"
+module {
+    %0:2 = "dialect.op1"() : () -> (i32, i32)
+    "dialect.op2"(%0#0, %0#1) : (i32, i32) -> ()
+    "dialect.op3"(%0#0) : (i32) -> ()
+}
+"#;
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let operation = Operation::parse(&context, operation_source, "test.mlir").unwrap();
+        let op1 = operation.ops_named("dialect.op1").next().unwrap();
+        let op2 = operation.ops_named("dialect.op2").next().unwrap();
+
+        assert_eq!(op1.result(0).num_uses(), 2);
+        assert!(!op1.result(0).has_one_use());
+        assert_eq!(op1.result(1).num_uses(), 1);
+        assert!(op1.result(1).has_one_use());
+        assert_eq!(op1.result(1).users().collect::<Vec<_>>(), vec![op2]);
+    }
+
+    #[test]
+    fn replace_uses_where() {
+        #[rustfmt::skip]
+        let operation_source = r#"
+module {
+    %0 = "dialect.op1"() : () -> i32
+    %1 = "dialect.op2"() : () -> i32
+    "dialect.op3"(%0, %0) : (i32, i32) -> ()
+    "dialect.op4"(%0) : (i32) -> ()
+}
+"#;
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let operation = Operation::parse(&context, operation_source, "test.mlir").unwrap();
+        let old_value = operation.ops_named("dialect.op1").next().unwrap().result(0);
+        let new_value = operation.ops_named("dialect.op2").next().unwrap().result(0);
+        let op3 = operation.ops_named("dialect.op3").next().unwrap();
+        let op4 = operation.ops_named("dialect.op4").next().unwrap();
+
+        old_value.replace_uses_where(new_value, |owner, idx| owner == op3 && idx == 1);
+        assert_eq!(op3.operand(0), old_value);
+        assert_eq!(op3.operand(1), new_value);
+        assert_eq!(op4.operand(0), old_value);
+
+        old_value.replace_uses_where(new_value, |owner, _| owner != op4);
+        assert_eq!(op3.operand(0), new_value);
+        assert_eq!(op4.operand(0), old_value);
+        assert!(old_value.has_one_use());
+    }
+
+    #[test]
+    fn to_operand_string() {
+        #[rustfmt::skip]
+        let operation_source = r#"
+module {
+    %0 = "dialect.op1"() : () -> i32
+    %1:2 = "dialect.op2"(%0) : (i32) -> (i32, i32)
+}
+"#;
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let operation = Operation::parse(&context, operation_source, "test.mlir").unwrap();
+        let op2 = operation.ops_named("dialect.op2").next().unwrap();
+        assert_eq!(op2.operand(0).to_operand_string(), "%0");
+
+        let state = AsmState::for_operation(&operation);
+        assert_eq!(op2.result(0).to_operand_string_with_state(&state), "%1#0");
+        assert_eq!(op2.result(1).to_operand_string_with_state(&state), "%1#1");
+    }
+
+    #[test]
+    fn replace_all_uses_with() {
+        #[rustfmt::skip]
+        let operation_source = r#"
+module {
+    %0 = "dialect.op1"() : () -> i32
+    %1 = "dialect.op2"() : () -> i32
+    "dialect.op3"(%0, %0) : (i32, i32) -> ()
+}
+"#;
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let operation = Operation::parse(&context, operation_source, "test.mlir").unwrap();
+        let first_op = operation
+            .region(0)
+            .first_block()
+            .unwrap()
+            .first_operation()
+            .unwrap();
+        let second_op = first_op.next_in_parent_block().unwrap();
+        let third_op = second_op.next_in_parent_block().unwrap();
+        first_op
+            .result(0)
+            .replace_all_uses_with(second_op.result(0));
+        assert_eq!(third_op.operand(0), second_op.result(0));
+        assert_eq!(third_op.operand(1), second_op.result(0));
+    }
 
     #[test]
     #[should_panic]
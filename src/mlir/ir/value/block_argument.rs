@@ -0,0 +1,97 @@
+use super::impl_value_variant;
+use crate::{
+    ir::{BlockRef, TypeRef},
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{
+    mlirBlockArgumentGetArgNumber, mlirBlockArgumentGetOwner, mlirBlockArgumentSetType,
+    mlirValueIsABlockArgument, MlirValue,
+};
+
+/// [BlockArgumentRef] is a reference to an instance of the `mlir::BlockArgument` class, which
+/// represents a value that is an argument to a block.
+///
+/// All relevant bindings into the MLIR C API are used/supported:
+/// - `mlirBlockArgumentGetArgNumber`
+/// - `mlirBlockArgumentGetOwner`
+/// - `mlirBlockArgumentSetType`
+/// - `mlirValueIsABlockArgument`
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct BlockArgumentRef<'c> {
+    _context: PhantomData<&'c ()>,
+}
+
+impl_unowned_mlir_value!(context_ref, BlockArgumentRef, MlirValue);
+impl_value_variant!(BlockArgumentRef, mlirValueIsABlockArgument);
+
+impl<'c> BlockArgumentRef<'c> {
+    /// # Returns
+    /// Returns the block that owns the argument.
+    pub fn owner(&self) -> &BlockRef<'c> {
+        unsafe { BlockRef::from_raw(mlirBlockArgumentGetOwner(self.to_raw())) }
+    }
+
+    /// # Returns
+    /// Returns the position of the argument in the argument list of its owning block.
+    pub fn arg_number(&self) -> isize {
+        unsafe { mlirBlockArgumentGetArgNumber(self.to_raw()) }
+    }
+
+    /// Sets the type of the argument.
+    ///
+    /// # Arguments
+    /// * `ty` - The type to set the argument to.
+    pub fn set_type(&self, ty: &'c TypeRef) {
+        unsafe { mlirBlockArgumentSetType(self.to_raw(), ty.to_raw()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{Block, IntegerTypeRef, LocationRef, Operation},
+        Context,
+    };
+
+    #[test]
+    fn block_argument() {
+        let context = Context::new(None, false);
+        let loc = LocationRef::new_unknown(&context);
+        let i8_type = IntegerTypeRef::new_signless(&context, 8);
+        let i16_type = IntegerTypeRef::new_signless(&context, 16);
+        let block = Block::new(&[(i8_type, loc), (i8_type, loc)]);
+        let argument = BlockArgumentRef::try_from_value(block.argument(1)).unwrap();
+        assert_eq!(argument.owner(), &block);
+        assert_eq!(argument.arg_number(), 1);
+
+        argument.set_type(i16_type);
+        assert_eq!(block.argument(1).r#type(), i16_type.as_type());
+    }
+
+    #[test]
+    fn from_value() {
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let operation_source = r#"%0 = "dialect.op1"() : () -> i32"#;
+        let module = Operation::parse(&context, operation_source, "").unwrap();
+        let operation = module.ops_named("dialect.op1").next().unwrap();
+        assert!(BlockArgumentRef::try_from_value(operation.result(0)).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_block_argument_ref() {
+        let _block_argument_ref = BlockArgumentRef {
+            _context: PhantomData,
+        };
+    }
+}
@@ -0,0 +1,82 @@
+use super::impl_value_variant;
+use crate::{
+    ir::OperationRef,
+    support::binding::{impl_unowned_mlir_value, UnownedMlirValue},
+};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{
+    mlirOpResultGetOwner, mlirOpResultGetResultNumber, mlirValueIsAOpResult, MlirValue,
+};
+
+/// [OpResultRef] is a reference to an instance of the `mlir::OpResult` class, which represents a
+/// value that is a result of an operation.
+///
+/// All relevant bindings into the MLIR C API are used/supported:
+/// - `mlirOpResultGetOwner`
+/// - `mlirOpResultGetResultNumber`
+/// - `mlirValueIsAOpResult`
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct OpResultRef<'c> {
+    _context: PhantomData<&'c ()>,
+}
+
+impl_unowned_mlir_value!(context_ref, OpResultRef, MlirValue);
+impl_value_variant!(OpResultRef, mlirValueIsAOpResult);
+
+impl<'c> OpResultRef<'c> {
+    /// # Returns
+    /// Returns the operation that defines the result.
+    pub fn owner(&self) -> &OperationRef<'c> {
+        unsafe { OperationRef::from_raw(mlirOpResultGetOwner(self.to_raw())) }
+    }
+
+    /// # Returns
+    /// Returns the position of the result in the result list of its defining operation.
+    pub fn result_number(&self) -> isize {
+        unsafe { mlirOpResultGetResultNumber(self.to_raw()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{Block, IntegerTypeRef, LocationRef, Operation},
+        Context,
+    };
+
+    #[test]
+    fn op_result() {
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let operation_source = r#"%0:2 = "dialect.op1"() : () -> (i32, i64)"#;
+        let operation = Operation::parse(&context, operation_source, "").unwrap();
+        let result = OpResultRef::try_from_value(operation.result(1)).unwrap();
+        assert_eq!(result.owner(), &operation);
+        assert_eq!(result.result_number(), 1);
+    }
+
+    #[test]
+    fn from_value() {
+        let context = Context::new(None, false);
+        let loc = LocationRef::new_unknown(&context);
+        let i8_type = IntegerTypeRef::new_signless(&context, 8);
+        let block = Block::new(&[(i8_type, loc)]);
+        assert!(OpResultRef::try_from_value(block.argument(0)).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_op_result_ref() {
+        let _op_result_ref = OpResultRef {
+            _context: PhantomData,
+        };
+    }
+}
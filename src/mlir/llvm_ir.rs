@@ -0,0 +1,140 @@
+use crate::{ir::ModuleRef, Diagnostic, UnownedMlirValue};
+
+use llvm_sys::prelude::{LLVMContextRef, LLVMModuleRef};
+use mlir_sys::mlirTranslateModuleToLLVMIR;
+
+/// Translates a module lowered to the LLVM dialect to LLVM IR, so that it can be compiled further
+/// using the LLVM C API (e.g. through `llvm-sys`). The translations of all dialects to LLVM IR are
+/// registered in the context of the module as needed (see
+/// [crate::ContextRef::register_all_llvm_translations]).
+///
+/// # Arguments
+/// * `module` - The module to translate, which must only contain operations of the LLVM dialect
+///   (and of other dialects which can be translated to LLVM IR, such as `omp`).
+/// * `llvm_context` - The LLVM context to create the LLVM module in.
+///
+/// # Returns
+/// Returns the new LLVM module, which is owned by the caller and must be disposed of using
+/// `LLVMDisposeModule`, or the diagnostics emitted while translating the module if it could not
+/// be translated.
+///
+/// # Safety
+/// The caller of this function is responsible for ensuring that `llvm_context` is a valid LLVM
+/// context, created by the same LLVM library MLIR is linked against.
+pub unsafe fn to_llvm_ir(
+    module: &ModuleRef,
+    llvm_context: LLVMContextRef,
+) -> Result<LLVMModuleRef, Vec<Diagnostic>> {
+    let context = module.context();
+    context.register_all_llvm_translations();
+    let capture = context.capture_diagnostics();
+    // The LLVM types of `mlir-sys` and `llvm-sys` are distinct, but refer to the same classes.
+    let llvm_module =
+        mlirTranslateModuleToLLVMIR(module.as_operation().to_raw(), llvm_context.cast());
+    if llvm_module.is_null() {
+        Err(capture.take())
+    } else {
+        Ok(llvm_module.cast())
+    }
+}
+
+/// Translates a module lowered to the LLVM dialect to an `inkwell` module (see [to_llvm_ir]).
+///
+/// # Arguments
+/// * `module` - The module to translate.
+/// * `llvm_context` - The `inkwell` context to create the module in.
+///
+/// # Returns
+/// Returns the new `inkwell` module, or the diagnostics emitted while translating the module if it
+/// could not be translated.
+#[cfg(feature = "inkwell")]
+pub fn to_inkwell_module<'ctx>(
+    module: &ModuleRef,
+    llvm_context: &'ctx inkwell::context::Context,
+) -> Result<inkwell::module::Module<'ctx>, Vec<Diagnostic>> {
+    use inkwell::context::AsContextRef;
+
+    unsafe {
+        let llvm_module = to_llvm_ir(module, llvm_context.as_ctx_ref().cast())?;
+        Ok(inkwell::module::Module::new(llvm_module.cast()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::Module,
+        pass::{Pass, PassManager},
+        Context, DialectRegistry,
+    };
+
+    use std::ffi::CStr;
+
+    use llvm_sys::core::{
+        LLVMContextCreate, LLVMContextDispose, LLVMDisposeMessage, LLVMDisposeModule,
+        LLVMPrintModuleToString,
+    };
+
+    fn context() -> Context {
+        let dialect_registry = DialectRegistry::default();
+        dialect_registry.register_all_dialects();
+        let context = Context::new(Some(&dialect_registry), false);
+        context.load_all_available_dialects();
+        context
+    }
+
+    fn add_module(context: &Context) -> Module {
+        let module_source = r#"
+func.func @add(%arg0: i32, %arg1: i32) -> i32 {
+  %0 = arith.addi %arg0, %arg1 : i32
+  return %0 : i32
+}
+"#;
+        let module = Module::parse(context, module_source).unwrap();
+        let pass_manager = PassManager::new(context);
+        pass_manager.add_owned_pass(Pass::arith_to_llvm());
+        pass_manager.add_owned_pass(Pass::func_to_llvm());
+        pass_manager.add_owned_pass(Pass::reconcile_unrealized_casts());
+        pass_manager.run(module.as_operation()).unwrap();
+        module
+    }
+
+    #[test]
+    fn translate_to_llvm_ir() {
+        let context = context();
+        let module = add_module(&context);
+        unsafe {
+            let llvm_context = LLVMContextCreate();
+            let llvm_module = to_llvm_ir(&module, llvm_context).unwrap();
+            let printed = LLVMPrintModuleToString(llvm_module);
+            assert!(CStr::from_ptr(printed)
+                .to_string_lossy()
+                .contains("define i32 @add(i32"));
+            LLVMDisposeMessage(printed);
+            LLVMDisposeModule(llvm_module);
+            LLVMContextDispose(llvm_context);
+        }
+    }
+
+    #[test]
+    fn translate_unlowered_module() {
+        let context = context();
+        let module = Module::parse(&context, "func.func private @f()").unwrap();
+        unsafe {
+            let llvm_context = LLVMContextCreate();
+            assert!(to_llvm_ir(&module, llvm_context).is_err());
+            LLVMContextDispose(llvm_context);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "inkwell")]
+    fn translate_to_inkwell_module() {
+        let context = context();
+        let module = add_module(&context);
+        let llvm_context = inkwell::context::Context::create();
+        let llvm_module = to_inkwell_module(&module, &llvm_context).unwrap();
+        assert!(llvm_module.get_function("add").is_some());
+    }
+}
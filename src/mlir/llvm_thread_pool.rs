@@ -0,0 +1,82 @@
+use crate::support::binding::{impl_owned_mlir_value, impl_unowned_mlir_value, UnownedMlirValue};
+
+use std::marker::PhantomData;
+
+use mlir_sys::{mlirLlvmThreadPoolCreate, mlirLlvmThreadPoolDestroy, MlirLlvmThreadPool};
+
+/// [LlvmThreadPool] wraps the `llvm::ThreadPool` class, a pool of worker threads that can be
+/// shared by several contexts (see [ContextRef::set_thread_pool]) instead of each of them
+/// creating its own.
+///
+/// All relevant bindings into the MLIR C API are used/supported:
+/// - `mlirLlvmThreadPoolCreate`
+/// - `mlirLlvmThreadPoolDestroy`
+///
+/// [ContextRef::set_thread_pool]: crate::ContextRef::set_thread_pool
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct LlvmThreadPool {
+    raw: MlirLlvmThreadPool,
+}
+
+impl_owned_mlir_value!(no_refs, LlvmThreadPool, MlirLlvmThreadPool);
+
+impl Default for LlvmThreadPool {
+    fn default() -> Self {
+        Self {
+            raw: unsafe { mlirLlvmThreadPoolCreate() },
+        }
+    }
+}
+
+impl Drop for LlvmThreadPool {
+    fn drop(&mut self) {
+        unsafe { mlirLlvmThreadPoolDestroy(self.raw) }
+    }
+}
+
+/// [LlvmThreadPoolRef] is a reference to an instance of the `llvm::ThreadPool` class.
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct LlvmThreadPoolRef {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(
+    no_refs,
+    LlvmThreadPool,
+    LlvmThreadPoolRef,
+    MlirLlvmThreadPool
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Context;
+
+    #[test]
+    fn shared_thread_pool() {
+        let thread_pool = LlvmThreadPool::default();
+        let context1 = Context::new(None, false);
+        let context2 = Context::new(None, true);
+        unsafe {
+            context1.set_thread_pool(&thread_pool);
+            context2.set_thread_pool(&thread_pool);
+        }
+        assert!(context1.get_or_load_dialect(&"builtin").is_some());
+        context1.set_threading_enabled(false);
+        context2.set_threading_enabled(false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_thread_pool_ref() {
+        let _thread_pool_ref = LlvmThreadPoolRef {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
@@ -0,0 +1,35 @@
+mod attribution;
+mod bufferization;
+mod conversion;
+mod external;
+mod gpu;
+mod pass_manager;
+mod pipeline;
+mod registration;
+mod reproducer;
+
+pub use self::{
+    attribution::{AttributedElement, PassDiagnostic},
+    bufferization::*,
+    external::*,
+    gpu::*,
+    pass_manager::*,
+    pipeline::*,
+    registration::*,
+};
+use crate::support::binding::impl_owned_mlir_value;
+
+use mlir_sys::MlirPass;
+
+/// [Pass] wraps the `mlir::Pass` class, a single transformation or analysis which can be added to
+/// a [PassManager].
+///
+/// The pass manager takes ownership of every pass added to it. The MLIR C API provides no way to
+/// destroy a pass otherwise, so a [Pass] which is never added to a pass manager is leaked.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct Pass {
+    raw: MlirPass,
+}
+
+impl_owned_mlir_value!(no_refs, Pass, MlirPass);
@@ -0,0 +1,335 @@
+use super::{ExternalPassHandle, Pass, PassManager, RustPass};
+use crate::{ir::OperationRef, support::binding::OwnedMlirValue, Diagnostic};
+
+use std::{
+    cell::RefCell,
+    fmt::{Display, Formatter},
+    sync::{Arc, Mutex},
+};
+
+use mlir_sys::mlirPassManagerAddOwnedPass;
+
+/// The command line argument of the marker passes, which is stripped from printed pipelines.
+pub(crate) const MARKER_ARGUMENT: &str = "mlir-rs-pass-attribution";
+
+thread_local! {
+    /// The progress of the pipelines being run on the current thread, innermost last. Marker passes
+    /// are appended to the top level of a pipeline, so they run on the thread running it.
+    static RUNNING_PIPELINES: RefCell<Vec<Arc<Mutex<PassProgress>>>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+/// The progress of a run of a pipeline, as recorded by its marker passes.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PassProgress {
+    /// The element of the pipeline that is running, or `None` before the first marker pass ran.
+    element: Option<AttributedElement>,
+    /// The name of the operation the last marker pass ran on, which the element runs on.
+    operation: Option<String>,
+}
+
+impl PassProgress {
+    /// # Returns
+    /// Returns the element of the pipeline that is running, if any.
+    pub(crate) fn element(&self) -> Option<AttributedElement> {
+        self.element.clone()
+    }
+
+    /// # Arguments
+    /// * `diagnostic` - The diagnostic to attribute.
+    ///
+    /// # Returns
+    /// Returns the diagnostic, attributed to the element that is running, if any.
+    pub(crate) fn attribute(&self, diagnostic: Diagnostic) -> PassDiagnostic {
+        PassDiagnostic {
+            pass: self.element.clone(),
+            operation: self.operation.clone(),
+            diagnostic,
+        }
+    }
+}
+
+/// [PipelineRun] makes the marker passes running on the current thread record their progress,
+/// until it is dropped.
+pub(crate) struct PipelineRun {
+    progress: Arc<Mutex<PassProgress>>,
+}
+
+impl PipelineRun {
+    /// Starts recording the progress of a run of a pipeline on the current thread.
+    pub(crate) fn start() -> Self {
+        let progress = Arc::new(Mutex::new(PassProgress::default()));
+        RUNNING_PIPELINES.with(|runs| runs.borrow_mut().push(progress.clone()));
+        PipelineRun { progress }
+    }
+
+    /// # Returns
+    /// Returns the progress of the run, which is updated by the marker passes.
+    pub(crate) fn progress(&self) -> &Arc<Mutex<PassProgress>> {
+        &self.progress
+    }
+}
+
+impl Drop for PipelineRun {
+    fn drop(&mut self) {
+        RUNNING_PIPELINES.with(|runs| runs.borrow_mut().pop());
+    }
+}
+
+/// [AttributionMarker] is the pass preceding every element of a pipeline, which records that the
+/// element is running.
+#[derive(Clone)]
+struct AttributionMarker {
+    element: AttributedElement,
+}
+
+impl RustPass for AttributionMarker {
+    fn run(&mut self, operation: &OperationRef, _pass: &ExternalPassHandle) {
+        RUNNING_PIPELINES.with(|runs| {
+            if let Some(progress) = runs.borrow().last() {
+                *progress.lock().unwrap() = PassProgress {
+                    element: Some(self.element.clone()),
+                    operation: Some(operation.name().value().as_str().to_owned()),
+                };
+            }
+        });
+    }
+}
+
+/// The MLIR C API provides no pass instrumentation, so the pass emitting a diagnostic is
+/// attributed by preceding every element appended to a pass manager (a pass, or a textual
+/// pipeline) with a marker pass recording the progress of the run. Marker passes are hidden when
+/// printing the pipeline of the pass manager.
+impl<'c> PassManager<'c> {
+    /// Enables attributing the diagnostics emitted while running the pipeline to the element of
+    /// the pipeline (a pass, or a textual pipeline) that emitted them (see [PassDiagnostic]).
+    /// Only the elements appended afterwards through the [PassManager] itself are attributed.
+    ///
+    /// Every element is preceded by a marker pass. As a consequence, the verifier (if enabled)
+    /// also runs after each marker, and adjacent nested pipelines (e.g.
+    /// `func.func(cse),func.func(canonicalize)`) are no longer merged into a single one.
+    pub fn enable_pass_attribution(&self) {
+        if self.attributed_elements.get().is_none() {
+            self.attributed_elements.set(Some(0));
+        }
+    }
+
+    /// Appends the marker pass preceding the next element of the pipeline, if pass attribution
+    /// is enabled.
+    ///
+    /// # Arguments
+    /// * `pipeline` - The textual pipeline about to be appended, or `None` if a pass is about to
+    ///   be appended.
+    pub(crate) fn add_attribution_marker(&self, pipeline: Option<&str>) {
+        let Some(index) = self.attributed_elements.get() else {
+            return;
+        };
+        let marker = AttributionMarker {
+            element: AttributedElement {
+                index,
+                pipeline: pipeline.map(|pipeline| pipeline.trim().to_string()),
+            },
+        };
+        let pass = Pass::new_external(marker, "PassAttribution", MARKER_ARGUMENT, "", None, &[]);
+        unsafe { mlirPassManagerAddOwnedPass(self.to_raw(), pass.to_raw()) }
+    }
+
+    /// Records that the element preceded by the last marker pass was appended to the pipeline, if
+    /// pass attribution is enabled. If appending the element failed instead, the next element
+    /// reuses its index, and the marker pass is superseded by the marker of the next element.
+    pub(crate) fn record_attributed_element(&self) {
+        if let Some(index) = self.attributed_elements.get() {
+            self.attributed_elements.set(Some(index + 1));
+        }
+    }
+}
+
+/// [AttributedElement] identifies the element of a pipeline (a pass, or a textual pipeline) that
+/// was running when a diagnostic was emitted, if pass attribution is enabled (see
+/// [PassManager::enable_pass_attribution]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttributedElement {
+    /// The index of the element among the elements appended since pass attribution was enabled.
+    pub index: usize,
+    /// The textual pipeline, if the element was appended as one. The MLIR C API cannot print a
+    /// single pass, so elements appended as a [Pass] are only identified by their index.
+    pub pipeline: Option<String>,
+}
+
+impl Display for AttributedElement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.pipeline {
+            Some(pipeline) => write!(f, "#{} ({})", self.index, pipeline),
+            None => write!(f, "#{}", self.index),
+        }
+    }
+}
+
+/// [PassDiagnostic] is a diagnostic emitted while running a pipeline, together with the element
+/// of the pipeline that was running when it was emitted, if pass attribution is enabled (see
+/// [PassManager::enable_pass_attribution]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PassDiagnostic {
+    /// The element of the pipeline that was running, or `None` if the diagnostic was emitted
+    /// outside of any attributed element.
+    pub pass: Option<AttributedElement>,
+    /// The name of the operation the element was running on (e.g. `builtin.module`), which is the
+    /// anchor of the pass manager. Nested pipelines run on operations nested within it, which are
+    /// identified by the location of the diagnostic.
+    pub operation: Option<String>,
+    /// The diagnostic itself.
+    pub diagnostic: Diagnostic,
+}
+
+impl Display for PassDiagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (&self.pass, &self.operation) {
+            (Some(pass), Some(operation)) => {
+                write!(f, "[{} on '{}'] {}", pass, operation, self.diagnostic)
+            }
+            (Some(pass), None) => write!(f, "[{}] {}", pass, self.diagnostic),
+            (None, _) => write!(f, "{}", self.diagnostic),
+        }
+    }
+}
+
+/// Hides the marker passes from a printed pipeline.
+///
+/// # Arguments
+/// * `pipeline` - The printed pipeline, which may contain marker passes.
+///
+/// # Returns
+/// Returns the pipeline without the marker passes.
+pub(crate) fn strip_attribution_markers(pipeline: &str) -> String {
+    pipeline
+        .replace(&format!(",{}", MARKER_ARGUMENT), "")
+        .replace(&format!("{},", MARKER_ARGUMENT), "")
+        .replace(MARKER_ARGUMENT, "")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::Module, pass::register_all_passes, Context, UnownedMlirValue};
+
+    use std::ffi::CString;
+
+    use mlir_sys::mlirEmitError;
+
+    #[derive(Clone)]
+    struct Fail;
+
+    impl RustPass for Fail {
+        fn run(&mut self, operation: &OperationRef, pass: &ExternalPassHandle) {
+            let message = CString::new("failing on purpose").unwrap();
+            unsafe { mlirEmitError(operation.location().to_raw(), message.as_ptr()) };
+            pass.signal_failure();
+        }
+    }
+
+    #[derive(Clone)]
+    struct Succeed;
+
+    impl RustPass for Succeed {
+        fn run(&mut self, _operation: &OperationRef, _pass: &ExternalPassHandle) {}
+    }
+
+    #[test]
+    fn attribute_diagnostics() {
+        let context = Context::new(None, false);
+        let module = Module::parse(&context, "").unwrap();
+        let pass_manager = PassManager::new(&context);
+        pass_manager.enable_pass_attribution();
+        pass_manager.add_owned_pass(Pass::new_external(
+            Succeed,
+            "Succeed",
+            "succeed",
+            "",
+            None,
+            &[],
+        ));
+        pass_manager.add_owned_pass(Pass::new_external(Fail, "Fail", "fail", "", None, &[]));
+        assert_eq!(pass_manager.to_string(), "builtin.module(succeed,fail)");
+
+        let error = pass_manager.run(module.as_operation()).unwrap_err();
+        let failed = AttributedElement {
+            index: 1,
+            pipeline: None,
+        };
+        assert_eq!(error.failed_pass.as_ref(), Some(&failed));
+        assert_eq!(error.diagnostics.len(), 1);
+        assert_eq!(error.diagnostics[0].pass.as_ref(), Some(&failed));
+        assert_eq!(
+            error.diagnostics[0].operation.as_deref(),
+            Some("builtin.module")
+        );
+        assert!(error.diagnostics[0]
+            .to_string()
+            .starts_with("[#1 on 'builtin.module'] "));
+    }
+
+    #[test]
+    fn attribute_pipeline() {
+        register_all_passes();
+        let context = Context::new(None, false);
+        let module = Module::parse(&context, "").unwrap();
+        let pass_manager = PassManager::new(&context);
+        pass_manager.enable_pass_attribution();
+        pass_manager.add_pipeline(" canonicalize ").unwrap();
+        assert!(pass_manager.add_pipeline("no-such-pass").is_err());
+        pass_manager.add_pipeline("cse").unwrap();
+        pass_manager.add_owned_pass(Pass::new_external(Fail, "Fail", "fail", "", None, &[]));
+        assert_eq!(
+            pass_manager.to_string(),
+            "builtin.module(canonicalize,cse,fail)"
+        );
+
+        let error = pass_manager.run(module.as_operation()).unwrap_err();
+        assert_eq!(
+            error.failed_pass,
+            Some(AttributedElement {
+                index: 2,
+                pipeline: None,
+            })
+        );
+        assert_eq!(
+            AttributedElement {
+                index: 0,
+                pipeline: Some("canonicalize".to_string()),
+            }
+            .to_string(),
+            "#0 (canonicalize)"
+        );
+    }
+
+    #[test]
+    fn no_attribution_by_default() {
+        let context = Context::new(None, false);
+        let module = Module::parse(&context, "").unwrap();
+        let pass_manager = PassManager::new(&context);
+        pass_manager.add_owned_pass(Pass::new_external(Fail, "Fail", "fail", "", None, &[]));
+        assert_eq!(pass_manager.to_string(), "builtin.module(fail)");
+
+        let error = pass_manager.run(module.as_operation()).unwrap_err();
+        assert!(error.failed_pass.is_none());
+        assert_eq!(error.diagnostics.len(), 1);
+        assert!(error.diagnostics[0].pass.is_none());
+        assert!(error.diagnostics[0].operation.is_none());
+    }
+
+    #[test]
+    fn attribute_outside_of_passes() {
+        let context = Context::new(None, false);
+        let module = Module::parse(&context, "").unwrap();
+        let pass_manager = PassManager::new_on_operation(&context, "func.func");
+        pass_manager.enable_pass_attribution();
+        pass_manager.add_owned_pass(Pass::new_external(Fail, "Fail", "fail", "", None, &[]));
+
+        let error = pass_manager.run(module.as_operation()).unwrap_err();
+        assert!(error.failed_pass.is_none());
+        assert!(error
+            .diagnostics
+            .iter()
+            .all(|diagnostic| diagnostic.pass.is_none()));
+    }
+}
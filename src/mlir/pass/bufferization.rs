@@ -0,0 +1,166 @@
+use super::{register_all_passes, PassManager, PipelineError};
+use crate::ir::ModuleRef;
+
+/// [LayoutMapOption] specifies the layout of the memref types that function arguments and results
+/// are bufferized to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutMapOption {
+    /// The layout is inferred from the function body where possible, falling back to a fully
+    /// dynamic layout.
+    InferLayoutMap,
+    /// The identity layout is used, which is what most lowerings to the LLVM dialect expect.
+    IdentityLayoutMap,
+    /// A layout with dynamic offset and strides is used.
+    FullyDynamicLayoutMap,
+}
+
+impl LayoutMapOption {
+    /// # Returns
+    /// Returns the value of the option in a textual pass pipeline.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LayoutMapOption::InferLayoutMap => "infer-layout-map",
+            LayoutMapOption::IdentityLayoutMap => "identity-layout-map",
+            LayoutMapOption::FullyDynamicLayoutMap => "fully-dynamic-layout-map",
+        }
+    }
+}
+
+/// [BufferizationOptions] configures the pipeline run by [bufferize], which converts tensors to
+/// memrefs using the `one-shot-bufferize` pass and then (optionally) inserts the deallocations of
+/// the allocated buffers using the `buffer-deallocation-pipeline`.
+///
+/// By default, function boundaries are bufferized to memrefs with the identity layout, unknown
+/// operations are rejected, and deallocations are inserted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BufferizationOptions {
+    /// Whether function arguments and results are bufferized as well.
+    pub bufferize_function_boundaries: bool,
+    /// The layout of the memrefs function arguments and results are bufferized to.
+    pub function_boundary_type_conversion: LayoutMapOption,
+    /// Whether operations which do not implement the bufferization interfaces are allowed, in
+    /// which case `bufferization.to_tensor` and `bufferization.to_memref` operations are inserted
+    /// around them.
+    pub allow_unknown_ops: bool,
+    /// Whether loops are allowed to yield newly allocated buffers.
+    pub allow_return_allocs_from_loops: bool,
+    /// Whether every tensor write is bufferized out-of-place, skipping the analysis.
+    pub copy_before_write: bool,
+    /// Whether the `buffer-deallocation-pipeline` runs after bufferization.
+    pub deallocate: bool,
+    /// Whether private functions pass the ownership of their memref arguments dynamically, rather
+    /// than following the calling convention of public functions.
+    pub private_function_dynamic_ownership: bool,
+}
+
+impl BufferizationOptions {
+    /// # Returns
+    /// Returns the textual pipeline configured by the options, which can also be added to a pass
+    /// manager using [super::PassManagerRef::add_pipeline].
+    pub fn pipeline(&self) -> String {
+        let mut pipeline = format!(
+            concat!(
+                "one-shot-bufferize{{",
+                "allow-return-allocs-from-loops={} ",
+                "allow-unknown-ops={} ",
+                "bufferize-function-boundaries={} ",
+                "copy-before-write={} ",
+                "function-boundary-type-conversion={}",
+                "}}"
+            ),
+            self.allow_return_allocs_from_loops,
+            self.allow_unknown_ops,
+            self.bufferize_function_boundaries,
+            self.copy_before_write,
+            self.function_boundary_type_conversion.as_str(),
+        );
+        if self.deallocate {
+            pipeline.push_str(&format!(
+                ",buffer-deallocation-pipeline{{private-function-dynamic-ownership={}}}",
+                self.private_function_dynamic_ownership
+            ));
+        }
+        pipeline
+    }
+}
+
+impl Default for BufferizationOptions {
+    fn default() -> Self {
+        BufferizationOptions {
+            bufferize_function_boundaries: true,
+            function_boundary_type_conversion: LayoutMapOption::IdentityLayoutMap,
+            allow_unknown_ops: false,
+            allow_return_allocs_from_loops: false,
+            copy_before_write: false,
+            deallocate: true,
+            private_function_dynamic_ownership: false,
+        }
+    }
+}
+
+/// Bufferizes a module, converting its tensors to memrefs as configured by the provided options
+/// (see [BufferizationOptions::pipeline]).
+///
+/// The MLIR C API provides no constructors for the bufferization passes, so all passes are
+/// registered globally (see [register_all_passes]) to parse the pipeline the first time a module
+/// is bufferized.
+///
+/// # Arguments
+/// * `module` - The module to bufferize.
+/// * `options` - The options of the bufferization pipeline.
+///
+/// # Returns
+/// Returns `Ok(())` if the module was bufferized, otherwise a [PipelineError], e.g. because an
+/// operation could not be bufferized.
+pub fn bufferize(module: &ModuleRef, options: &BufferizationOptions) -> Result<(), PipelineError> {
+    register_all_passes();
+    let pass_manager = PassManager::new(module.context());
+    pass_manager.add_pipeline(&options.pipeline())?;
+    pass_manager.run(module.as_operation())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::Module, Context, DialectRegistry};
+
+    fn context() -> Context {
+        let dialect_registry = DialectRegistry::default();
+        dialect_registry.register_all_dialects();
+        let context = Context::new(Some(&dialect_registry), false);
+        context.load_all_available_dialects();
+        context
+    }
+
+    #[test]
+    fn pipeline() {
+        let options = BufferizationOptions {
+            deallocate: false,
+            ..Default::default()
+        };
+        let pipeline = options.pipeline();
+        assert!(pipeline.starts_with("one-shot-bufferize{"));
+        assert!(pipeline.contains("function-boundary-type-conversion=identity-layout-map"));
+        assert!(!pipeline.contains("buffer-deallocation-pipeline"));
+        assert!(BufferizationOptions::default()
+            .pipeline()
+            .ends_with("buffer-deallocation-pipeline{private-function-dynamic-ownership=false}"));
+    }
+
+    #[test]
+    fn bufferize_module() {
+        let context = context();
+        let module_source = r#"
+func.func @f(%arg0: tensor<4xf32>, %arg1: f32, %arg2: index) -> tensor<4xf32> {
+  %0 = tensor.insert %arg1 into %arg0[%arg2] : tensor<4xf32>
+  return %0 : tensor<4xf32>
+}
+"#;
+        let module = Module::parse(&context, module_source).unwrap();
+        bufferize(&module, &BufferizationOptions::default()).unwrap();
+        let bufferized = module.to_string();
+        assert!(bufferized.contains("memref<4xf32>"));
+        assert!(!bufferized.contains("tensor<4xf32>"));
+    }
+}
@@ -0,0 +1,173 @@
+use super::Pass;
+use crate::support::binding::OwnedMlirValue;
+
+use mlir_sys::{
+    mlirCreateConversionArithToLLVMConversionPass, mlirCreateConversionConvertAffineToStandard,
+    mlirCreateConversionConvertComplexToLLVMPass, mlirCreateConversionConvertControlFlowToLLVMPass,
+    mlirCreateConversionConvertFuncToLLVMPass, mlirCreateConversionConvertIndexToLLVMPass,
+    mlirCreateConversionConvertLinalgToStandard, mlirCreateConversionConvertMathToLLVMPass,
+    mlirCreateConversionConvertMathToLibm, mlirCreateConversionConvertVectorToLLVMPass,
+    mlirCreateConversionConvertVectorToSCF, mlirCreateConversionFinalizeMemRefToLLVMConversionPass,
+    mlirCreateConversionReconcileUnrealizedCasts, mlirCreateConversionSCFToControlFlow,
+};
+
+/// Constructors for the dialect conversion passes of MLIR, which together can lower the standard
+/// dialects to the LLVM dialect.
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirCreateConversionArithToLLVMConversionPass`
+/// - `mlirCreateConversionConvertAffineToStandard`
+/// - `mlirCreateConversionConvertComplexToLLVMPass`
+/// - `mlirCreateConversionConvertControlFlowToLLVMPass`
+/// - `mlirCreateConversionConvertFuncToLLVMPass`
+/// - `mlirCreateConversionConvertIndexToLLVMPass`
+/// - `mlirCreateConversionConvertLinalgToStandard`
+/// - `mlirCreateConversionConvertMathToLLVMPass`
+/// - `mlirCreateConversionConvertMathToLibm`
+/// - `mlirCreateConversionConvertVectorToLLVMPass`
+/// - `mlirCreateConversionConvertVectorToSCF`
+/// - `mlirCreateConversionFinalizeMemRefToLLVMConversionPass`
+/// - `mlirCreateConversionReconcileUnrealizedCasts`
+/// - `mlirCreateConversionSCFToControlFlow`
+impl Pass {
+    /// # Returns
+    /// Returns a new `convert-arith-to-llvm` pass, which lowers the `arith` dialect to the LLVM
+    /// dialect.
+    pub fn arith_to_llvm() -> Self {
+        unsafe { Self::from_raw(mlirCreateConversionArithToLLVMConversionPass()) }
+    }
+
+    /// # Returns
+    /// Returns a new `lower-affine` pass, which lowers the `affine` dialect to the `arith`,
+    /// `memref` and `scf` dialects.
+    pub fn affine_to_standard() -> Self {
+        unsafe { Self::from_raw(mlirCreateConversionConvertAffineToStandard()) }
+    }
+
+    /// # Returns
+    /// Returns a new `convert-complex-to-llvm` pass, which lowers the `complex` dialect to the LLVM
+    /// dialect.
+    pub fn complex_to_llvm() -> Self {
+        unsafe { Self::from_raw(mlirCreateConversionConvertComplexToLLVMPass()) }
+    }
+
+    /// # Returns
+    /// Returns a new `convert-cf-to-llvm` pass, which lowers the `cf` dialect to the LLVM dialect.
+    pub fn cf_to_llvm() -> Self {
+        unsafe { Self::from_raw(mlirCreateConversionConvertControlFlowToLLVMPass()) }
+    }
+
+    /// # Returns
+    /// Returns a new `convert-func-to-llvm` pass, which lowers the `func` dialect to the LLVM
+    /// dialect.
+    pub fn func_to_llvm() -> Self {
+        unsafe { Self::from_raw(mlirCreateConversionConvertFuncToLLVMPass()) }
+    }
+
+    /// # Returns
+    /// Returns a new `convert-index-to-llvm` pass, which lowers the `index` dialect to the LLVM
+    /// dialect.
+    pub fn index_to_llvm() -> Self {
+        unsafe { Self::from_raw(mlirCreateConversionConvertIndexToLLVMPass()) }
+    }
+
+    /// # Returns
+    /// Returns a new `convert-linalg-to-std` pass, which lowers `linalg` operations to calls to
+    /// library functions.
+    pub fn linalg_to_standard() -> Self {
+        unsafe { Self::from_raw(mlirCreateConversionConvertLinalgToStandard()) }
+    }
+
+    /// # Returns
+    /// Returns a new `convert-math-to-llvm` pass, which lowers the `math` dialect to the LLVM
+    /// dialect.
+    pub fn math_to_llvm() -> Self {
+        unsafe { Self::from_raw(mlirCreateConversionConvertMathToLLVMPass()) }
+    }
+
+    /// # Returns
+    /// Returns a new `convert-math-to-libm` pass, which lowers `math` operations to calls to
+    /// `libm`.
+    pub fn math_to_libm() -> Self {
+        unsafe { Self::from_raw(mlirCreateConversionConvertMathToLibm()) }
+    }
+
+    /// # Returns
+    /// Returns a new `convert-vector-to-llvm` pass, which lowers the `vector` dialect to the LLVM
+    /// dialect.
+    pub fn vector_to_llvm() -> Self {
+        unsafe { Self::from_raw(mlirCreateConversionConvertVectorToLLVMPass()) }
+    }
+
+    /// # Returns
+    /// Returns a new `convert-vector-to-scf` pass, which lowers `vector` transfer operations to
+    /// loops of the `scf` dialect.
+    pub fn vector_to_scf() -> Self {
+        unsafe { Self::from_raw(mlirCreateConversionConvertVectorToSCF()) }
+    }
+
+    /// # Returns
+    /// Returns a new `finalize-memref-to-llvm` pass, which lowers the `memref` dialect to the LLVM
+    /// dialect.
+    pub fn finalize_memref_to_llvm() -> Self {
+        unsafe { Self::from_raw(mlirCreateConversionFinalizeMemRefToLLVMConversionPass()) }
+    }
+
+    /// # Returns
+    /// Returns a new `reconcile-unrealized-casts` pass, which removes the
+    /// `builtin.unrealized_conversion_cast` operations left behind by partial lowerings.
+    pub fn reconcile_unrealized_casts() -> Self {
+        unsafe { Self::from_raw(mlirCreateConversionReconcileUnrealizedCasts()) }
+    }
+
+    /// # Returns
+    /// Returns a new `convert-scf-to-cf` pass, which lowers the `scf` dialect to the `cf` dialect.
+    pub fn scf_to_cf() -> Self {
+        unsafe { Self::from_raw(mlirCreateConversionSCFToControlFlow()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::Module, pass::PassManager, Context, DialectRegistry};
+
+    #[test]
+    fn lower_to_llvm() {
+        let dialect_registry = DialectRegistry::default();
+        dialect_registry.register_all_dialects();
+        let context = Context::new(Some(&dialect_registry), false);
+        context.load_all_available_dialects();
+        let module_source = r#"
+func.func @f(%arg0: i32, %arg1: index) -> i32 {
+  %0 = arith.constant 0 : index
+  %1 = scf.for %i = %0 to %arg1 step %arg1 iter_args(%acc = %arg0) -> (i32) {
+    %2 = arith.addi %acc, %acc : i32
+    scf.yield %2 : i32
+  }
+  return %1 : i32
+}
+"#;
+        let module = Module::parse(&context, module_source).unwrap();
+        let pass_manager = PassManager::new(&context);
+        for pass in [
+            Pass::scf_to_cf(),
+            Pass::arith_to_llvm(),
+            Pass::cf_to_llvm(),
+            Pass::index_to_llvm(),
+            Pass::func_to_llvm(),
+            Pass::reconcile_unrealized_casts(),
+        ] {
+            pass_manager.add_owned_pass(pass);
+        }
+        pass_manager.run(module.as_operation()).unwrap();
+
+        let operations = module
+            .body()
+            .operations()
+            .map(|operation| operation.name().value().as_str().to_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(operations, ["llvm.func"]);
+        assert!(!module.to_string().contains("unrealized_conversion_cast"));
+    }
+}
@@ -0,0 +1,312 @@
+use super::Pass;
+use crate::{
+    ir::OperationRef,
+    support::binding::{impl_unowned_mlir_value, OwnedMlirValue, UnownedMlirValue},
+    ContextRef, DialectHandle, StringRef,
+};
+
+use std::{
+    any::TypeId as RustTypeId,
+    collections::HashMap,
+    marker::PhantomData,
+    os::raw::c_void,
+    panic::{catch_unwind, AssertUnwindSafe},
+    process::abort,
+    sync::{Mutex, OnceLock},
+};
+
+use mlir_sys::{
+    mlirCreateExternalPass, mlirExternalPassSignalFailure, mlirTypeIDCreate, MlirContext,
+    MlirDialectHandle, MlirExternalPass, MlirExternalPassCallbacks, MlirLogicalResult,
+    MlirOperation, MlirTypeID,
+};
+
+/// [RustPass] is implemented by passes written in Rust, which can be turned into a [Pass] using
+/// [Pass::new_external] and run by a pass manager like any pass implemented in C++.
+///
+/// The pass manager clones the pass (using [Clone]) whenever it needs several instances of it,
+/// e.g. to run it on several operations in parallel. As the pass manager cannot recover from a
+/// failure to clone or destroy a pass, the process is aborted if cloning or dropping the pass
+/// panics.
+pub trait RustPass: Clone + Send + 'static {
+    /// Initializes the pass before it is run, once per pass manager run.
+    ///
+    /// # Arguments
+    /// * `context` - The context the pass is run in.
+    ///
+    /// # Returns
+    /// Returns whether the pass was initialized successfully. If it was not, or if initializing it
+    /// panics, the pipeline fails.
+    fn initialize(&mut self, _context: &ContextRef) -> bool {
+        true
+    }
+
+    /// Runs the pass on the provided operation. If the pass panics, the panic is caught before it
+    /// reaches MLIR and the pass fails instead.
+    ///
+    /// # Arguments
+    /// * `operation` - The operation to run the pass on.
+    /// * `pass` - The handle of the running pass, used to signal failure.
+    fn run(&mut self, operation: &OperationRef, pass: &ExternalPassHandle);
+}
+
+impl Pass {
+    /// Creates a new pass running the provided Rust pass.
+    ///
+    /// The following bindings into the MLIR C API are used/supported:
+    /// - `mlirCreateExternalPass`
+    /// - `mlirTypeIDCreate`
+    ///
+    /// # Arguments
+    /// * `pass` - The Rust pass to run.
+    /// * `name` - The name of the pass, used e.g. when printing the IR after the pass.
+    /// * `argument` - The command line argument of the pass (e.g. `my-pass`).
+    /// * `description` - The description of the pass.
+    /// * `op_name` - The name of the operations the pass runs on, or `None` if it can run on any
+    ///   operation.
+    /// * `dependent_dialects` - The dialects the pass may create operations from, which are
+    ///   loaded before the pass is run.
+    ///
+    /// # Returns
+    /// Returns a new [Pass] instance.
+    pub fn new_external<T: RustPass>(
+        pass: T,
+        name: &str,
+        argument: &str,
+        description: &str,
+        op_name: Option<&str>,
+        dependent_dialects: &[DialectHandle],
+    ) -> Self {
+        let mut dependent_dialects = dependent_dialects
+            .iter()
+            .map(|dialect| dialect.to_raw())
+            .collect::<Vec<MlirDialectHandle>>();
+        let callbacks = MlirExternalPassCallbacks {
+            construct: Some(construct_external_pass),
+            destruct: Some(destruct_external_pass::<T>),
+            initialize: Some(initialize_external_pass::<T>),
+            clone: Some(clone_external_pass::<T>),
+            run: Some(run_external_pass::<T>),
+        };
+        unsafe {
+            Self::from_raw(mlirCreateExternalPass(
+                external_pass_type_id::<T>(),
+                StringRef::from(&name).to_raw(),
+                StringRef::from(&argument).to_raw(),
+                StringRef::from(&description).to_raw(),
+                StringRef::from(&op_name.unwrap_or("")).to_raw(),
+                dependent_dialects.len() as isize,
+                dependent_dialects.as_mut_ptr(),
+                callbacks,
+                Box::into_raw(Box::new(pass)) as *mut c_void,
+            ))
+        }
+    }
+}
+
+/// [ExternalPassHandle] is a reference to the `mlir::Pass` instance running a [RustPass].
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirExternalPassSignalFailure`
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct ExternalPassHandle {
+    _prevent_external_instantiation: PhantomData<()>,
+}
+
+impl_unowned_mlir_value!(no_refs, ExternalPassHandle, MlirExternalPass);
+
+impl ExternalPassHandle {
+    /// Signals that the current run of the pass failed, which makes the pipeline fail once the
+    /// pass returns.
+    pub fn signal_failure(&self) {
+        unsafe { mlirExternalPassSignalFailure(self.to_raw()) }
+    }
+}
+
+/// # Returns
+/// Returns the MLIR type ID identifying the passes running the Rust pass type `T`, allocating it
+/// the first time it is requested.
+fn external_pass_type_id<T: RustPass>() -> MlirTypeID {
+    static TYPE_IDS: OnceLock<Mutex<HashMap<RustTypeId, usize>>> = OnceLock::new();
+    let mut type_ids = TYPE_IDS.get_or_init(Default::default).lock().unwrap();
+    // MLIR type IDs are created from unique, 8-byte aligned addresses, which are never freed.
+    let address = *type_ids
+        .entry(RustTypeId::of::<T>())
+        .or_insert_with(|| Box::into_raw(Box::new(0u64)) as usize);
+    unsafe { mlirTypeIDCreate(address as *const c_void) }
+}
+
+/// Invoked when a pass running a [RustPass] is constructed. The Rust pass is created before the
+/// pass, so there is nothing to do.
+unsafe extern "C" fn construct_external_pass(_user_data: *mut c_void) {}
+
+/// Drops the Rust pass once the pass running it is destroyed.
+///
+/// # Safety
+/// The provided user data must be the pointer returned by `Box::into_raw` for a Rust pass of the
+/// type `T`.
+unsafe extern "C" fn destruct_external_pass<T: RustPass>(user_data: *mut c_void) {
+    let pass = Box::from_raw(user_data as *mut T);
+    // Unwinding into MLIR is undefined behavior and the pass cannot fail to be destroyed.
+    if catch_unwind(AssertUnwindSafe(|| drop(pass))).is_err() {
+        abort();
+    }
+}
+
+/// Invokes [RustPass::initialize] through the raw external pass callback.
+///
+/// # Safety
+/// The provided user data must point to a live Rust pass of the type `T`.
+unsafe extern "C" fn initialize_external_pass<T: RustPass>(
+    raw_context: MlirContext,
+    user_data: *mut c_void,
+) -> MlirLogicalResult {
+    let pass = &mut *(user_data as *mut T);
+    // Unwinding into MLIR is undefined behavior, so a panicking pass fails to initialize instead.
+    let initialized = catch_unwind(AssertUnwindSafe(|| {
+        pass.initialize(ContextRef::from_raw(raw_context))
+    }))
+    .unwrap_or(false);
+    MlirLogicalResult {
+        value: initialized as i8,
+    }
+}
+
+/// Clones the Rust pass for a clone of the pass running it.
+///
+/// # Safety
+/// The provided user data must point to a live Rust pass of the type `T`.
+unsafe extern "C" fn clone_external_pass<T: RustPass>(user_data: *mut c_void) -> *mut c_void {
+    let pass = &*(user_data as *const T);
+    // Unwinding into MLIR is undefined behavior and the pass cannot fail to be cloned.
+    match catch_unwind(AssertUnwindSafe(|| pass.clone())) {
+        Ok(clone) => Box::into_raw(Box::new(clone)) as *mut c_void,
+        Err(_) => abort(),
+    }
+}
+
+/// Invokes [RustPass::run] through the raw external pass callback.
+///
+/// # Safety
+/// The provided user data must point to a live Rust pass of the type `T`.
+unsafe extern "C" fn run_external_pass<T: RustPass>(
+    raw_operation: MlirOperation,
+    raw_pass: MlirExternalPass,
+    user_data: *mut c_void,
+) {
+    let pass = &mut *(user_data as *mut T);
+    let pass_handle = ExternalPassHandle::from_raw(raw_pass);
+    // Unwinding into MLIR is undefined behavior, so a panicking pass fails instead.
+    let run = catch_unwind(AssertUnwindSafe(|| {
+        pass.run(OperationRef::from_raw(raw_operation), pass_handle)
+    }));
+    if run.is_err() {
+        pass_handle.signal_failure();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{Module, WalkOrder},
+        pass::PassManager,
+        Context,
+    };
+
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    #[derive(Clone)]
+    struct CountOperations {
+        count: Arc<AtomicUsize>,
+        fail: bool,
+        panic: bool,
+    }
+
+    impl RustPass for CountOperations {
+        fn run(&mut self, operation: &OperationRef, pass: &ExternalPassHandle) {
+            operation.walk(WalkOrder::PreOrder, |_| {
+                self.count.fetch_add(1, Ordering::Relaxed);
+            });
+            if self.fail {
+                pass.signal_failure();
+            }
+            if self.panic {
+                panic!("failed to count operations");
+            }
+        }
+    }
+
+    fn count_operations(pass: CountOperations) -> Pass {
+        Pass::new_external(
+            pass,
+            "CountOperations",
+            "count-operations",
+            "Counts the operations nested within an operation.",
+            Some("builtin.module"),
+            &[],
+        )
+    }
+
+    #[test]
+    fn run() {
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        let module = Module::parse(&context, r#""dialect.op"() : () -> ()"#).unwrap();
+        let count = Arc::new(AtomicUsize::new(0));
+        let pass_manager = PassManager::new(&context);
+        pass_manager.add_owned_pass(count_operations(CountOperations {
+            count: count.clone(),
+            fail: false,
+            panic: false,
+        }));
+        pass_manager.run(module.as_operation()).unwrap();
+        assert_eq!(count.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn signal_failure() {
+        let context = Context::new(None, false);
+        let module = Module::parse(&context, "").unwrap();
+        let count = Arc::new(AtomicUsize::new(0));
+        let pass_manager = PassManager::new(&context);
+        pass_manager.add_owned_pass(count_operations(CountOperations {
+            count: count.clone(),
+            fail: true,
+            panic: false,
+        }));
+        assert!(pass_manager.run(module.as_operation()).is_err());
+        assert_eq!(count.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn panic() {
+        let context = Context::new(None, false);
+        let module = Module::parse(&context, "").unwrap();
+        let count = Arc::new(AtomicUsize::new(0));
+        let pass_manager = PassManager::new(&context);
+        pass_manager.add_owned_pass(count_operations(CountOperations {
+            count: count.clone(),
+            fail: false,
+            panic: true,
+        }));
+        assert!(pass_manager.run(module.as_operation()).is_err());
+        assert_eq!(count.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_external_pass_handle() {
+        let _external_pass_handle = ExternalPassHandle {
+            _prevent_external_instantiation: PhantomData,
+        };
+    }
+}
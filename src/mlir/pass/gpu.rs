@@ -0,0 +1,199 @@
+use super::{register_all_passes, PassManager, PipelineError};
+use crate::ir::{CompilationTarget, ModuleRef};
+
+/// [GPUTarget] is the kind of GPU the kernels of a module are compiled for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GPUTarget {
+    /// NVIDIA GPUs, through the `nvvm` dialect.
+    NVVM,
+    /// AMD GPUs, through the `rocdl` dialect.
+    ROCDL,
+}
+
+impl GPUTarget {
+    /// # Returns
+    /// Returns the name of the dialect the kernels are lowered to, which prefixes the names of
+    /// the passes specific to the target.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GPUTarget::NVVM => "nvvm",
+            GPUTarget::ROCDL => "rocdl",
+        }
+    }
+}
+
+/// [GPUCompilationOptions] configures the pipeline run by [compile_gpu], which outlines the
+/// `gpu.launch` operations of a module into kernels (`gpu-kernel-outlining`), attaches the target
+/// to every `gpu.module` operation (`nvvm-attach-target` or `rocdl-attach-target`), lowers the
+/// kernels to the dialect of the target (`convert-gpu-to-nvvm` or `convert-gpu-to-rocdl`), and
+/// finally compiles every `gpu.module` operation into a `gpu.binary` operation
+/// (`gpu-module-to-binary`).
+///
+/// The host code is not lowered, which is left to the conversion passes (e.g. `gpu-to-llvm`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GPUCompilationOptions {
+    /// The kind of GPU the kernels are compiled for.
+    pub target: GPUTarget,
+    /// The target architecture, e.g. `sm_70` or `gfx90a`.
+    pub chip: String,
+    /// The target features, e.g. `+ptx60`.
+    pub features: String,
+    /// The optimization level, from 0 to 3.
+    pub opt_level: u32,
+    /// The bitwidth of the `index` type in the kernels, or 0 to derive it from the data layout.
+    pub index_bitwidth: u32,
+    /// The format of the compiled objects.
+    pub format: CompilationTarget,
+}
+
+impl GPUCompilationOptions {
+    /// Constructs the default options for the provided target, which compile the kernels into a
+    /// fat binary for the oldest architecture supported by MLIR.
+    ///
+    /// # Arguments
+    /// * `target` - The kind of GPU the kernels are compiled for.
+    ///
+    /// # Returns
+    /// Returns a new [GPUCompilationOptions] instance.
+    pub fn new(target: GPUTarget) -> Self {
+        let (chip, features) = match target {
+            GPUTarget::NVVM => ("sm_50", "+ptx60"),
+            GPUTarget::ROCDL => ("gfx900", ""),
+        };
+        GPUCompilationOptions {
+            target,
+            chip: chip.to_string(),
+            features: features.to_string(),
+            opt_level: 2,
+            index_bitwidth: 0,
+            format: CompilationTarget::Fatbin,
+        }
+    }
+
+    /// # Returns
+    /// Returns the textual pipeline configured by the options, which can also be added to a pass
+    /// manager using [super::PassManagerRef::add_pipeline].
+    pub fn pipeline(&self) -> String {
+        let mut attach_target = format!("chip={} O={}", self.chip, self.opt_level);
+        if !self.features.is_empty() {
+            // The features are quoted, as they may hold commas (e.g. `+sramecc,-xnack`).
+            attach_target.push_str(&format!(" features=\"{}\"", self.features));
+        }
+        let lowering = match self.target {
+            GPUTarget::NVVM => format!(
+                "convert-gpu-to-nvvm{{index-bitwidth={}}}",
+                self.index_bitwidth
+            ),
+            GPUTarget::ROCDL => format!(
+                "convert-gpu-to-rocdl{{chipset={} index-bitwidth={}}}",
+                self.chip, self.index_bitwidth
+            ),
+        };
+        let binary_format = match self.format {
+            CompilationTarget::Offload => "offloading",
+            CompilationTarget::Assembly => "assembly",
+            CompilationTarget::Binary => "binary",
+            CompilationTarget::Fatbin => "fatbin",
+        };
+        format!(
+            concat!(
+                "gpu-kernel-outlining,",
+                "{}-attach-target{{{}}},",
+                "gpu.module({}),",
+                "gpu-module-to-binary{{format={}}}"
+            ),
+            self.target.as_str(),
+            attach_target,
+            lowering,
+            binary_format,
+        )
+    }
+}
+
+/// Compiles the kernels of a module into `gpu.binary` operations, as configured by the provided
+/// options (see [GPUCompilationOptions::pipeline]). The module must have the
+/// [crate::dialects::gpu::CONTAINER_MODULE_ATTRIBUTE_NAME] attribute.
+///
+/// The MLIR C API provides no constructors for the GPU passes, so all passes are registered
+/// globally (see [register_all_passes]) to parse the pipeline. Compiling the kernels into objects
+/// requires the targets to be registered with the context, e.g. by registering all dialects.
+///
+/// # Arguments
+/// * `module` - The module whose kernels are compiled.
+/// * `options` - The options of the compilation pipeline.
+///
+/// # Returns
+/// Returns `Ok(())` if the kernels were compiled, otherwise a [PipelineError], e.g. because the
+/// target is not available.
+pub fn compile_gpu(
+    module: &ModuleRef,
+    options: &GPUCompilationOptions,
+) -> Result<(), PipelineError> {
+    register_all_passes();
+    let pass_manager = PassManager::new(module.context());
+    pass_manager.add_pipeline(&options.pipeline())?;
+    pass_manager.run(module.as_operation())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{AttributeRef, Module},
+        Context, DialectRegistry,
+    };
+
+    fn context() -> Context {
+        let dialect_registry = DialectRegistry::default();
+        dialect_registry.register_all_dialects();
+        let context = Context::new(Some(&dialect_registry), false);
+        context.load_all_available_dialects();
+        context
+    }
+
+    #[test]
+    fn pipeline() {
+        let nvvm_pipeline = GPUCompilationOptions::new(GPUTarget::NVVM).pipeline();
+        assert!(nvvm_pipeline.starts_with("gpu-kernel-outlining,"));
+        assert!(nvvm_pipeline.contains("nvvm-attach-target{chip=sm_50 O=2 features=\"+ptx60\"}"));
+        assert!(nvvm_pipeline.contains("gpu.module(convert-gpu-to-nvvm{index-bitwidth=0})"));
+        assert!(nvvm_pipeline.ends_with("gpu-module-to-binary{format=fatbin}"));
+
+        let rocdl_options = GPUCompilationOptions {
+            chip: "gfx90a".to_string(),
+            format: CompilationTarget::Assembly,
+            ..GPUCompilationOptions::new(GPUTarget::ROCDL)
+        };
+        let rocdl_pipeline = rocdl_options.pipeline();
+        assert!(rocdl_pipeline.contains("rocdl-attach-target{chip=gfx90a O=2}"));
+        assert!(rocdl_pipeline.contains("convert-gpu-to-rocdl{chipset=gfx90a index-bitwidth=0}"));
+        assert!(rocdl_pipeline.ends_with("gpu-module-to-binary{format=assembly}"));
+    }
+
+    #[test]
+    fn parse_pipeline() {
+        register_all_passes();
+        let context = context();
+        let rocdl_options = GPUCompilationOptions {
+            features: "+sramecc,-xnack".to_string(),
+            ..GPUCompilationOptions::new(GPUTarget::ROCDL)
+        };
+        for options in [GPUCompilationOptions::new(GPUTarget::NVVM), rocdl_options] {
+            let pass_manager = PassManager::new(&context);
+            pass_manager.add_pipeline(&options.pipeline()).unwrap();
+        }
+    }
+
+    #[test]
+    fn compile_without_kernels() {
+        let context = context();
+        let module =
+            Module::parse(&context, "module attributes {gpu.container_module} {}").unwrap();
+        compile_gpu(&module, &GPUCompilationOptions::new(GPUTarget::NVVM)).unwrap();
+        assert_eq!(
+            module.as_operation().attribute("gpu.container_module"),
+            Some(AttributeRef::unit(&context))
+        );
+    }
+}
@@ -0,0 +1,437 @@
+use super::{
+    attribution::{strip_attribution_markers, PipelineRun},
+    reproducer::write_crash_reproducer,
+    AttributedElement, Pass, PassDiagnostic,
+};
+use crate::{
+    ir::OperationRef,
+    support::{
+        binding::{impl_unowned_mlir_value, OwnedMlirValue, UnownedMlirValue},
+        string_reader::StringReader,
+    },
+    ContextRef, StringRef,
+};
+
+use std::{
+    cell::Cell,
+    error::Error,
+    fmt::{Display, Formatter},
+    marker::PhantomData,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use mlir_sys::{
+    mlirOpPassManagerAddPipeline, mlirPassManagerAddOwnedPass, mlirPassManagerCreate,
+    mlirPassManagerCreateOnOperation, mlirPassManagerDestroy, mlirPassManagerEnableIRPrinting,
+    mlirPassManagerEnableVerifier, mlirPassManagerGetAsOpPassManager, mlirPassManagerRunOnOp,
+    mlirPrintPassPipeline, MlirPassManager,
+};
+
+/// [PassManager] wraps the `mlir::PassManager` class, the top-level pass manager which runs a
+/// pipeline of passes over an operation.
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirOpPassManagerAddPipeline`
+/// - `mlirPassManagerAddOwnedPass`
+/// - `mlirPassManagerCreate`
+/// - `mlirPassManagerCreateOnOperation`
+/// - `mlirPassManagerDestroy`
+/// - `mlirPassManagerEnableIRPrinting`
+/// - `mlirPassManagerEnableVerifier`
+/// - `mlirPassManagerGetAsOpPassManager`
+/// - `mlirPassManagerRunOnOp`
+/// - `mlirPrintPassPipeline`
+///
+/// The following bindings are not used/supported:
+/// - `mlirPassManagerGetNestedUnder`
+#[derive(Debug)]
+pub struct PassManager<'c> {
+    raw: MlirPassManager,
+    /// The number of elements appended since pass attribution was enabled, or `None` if it is
+    /// disabled (see [PassManager::enable_pass_attribution]).
+    pub(super) attributed_elements: Cell<Option<usize>>,
+    _context: PhantomData<&'c ()>,
+}
+
+impl<'c> OwnedMlirValue for PassManager<'c> {
+    type Binding = MlirPassManager;
+
+    unsafe fn from_raw(raw: Self::Binding) -> Self {
+        Self {
+            raw,
+            attributed_elements: Cell::new(None),
+            _context: PhantomData,
+        }
+    }
+
+    unsafe fn try_from_raw(raw: Self::Binding) -> Option<Self> {
+        if raw.ptr.is_null() {
+            None
+        } else {
+            Some(Self::from_raw(raw))
+        }
+    }
+
+    fn to_raw(&self) -> Self::Binding {
+        self.raw
+    }
+}
+
+impl<'c> PassManager<'c> {
+    /// Creates a new, empty pass manager anchored on `builtin.module` operations.
+    ///
+    /// # Arguments
+    /// * `context` - The context to associate with the pass manager.
+    ///
+    /// # Returns
+    /// Returns a new [PassManager] instance.
+    pub fn new(context: &'c ContextRef) -> Self {
+        unsafe { Self::from_raw(mlirPassManagerCreate(context.to_raw())) }
+    }
+
+    /// Creates a new, empty pass manager anchored on operations with the provided name.
+    ///
+    /// # Arguments
+    /// * `context` - The context to associate with the pass manager.
+    /// * `anchor_op` - The name of the operations the pass manager runs on (e.g. `func.func`).
+    ///
+    /// # Returns
+    /// Returns a new [PassManager] instance.
+    pub fn new_on_operation(context: &'c ContextRef, anchor_op: &str) -> Self {
+        unsafe {
+            Self::from_raw(mlirPassManagerCreateOnOperation(
+                context.to_raw(),
+                StringRef::from(&anchor_op).to_raw(),
+            ))
+        }
+    }
+
+    /// Appends the provided pass to the pipeline of the pass manager, which takes ownership of it.
+    /// Unlike [PassManagerRef::add_owned_pass], the pass is attributed the diagnostics it emits if
+    /// pass attribution is enabled (see [PassManager::enable_pass_attribution]).
+    ///
+    /// # Arguments
+    /// * `pass` - The pass to append.
+    pub fn add_owned_pass(&self, pass: Pass) {
+        self.add_attribution_marker(None);
+        PassManagerRef::add_owned_pass(self, pass);
+        self.record_attributed_element();
+    }
+
+    /// Appends the passes of the provided textual pipeline to the pipeline of the pass manager.
+    /// Unlike [PassManagerRef::add_pipeline], the pipeline is attributed the diagnostics its
+    /// passes emit if pass attribution is enabled (see [PassManager::enable_pass_attribution]).
+    ///
+    /// # Arguments
+    /// * `pipeline` - The textual pipeline to append, e.g. `canonicalize,func.func(cse)`.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the pipeline could be parsed, otherwise a [ParsePipelineError]
+    /// holding the message describing why it could not.
+    pub fn add_pipeline(&self, pipeline: &str) -> Result<(), ParsePipelineError> {
+        self.add_attribution_marker(Some(pipeline));
+        PassManagerRef::add_pipeline(self, pipeline)?;
+        self.record_attributed_element();
+        Ok(())
+    }
+}
+
+impl<'c> Drop for PassManager<'c> {
+    fn drop(&mut self) {
+        // Forget the reproducer directory, so that it does not leak into a pass manager later
+        // allocated at the same address.
+        self.disable_crash_reproducer();
+        unsafe { mlirPassManagerDestroy(self.raw) }
+    }
+}
+
+/// [PassManagerRef] is a reference to an instance of the `mlir::PassManager` class.
+///
+/// # Safety
+/// This type is ONLY ever safe to use if it is a **reference**! Owned instances will cause
+/// undefined behaviour.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct PassManagerRef<'c> {
+    _context: PhantomData<&'c ()>,
+}
+
+impl_unowned_mlir_value!(context_ref, PassManager, PassManagerRef, MlirPassManager);
+
+impl<'c> PassManagerRef<'c> {
+    /// Appends the provided pass to the pipeline of the pass manager, which takes ownership of it.
+    ///
+    /// # Arguments
+    /// * `pass` - The pass to append.
+    pub fn add_owned_pass(&self, pass: Pass) {
+        unsafe { mlirPassManagerAddOwnedPass(self.to_raw(), pass.to_raw()) }
+    }
+
+    /// Appends the passes of the provided textual pipeline to the pipeline of the pass manager.
+    /// The passes must have been registered beforehand, e.g. using [super::register_all_passes].
+    ///
+    /// # Arguments
+    /// * `pipeline` - The textual pipeline to append, e.g. `canonicalize,func.func(cse)`.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the pipeline could be parsed, otherwise a [ParsePipelineError]
+    /// holding the message describing why it could not.
+    pub fn add_pipeline(&self, pipeline: &str) -> Result<(), ParsePipelineError> {
+        let mut message = String::new();
+        let mut reader = StringReader::new(&mut message);
+        let result = unsafe {
+            mlirOpPassManagerAddPipeline(
+                mlirPassManagerGetAsOpPassManager(self.to_raw()),
+                StringRef::from(&pipeline).to_raw(),
+                reader.callback(),
+                reader.as_raw_mut(),
+            )
+        };
+        if result.value != 0 {
+            Ok(())
+        } else {
+            Err(ParsePipelineError {
+                pipeline: pipeline.to_string(),
+                message,
+            })
+        }
+    }
+
+    /// Enables or disables verifying the IR after each pass of the pipeline. The verifier is
+    /// enabled by default; disabling it speeds up pipelines whose passes are trusted to produce
+    /// valid IR.
+    ///
+    /// # Arguments
+    /// * `enable` - Whether the verifier should be enabled.
+    pub fn enable_verifier(&self, enable: bool) {
+        unsafe { mlirPassManagerEnableVerifier(self.to_raw(), enable) }
+    }
+
+    /// Enables printing the IR before and after every pass of the pipeline to the standard error
+    /// stream, which is useful to debug a misbehaving pipeline.
+    ///
+    /// The MLIR 18 C API does not expose the printing options of `mlir::PassManager`, so the
+    /// defaults of `mlir::PassManager::enableIRPrinting` are used: the IR is printed before every
+    /// pass, and after every pass that changed it, at module scope.
+    pub fn enable_ir_printing(&self) {
+        unsafe { mlirPassManagerEnableIRPrinting(self.to_raw()) }
+    }
+
+    /// Runs the pipeline of the pass manager over the provided operation. If the pipeline fails
+    /// and crash reproducers are enabled (see [PassManagerRef::enable_crash_reproducer]), a
+    /// reproducer is written as well.
+    ///
+    /// # Arguments
+    /// * `operation` - The operation to run the pipeline over, whose name must match the anchor
+    ///   of the pass manager.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the pipeline ran successfully, otherwise a [PassError] holding the
+    /// diagnostics emitted while running it, each attributed to the element of the pipeline that
+    /// emitted it if pass attribution is enabled (see [PassManager::enable_pass_attribution]).
+    pub fn run(&self, operation: &OperationRef<'c>) -> Result<(), PassError> {
+        let reproducer_directory = self.crash_reproducer_directory();
+        let input = reproducer_directory
+            .as_ref()
+            .map(|_| operation.to_string_generic(true));
+
+        let run = PipelineRun::start();
+        let diagnostics = Arc::new(Mutex::new(Vec::new()));
+        let handler_diagnostics = diagnostics.clone();
+        let handler_progress = run.progress().clone();
+        let context = operation.context();
+        let handler_id = context.attach_diagnostic_handler(move |diagnostic| {
+            let diagnostic = handler_progress.lock().unwrap().attribute(diagnostic);
+            handler_diagnostics.lock().unwrap().push(diagnostic);
+            true
+        });
+        let result = unsafe { mlirPassManagerRunOnOp(self.to_raw(), operation.to_raw()) };
+        context.detach_diagnostic_handler(handler_id);
+        let failed_pass = run.progress().lock().unwrap().element();
+        drop(run);
+        if result.value != 0 {
+            return Ok(());
+        }
+
+        let diagnostics = std::mem::take(&mut *diagnostics.lock().unwrap());
+        // Failing to write the reproducer must not hide the failure of the pipeline itself.
+        let reproducer = reproducer_directory
+            .zip(input)
+            .and_then(|(directory, input)| {
+                write_crash_reproducer(&directory, &input, &self.to_string(), &diagnostics).ok()
+            });
+        Err(PassError {
+            diagnostics,
+            failed_pass,
+            reproducer,
+        })
+    }
+}
+
+impl<'c> Display for PassManagerRef<'c> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut pipeline = String::new();
+        let mut reader = StringReader::new(&mut pipeline);
+        unsafe {
+            mlirPrintPassPipeline(
+                mlirPassManagerGetAsOpPassManager(self.to_raw()),
+                reader.callback(),
+                reader.as_raw_mut(),
+            )
+        }
+        // Hide the marker passes used to attribute diagnostics to passes.
+        write!(f, "{}", strip_attribution_markers(&pipeline))
+    }
+}
+
+/// [ParsePipelineError] is returned by [PassManagerRef::add_pipeline] when a textual pipeline
+/// could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePipelineError {
+    /// The textual pipeline which could not be parsed.
+    pub pipeline: String,
+    /// The message describing why the pipeline could not be parsed.
+    pub message: String,
+}
+
+impl Display for ParsePipelineError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "failed to parse pass pipeline '{}': {}",
+            self.pipeline, self.message
+        )
+    }
+}
+
+impl Error for ParsePipelineError {}
+
+/// [PassError] is returned by [PassManagerRef::run] when the pipeline failed to run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PassError {
+    /// The diagnostics emitted while running the pipeline.
+    pub diagnostics: Vec<PassDiagnostic>,
+    /// The element of the pipeline that failed, if pass attribution is enabled (see
+    /// [PassManager::enable_pass_attribution]) and the pipeline failed while running one.
+    pub failed_pass: Option<AttributedElement>,
+    /// The directory the crash reproducer was written to, if crash reproducers are enabled.
+    pub reproducer: Option<PathBuf>,
+}
+
+impl Display for PassError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.failed_pass {
+            Some(pass) => write!(f, "failed to run pass pipeline element {}", pass)?,
+            None => write!(f, "failed to run pass pipeline")?,
+        }
+        for diagnostic in &self.diagnostics {
+            write!(f, "\n{}", diagnostic)?;
+        }
+        if let Some(reproducer) = &self.reproducer {
+            write!(f, "\nreproducer written to '{}'", reproducer.display())?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for PassError {}
+
+/// [PipelineError] is returned by the helpers which build a pipeline and run it right away (e.g.
+/// [super::bufferize]), when the pipeline could not be parsed or failed to run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PipelineError {
+    /// The textual pipeline could not be parsed.
+    Parse(ParsePipelineError),
+    /// The pipeline failed to run.
+    Run(PassError),
+}
+
+impl From<ParsePipelineError> for PipelineError {
+    fn from(error: ParsePipelineError) -> Self {
+        PipelineError::Parse(error)
+    }
+}
+
+impl From<PassError> for PipelineError {
+    fn from(error: PassError) -> Self {
+        PipelineError::Run(error)
+    }
+}
+
+impl Display for PipelineError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PipelineError::Parse(error) => Display::fmt(error, f),
+            PipelineError::Run(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+impl Error for PipelineError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PipelineError::Parse(error) => Some(error),
+            PipelineError::Run(error) => Some(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::Module, Context, DialectRegistry};
+
+    fn context() -> Context {
+        let dialect_registry = DialectRegistry::default();
+        dialect_registry.register_all_dialects();
+        let context = Context::new(Some(&dialect_registry), false);
+        context.load_all_available_dialects();
+        context
+    }
+
+    #[test]
+    fn run() {
+        let context = context();
+        let module = Module::parse(&context, "func.func private @f()").unwrap();
+        let pass_manager = PassManager::new(&context);
+        pass_manager.run(module.as_operation()).unwrap();
+    }
+
+    #[test]
+    fn run_without_verifier() {
+        let context = context();
+        let module = Module::parse(&context, "func.func private @f()").unwrap();
+        let pass_manager = PassManager::new(&context);
+        pass_manager.enable_verifier(false);
+        pass_manager.run(module.as_operation()).unwrap();
+    }
+
+    #[test]
+    fn run_with_ir_printing() {
+        let context = context();
+        let module = Module::parse(&context, "func.func private @f()").unwrap();
+        let pass_manager = PassManager::new(&context);
+        pass_manager.enable_ir_printing();
+        pass_manager.run(module.as_operation()).unwrap();
+    }
+
+    #[test]
+    fn run_on_operation() {
+        let context = context();
+        let module = Module::parse(&context, "func.func private @f()").unwrap();
+        let function = module.body().first_operation().unwrap();
+        let pass_manager = PassManager::new_on_operation(&context, "func.func");
+        pass_manager.run(function).unwrap();
+        let error = pass_manager.run(module.as_operation()).unwrap_err();
+        assert!(!error.diagnostics.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_owned_pass_manager_ref() {
+        let _pass_manager_ref = PassManagerRef {
+            _context: PhantomData,
+        };
+    }
+}
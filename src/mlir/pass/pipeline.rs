@@ -0,0 +1,194 @@
+use super::{ParsePipelineError, Pass, PassManager};
+use crate::ContextRef;
+
+use std::{
+    fmt::{Debug, Formatter},
+    sync::Arc,
+};
+
+/// The type of the pass constructors added by [PassPipeline::add_pass].
+type PassConstructor = Arc<dyn Fn() -> Pass + Send + Sync>;
+
+/// A single element of a [PassPipeline].
+#[derive(Clone)]
+enum PipelineElement {
+    /// A pass created by a constructor.
+    Pass(PassConstructor),
+    /// A textual pipeline.
+    Textual(String),
+}
+
+/// [PassPipeline] describes a pipeline of passes independently of any context, so that it can be
+/// built once and instantiated into a [PassManager] for each context that needs it.
+///
+/// Cloning a [PassPipeline] is cheap, as the elements of the pipeline are shared between clones
+/// until one of them is modified.
+///
+/// By default, the pipeline is anchored on `builtin.module` operations, is empty, and has the
+/// verifier enabled.
+#[derive(Clone)]
+pub struct PassPipeline {
+    anchor_op: Option<Arc<str>>,
+    elements: Arc<Vec<PipelineElement>>,
+    verifier_enabled: bool,
+}
+
+impl PassPipeline {
+    /// # Returns
+    /// Returns a new, empty [PassPipeline] anchored on `builtin.module` operations.
+    pub fn new() -> Self {
+        PassPipeline {
+            anchor_op: None,
+            elements: Arc::new(Vec::new()),
+            verifier_enabled: true,
+        }
+    }
+
+    /// # Arguments
+    /// * `anchor_op` - The name of the operations the pipeline runs on (e.g. `func.func`).
+    ///
+    /// # Returns
+    /// Returns a new, empty [PassPipeline] anchored on operations with the provided name.
+    pub fn new_on_operation(anchor_op: &str) -> Self {
+        PassPipeline {
+            anchor_op: Some(Arc::from(anchor_op)),
+            ..Self::new()
+        }
+    }
+
+    /// Appends a pass to the pipeline, which is created using the provided constructor each time
+    /// the pipeline is instantiated.
+    ///
+    /// # Arguments
+    /// * `constructor` - The constructor of the pass, e.g. [Pass::func_to_llvm].
+    pub fn add_pass<F>(mut self, constructor: F) -> Self
+    where
+        F: Fn() -> Pass + Send + Sync + 'static,
+    {
+        let elements = Arc::make_mut(&mut self.elements);
+        elements.push(PipelineElement::Pass(Arc::new(constructor)));
+        self
+    }
+
+    /// Appends the passes of a textual pipeline to the pipeline, which is parsed each time the
+    /// pipeline is instantiated (see [super::PassManagerRef::add_pipeline]).
+    ///
+    /// # Arguments
+    /// * `pipeline` - The textual pipeline to append, e.g. `canonicalize,func.func(cse)`.
+    pub fn add_pipeline(mut self, pipeline: &str) -> Self {
+        let elements = Arc::make_mut(&mut self.elements);
+        elements.push(PipelineElement::Textual(pipeline.to_string()));
+        self
+    }
+
+    /// Enables or disables verifying the IR after each pass of the pipeline.
+    ///
+    /// # Arguments
+    /// * `enable` - Whether the verifier should be enabled.
+    pub fn enable_verifier(mut self, enable: bool) -> Self {
+        self.verifier_enabled = enable;
+        self
+    }
+
+    /// Creates a new pass manager running the pipeline in the provided context.
+    ///
+    /// # Arguments
+    /// * `context` - The context to associate with the pass manager.
+    ///
+    /// # Returns
+    /// Returns a new [PassManager] instance, or a [ParsePipelineError] if one of the textual
+    /// pipelines could not be parsed.
+    pub fn instantiate<'c>(
+        &self,
+        context: &'c ContextRef,
+    ) -> Result<PassManager<'c>, ParsePipelineError> {
+        let pass_manager = match &self.anchor_op {
+            Some(anchor_op) => PassManager::new_on_operation(context, anchor_op),
+            None => PassManager::new(context),
+        };
+        pass_manager.enable_verifier(self.verifier_enabled);
+        for element in self.elements.iter() {
+            match element {
+                PipelineElement::Pass(constructor) => pass_manager.add_owned_pass(constructor()),
+                PipelineElement::Textual(pipeline) => pass_manager.add_pipeline(pipeline)?,
+            }
+        }
+        Ok(pass_manager)
+    }
+}
+
+impl Default for PassPipeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for PassPipeline {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let elements = self
+            .elements
+            .iter()
+            .map(|element| match element {
+                PipelineElement::Pass(_) => "<pass>",
+                PipelineElement::Textual(pipeline) => pipeline.as_str(),
+            })
+            .collect::<Vec<_>>();
+        f.debug_struct("PassPipeline")
+            .field("anchor_op", &self.anchor_op)
+            .field("elements", &elements)
+            .field("verifier_enabled", &self.verifier_enabled)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::Module, pass::register_all_passes, Context, DialectRegistry};
+
+    fn context() -> Context {
+        let dialect_registry = DialectRegistry::default();
+        dialect_registry.register_all_dialects();
+        let context = Context::new(Some(&dialect_registry), false);
+        context.load_all_available_dialects();
+        context
+    }
+
+    #[test]
+    fn instantiate() {
+        register_all_passes();
+        let pipeline = PassPipeline::new()
+            .add_pipeline("canonicalize")
+            .add_pass(Pass::func_to_llvm);
+        let shared = pipeline.clone().enable_verifier(false);
+        assert!(Arc::ptr_eq(&pipeline.elements, &shared.elements));
+
+        for _ in 0..2 {
+            let context = context();
+            let module = Module::parse(&context, "func.func private @f()").unwrap();
+            let pass_manager = pipeline.instantiate(&context).unwrap();
+            let printed_pipeline = pass_manager.to_string();
+            assert!(printed_pipeline.contains("canonicalize"));
+            assert!(printed_pipeline.contains("convert-func-to-llvm"));
+            pass_manager.run(module.as_operation()).unwrap();
+            assert!(module.to_string().contains("llvm.func"));
+        }
+    }
+
+    #[test]
+    fn add_after_clone() {
+        let pipeline = PassPipeline::new_on_operation("func.func");
+        let extended = pipeline.clone().add_pass(Pass::arith_to_llvm);
+        assert!(!Arc::ptr_eq(&pipeline.elements, &extended.elements));
+        assert!(pipeline.elements.is_empty());
+        assert_eq!(extended.elements.len(), 1);
+    }
+
+    #[test]
+    fn invalid_pipeline() {
+        let context = context();
+        let pipeline = PassPipeline::new().add_pipeline("no-such-pass");
+        let error = pipeline.instantiate(&context).unwrap_err();
+        assert_eq!(error.pipeline, "no-such-pass");
+    }
+}
@@ -0,0 +1,145 @@
+#[cfg(feature = "async-passes")]
+use mlir_sys::mlirRegisterAsyncPasses;
+#[cfg(feature = "conversion-passes")]
+use mlir_sys::mlirRegisterConversionPasses;
+#[cfg(feature = "gpu-passes")]
+use mlir_sys::mlirRegisterGPUPasses;
+#[cfg(feature = "linalg-passes")]
+use mlir_sys::mlirRegisterLinalgPasses;
+#[cfg(feature = "sparse-tensor-passes")]
+use mlir_sys::mlirRegisterSparseTensorPasses;
+#[cfg(feature = "transforms-passes")]
+use mlir_sys::mlirRegisterTransformsPasses;
+
+use mlir_sys::mlirRegisterAllPasses;
+
+use std::sync::Once;
+
+/// Registers all passes known to MLIR globally, so that textual pass pipelines (see
+/// [PassManagerRef::add_pipeline]) can refer to them by their argument. The passes are only
+/// registered the first time this function is called.
+///
+/// [PassManagerRef::add_pipeline]: super::PassManagerRef::add_pipeline
+pub fn register_all_passes() {
+    static REGISTERED: Once = Once::new();
+    REGISTERED.call_once(|| unsafe { mlirRegisterAllPasses() });
+}
+
+/// Registers the groups of passes selected by the enabled cargo features globally (all of them
+/// by default), which is cheaper than [register_all_passes] if only some groups are needed:
+/// - `async-passes`: see [register_async_passes]
+/// - `conversion-passes`: see [register_conversion_passes]
+/// - `gpu-passes`: see [register_gpu_passes]
+/// - `linalg-passes`: see [register_linalg_passes]
+/// - `sparse-tensor-passes`: see [register_sparse_tensor_passes]
+/// - `transforms-passes`: see [register_transforms_passes]
+pub fn register_passes() {
+    #[cfg(feature = "async-passes")]
+    register_async_passes();
+    #[cfg(feature = "conversion-passes")]
+    register_conversion_passes();
+    #[cfg(feature = "gpu-passes")]
+    register_gpu_passes();
+    #[cfg(feature = "linalg-passes")]
+    register_linalg_passes();
+    #[cfg(feature = "sparse-tensor-passes")]
+    register_sparse_tensor_passes();
+    #[cfg(feature = "transforms-passes")]
+    register_transforms_passes();
+}
+
+/// Registers the passes of the `async` dialect globally (e.g. `async-to-async-runtime`). The
+/// passes are only registered the first time this function is called.
+#[cfg(feature = "async-passes")]
+pub fn register_async_passes() {
+    static REGISTERED: Once = Once::new();
+    REGISTERED.call_once(|| unsafe { mlirRegisterAsyncPasses() });
+}
+
+/// Registers the dialect conversion passes globally (e.g. `convert-func-to-llvm`). The passes
+/// are only registered the first time this function is called.
+#[cfg(feature = "conversion-passes")]
+pub fn register_conversion_passes() {
+    static REGISTERED: Once = Once::new();
+    REGISTERED.call_once(|| unsafe { mlirRegisterConversionPasses() });
+}
+
+/// Registers the passes of the `gpu` dialect globally (e.g. `gpu-kernel-outlining`). The passes
+/// are only registered the first time this function is called.
+#[cfg(feature = "gpu-passes")]
+pub fn register_gpu_passes() {
+    static REGISTERED: Once = Once::new();
+    REGISTERED.call_once(|| unsafe { mlirRegisterGPUPasses() });
+}
+
+/// Registers the passes of the `linalg` dialect globally (e.g. `convert-linalg-to-loops`). The
+/// passes are only registered the first time this function is called.
+#[cfg(feature = "linalg-passes")]
+pub fn register_linalg_passes() {
+    static REGISTERED: Once = Once::new();
+    REGISTERED.call_once(|| unsafe { mlirRegisterLinalgPasses() });
+}
+
+/// Registers the passes of the `sparse_tensor` dialect globally (e.g. `sparsification`). The
+/// passes are only registered the first time this function is called.
+#[cfg(feature = "sparse-tensor-passes")]
+pub fn register_sparse_tensor_passes() {
+    static REGISTERED: Once = Once::new();
+    REGISTERED.call_once(|| unsafe { mlirRegisterSparseTensorPasses() });
+}
+
+/// Registers the dialect-independent transformation passes globally (e.g. `canonicalize` and
+/// `cse`). The passes are only registered the first time this function is called.
+#[cfg(feature = "transforms-passes")]
+pub fn register_transforms_passes() {
+    static REGISTERED: Once = Once::new();
+    REGISTERED.call_once(|| unsafe { mlirRegisterTransformsPasses() });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{pass::PassManager, Context};
+
+    #[test]
+    fn all_passes() {
+        register_all_passes();
+        // Registering the passes again is a no-op.
+        register_all_passes();
+        let context = Context::new(None, false);
+        let pass_manager = PassManager::new(&context);
+        pass_manager
+            .add_pipeline("canonicalize,convert-func-to-llvm")
+            .unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "transforms-passes")]
+    fn transforms_passes() {
+        register_transforms_passes();
+        let context = Context::new(None, false);
+        let pass_manager = PassManager::new(&context);
+        pass_manager.add_pipeline("cse").unwrap();
+    }
+
+    #[test]
+    #[cfg(all(feature = "conversion-passes", feature = "transforms-passes"))]
+    fn enabled_passes() {
+        register_passes();
+        register_passes();
+        let context = Context::new(None, false);
+        let pass_manager = PassManager::new(&context);
+        pass_manager
+            .add_pipeline("canonicalize,convert-func-to-llvm")
+            .unwrap();
+    }
+
+    #[test]
+    fn unregistered_pass() {
+        let context = Context::new(None, false);
+        let pass_manager = PassManager::new(&context);
+        let error = pass_manager.add_pipeline("no-such-pass").unwrap_err();
+        assert_eq!(error.pipeline, "no-such-pass");
+        assert!(!error.message.is_empty());
+    }
+}
@@ -0,0 +1,183 @@
+use super::{PassDiagnostic, PassManagerRef};
+use crate::UnownedMlirValue;
+
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+    process,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex, OnceLock,
+    },
+};
+
+/// The reproducer directories of every pass manager with crash reproducers enabled, keyed by the
+/// address of the pass manager.
+fn reproducer_directories() -> &'static Mutex<HashMap<usize, PathBuf>> {
+    static DIRECTORIES: OnceLock<Mutex<HashMap<usize, PathBuf>>> = OnceLock::new();
+    DIRECTORIES.get_or_init(Default::default)
+}
+
+/// Crash reproducers mimic the `--mlir-pass-pipeline-crash-reproducer` option of `mlir-opt`: when
+/// a pipeline fails, the IR it was run on, the pipeline itself, and the diagnostics emitted while
+/// running it are written to a new directory, so that the failure can be reproduced outside of the
+/// program that hit it.
+///
+/// Each reproducer is written to a new `reproducer-<pid>-<n>` directory holding:
+/// - `input.mlir`, the IR the pipeline was run on, which also records the pipeline so that it can
+///   be rerun using `mlir-opt --run-reproducer input.mlir`;
+/// - `pipeline.txt`, the textual pipeline;
+/// - `diagnostics.txt`, the diagnostics emitted while running the pipeline.
+impl<'c> PassManagerRef<'c> {
+    /// Enables writing a crash reproducer whenever the pipeline of the pass manager fails to run.
+    /// Crash reproducers are disabled by default, since the IR has to be printed before every run
+    /// of the pipeline.
+    ///
+    /// # Arguments
+    /// * `directory` - The directory to write the reproducers to, which is created if needed.
+    pub fn enable_crash_reproducer(&self, directory: impl AsRef<Path>) {
+        let mut directories = reproducer_directories().lock().unwrap();
+        directories.insert(self.to_raw().ptr as usize, directory.as_ref().to_path_buf());
+    }
+
+    /// Disables writing crash reproducers when the pipeline of the pass manager fails to run.
+    pub fn disable_crash_reproducer(&self) {
+        let mut directories = reproducer_directories().lock().unwrap();
+        directories.remove(&(self.to_raw().ptr as usize));
+    }
+
+    /// # Returns
+    /// Returns the directory crash reproducers of the pass manager are written to, if they are
+    /// enabled.
+    pub(crate) fn crash_reproducer_directory(&self) -> Option<PathBuf> {
+        let directories = reproducer_directories().lock().unwrap();
+        directories.get(&(self.to_raw().ptr as usize)).cloned()
+    }
+}
+
+/// Writes a crash reproducer to a new directory within the provided directory.
+///
+/// # Arguments
+/// * `directory` - The directory to create the reproducer directory in.
+/// * `input` - The IR the pipeline was run on.
+/// * `pipeline` - The textual pipeline that failed.
+/// * `diagnostics` - The diagnostics emitted while running the pipeline.
+///
+/// # Returns
+/// Returns the path of the reproducer directory, or the error which prevented writing it.
+pub(crate) fn write_crash_reproducer(
+    directory: &Path,
+    input: &str,
+    pipeline: &str,
+    diagnostics: &[PassDiagnostic],
+) -> io::Result<PathBuf> {
+    static NEXT_REPRODUCER: AtomicUsize = AtomicUsize::new(0);
+
+    fs::create_dir_all(directory)?;
+    let reproducer_directory = loop {
+        let idx = NEXT_REPRODUCER.fetch_add(1, Ordering::Relaxed);
+        let path = directory.join(format!("reproducer-{}-{}", process::id(), idx));
+        match fs::create_dir(&path) {
+            Ok(()) => break path,
+            Err(error) if error.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(error) => return Err(error),
+        }
+    };
+
+    let escaped_pipeline = pipeline.replace('\\', "\\\\").replace('"', "\\\"");
+    let input = format!(
+        concat!(
+            "{}\n",
+            "{{-#\n",
+            "  external_resources: {{\n",
+            "    mlir_reproducer: {{\n",
+            "      pipeline: \"{}\"\n",
+            "    }}\n",
+            "  }}\n",
+            "#-}}\n",
+        ),
+        input.trim_end(),
+        escaped_pipeline
+    );
+    fs::write(reproducer_directory.join("input.mlir"), input)?;
+    fs::write(reproducer_directory.join("pipeline.txt"), pipeline)?;
+    let diagnostics = diagnostics
+        .iter()
+        .map(|diagnostic| format!("{}\n", diagnostic))
+        .collect::<String>();
+    fs::write(reproducer_directory.join("diagnostics.txt"), diagnostics)?;
+    Ok(reproducer_directory)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ir::{Module, OperationRef},
+        pass::{attribution::MARKER_ARGUMENT, ExternalPassHandle, Pass, PassManager, RustPass},
+        Context, UnownedMlirValue,
+    };
+
+    use std::{ffi::CString, fs};
+
+    use mlir_sys::mlirEmitError;
+
+    #[derive(Clone)]
+    struct Fail;
+
+    impl RustPass for Fail {
+        fn run(&mut self, operation: &OperationRef, pass: &ExternalPassHandle) {
+            let message = CString::new("failing on purpose").unwrap();
+            unsafe { mlirEmitError(operation.location().to_raw(), message.as_ptr()) };
+            pass.signal_failure();
+        }
+    }
+
+    #[test]
+    fn crash_reproducer() {
+        let directory = std::env::temp_dir().join("mlir-rs-crash-reproducer");
+        let _ = fs::remove_dir_all(&directory);
+        let context = Context::new(None, false);
+        let module = Module::parse(&context, "").unwrap();
+        let pass_manager = PassManager::new(&context);
+        pass_manager.add_owned_pass(Pass::new_external(Fail, "Fail", "fail", "", None, &[]));
+
+        pass_manager.enable_crash_reproducer(&directory);
+        let error = pass_manager.run(module.as_operation()).unwrap_err();
+        let reproducer = error.reproducer.unwrap();
+        assert!(reproducer.starts_with(&directory));
+        let input = fs::read_to_string(reproducer.join("input.mlir")).unwrap();
+        assert!(input.starts_with("\"builtin.module\"()"));
+        assert!(input.contains("mlir_reproducer"));
+        let pipeline = fs::read_to_string(reproducer.join("pipeline.txt")).unwrap();
+        assert_eq!(pipeline, pass_manager.to_string());
+        let diagnostics = fs::read_to_string(reproducer.join("diagnostics.txt")).unwrap();
+        assert!(diagnostics.contains("failing on purpose"));
+
+        pass_manager.disable_crash_reproducer();
+        let error = pass_manager.run(module.as_operation()).unwrap_err();
+        assert!(error.reproducer.is_none());
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn crash_reproducer_with_pass_attribution() {
+        let directory = std::env::temp_dir().join("mlir-rs-crash-reproducer-attribution");
+        let _ = fs::remove_dir_all(&directory);
+        let context = Context::new(None, false);
+        let module = Module::parse(&context, "").unwrap();
+        let pass_manager = PassManager::new(&context);
+        pass_manager.enable_pass_attribution();
+        pass_manager.add_owned_pass(Pass::new_external(Fail, "Fail", "fail", "", None, &[]));
+
+        pass_manager.enable_crash_reproducer(&directory);
+        let error = pass_manager.run(module.as_operation()).unwrap_err();
+        let reproducer = error.reproducer.unwrap();
+        let pipeline = fs::read_to_string(reproducer.join("pipeline.txt")).unwrap();
+        assert_eq!(pipeline, "builtin.module(fail)");
+        let input = fs::read_to_string(reproducer.join("input.mlir")).unwrap();
+        assert!(input.contains("pipeline: \"builtin.module(fail)\""));
+        assert!(!input.contains(MARKER_ARGUMENT));
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,153 @@
+mod driver;
+mod rewriter;
+
+pub use self::{driver::*, rewriter::*};
+use crate::ir::OperationRef;
+
+/// [RewritePattern] is a local rewrite of the IR written in Rust, which is applied by
+/// [apply_patterns_greedily] to every operation it matches until no pattern matches anymore.
+///
+/// All modifications of the IR made by [RewritePattern::rewrite] must go through the provided
+/// [IRRewriter], so that the driver can keep track of erased operations.
+pub trait RewritePattern {
+    /// # Returns
+    /// Returns the fully-qualified name of the operations the pattern can match (e.g.
+    /// `arith.addi`), or `None` if it can match any operation. Defaults to `None`.
+    fn root_name(&self) -> Option<&str> {
+        None
+    }
+
+    /// # Returns
+    /// Returns the benefit of the pattern; patterns with a higher benefit are tried first.
+    /// Defaults to 1.
+    fn benefit(&self) -> u16 {
+        1
+    }
+
+    /// # Arguments
+    /// * `operation` - The operation to match, whose name is the root name of the pattern if it
+    ///   has one.
+    ///
+    /// # Returns
+    /// Returns whether the pattern applies to the operation, in which case
+    /// [RewritePattern::rewrite] is invoked on it.
+    fn matches(&self, operation: &OperationRef) -> bool;
+
+    /// Rewrites an operation the pattern matched. The rewrite must modify the IR, otherwise the
+    /// pattern keeps matching and the driver does not converge.
+    ///
+    /// # Arguments
+    /// * `operation` - The matched operation.
+    /// * `rewriter` - The rewriter to modify the IR with.
+    fn rewrite<'c>(&self, operation: &OperationRef<'c>, rewriter: &mut IRRewriter<'c>);
+}
+
+/// [RewritePatternSet] holds the patterns applied by [apply_patterns_greedily], as well as the
+/// maximum number of times the driver sweeps over the IR before giving up.
+///
+/// By default, the set is empty and the driver sweeps over the IR at most 10 times, like the
+/// greedy pattern rewrite driver of MLIR.
+pub struct RewritePatternSet {
+    patterns: Vec<Box<dyn RewritePattern>>,
+    max_iterations: usize,
+}
+
+impl RewritePatternSet {
+    /// # Returns
+    /// Returns a new, empty [RewritePatternSet].
+    pub fn new() -> Self {
+        RewritePatternSet {
+            patterns: Vec::new(),
+            max_iterations: 10,
+        }
+    }
+
+    /// Adds a pattern to the set.
+    ///
+    /// # Arguments
+    /// * `pattern` - The pattern to add.
+    pub fn add_pattern<P>(mut self, pattern: P) -> Self
+    where
+        P: RewritePattern + 'static,
+    {
+        self.patterns.push(Box::new(pattern));
+        // Stable, so that patterns with the same benefit are tried in the order they were added.
+        self.patterns
+            .sort_by_key(|pattern| std::cmp::Reverse(pattern.benefit()));
+        self
+    }
+
+    /// Sets the maximum number of times the driver sweeps over the IR before giving up.
+    ///
+    /// # Arguments
+    /// * `max_iterations` - The maximum number of sweeps, which must be at least 1.
+    pub fn max_iterations(mut self, max_iterations: usize) -> Self {
+        if max_iterations == 0 {
+            panic!("The maximum number of iterations must be at least 1");
+        }
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// # Returns
+    /// Returns the number of patterns in the set.
+    pub fn len(&self) -> usize {
+        self.patterns.len()
+    }
+
+    /// # Returns
+    /// Returns whether the set holds no patterns.
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+}
+
+impl Default for RewritePatternSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Named(&'static str, u16);
+
+    impl RewritePattern for Named {
+        fn root_name(&self) -> Option<&str> {
+            Some(self.0)
+        }
+
+        fn benefit(&self) -> u16 {
+            self.1
+        }
+
+        fn matches(&self, _operation: &OperationRef) -> bool {
+            false
+        }
+
+        fn rewrite<'c>(&self, _operation: &OperationRef<'c>, _rewriter: &mut IRRewriter<'c>) {}
+    }
+
+    #[test]
+    fn order_by_benefit() {
+        let patterns = RewritePatternSet::new()
+            .add_pattern(Named("a", 1))
+            .add_pattern(Named("b", 2))
+            .add_pattern(Named("c", 1));
+        assert_eq!(patterns.len(), 3);
+        let names = patterns
+            .patterns
+            .iter()
+            .map(|pattern| pattern.root_name().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["b", "a", "c"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_iterations() {
+        let _ = RewritePatternSet::new().max_iterations(0);
+    }
+}
@@ -0,0 +1,188 @@
+use super::{IRRewriter, RewritePatternSet};
+use crate::ir::{OperationRef, WalkOrder};
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+/// Applies the provided patterns to the operations nested within an operation until none of them
+/// matches anymore, mirroring the greedy pattern rewrite driver of MLIR.
+///
+/// Each sweep visits the nested operations in post-order, and applies the first pattern (by
+/// decreasing benefit) matching each of them. Operations created during a sweep are only visited
+/// by the next one. The operation itself is never rewritten.
+///
+/// # Arguments
+/// * `operation` - The operation whose nested operations are rewritten.
+/// * `patterns` - The patterns to apply.
+///
+/// # Returns
+/// Returns whether the IR was modified, or a [ConvergenceError] if the patterns still matched
+/// after the maximum number of sweeps (see [RewritePatternSet::max_iterations]).
+pub fn apply_patterns_greedily<'c>(
+    operation: &OperationRef<'c>,
+    patterns: &RewritePatternSet,
+) -> Result<bool, ConvergenceError> {
+    let mut rewriter = IRRewriter::new();
+    let mut changed = false;
+    for _ in 0..patterns.max_iterations {
+        rewriter.clear_erased();
+        let mut operations = Vec::new();
+        operation.walk(WalkOrder::PostOrder, |nested| operations.push(nested));
+        // The operation itself is visited last.
+        operations.pop();
+
+        let mut sweep_changed = false;
+        for nested in operations {
+            // Erased operations are destroyed, so they are only compared against the erased set
+            // and never dereferenced.
+            if rewriter.is_erased(nested) {
+                continue;
+            }
+            let name = nested.name().value();
+            let pattern = patterns.patterns.iter().find(|pattern| {
+                pattern
+                    .root_name()
+                    .is_none_or(|root_name| name == root_name)
+                    && pattern.matches(nested)
+            });
+            if let Some(pattern) = pattern {
+                pattern.rewrite(nested, &mut rewriter);
+                sweep_changed |= rewriter.take_changed();
+            }
+        }
+        if !sweep_changed {
+            return Ok(changed);
+        }
+        changed = true;
+    }
+    Err(ConvergenceError {
+        max_iterations: patterns.max_iterations,
+    })
+}
+
+/// [ConvergenceError] is returned by [apply_patterns_greedily] when the patterns still matched
+/// after the maximum number of sweeps over the IR.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConvergenceError {
+    /// The maximum number of sweeps which were made.
+    pub max_iterations: usize,
+}
+
+impl Display for ConvergenceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "patterns did not converge after {} iterations",
+            self.max_iterations
+        )
+    }
+}
+
+impl Error for ConvergenceError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{LocationRef, Module, OperationBuilder},
+        rewrite::RewritePattern,
+        Context,
+    };
+
+    /// Replaces `test.old` operations with `test.new` operations.
+    struct OldToNew;
+
+    impl RewritePattern for OldToNew {
+        fn root_name(&self) -> Option<&str> {
+            Some("test.old")
+        }
+
+        fn matches(&self, _operation: &OperationRef) -> bool {
+            true
+        }
+
+        fn rewrite<'c>(&self, operation: &OperationRef<'c>, rewriter: &mut IRRewriter<'c>) {
+            let new =
+                OperationBuilder::new("test.new", LocationRef::new_unknown(operation.context()))
+                    .add_results(&operation.result_types().collect::<Vec<_>>())
+                    .build()
+                    .unwrap();
+            rewriter.replace_op_with(operation, new);
+        }
+    }
+
+    /// Erases operations whose results are all unused.
+    struct EraseDead;
+
+    impl RewritePattern for EraseDead {
+        fn matches(&self, operation: &OperationRef) -> bool {
+            operation.num_results() > 0
+                && (0..operation.num_results())
+                    .all(|idx| operation.result(idx).first_use().is_none())
+        }
+
+        fn rewrite<'c>(&self, operation: &OperationRef<'c>, rewriter: &mut IRRewriter<'c>) {
+            rewriter.erase_op(operation);
+        }
+    }
+
+    /// Matches every operation and pretends to modify it.
+    struct Touch;
+
+    impl RewritePattern for Touch {
+        fn matches(&self, _operation: &OperationRef) -> bool {
+            true
+        }
+
+        fn rewrite<'c>(&self, operation: &OperationRef<'c>, rewriter: &mut IRRewriter<'c>) {
+            rewriter.modify_op_in_place(operation, |_| {});
+        }
+    }
+
+    fn context() -> Context {
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        context
+    }
+
+    #[test]
+    fn rewrite_to_fixpoint() {
+        let context = context();
+        let module = Module::parse(
+            &context,
+            r#"
+%0 = "test.old"() : () -> i32
+%1 = "test.old"() : () -> i32
+%2 = "test.user"(%0) : (i32) -> i32
+"test.sink"(%1) : (i32) -> ()
+"#,
+        )
+        .unwrap();
+        let patterns = RewritePatternSet::new()
+            .add_pattern(OldToNew)
+            .add_pattern(EraseDead);
+        assert!(apply_patterns_greedily(module.as_operation(), &patterns).unwrap());
+
+        let operations = module
+            .body()
+            .operations()
+            .map(|operation| operation.name().value().as_str().to_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(operations, ["test.new", "test.sink"]);
+        assert!(module.as_operation().verify());
+        assert!(!apply_patterns_greedily(module.as_operation(), &patterns).unwrap());
+    }
+
+    #[test]
+    fn no_convergence() {
+        let context = context();
+        let module = Module::parse(&context, r#""test.op"() : () -> ()"#).unwrap();
+        let patterns = RewritePatternSet::new()
+            .add_pattern(Touch)
+            .max_iterations(3);
+        let error = apply_patterns_greedily(module.as_operation(), &patterns).unwrap_err();
+        assert_eq!(error.max_iterations, 3);
+    }
+}
@@ -0,0 +1,268 @@
+use crate::{
+    ir::{Block, BlockRef, Operation, OperationRef, RegionRef, ValueRef, WalkOrder},
+    support::binding::OwnedMlirValue,
+    UnownedMlirValue,
+};
+
+use std::{collections::HashSet, marker::PhantomData};
+
+/// [IRRewriter] mirrors the `mlir::IRRewriter` class in Rust: it modifies the IR on behalf of a
+/// [super::RewritePattern], keeping track of whether the IR changed and of the operations that
+/// were erased.
+///
+/// Erased operations are destroyed immediately, which drops the uses of their operands so that
+/// the operations producing them can in turn be seen as dead. References to erased operations
+/// must therefore no longer be dereferenced, but can still be checked with
+/// [IRRewriter::is_erased].
+#[derive(Debug, Default)]
+pub struct IRRewriter<'c> {
+    erased: HashSet<usize>,
+    changed: bool,
+    _context: PhantomData<&'c ()>,
+}
+
+impl<'c> IRRewriter<'c> {
+    /// # Returns
+    /// Returns a new [IRRewriter] instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts the given operation before the reference operation.
+    ///
+    /// # Arguments
+    /// * `reference` - The operation to insert before, which must be in a block.
+    /// * `operation` - The operation to insert.
+    ///
+    /// # Returns
+    /// Returns a reference to the inserted operation.
+    pub fn insert_before<'a>(
+        &mut self,
+        reference: &'a OperationRef<'c>,
+        operation: Operation<'c>,
+    ) -> &'a OperationRef<'c> {
+        let block = reference
+            .parent_block()
+            .expect("Reference operation is not in a block");
+        self.changed = true;
+        block.insert_operation_before(reference, operation)
+    }
+
+    /// Inserts the given operation after the reference operation.
+    ///
+    /// # Arguments
+    /// * `reference` - The operation to insert after, which must be in a block.
+    /// * `operation` - The operation to insert.
+    ///
+    /// # Returns
+    /// Returns a reference to the inserted operation.
+    pub fn insert_after<'a>(
+        &mut self,
+        reference: &'a OperationRef<'c>,
+        operation: Operation<'c>,
+    ) -> &'a OperationRef<'c> {
+        let block = reference
+            .parent_block()
+            .expect("Reference operation is not in a block");
+        self.changed = true;
+        block.insert_operation_after(reference, operation)
+    }
+
+    /// Replaces all uses of the results of an operation with the provided values, and erases the
+    /// operation.
+    ///
+    /// # Arguments
+    /// * `operation` - The operation to replace.
+    /// * `values` - The values to replace the results with, one per result.
+    pub fn replace_op(&mut self, operation: &OperationRef<'c>, values: &[&ValueRef<'c>]) {
+        if values.len() as isize != operation.num_results() {
+            panic!(
+                "Expected {} replacement values, got {}",
+                operation.num_results(),
+                values.len()
+            );
+        }
+        for (idx, value) in values.iter().enumerate() {
+            operation.result(idx as isize).replace_all_uses_with(value);
+        }
+        self.erase_op(operation);
+    }
+
+    /// Inserts the given operation before an operation, replaces all uses of the results of the
+    /// operation with the results of the new one, and erases the operation.
+    ///
+    /// # Arguments
+    /// * `operation` - The operation to replace.
+    /// * `new_operation` - The operation to replace it with, which must have as many results.
+    ///
+    /// # Returns
+    /// Returns a reference to the inserted operation.
+    pub fn replace_op_with<'a>(
+        &mut self,
+        operation: &'a OperationRef<'c>,
+        new_operation: Operation<'c>,
+    ) -> &'a OperationRef<'c> {
+        let new_operation = self.insert_before(operation, new_operation);
+        let values = (0..new_operation.num_results())
+            .map(|idx| new_operation.result(idx))
+            .collect::<Vec<_>>();
+        self.replace_op(operation, &values);
+        new_operation
+    }
+
+    /// Erases and destroys an operation, along with the operations nested within it.
+    ///
+    /// # Arguments
+    /// * `operation` - The operation to erase, which must be in a block and whose results must no
+    ///   longer be used. It must not be dereferenced after this call.
+    pub fn erase_op(&mut self, operation: &OperationRef<'c>) {
+        if operation.parent_block().is_none() {
+            panic!("Cannot erase an operation which is not in a block");
+        }
+        if (0..operation.num_results()).any(|idx| operation.result(idx).first_use().is_some()) {
+            panic!("Cannot erase an operation whose results are still used");
+        }
+        operation.walk(WalkOrder::PreOrder, |nested| {
+            self.erased.insert(nested.to_raw().ptr as usize);
+        });
+        operation.remove_from_parent();
+        // The operation is no longer owned by a block, so it is owned here and destroyed.
+        drop(unsafe { Operation::from_raw(operation.to_raw()) });
+        self.changed = true;
+    }
+
+    /// Modifies an operation in place, e.g. to change its attributes or operands.
+    ///
+    /// # Arguments
+    /// * `operation` - The operation to modify.
+    /// * `modify` - The function modifying the operation.
+    pub fn modify_op_in_place<F>(&mut self, operation: &OperationRef<'c>, modify: F)
+    where
+        F: FnOnce(&OperationRef<'c>),
+    {
+        modify(operation);
+        self.changed = true;
+    }
+
+    /// Moves all operations of a block before an operation (see [BlockRef::inline_before]).
+    ///
+    /// # Arguments
+    /// * `block` - The block to inline.
+    /// * `target` - The operation to move the operations before.
+    /// * `arguments` - The values to replace the arguments of the block with, one per argument.
+    pub fn inline_block_before(
+        &mut self,
+        block: &BlockRef<'c>,
+        target: &OperationRef<'c>,
+        arguments: &[&ValueRef<'c>],
+    ) {
+        block.inline_before(target, arguments);
+        self.changed = true;
+    }
+
+    /// Moves all operations of the single block of a region before an operation (see
+    /// [RegionRef::inline_before]).
+    ///
+    /// # Arguments
+    /// * `region` - The region to inline.
+    /// * `target` - The operation to move the operations before.
+    /// * `arguments` - The values to replace the arguments of the block with, one per argument.
+    pub fn inline_region_before(
+        &mut self,
+        region: &RegionRef<'c>,
+        target: &OperationRef<'c>,
+        arguments: &[&ValueRef<'c>],
+    ) {
+        region.inline_before(target, arguments);
+        self.changed = true;
+    }
+
+    /// Moves all operations of a block to the end of another one, and destroys the former (see
+    /// [BlockRef::merge_block]).
+    ///
+    /// # Arguments
+    /// * `source` - The (detached) block to merge into the destination block.
+    /// * `dest` - The block to merge the source block into.
+    /// * `arguments` - The values to replace the arguments of the source block with.
+    pub fn merge_blocks(
+        &mut self,
+        source: Block<'c>,
+        dest: &BlockRef<'c>,
+        arguments: &[&ValueRef<'c>],
+    ) {
+        dest.merge_block(source, arguments);
+        self.changed = true;
+    }
+
+    /// # Returns
+    /// Returns whether the provided operation was erased by this rewriter since the last sweep of
+    /// the driver, either directly or as part of an operation it was nested within.
+    pub fn is_erased(&self, operation: &OperationRef<'c>) -> bool {
+        self.erased.contains(&(operation.to_raw().ptr as usize))
+    }
+
+    /// # Returns
+    /// Returns whether the rewriter modified the IR since the last call, and resets the flag.
+    pub(crate) fn take_changed(&mut self) -> bool {
+        std::mem::take(&mut self.changed)
+    }
+
+    /// Forgets the erased operations, whose addresses may be reused by operations created later.
+    /// Called by the driver before each sweep, once no reference to an erased operation is held.
+    pub(crate) fn clear_erased(&mut self) {
+        self.erased.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{Module, OperationBuilder},
+        Context,
+    };
+
+    fn context() -> Context {
+        let context = Context::new(None, false);
+        context.set_allow_unregistered_dialects(true);
+        context
+    }
+
+    #[test]
+    fn replace_op_with() {
+        let context = context();
+        let module = Module::parse(
+            &context,
+            r#"
+%0 = "test.old"() : () -> i32
+"test.use"(%0) : (i32) -> ()
+"#,
+        )
+        .unwrap();
+        let old = module.body().first_operation().unwrap();
+        let new = OperationBuilder::from_operation(old).build().unwrap();
+        let mut rewriter = IRRewriter::new();
+        rewriter.replace_op_with(old, new);
+        assert!(rewriter.is_erased(old));
+        assert!(rewriter.take_changed());
+        assert!(!rewriter.take_changed());
+        assert_eq!(module.body().operations().count(), 2);
+        assert!(module.as_operation().verify());
+    }
+
+    #[test]
+    #[should_panic]
+    fn erase_used_op() {
+        let context = context();
+        let module = Module::parse(
+            &context,
+            r#"
+%0 = "test.old"() : () -> i32
+"test.use"(%0) : (i32) -> ()
+"#,
+        )
+        .unwrap();
+        let mut rewriter = IRRewriter::new();
+        rewriter.erase_op(module.body().first_operation().unwrap());
+    }
+}
@@ -56,6 +56,24 @@ impl<'a> StringRef<'a> {
         }
     }
 
+    /// Constructs a [StringRef] referencing the provided bytes, which are not required to be valid
+    /// UTF-8 (e.g. a binary blob).
+    ///
+    /// # Arguments
+    /// * `bytes` - The backing data for the string.
+    ///
+    /// # Returns
+    /// Returns a new [StringRef] instance.
+    pub fn from_bytes(bytes: &'a [u8]) -> StringRef<'a> {
+        Self {
+            raw: MlirStringRef {
+                data: bytes.as_ptr() as *const c_char,
+                length: bytes.len(),
+            },
+            _string_owner: PhantomData,
+        }
+    }
+
     /// # Returns
     /// Returns the [StringRef] as a `&str`.
     pub fn as_str(&self) -> &'a str {
@@ -72,6 +90,16 @@ impl<'a> StringRef<'a> {
 
         str::from_utf8(string_bytes).expect("MLIR StringRef was not valid UTF-8")
     }
+
+    /// # Returns
+    /// Returns the raw bytes referenced by the [StringRef], which are not required to be valid
+    /// UTF-8.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        if self.raw.length == 0 {
+            return &[];
+        }
+        unsafe { slice::from_raw_parts(self.raw.data as *const u8, self.raw.length) }
+    }
 }
 
 impl<'a, T> From<&'a T> for StringRef<'a>
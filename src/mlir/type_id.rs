@@ -0,0 +1,54 @@
+use crate::support::binding::{impl_owned_mlir_value, OwnedMlirValue};
+
+use std::hash::{Hash, Hasher};
+
+use mlir_sys::{mlirTypeIDEqual, mlirTypeIDHashValue, MlirTypeID};
+
+/// [TypeId] wraps the raw `MlirTypeID` type from the MLIR C API, which uniquely identifies a C++
+/// class (such as a type or attribute class) within MLIR.
+///
+/// The following bindings into the MLIR C API are used/supported:
+/// - `mlirTypeIDEqual`
+/// - `mlirTypeIDHashValue`
+///
+/// The following bindings are not used/supported:
+/// - `mlirTypeIDCreate`
+/// - `mlirTypeIDAllocatorAllocateTypeID`
+/// - `mlirTypeIDAllocatorCreate`
+/// - `mlirTypeIDAllocatorDestroy`
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy)]
+pub struct TypeId {
+    raw: MlirTypeID,
+}
+
+impl_owned_mlir_value!(no_refs, TypeId, MlirTypeID);
+
+impl PartialEq for TypeId {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { mlirTypeIDEqual(self.to_raw(), other.to_raw()) }
+    }
+}
+
+impl Eq for TypeId {}
+
+impl Hash for TypeId {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        unsafe { mlirTypeIDHashValue(self.to_raw()) }.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ir::TypeRef, Context};
+
+    #[test]
+    fn compare_type_ids() {
+        let context = Context::new(None, false);
+        let i8_type = TypeRef::parse(&context, "i8").unwrap();
+        let i32_type = TypeRef::parse(&context, "i32").unwrap();
+        let f32_type = TypeRef::parse(&context, "f32").unwrap();
+        assert_eq!(i8_type.type_id(), i32_type.type_id());
+        assert_ne!(i8_type.type_id(), f32_type.type_id());
+    }
+}
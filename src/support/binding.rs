@@ -91,6 +91,30 @@ where
     fn to_raw(&self) -> Self::Binding;
 }
 
+/// [OptionalMlirValue] is a trait for optional references to unowned MLIR values, which converts
+/// them into their raw form following the MLIR C API convention of representing an absent value
+/// (e.g. an absent encoding, layout or metadata attribute) with a null value.
+pub(crate) trait OptionalMlirValue {
+    /// The C API type of the referenced value.
+    type Binding;
+
+    /// # Returns
+    /// Returns the raw MLIR value of the reference, or a null raw value if there is none.
+    fn to_raw_or_null(&self) -> Self::Binding;
+}
+
+impl<T: UnownedMlirValue> OptionalMlirValue for Option<&T> {
+    type Binding = T::Binding;
+
+    fn to_raw_or_null(&self) -> Self::Binding {
+        match self {
+            Some(value) => value.to_raw(),
+            // Every binding is a struct holding a single pointer, which is null when zeroed.
+            None => unsafe { std::mem::zeroed() },
+        }
+    }
+}
+
 macro_rules! impl_owned_mlir_value {
     (no_refs, $owning_type:ident, $binding:ty) => {
         impl $crate::support::binding::OwnedMlirValue for $owning_type {